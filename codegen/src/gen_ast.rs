@@ -1,6 +1,8 @@
 use std::collections::{HashMap, HashSet};
 
-use bord_sqlite3_parser::ungram::{rule_to_str, Rule, UNGRAMMAR};
+use bord_sqlite3_parser::ungram::{
+    builder_fields, remove_opt, rule_to_str, BuilderFieldKind, Rule, UNGRAMMAR,
+};
 use convert_case::{Case, Casing};
 use itertools::Itertools;
 use quote::{format_ident, quote};
@@ -84,8 +86,16 @@ fn main() {
 
     generator.fix_enum_lifetimebounds();
 
+    // Usage: `gen_ast > generated.rs`, `gen_ast visitor > visitor.rs` and
+    // `gen_ast builder > builder.rs`
+    let mode = std::env::args().nth(1);
+
     // let skip_set = to_write_manually.union(&bin_op_structs).copied().collect();
-    let code = write_rust_code(&generator, to_write_manually);
+    let code = match mode.as_deref() {
+        Some("visitor") => write_visitor_code(&generator, to_write_manually),
+        Some("builder") => write_builder_code(&generator),
+        _ => write_rust_code(&generator, to_write_manually),
+    };
 
     let code_str = prettyplease::unparse(&syn::parse2(code).unwrap());
 
@@ -346,7 +356,7 @@ fn write_rust_code(generator: &AstGenerator, skip_set: HashSet<&str>) -> proc_ma
                 },
                 RustEnumFieldKind::Token => {
                     quote! {
-                        CstNodeDataKind::Token(SqliteToken { kind: TokenKind::#variant_name, .. }) => Some(Self::#variant_name(node))
+                        CstNodeDataKind::Token(SqliteToken { kind: TokenKind::#variant_name, .. }) => Some(Self::#variant_name(#node_ident_name))
                     }
                 },
             }
@@ -543,24 +553,13 @@ fn write_rust_code(generator: &AstGenerator, skip_set: HashSet<&str>) -> proc_ma
                             }
                         }
                     } else if generator.enums.contains_key(&method.return_ty) {
-                        let is_node = generator
-                            .enums
-                            .get(&method.return_ty)
-                            .unwrap()
-                            .is_non_terminal_node;
-
-                        let cast_code = if is_node {
-                            quote! {#return_ty::cast}
-                        } else {
-                            quote! { #return_ty::cast }
-                        };
-
+                        // Only enums made from grammar nodes can be repeated and they have a
+                        // tree of their own, which is what `cast` expects
                         quote! {
                             pub fn #method_name(&self) -> impl Iterator<Item = #return_ty<N>> + use<'_, 'a, N> {
                                 self.inner
                                     .find_children(SqliteTreeKind::#return_ty)
-                                    .flat_map(|it| it.children())
-                                    .flat_map(#cast_code)
+                                    .flat_map(#return_ty::cast)
                             }
                         }
                     } else {
@@ -669,12 +668,397 @@ fn write_rust_code(generator: &AstGenerator, skip_set: HashSet<&str>) -> proc_ma
     }
 }
 
-fn remove_opt(mut rule: &Rule) -> &Rule {
-    while let Rule::Opt(inner) = rule {
-        rule = inner
+/// Generates a `Visitor` trait with a `visit_*` method for every typed node and a `walk_*`
+/// function that visits the children of the node. Items are sorted so that the output is stable
+/// across runs.
+fn write_visitor_code(
+    generator: &AstGenerator,
+    skip_set: HashSet<&str>,
+) -> proc_macro2::TokenStream {
+    let visit_ident = |name: &str| format_ident!("visit_{}", name.to_case(Case::Snake));
+    let walk_ident = |name: &str| format_ident!("walk_{}", name.to_case(Case::Snake));
+
+    let mut type_names: Vec<&str> = generator
+        .structs
+        .keys()
+        .chain(generator.enums.keys())
+        .map(|it| it.as_str())
+        .chain(skip_set.iter().copied())
+        .collect();
+    type_names.sort();
+
+    let visit_methods = type_names.iter().map(|name| {
+        let ty = format_ident!("{name}");
+        let visit_fn = visit_ident(name);
+        let walk_fn = walk_ident(name);
+
+        quote! {
+            fn #visit_fn(&mut self, node: &#ty<N>) {
+                #walk_fn(self, node)
+            }
+        }
+    });
+
+    let walk_fns = type_names.iter().map(|name| {
+        let ty = format_ident!("{name}");
+        let walk_fn = walk_ident(name);
+
+        let body = if let Some(struct_) = generator.structs.get(*name) {
+            let statements = struct_.methods.iter().map(|method| {
+                let method_name = format_ident!("{}", method.name);
+
+                match method.search_kind {
+                    SearchKind::Node | SearchKind::Alt => {
+                        let visit_fn = visit_ident(&method.return_ty);
+                        quote! {
+                            if let Some(it) = node.#method_name() {
+                                v.#visit_fn(&it);
+                            }
+                        }
+                    }
+                    SearchKind::Token | SearchKind::TokenSet => quote! {
+                        if let Some(it) = node.#method_name() {
+                            v.visit_token(it);
+                        }
+                    },
+                    SearchKind::Rep => {
+                        let visit_fn = visit_ident(&method.return_ty);
+                        quote! {
+                            for it in node.#method_name() {
+                                v.#visit_fn(&it);
+                            }
+                        }
+                    }
+                }
+            });
+
+            quote! { #(#statements)* }
+        } else if let Some(enum_) = generator.enums.get(*name) {
+            let arms = enum_.fields.iter().map(|field| {
+                let variant_name = format_ident!(
+                    "{}",
+                    convert_symbol_tokens(&field.name).unwrap_or(&field.name)
+                );
+
+                match field.kind {
+                    RustEnumFieldKind::Node => {
+                        let visit_fn = visit_ident(&field.name);
+                        quote! { #ty::#variant_name(it) => v.#visit_fn(it) }
+                    }
+                    RustEnumFieldKind::Token => {
+                        quote! { #ty::#variant_name(it) => v.visit_token(*it) }
+                    }
+                }
+            });
+
+            quote! {
+                match node {
+                    #(#arms,)*
+                }
+            }
+        } else {
+            // Manually written nodes have no generated accessors to rely on
+            quote! {
+                for child in node.untyped().valid_children() {
+                    visit_untyped(v, child);
+                }
+            }
+        };
+
+        quote! {
+            pub fn #walk_fn<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+                v: &mut V,
+                node: &#ty<N>,
+            ) {
+                #body
+            }
+        }
+    });
+
+    // Only nodes of the grammar have a tree kind of their own (unlike enums created from labels)
+    let tree_names = type_names.iter().filter(|name| {
+        generator.structs.contains_key(**name)
+            || skip_set.contains(**name)
+            || generator
+                .enums
+                .get(**name)
+                .is_some_and(|it| it.is_non_terminal_node)
+    });
+
+    let dispatch_arms = tree_names.map(|name| {
+        let ty = format_ident!("{name}");
+        let visit_fn = visit_ident(name);
+
+        quote! {
+            Some(SqliteTreeKind::#ty) => {
+                if let Some(it) = #ty::cast(node) {
+                    v.#visit_fn(&it);
+                }
+            }
+        }
+    });
+
+    quote! {
+        // Some nodes have no children to walk
+        #![allow(unused_variables)]
+
+        use super::*;
+        use crate::{CstNodeTrait, SqliteTreeKind};
+
+        /// Traverses the typed AST. Every `visit_*` method calls the matching `walk_*` function by
+        /// default, which in turn visits the children of the node. Override a method to act on a
+        /// node and call the `walk_*` function from it to keep descending.
+        pub trait Visitor<'a, N: CstNodeTrait<'a>> {
+            /// Called for tokens that are exposed by the typed AST (Ex: names and literals)
+            fn visit_token(&mut self, token: N) {}
+
+            #(#visit_methods)*
+        }
+
+        /// Visits an untyped node by casting it to its typed counterpart. Tokens are passed to
+        /// [`Visitor::visit_token`] and error nodes are descended into.
+        pub fn visit_untyped<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(v: &mut V, node: N) {
+            if node.token().is_some() {
+                v.visit_token(node);
+                return;
+            }
+
+            match node.tree() {
+                #(#dispatch_arms)*
+                _ => {
+                    for child in node.valid_children() {
+                        visit_untyped(v, child);
+                    }
+                }
+            }
+        }
+
+        #(#walk_fns)*
     }
+}
 
-    rule
+/// Generates a builder for every node of the grammar (Ex: `SelectStmt::build()`) and conversions
+/// between the built nodes (Ex: an `OpAdd` into an `Expr`). Builders are typed wrappers around
+/// `NodeBuilder` which renders the node by following its ungrammar rule.
+fn write_builder_code(generator: &AstGenerator) -> proc_macro2::TokenStream {
+    let mut node_names: Vec<&str> = UNGRAMMAR.nodes().map(|it| it.name.as_str()).collect();
+    node_names.sort();
+
+    let builders = node_names
+        .iter()
+        .filter(|name| !generator.enums.contains_key(**name))
+        .map(|name| {
+            let ty = format_ident!("{name}");
+            let builder_ty = format_ident!("{name}Builder");
+            let fields = builder_fields(name);
+
+            let setters = fields.iter().map(|field| {
+                let setter = builder_ident(&field.name);
+                let field_name = &field.name;
+
+                match &field.kind {
+                    BuilderFieldKind::Node(node_name) => {
+                        let node_ty = format_ident!("{node_name}");
+                        quote! {
+                            pub fn #setter(mut self, value: impl Into<Built<#node_ty<()>>>) -> Self {
+                                self.0.node(#field_name, value.into());
+                                self
+                            }
+                        }
+                    }
+                    BuilderFieldKind::Choice(choices) => {
+                        let enum_name = choice_enum(generator, field_name, choices);
+                        let enum_ty = format_ident!("{enum_name}");
+                        quote! {
+                            pub fn #setter(mut self, value: impl Into<Built<#enum_ty<()>>>) -> Self {
+                                self.0.node(#field_name, value.into());
+                                self
+                            }
+                        }
+                    }
+                    BuilderFieldKind::Token(_) => quote! {
+                        pub fn #setter(mut self, value: &str) -> Self {
+                            self.0.token(#field_name, value);
+                            self
+                        }
+                    },
+                    BuilderFieldKind::Flag => quote! {
+                        pub fn #setter(mut self) -> Self {
+                            self.0.flag(#field_name);
+                            self
+                        }
+                    },
+                }
+            });
+
+            // Nodes that wrap a single token (Ex: `TableName`) can be created in one go
+            let new_fn = match fields.as_slice() {
+                [field] if matches!(field.kind, BuilderFieldKind::Token(_)) => {
+                    let setter = builder_ident(&field.name);
+                    quote! {
+                        pub fn new(value: &str) -> Result<Built<#ty<()>>, BuildError> {
+                            Self::build().#setter(value).finish()
+                        }
+                    }
+                }
+                _ => quote! {},
+            };
+
+            quote! {
+                pub struct #builder_ty(NodeBuilder);
+
+                impl #ty<()> {
+                    pub fn build() -> #builder_ty {
+                        #builder_ty(NodeBuilder::new(#name))
+                    }
+
+                    #new_fn
+                }
+
+                impl #builder_ty {
+                    #(#setters)*
+
+                    pub fn finish(self) -> Result<Built<#ty<()>>, BuildError> {
+                        self.0.finish()
+                    }
+                }
+            }
+        });
+
+    let mut enum_names: Vec<&str> = generator.enums.keys().map(|it| it.as_str()).collect();
+    enum_names.sort();
+
+    let conversions = enum_names.iter().map(|name| {
+        let enum_ = &generator.enums[*name];
+        let ty = format_ident!("{name}");
+
+        // Variants of nested enums can be converted directly (Ex: an `OpAdd` into an `Expr`)
+        let mut variants = Vec::new();
+        let mut stack: Vec<&str> = enum_
+            .fields
+            .iter()
+            .filter(|it| it.kind == RustEnumFieldKind::Node)
+            .map(|it| it.name.as_str())
+            .collect();
+        while let Some(variant) = stack.pop() {
+            if variant == *name || variants.contains(&variant) {
+                continue;
+            }
+            variants.push(variant);
+
+            if let Some(nested) = generator.enums.get(variant) {
+                stack.extend(
+                    nested
+                        .fields
+                        .iter()
+                        .filter(|it| it.kind == RustEnumFieldKind::Node)
+                        .map(|it| it.name.as_str()),
+                );
+            }
+        }
+        variants.sort();
+
+        let from_impls = variants.iter().map(|variant| {
+            let variant_ty = format_ident!("{variant}");
+            quote! {
+                impl From<Built<#variant_ty<()>>> for Built<#ty<()>> {
+                    fn from(value: Built<#variant_ty<()>>) -> Self {
+                        value.convert(#name)
+                    }
+                }
+            }
+        });
+
+        let token_fns = enum_
+            .fields
+            .iter()
+            .filter(|it| it.kind == RustEnumFieldKind::Token)
+            .map(|field| {
+                let token_name = &field.name;
+                let fn_name = format_ident!(
+                    "build_{}",
+                    convert_symbol_tokens(token_name)
+                        .unwrap_or(token_name)
+                        .to_case(Case::Snake)
+                );
+
+                if [
+                    "INT_LIT", "HEX_LIT", "REAL_LIT", "STR_LIT", "BLOB_LIT", "IDEN", "PARAM",
+                ]
+                .contains(&token_name.as_str())
+                {
+                    quote! {
+                        pub fn #fn_name(text: &str) -> Result<Built<#ty<()>>, BuildError> {
+                            Built::token(#name, #token_name, text)
+                        }
+                    }
+                } else {
+                    quote! {
+                        pub fn #fn_name() -> Built<#ty<()>> {
+                            Built::fixed_token(#name, #token_name)
+                        }
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let token_impl = if token_fns.is_empty() {
+            quote! {}
+        } else {
+            quote! {
+                impl #ty<()> {
+                    #(#token_fns)*
+                }
+            }
+        };
+
+        quote! {
+            #(#from_impls)*
+
+            #token_impl
+        }
+    });
+
+    quote! {
+        // Setters are named after keywords (Ex: `not`)
+        #![allow(clippy::should_implement_trait)]
+
+        use super::*;
+
+        #(#builders)*
+
+        #(#conversions)*
+    }
+}
+
+/// The typed AST enum of a labeled alternation (Ex: `FromClauseValue`). Manually written nodes
+/// reuse the enums of other nodes with the same alternatives.
+fn choice_enum<'g>(generator: &'g AstGenerator, label: &str, choices: &[String]) -> &'g str {
+    let same_choices = |it: &RustEnum| it.fields.iter().map(|field| &field.name).eq(choices);
+
+    if let Some(enum_) = generator
+        .enums
+        .get(&label.to_case(Case::UpperCamel))
+        .filter(|it| same_choices(it))
+    {
+        return &enum_.name;
+    }
+
+    generator
+        .enums
+        .values()
+        .filter(|it| same_choices(it))
+        .map(|it| it.name.as_str())
+        .min()
+        .unwrap_or_else(|| panic!("No enum found for {label}: {choices:?}"))
+}
+
+fn builder_ident(name: &str) -> proc_macro2::Ident {
+    if syn::parse_str::<syn::Ident>(name).is_ok() {
+        format_ident!("{name}")
+    } else {
+        format_ident!("r#{name}")
+    }
 }
 
 fn generic_args(generator: &AstGenerator, name: &str) -> proc_macro2::TokenStream {
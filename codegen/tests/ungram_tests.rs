@@ -33,7 +33,7 @@ fn test_tree_kinds_are_correct() {
         .unwrap();
 
     let expected = quote! {
-        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
         pub enum SqliteTreeKind {
             #(#enum_variants ,)*
         }
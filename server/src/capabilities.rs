@@ -12,6 +12,8 @@ pub fn server_capabilities() -> ServerCapabilities {
             all_commit_characters: None,
             ..Default::default()
         }),
+        code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+        definition_provider: Some(OneOf::Left(true)),
         // execute_command_provider: Some(ExecuteCommandOptions {
         //     commands: vec!["dummy.do_something".to_string()],
         //     work_done_progress_options: Default::default(),
@@ -1,41 +1,333 @@
+use bord_sqlite3_analyzer::LintConfig;
+use bord_sqlite3_parser::ParserConfig;
 use hashbrown::HashMap;
 use regex::Regex;
 
+use line_index::{TextRange, TextSize};
+
+use crate::{markdown, profiles, source_map::EscapeStyle};
+
 #[derive(Debug)]
 pub struct BordConfig {
-    pub match_patterns: HashMap<String, MatchPattern>,
+    /// How SQL is found in the files of each language (by language id)
+    pub extractors: HashMap<String, SqlExtractor>,
+    /// The SQLite version and compile-time options that the SQL is checked against
+    pub parser: ParserConfig,
+    /// Which lint rules are turned on and their severities
+    pub lints: LintConfig,
+    /// Parse the whole file again after every incremental reparse and compare the results. This
+    /// doubles the cost of every edit so it is only on by default in debug builds.
+    pub validate_incremental_parsing: bool,
 }
 
+/// Finds the SQL embedded in documents of another language
 #[derive(Debug)]
+pub enum SqlExtractor {
+    Regex(MatchPattern),
+    /// Fenced code blocks tagged `sql` or `sqlite`
+    Markdown,
+}
+
+/// Finds SQL embedded in the string literals of another language
+#[derive(Debug, Default)]
 pub struct MatchPattern {
+    rules: Vec<MatchRule>,
+}
+
+#[derive(Debug)]
+struct MatchRule {
     re: Regex,
-    named_groups: Vec<String>,
+    /// Groups that capture the contents of a string literal and how the literal escapes
+    /// characters
+    named_groups: Vec<(String, EscapeStyle)>,
+    /// Whether the rule comes from a built-in profile (rather than the user's settings)
+    builtin: bool,
 }
 
 impl Default for BordConfig {
     fn default() -> Self {
         Self {
+            extractors: profiles::default_extractors(),
+            parser: ParserConfig::default(),
+            lints: LintConfig::default(),
+            validate_incremental_parsing: cfg!(debug_assertions),
+        }
+    }
+}
+
+impl BordConfig {
+    /// Updates the config using the settings sent by the client (`initializationOptions` or
+    /// `workspace/didChangeConfiguration`). Unknown or invalid settings are ignored.
+    pub fn update_from_json(&mut self, value: &serde_json::Value) {
+        // Some clients nest the settings under the server's name
+        let value = value.get("bord").unwrap_or(value);
+
+        if let Some(version) = value.get("sqliteVersion").and_then(|it| it.as_str()) {
+            match version.parse() {
+                Ok(version) => self.parser.version = version,
+                Err(err) => tracing::warn!("{err}"),
+            }
+        }
+
+        // Ex: ["SQLITE_ENABLE_UPDATE_DELETE_LIMIT", "SQLITE_OMIT_CTE"]
+        if let Some(options) = value.get("compileOptions").and_then(|it| it.as_array()) {
+            let mut parser = ParserConfig {
+                templates: self.parser.templates,
+                ..ParserConfig::with_version(self.parser.version)
+            };
+
+            for option in options.iter().filter_map(|it| it.as_str()) {
+                if let Err(err) = parser.set_compile_option(option) {
+                    tracing::warn!("{err}");
+                }
+            }
+
+            self.parser = parser;
+        }
+
+        // Jinja/dbt tags and sqlc macros
+        if let Some(templates) = value.get("templates").and_then(|it| it.as_bool()) {
+            self.parser.templates = templates;
+        }
+
+        // Ex: { "null-comparison": "error", "implicit-cross-join": "off" }
+        if let Some(lints) = value.get("lints").and_then(|it| it.as_object()) {
+            for (id, severity) in lints {
+                let Some(severity) = severity.as_str() else {
+                    tracing::warn!("Invalid severity for lint rule `{id}`: {severity}");
+                    continue;
+                };
+
+                if let Err(err) = self.lints.configure(id, severity) {
+                    tracing::warn!("{err}");
+                }
+            }
+        }
+
+        if let Some(validate) = value
+            .get("validateIncrementalParsing")
+            .and_then(|it| it.as_bool())
+        {
+            self.validate_incremental_parsing = validate;
+        }
 
-            match_patterns: HashMap::from([
-                (
-                    "rust".to_string(), 
-                    MatchPattern {
-                        re: Regex::new(r#"sql!\(\s*(?:"?(?<n1>(?:\\.|[^"\\])*)"|r#"(?<n2>(?:\\.|[^"\\])*)"\#)\s*\)"#).unwrap(), 
-                        named_groups: vec!["n1".into(), "n2".into()] 
-                    }
-                )
-            ])
+        // Ex: { "vue": { "profile": "typescript" }, "go": { "profile": false }, "python": {
+        //     "patterns": [{ "regex": "run_sql\\('(?<sql>[^']*)'", "escapes": "none" }] } }
+        if let Some(languages) = value.get("embeddedSql").and_then(|it| it.as_object()) {
+            for (lang_id, settings) in languages {
+                match extractor_from_json(lang_id, settings) {
+                    Some(extractor) => self.extractors.insert(lang_id.clone(), extractor),
+                    None => self.extractors.remove(lang_id),
+                };
+            }
+        }
+    }
+}
+
+/// The built-in profile of the language (unless it is disabled or replaced with another one)
+/// along with the user's own patterns. `None` if no SQL should be looked for.
+fn extractor_from_json(lang_id: &str, settings: &serde_json::Value) -> Option<SqlExtractor> {
+    let profile_name = match settings.get("profile") {
+        None | Some(serde_json::Value::Bool(true)) => profiles::default_profile(lang_id),
+        Some(serde_json::Value::String(name)) => Some(name.as_str()),
+        Some(_) => None,
+    };
+
+    let profile = profile_name
+        .and_then(|name| {
+            let profile = profiles::profile(name);
+            if profile.is_none() {
+                tracing::warn!(
+                    "Unknown embedded SQL profile `{name}` (expected one of {:?})",
+                    profiles::PROFILES
+                );
+            }
+
+            profile
+        })
+        .unwrap_or(SqlExtractor::Regex(MatchPattern::default()));
+
+    let user_patterns = settings.get("patterns").and_then(|it| it.as_array());
+
+    let mut pattern = match profile {
+        SqlExtractor::Regex(pattern) => pattern,
+        SqlExtractor::Markdown => {
+            if user_patterns.is_some() {
+                tracing::warn!("Patterns cannot be added to the `markdown` profile");
+            }
+
+            return Some(SqlExtractor::Markdown);
+        }
+    };
+
+    for user_pattern in user_patterns.into_iter().flatten() {
+        let Some(re) = user_pattern.get("regex").and_then(|it| it.as_str()) else {
+            tracing::warn!("Embedded SQL pattern without a regex: {user_pattern}");
+            continue;
+        };
+
+        let re = match Regex::new(re) {
+            Ok(re) => re,
+            Err(err) => {
+                tracing::warn!("{err}");
+                continue;
+            }
+        };
+
+        let quote = user_pattern
+            .get("quote")
+            .and_then(|it| it.as_str())
+            .and_then(|it| it.chars().next())
+            .unwrap_or('"');
+
+        let style = match user_pattern.get("escapes").and_then(|it| it.as_str()) {
+            None | Some("none") => EscapeStyle::Raw,
+            Some("backslash") => EscapeStyle::Backslash { quote },
+            Some("template") => EscapeStyle::Template,
+            Some(other) => {
+                tracing::warn!(
+                    "Unknown escapes `{other}` (expected `none`, `backslash` or `template`)"
+                );
+                continue;
+            }
+        };
+
+        // Every named group captures SQL
+        let named_groups: Vec<_> = re
+            .capture_names()
+            .flatten()
+            .map(|name| (name.to_owned(), style))
+            .collect();
+
+        if named_groups.is_empty() {
+            tracing::warn!("Embedded SQL pattern `{re}` has no named groups");
+            continue;
+        }
+
+        pattern.rules.push(MatchRule {
+            re,
+            named_groups,
+            builtin: false,
+        });
+    }
+
+    (!pattern.rules.is_empty()).then_some(SqlExtractor::Regex(pattern))
+}
+
+impl SqlExtractor {
+    /// Whether the SQL can only start and end at a quote, a backslash escape, a line break or a
+    /// fence. True for the built-in profiles, but the user's patterns can end anywhere (Ex: at
+    /// the `)` of a call).
+    pub fn has_known_delimiters(&self) -> bool {
+        match self {
+            SqlExtractor::Regex(pattern) => pattern.rules.iter().all(|it| it.builtin),
+            SqlExtractor::Markdown => true,
+        }
+    }
+
+    /// Ranges of the SQL in the document (sorted by position) and how it is escaped
+    pub fn find_sql(&self, text: &str) -> Vec<(TextRange, EscapeStyle)> {
+        match self {
+            SqlExtractor::Regex(pattern) => pattern
+                .match_on_haystack(text)
+                .map(|(it, style)| {
+                    let range = TextRange::new(
+                        TextSize::from(it.start() as u32),
+                        TextSize::from(it.end() as u32),
+                    );
+
+                    (range, style)
+                })
+                .collect(),
+            SqlExtractor::Markdown => markdown::sql_code_blocks(text)
+                .into_iter()
+                .map(|it| (it, EscapeStyle::Raw))
+                .collect(),
         }
     }
 }
 
 impl MatchPattern {
+    /// Panics if the regex is invalid
+    pub fn with_rule(mut self, re: &str, named_groups: &[(&str, EscapeStyle)]) -> Self {
+        self.rules.push(MatchRule {
+            re: Regex::new(re).unwrap(),
+            named_groups: named_groups
+                .iter()
+                .map(|(name, style)| (name.to_string(), *style))
+                .collect(),
+            builtin: true,
+        });
+
+        self
+    }
+
+    /// Matches of all the rules sorted by position. When matches overlap, only the first one
+    /// is kept.
     pub fn match_on_haystack<'a, 'b: 'a>(
         &'b self,
         haystack: &'a str,
-    ) -> impl Iterator<Item = regex::Match<'a>> {
-        self.re
-            .captures_iter(haystack)
-            .flat_map(|cap| self.named_groups.iter().flat_map(move |nm| cap.name(nm)))
+    ) -> impl Iterator<Item = (regex::Match<'a>, EscapeStyle)> {
+        let mut matches: Vec<_> = self
+            .rules
+            .iter()
+            .flat_map(|rule| {
+                rule.re.captures_iter(haystack).flat_map(|cap| {
+                    rule.named_groups
+                        .iter()
+                        .flat_map(move |(nm, style)| Some((cap.name(nm)?, *style)))
+                })
+            })
+            .collect();
+
+        matches.sort_by_key(|(it, _)| it.start());
+
+        // String literals cannot touch so only the end of the last kept match matters
+        let mut end = None;
+        matches.retain(|(it, _)| {
+            let keep = !matches!(end, Some(end) if it.start() <= end);
+            if keep {
+                end = Some(it.end());
+            }
+
+            keep
+        });
+
+        matches.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn embedded_sql_is_configurable() {
+        let mut config = BordConfig::default();
+        config.update_from_json(&serde_json::json!({
+            "embeddedSql": {
+                "vue": { "profile": "typescript" },
+                "go": { "profile": false },
+                "python": {
+                    "patterns": [{ "regex": "run_sql\\(\"(?<sql>[^\"]*)\"", "escapes": "backslash" }]
+                }
+            }
+        }));
+
+        let find_sql = |lang_id: &str, text: &str| -> Vec<String> {
+            config.extractors[lang_id]
+                .find_sql(text)
+                .into_iter()
+                .map(|(range, _)| text[range].to_owned())
+                .collect()
+        };
+
+        assert_eq!(find_sql("vue", "sql`SELECT 1`"), ["SELECT 1"]);
+        assert!(!config.extractors.contains_key("go"));
+        // The built-in profile is still enabled
+        assert_eq!(
+            find_sql("python", r#"run_sql("SELECT 1"); cur.execute("SELECT 2")"#),
+            ["SELECT 1", "SELECT 2"]
+        );
     }
 }
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use async_lsp::lsp_types as lsp;
+use bord_sqlite3_analyzer::{assists, lint, Assist, AssistKind, LintConfig};
+use bord_sqlite3_parser::{CstNodeTrait, CstRewriter, CstTrait, LexError, TextEdit};
+use line_index::{TextRange, TextSize};
+
+use crate::{from_lsp, lines::LineIndex, source_map::SourceMap};
+
+pub(crate) fn code_actions<Cst: CstTrait>(
+    cst: &Cst,
+    line_index: &LineIndex,
+    source_map: &SourceMap,
+    uri: &lsp::Url,
+    range: TextRange,
+    lints: &LintConfig,
+) -> Vec<lsp::CodeActionOrCommand> {
+    let mut actions: Vec<_> = cst
+        .lex_errors()
+        .filter(|it| {
+            TextRange::new(it.start_pos(), it.end_pos())
+                .intersect(range)
+                .is_some()
+        })
+        .filter_map(|token| close_unterminated_literal(token, line_index, source_map, uri))
+        .collect();
+
+    let keyword_typo_fixes = cst
+        .errors()
+        .filter(|it| {
+            it.error_range()
+                .is_some_and(|err_range| err_range.intersect(range).is_some())
+        })
+        .filter_map(|err_node| {
+            let (token, keyword) = err_node.keyword_typo()?;
+            let text = &token.token()?.text;
+
+            // Respect the casing style of the user
+            let new_text = if text.chars().all(|it| !it.is_ascii_uppercase()) {
+                keyword.as_str().to_ascii_lowercase()
+            } else {
+                keyword.as_str().to_owned()
+            };
+
+            let mut rewriter = CstRewriter::new(token);
+            rewriter.replace(token, new_text.clone());
+            let edits = source_map.to_host_edits(&rewriter.edits().ok()?);
+            let edits = from_lsp::lsp_text_edits(line_index, &edits).ok()?;
+
+            Some(quick_fix(
+                format!("Replace `{text}` with `{new_text}`"),
+                uri,
+                edits,
+            ))
+        });
+
+    actions.extend(keyword_typo_fixes);
+
+    let lint_fixes = lint(cst.root(), lints)
+        .into_iter()
+        .filter(|it| it.range.intersect(range).is_some())
+        .filter_map(|it| {
+            let fix = it.fix?;
+            let edits = source_map.to_host_edits(&fix.edits);
+            let edits = from_lsp::lsp_text_edits(line_index, &edits).ok()?;
+
+            Some(quick_fix(fix.title, uri, edits))
+        });
+
+    actions.extend(lint_fixes);
+
+    let refactors = assists(cst.covering_element(range))
+        .into_iter()
+        .filter_map(|it| {
+            let edits = source_map.to_host_edits(&it.edits);
+            let edits = from_lsp::lsp_text_edits(line_index, &edits).ok()?;
+
+            Some(refactor(it, uri, edits))
+        });
+
+    actions.extend(refactors);
+
+    actions
+}
+
+/// Unterminated literals swallow the rest of the file, so we close them at the end of the
+/// line they start on (which is what the user wants most of the time)
+fn close_unterminated_literal<'a>(
+    token: impl CstNodeTrait<'a>,
+    line_index: &LineIndex,
+    source_map: &SourceMap,
+    uri: &lsp::Url,
+) -> Option<lsp::CodeActionOrCommand> {
+    let sqlite_token = token.token()?;
+    let text = sqlite_token.text.as_str();
+
+    let (closing, title) = match sqlite_token.error.as_ref()? {
+        LexError::UnterminatedStringLiteral => ("'", "Close string literal"),
+        LexError::UnterminatedBlobLiteral => ("'", "Close blob literal"),
+        LexError::UnterminatedQuotedIdentifier => match text.chars().next()? {
+            '[' => ("]", "Close quoted identifier"),
+            '`' => ("`", "Close quoted identifier"),
+            _ => ("\"", "Close quoted identifier"),
+        },
+        _ => return None,
+    };
+
+    let first_line = text.lines().next().unwrap_or(text).trim_end();
+    let insert_at = token.start_pos() + TextSize::of(first_line);
+    let edits = source_map.to_host_edits(&[TextEdit {
+        range: TextRange::empty(insert_at),
+        new_text: closing.to_owned(),
+    }]);
+
+    Some(quick_fix(
+        title.to_owned(),
+        uri,
+        from_lsp::lsp_text_edits(line_index, &edits).ok()?,
+    ))
+}
+
+pub(crate) fn quick_fix(
+    title: String,
+    uri: &lsp::Url,
+    edits: Vec<lsp::TextEdit>,
+) -> lsp::CodeActionOrCommand {
+    lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title,
+        kind: Some(lsp::CodeActionKind::QUICKFIX),
+        edit: Some(lsp::WorkspaceEdit {
+            changes: Some(HashMap::from([(uri.clone(), edits)])),
+            ..Default::default()
+        }),
+        is_preferred: Some(true),
+        ..Default::default()
+    })
+}
+
+fn refactor(assist: Assist, uri: &lsp::Url, edits: Vec<lsp::TextEdit>) -> lsp::CodeActionOrCommand {
+    let kind = match assist.kind {
+        AssistKind::Extract => lsp::CodeActionKind::REFACTOR_EXTRACT,
+        AssistKind::Inline => lsp::CodeActionKind::REFACTOR_INLINE,
+    };
+
+    lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: assist.title,
+        kind: Some(kind),
+        edit: Some(lsp::WorkspaceEdit {
+            changes: Some(HashMap::from([(uri.clone(), edits)])),
+            ..Default::default()
+        }),
+        ..Default::default()
+    })
+}
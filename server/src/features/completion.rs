@@ -8,21 +8,29 @@ use line_index::TextSize;
 
 pub(crate) fn create_completion_context<Cst: CstTrait>(cst: &Cst, cursor: TextSize) -> Vec<String> {
     //// Find the token to the left of autocomplete position. We ignore trivial tokens (like whitespace tokens)
-    let mut nodes_iter = cst.root().me_and_descendants().rev();
-
-    let Some(mut target) = nodes_iter.find(|it| match it.token() {
-        Some(_) => it.end_pos() <= cursor.into(),
-        None => false,
-    }) else {
+    let Some(mut target) = cst.token_at_offset(cursor).left_biased() else {
         return Vec::new();
     };
 
+    // The cursor is in the middle of a token, so the token to the left is the one before it
+    if target.end_pos() > cursor {
+        let Some(t) = target
+            .start_pos()
+            .checked_sub(TextSize::new(1))
+            .and_then(|offset| cst.root().token_containing(offset))
+        else {
+            return Vec::new();
+        };
+
+        target = t;
+    }
+
     // If the token immediately to the left of the cursor is a trivial token, it is important
     // for us to know this but we keep going to find a target that is not trivial
     let ends_in_whitespace = target.token_kind() == Some(SqliteTokenKind::WHITESPACE);
 
     if ends_in_whitespace {
-        let Some(t) = nodes_iter.find(|it| it.token().is_some_and(|it| !it.is_trivia())) else {
+        let Some(t) = target.prev_token_skip_trivia() else {
             return Vec::new();
         };
 
@@ -1,8 +1,191 @@
+use async_lsp::lsp_types as lsp;
+use bord_sqlite3_analyzer::{lint, LintConfig, Severity};
+use bord_sqlite3_parser::{
+    validate, CstNodeTrait, CstTrait, LexError, ParserConfig, SqliteTreeKind,
+};
+use line_index::TextRange;
+
+use super::directives::Directives;
+use crate::{config::BordConfig, from_lsp, lines::LineIndex, source_map::SourceMap};
+
 pub struct ParseError {
     pub range: (usize, usize),
     pub err: String,
 }
 
+/// All the diagnostics of the given CST, filtered and configured by its comment directives
+pub(crate) fn diagnostics<Cst: CstTrait>(
+    cst: &Cst,
+    line_index: &LineIndex,
+    source_map: &SourceMap,
+    uri: &lsp::Url,
+    config: &BordConfig,
+) -> Vec<lsp::Diagnostic> {
+    let directives = Directives::new(cst, line_index, source_map, &config.parser);
+
+    let mut diagnostics = syntax_diagnostics(cst, line_index, source_map, uri);
+    diagnostics.extend(validation_diagnostics(
+        cst,
+        line_index,
+        source_map,
+        directives.config(),
+    ));
+    diagnostics.extend(lint_diagnostics(cst, line_index, source_map, &config.lints));
+
+    directives.apply(diagnostics)
+}
+
+/// Lexer and parser errors of the given CST
+pub(crate) fn syntax_diagnostics<Cst: CstTrait>(
+    cst: &Cst,
+    line_index: &LineIndex,
+    source_map: &SourceMap,
+    uri: &lsp::Url,
+) -> Vec<lsp::Diagnostic> {
+    let mut diagnostics = lex_diagnostics(cst, line_index, source_map);
+    diagnostics.extend(parse_diagnostics(cst, line_index, source_map, uri));
+
+    diagnostics
+}
+
+pub(crate) fn lex_diagnostics<Cst: CstTrait>(
+    cst: &Cst,
+    line_index: &LineIndex,
+    source_map: &SourceMap,
+) -> Vec<lsp::Diagnostic> {
+    cst.lex_errors()
+        .filter_map(|node| {
+            let token = node.token()?;
+            let err = token.error.as_ref()?;
+            let range = TextRange::new(node.start_pos(), node.end_pos());
+            let range = from_lsp::lsp_range(line_index, source_map.to_host_range(range)).ok()?;
+
+            let message = match err {
+                LexError::UnknownToken => format!("{err} `{}`", token.text),
+                _ => err.to_string(),
+            };
+
+            Some(lsp::Diagnostic {
+                range,
+                severity: Some(lsp::DiagnosticSeverity::ERROR),
+                code: Some(lsp::NumberOrString::String(err.code().into())),
+                source: Some("bordsql".into()),
+                message,
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn parse_diagnostics<Cst: CstTrait>(
+    cst: &Cst,
+    line_index: &LineIndex,
+    source_map: &SourceMap,
+    uri: &lsp::Url,
+) -> Vec<lsp::Diagnostic> {
+    cst.errors()
+        .filter_map(|node| {
+            let err = node.error()?;
+
+            // Embedded SQL usually holds a single statement without a semicolon
+            if source_map.is_embedded()
+                && err.is_missing_semicolon_err()
+                && node.next_token_skip_trivia().is_none()
+            {
+                return None;
+            }
+
+            let range = source_map.to_host_range(node.error_range()?);
+            let range = from_lsp::lsp_range(line_index, range).ok()?;
+
+            // Point to the construct we were in the middle of parsing when we hit the error
+            let related_information = node
+                .ancestors()
+                .find(|it| it.tree().is_some_and(|it| it != SqliteTreeKind::File))
+                .and_then(|parent| {
+                    let first_token = parent
+                        .me_and_descendants()
+                        .find(|it| it.token().is_some_and(|tk| !tk.is_trivia()))?;
+                    let range = TextRange::new(first_token.start_pos(), first_token.end_pos());
+                    let range =
+                        from_lsp::lsp_range(line_index, source_map.to_host_range(range)).ok()?;
+
+                    Some(vec![lsp::DiagnosticRelatedInformation {
+                        location: lsp::Location {
+                            uri: uri.clone(),
+                            range,
+                        },
+                        message: format!("{} starts here", parent.tree()?.display_name()),
+                    }])
+                });
+
+            Some(lsp::Diagnostic {
+                range,
+                severity: Some(lsp::DiagnosticSeverity::ERROR),
+                code: Some(lsp::NumberOrString::String(err.code().into())),
+                source: Some("bordsql".into()),
+                message: node.error_message()?,
+                related_information,
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+/// Syntax that the targeted build of SQLite does not understand
+pub(crate) fn validation_diagnostics<Cst: CstTrait>(
+    cst: &Cst,
+    line_index: &LineIndex,
+    source_map: &SourceMap,
+    config: &ParserConfig,
+) -> Vec<lsp::Diagnostic> {
+    validate(cst.root(), config)
+        .into_iter()
+        .filter_map(|err| {
+            let range = source_map.to_host_range(err.range);
+
+            Some(lsp::Diagnostic {
+                range: from_lsp::lsp_range(line_index, range).ok()?,
+                severity: Some(lsp::DiagnosticSeverity::ERROR),
+                code: Some(lsp::NumberOrString::String(err.code.into())),
+                source: Some("bordsql".into()),
+                message: err.message,
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+/// Valid SQL that is most likely a mistake. The code of each diagnostic is the id of the rule so
+/// that it can be suppressed with `bord-ignore: null-comparison`.
+pub(crate) fn lint_diagnostics<Cst: CstTrait>(
+    cst: &Cst,
+    line_index: &LineIndex,
+    source_map: &SourceMap,
+    config: &LintConfig,
+) -> Vec<lsp::Diagnostic> {
+    lint(cst.root(), config)
+        .into_iter()
+        .filter_map(|it| {
+            let range = source_map.to_host_range(it.range);
+
+            Some(lsp::Diagnostic {
+                range: from_lsp::lsp_range(line_index, range).ok()?,
+                severity: Some(match it.severity {
+                    Severity::Error => lsp::DiagnosticSeverity::ERROR,
+                    Severity::Warning => lsp::DiagnosticSeverity::WARNING,
+                    Severity::Info => lsp::DiagnosticSeverity::INFORMATION,
+                    Severity::Hint => lsp::DiagnosticSeverity::HINT,
+                }),
+                code: Some(lsp::NumberOrString::String(it.rule.id().into())),
+                source: Some("bordsql".into()),
+                message: it.message,
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
 // pub fn perform_diagnostics(
 //     conn: &rusqlite::Connection,
 //     doc: &TextDocument,
@@ -67,3 +250,52 @@ pub struct ParseError {
 
 //     diagnostics
 // }
+
+#[cfg(test)]
+mod tests {
+    use bord_sqlite3_parser::{incr, parse};
+
+    use super::*;
+
+    #[test]
+    fn lints_are_configurable_and_suppressible() {
+        let text = "\
+DELETE FROM t;
+SELECT * FROM a, b WHERE a.id = NULL;
+-- bord-ignore: null-comparison
+SELECT * FROM a WHERE a.id != NULL;
+";
+        let cst: incr::IncrSqlCst = parse(text);
+        let line_index = LineIndex::new(text);
+        let uri = lsp::Url::parse("file:///main.sql").unwrap();
+
+        let mut config = BordConfig::default();
+        config.update_from_json(&serde_json::json!({
+            "lints": { "update-delete-without-where": "error", "implicit-cross-join": "off" }
+        }));
+
+        let found: Vec<_> = diagnostics(&cst, &line_index, &SourceMap::default(), &uri, &config)
+            .into_iter()
+            .map(|it| match it.code {
+                Some(lsp::NumberOrString::String(code)) => (it.range.start.line, code, it.severity),
+                _ => unreachable!(),
+            })
+            .collect();
+
+        assert_eq!(
+            found,
+            [
+                (
+                    0,
+                    "update-delete-without-where".to_owned(),
+                    Some(lsp::DiagnosticSeverity::ERROR)
+                ),
+                (
+                    1,
+                    "null-comparison".to_owned(),
+                    Some(lsp::DiagnosticSeverity::WARNING)
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,334 @@
+//! Comments that suppress or configure diagnostics:
+//!
+//! ```sql
+//! -- bord: sqlite-version=3.31 compile-options=SQLITE_OMIT_CTE,SQLITE_OMIT_VACUUM
+//!
+//! -- bord-ignore-next-line
+//! SELECT * FROM t LIMIT;
+//!
+//! -- bord-ignore: V0001, E0001
+//! UPDATE t SET a = 1 RETURNING *;
+//! ```
+//!
+//! `bord-ignore` applies to the statement that contains the comment (or the statement after it,
+//! unless the comment ends the line of another statement) and `bord-ignore-next-line` to the
+//! line after the comment. Without a list of codes, every
+//! diagnostic is suppressed.
+
+use async_lsp::lsp_types as lsp;
+use bord_sqlite3_parser::{CstNodeTrait, CstTrait, ParserConfig, SqliteTokenKind, SqliteTreeKind};
+use line_index::TextRange;
+
+use crate::{from_lsp, lines::LineIndex, source_map::SourceMap};
+
+pub(crate) struct Directives {
+    suppressions: Vec<Suppression>,
+    /// The config with the settings of `bord:` comments applied
+    config: ParserConfig,
+    /// Directives that could not be understood
+    diagnostics: Vec<lsp::Diagnostic>,
+}
+
+struct Suppression {
+    comment: lsp::Range,
+    /// Diagnostics that start in this range are suppressed. `None` if there is nothing to
+    /// suppress (Ex: `bord-ignore` after the last statement)
+    scope: Option<lsp::Range>,
+    /// Suppress every diagnostic if empty
+    codes: Vec<String>,
+}
+
+enum Directive<'a> {
+    IgnoreNextLine(Vec<&'a str>),
+    Ignore(Vec<&'a str>),
+    Configure(&'a str),
+}
+
+impl Suppression {
+    fn suppresses(&self, diagnostic: &lsp::Diagnostic) -> bool {
+        let Some(scope) = self.scope else {
+            return false;
+        };
+
+        let in_scope = scope.start <= diagnostic.range.start && diagnostic.range.start <= scope.end;
+        let has_code = self.codes.is_empty()
+            || matches!(
+                &diagnostic.code,
+                Some(lsp::NumberOrString::String(code))
+                    if self.codes.iter().any(|it| it.eq_ignore_ascii_case(code))
+            );
+
+        in_scope && has_code
+    }
+}
+
+impl Directives {
+    pub(crate) fn new<Cst: CstTrait>(
+        cst: &Cst,
+        line_index: &LineIndex,
+        source_map: &SourceMap,
+        config: &ParserConfig,
+    ) -> Directives {
+        use SqliteTokenKind::*;
+
+        let mut directives = Directives {
+            suppressions: Vec::new(),
+            config: *config,
+            diagnostics: Vec::new(),
+        };
+
+        let lsp_range = |range: TextRange| {
+            from_lsp::lsp_range(line_index, source_map.to_host_range(range)).ok()
+        };
+
+        let comments = cst.root().me_and_descendants().filter(|it| {
+            it.token()
+                .is_some_and(|tk| matches!(tk.kind, S_LINE_COMMENT | M_LINE_COMMENT))
+        });
+
+        for comment in comments {
+            let text = comment.token().unwrap().text.as_str();
+            let range = TextRange::new(comment.start_pos(), comment.end_pos());
+            let Some(lsp_comment) = lsp_range(range) else {
+                continue;
+            };
+
+            let (scope, codes) = match parse_directive(text) {
+                None => continue,
+                Some(Err(err)) => {
+                    directives.report(lsp_comment, err);
+                    continue;
+                }
+                Some(Ok(Directive::Configure(settings))) => {
+                    for err in configure(&mut directives.config, settings) {
+                        directives.report(lsp_comment, err);
+                    }
+                    continue;
+                }
+                Some(Ok(Directive::IgnoreNextLine(codes))) => {
+                    // Single line comments end with the newline
+                    let line = match text.ends_with('\n') {
+                        true => lsp_comment.end.line,
+                        false => lsp_comment.end.line + 1,
+                    };
+                    let scope = lsp::Range {
+                        start: lsp::Position { line, character: 0 },
+                        end: lsp::Position {
+                            line,
+                            character: u32::MAX,
+                        },
+                    };
+
+                    (Some(scope), codes)
+                }
+                Some(Ok(Directive::Ignore(codes))) => {
+                    // A comment at the end of a line (Ex: `VACUUM; -- bord-ignore`) applies to
+                    // the statement before it
+                    let trailing = comment.prev_token_skip_trivia().filter(|token| {
+                        lsp_range(TextRange::new(token.start_pos(), token.end_pos()))
+                            .is_some_and(|it| it.end.line == lsp_comment.start.line)
+                    });
+
+                    let mut statements = cst
+                        .root()
+                        .me_and_descendants()
+                        .filter(|it| it.tree() == Some(SqliteTreeKind::Statement));
+                    let stmt = match trailing {
+                        Some(token) => statements
+                            .filter(|it| it.start_pos() <= token.start_pos())
+                            .last(),
+                        None => statements.find(|it| it.end_pos() > comment.start_pos()),
+                    };
+                    let scope =
+                        stmt.and_then(|it| lsp_range(TextRange::new(it.start_pos(), it.end_pos())));
+
+                    (scope, codes)
+                }
+            };
+
+            directives.suppressions.push(Suppression {
+                comment: lsp_comment,
+                scope,
+                codes: codes.into_iter().map(|it| it.to_owned()).collect(),
+            });
+        }
+
+        directives
+    }
+
+    /// The config to validate the SQL with
+    pub(crate) fn config(&self) -> &ParserConfig {
+        &self.config
+    }
+
+    /// Removes the suppressed diagnostics and reports suppressions that did not suppress anything
+    pub(crate) fn apply(self, diagnostics: Vec<lsp::Diagnostic>) -> Vec<lsp::Diagnostic> {
+        let mut used = vec![false; self.suppressions.len()];
+
+        let mut diagnostics: Vec<_> = diagnostics
+            .into_iter()
+            .filter(|diagnostic| {
+                let mut is_suppressed = false;
+
+                for (suppression, used) in self.suppressions.iter().zip(&mut used) {
+                    if suppression.suppresses(diagnostic) {
+                        *used = true;
+                        is_suppressed = true;
+                    }
+                }
+
+                !is_suppressed
+            })
+            .collect();
+
+        let unused = self
+            .suppressions
+            .iter()
+            .zip(used)
+            .filter(|(_, used)| !used)
+            .map(|(suppression, _)| lsp::Diagnostic {
+                range: suppression.comment,
+                severity: Some(lsp::DiagnosticSeverity::WARNING),
+                code: Some(lsp::NumberOrString::String("D0001".into())),
+                source: Some("bordsql".into()),
+                message: "unused suppression: no diagnostics were suppressed".into(),
+                tags: Some(vec![lsp::DiagnosticTag::UNNECESSARY]),
+                ..Default::default()
+            });
+
+        diagnostics.extend(unused);
+        diagnostics.extend(self.diagnostics);
+
+        diagnostics
+    }
+
+    fn report(&mut self, range: lsp::Range, message: String) {
+        self.diagnostics.push(lsp::Diagnostic {
+            range,
+            severity: Some(lsp::DiagnosticSeverity::WARNING),
+            code: Some(lsp::NumberOrString::String("D0002".into())),
+            source: Some("bordsql".into()),
+            message,
+            ..Default::default()
+        });
+    }
+}
+
+/// `None` if the comment is not a directive
+fn parse_directive(comment: &str) -> Option<Result<Directive<'_>, String>> {
+    let body = match comment.strip_prefix("--") {
+        Some(body) => body,
+        None => comment.strip_prefix("/*")?.trim_end_matches("*/"),
+    }
+    .trim();
+
+    let name_len = body
+        .find(|ch: char| ch == ':' || ch.is_whitespace())
+        .unwrap_or(body.len());
+    let (name, rest) = body.split_at(name_len);
+    // Text after the name without a colon is a reason (Ex: `-- bord-ignore flaky on CI`)
+    let args = rest.trim_start().strip_prefix(':');
+
+    let codes = || {
+        args.unwrap_or_default()
+            .split(|ch: char| ch == ',' || ch.is_whitespace())
+            .filter(|it| !it.is_empty())
+            .collect()
+    };
+
+    let directive = match name {
+        "bord-ignore-next-line" => Directive::IgnoreNextLine(codes()),
+        "bord-ignore" => Directive::Ignore(codes()),
+        "bord" => Directive::Configure(args?),
+        _ if name.starts_with("bord-") => return Some(Err(format!("unknown directive `{name}`"))),
+        _ => return None,
+    };
+
+    Some(Ok(directive))
+}
+
+/// Applies settings like `sqlite-version=3.31 compile-options=SQLITE_OMIT_CTE`. Returns the
+/// settings that could not be applied.
+fn configure(config: &mut ParserConfig, settings: &str) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    for setting in settings.split_whitespace() {
+        let Some((key, value)) = setting.split_once('=') else {
+            errors.push(format!("expected `key=value`, found `{setting}`"));
+            continue;
+        };
+
+        match key {
+            "sqlite-version" => match value.parse() {
+                Ok(version) => config.version = version,
+                Err(err) => errors.push(err),
+            },
+            "compile-options" => {
+                for option in value.split(',').filter(|it| !it.is_empty()) {
+                    if let Err(err) = config.set_compile_option(option) {
+                        errors.push(err);
+                    }
+                }
+            }
+            _ => errors.push(format!("unknown setting `{key}`")),
+        }
+    }
+
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use bord_sqlite3_parser::{incr, parse};
+
+    use super::*;
+    use crate::config::BordConfig;
+
+    #[test]
+    fn directives_suppress_and_configure_diagnostics() {
+        let text = "\
+-- bord: sqlite-version=3.30 compile-options=SQLITE_OMIT_VACUUM
+-- bord-ignore-next-line
+SELECT * FROM t LIMIT;
+SELECT * FROM t LIMIT;
+-- bord-ignore: v0001
+UPDATE t SET a = 1 RETURNING *;
+SELECT a -> b FROM t;
+VACUUM; -- bord-ignore: V0002
+/* bord-ignore-next-line */
+SELECT 1;
+-- bord-frobnicate
+";
+        let cst: incr::IncrSqlCst = parse(text);
+        let line_index = LineIndex::new(text);
+        let uri = lsp::Url::parse("file:///main.sql").unwrap();
+
+        let diagnostics = crate::features::diagnostics(
+            &cst,
+            &line_index,
+            &SourceMap::default(),
+            &uri,
+            &BordConfig::default(),
+        );
+
+        let mut found: Vec<_> = diagnostics
+            .iter()
+            .map(|it| match &it.code {
+                Some(lsp::NumberOrString::String(code)) => (it.range.start.line, code.as_str()),
+                _ => unreachable!(),
+            })
+            .collect();
+        found.sort();
+
+        assert_eq!(
+            found,
+            [
+                (3, "E0001"),
+                (5, "update-delete-without-where"),
+                (6, "V0001"),
+                (8, "D0001"),
+                (10, "D0002"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use async_lsp::lsp_types as lsp;
+use bord_sqlite3_analyzer::window_definition;
+use bord_sqlite3_parser::{CstNodeTrait, CstTrait};
+use line_index::{TextRange, TextSize};
+
+use crate::{from_lsp, lines::LineIndex, source_map::SourceMap};
+
+/// Location of the definition of the name at the cursor. Only window names (`OVER w`) are
+/// resolved for now.
+pub(crate) fn goto_definition<Cst: CstTrait>(
+    cst: &Cst,
+    line_index: &LineIndex,
+    source_map: &SourceMap,
+    uri: &lsp::Url,
+    cursor: TextSize,
+) -> Option<lsp::Location> {
+    // The cursor can be right after the name (Ex: `OVER w|`), so both tokens touching it are tried
+    let definition = cst
+        .token_at_offset(cursor)
+        .filter(|it| it.is_non_trivia_token())
+        .find_map(|token| token.ancestors().find_map(window_definition))?;
+
+    let range = TextRange::new(
+        definition.start_pos_skip_trivia(),
+        definition.end_pos_skip_trivia(),
+    );
+
+    Some(lsp::Location {
+        uri: uri.clone(),
+        range: from_lsp::lsp_range(line_index, source_map.to_host_range(range)).ok()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use bord_sqlite3_parser::{incr, parse};
+
+    use super::*;
+
+    #[test]
+    fn window_names_resolve_to_their_definition() {
+        let text = "\
+SELECT sum(a) OVER w, rank() OVER (w ORDER BY b)
+FROM t
+WINDOW w AS (PARTITION BY c);
+";
+        let cst: incr::IncrSqlCst = parse(text);
+        let line_index = LineIndex::new(text);
+        let uri = lsp::Url::parse("file:///main.sql").unwrap();
+
+        let definition = |offset: usize| {
+            goto_definition(
+                &cst,
+                &line_index,
+                &SourceMap::default(),
+                &uri,
+                TextSize::new(offset as u32),
+            )
+            .map(|it| it.range)
+        };
+
+        let expected = Some(lsp::Range::new(
+            lsp::Position::new(2, 7),
+            lsp::Position::new(2, 28),
+        ));
+
+        assert_eq!(definition(text.find("OVER w").unwrap() + 5), expected);
+        assert_eq!(definition(text.find("OVER w").unwrap() + 6), expected);
+        assert_eq!(definition(text.find("(w ORDER").unwrap() + 1), expected);
+        assert_eq!(definition(text.find("sum").unwrap()), None);
+    }
+}
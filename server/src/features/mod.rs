@@ -1,5 +1,13 @@
+mod code_actions;
 mod completion;
 mod diagnostics;
+mod directives;
+mod goto_definition;
+mod named_queries;
 
+pub(crate) use code_actions::code_actions;
 pub(crate) use completion::create_completion_context;
+pub(crate) use diagnostics::diagnostics;
+pub(crate) use goto_definition::goto_definition;
+pub(crate) use named_queries::named_queries;
 // pub use diagnostics::perform_diagnostics;
@@ -0,0 +1,51 @@
+use bord_sqlite3_parser::CstTrait;
+use line_index::TextRange;
+
+use crate::{from_lsp, lines::LineIndex, lsp_ext, source_map::SourceMap};
+
+/// Named queries of the CST with their ranges converted to positions in the document
+pub(crate) fn named_queries<Cst: CstTrait>(
+    cst: &Cst,
+    line_index: &LineIndex,
+    source_map: &SourceMap,
+) -> Vec<lsp_ext::NamedQuery> {
+    let lsp_range =
+        |range: TextRange| from_lsp::lsp_range(line_index, source_map.to_host_range(range)).ok();
+
+    bord_sqlite3_parser::named_queries(cst)
+        .into_iter()
+        .filter_map(|query| {
+            let params = query
+                .params
+                .into_iter()
+                .filter_map(|it| {
+                    Some(lsp_ext::QueryParam {
+                        name: it.name.map(|it| it.to_string()),
+                        text: it.text.to_string(),
+                        range: lsp_range(it.range)?,
+                    })
+                })
+                .collect();
+
+            let columns = query
+                .columns
+                .into_iter()
+                .filter_map(|it| {
+                    Some(lsp_ext::QueryColumn {
+                        name: it.name.to_string(),
+                        range: lsp_range(it.range)?,
+                    })
+                })
+                .collect();
+
+            Some(lsp_ext::NamedQuery {
+                name: query.annotation.name.to_string(),
+                command: query.annotation.command.as_str().to_owned(),
+                range: lsp_range(query.range)?,
+                sql: query.sql,
+                params,
+                columns,
+            })
+        })
+        .collect()
+}
@@ -1,7 +1,9 @@
 use anyhow::format_err;
 use async_lsp::lsp_types as lsp;
-use bord_sqlite3_parser::{incr, CstNodeTrait};
-use line_index::{LineIndex, TextRange, TextSize, WideLineCol};
+use bord_sqlite3_parser::{incr, CstNodeTrait, TextEdit};
+use line_index::{TextRange, TextSize, WideLineCol};
+
+use crate::lines::LineIndex;
 
 /// Convert (line, column) for a particular text document into an offset value from the start
 /// of the text document.
@@ -56,6 +58,21 @@ pub fn lsp_range(line_index: &LineIndex, range: TextRange) -> anyhow::Result<lsp
     Ok(lsp::Range { start, end })
 }
 
+pub fn lsp_text_edits(
+    line_index: &LineIndex,
+    edits: &[TextEdit],
+) -> anyhow::Result<Vec<lsp::TextEdit>> {
+    edits
+        .iter()
+        .map(|edit| {
+            Ok(lsp::TextEdit {
+                range: lsp_range(line_index, edit.range)?,
+                new_text: edit.new_text.clone(),
+            })
+        })
+        .collect()
+}
+
 pub fn node_lsp_range<'a>(
     line_index: &LineIndex,
     node: &incr::IncrCstNode<'a>,
@@ -9,6 +9,11 @@ mod config;
 mod features;
 mod flycheck;
 mod from_lsp;
+mod lines;
+pub mod lsp_ext;
+mod markdown;
+mod profiles;
+mod source_map;
 mod text_document;
 mod vfs;
 
@@ -16,6 +21,7 @@ use async_lsp::lsp_types as lsp;
 use async_lsp::lsp_types::notification as not;
 use async_lsp::lsp_types::request as req;
 use async_lsp::router::Router;
+use source_map::SourceMap;
 use text_document::TextDocumentCstKind;
 
 #[derive(Debug)]
@@ -79,8 +85,12 @@ fn did_change_text_document(
         }
     };
 
-    doc.update_errors(&server.flycheck_db.lock().unwrap())
-        .unwrap();
+    doc.update_errors(
+        &doc_url,
+        &server.config,
+        &server.flycheck_db.lock().unwrap(),
+    )
+    .unwrap();
     // TODO: TERRIBLE! connection and flycheck need more work
     if let Err(err) =
         server
@@ -97,6 +107,37 @@ fn did_change_text_document(
     ControlFlow::Continue(())
 }
 
+fn did_change_configuration(
+    server: &mut BordLangServer,
+    params: lsp::DidChangeConfigurationParams,
+) -> ControlFlow<Result<(), async_lsp::Error>> {
+    server.config.update_from_json(&params.settings);
+
+    // The open documents were parsed and checked with the old settings (Ex: `sqliteVersion`,
+    // `embeddedSql` or `lints`)
+    for mut doc in server.vfs.files.iter_mut() {
+        let (doc_url, doc) = doc.pair_mut();
+
+        doc.reparse(server);
+        doc.update_errors(doc_url, &server.config, &server.flycheck_db.lock().unwrap())
+            .unwrap();
+
+        if let Err(err) =
+            server
+                .client
+                .notify::<not::PublishDiagnostics>(lsp::PublishDiagnosticsParams {
+                    uri: doc_url.clone(),
+                    diagnostics: doc.errors.clone(),
+                    version: Some(doc.doc_version),
+                })
+        {
+            return ControlFlow::Break(Err(err));
+        }
+    }
+
+    ControlFlow::Continue(())
+}
+
 fn completion(
     server: &mut BordLangServer,
     params: lsp::CompletionParams,
@@ -124,16 +165,17 @@ fn completion(
         TextDocumentCstKind::FullSqlFile(incr_cst) => {
             features::create_completion_context(incr_cst, cursor)
         }
-        TextDocumentCstKind::NonSqlFile { csts, .. } => {
-            csts.iter()
-                .find_map(|cst| {
-                    // Inclusive range to ensure we detect the right CST
-                    let range = cst.root().start_pos()..=cst.root().end_pos();
-                    if range.contains(&cursor.into()) {
-                        Some(features::create_completion_context(cst, cursor))
-                    } else {
-                        None
-                    }
+        TextDocumentCstKind::NonSqlFile { snippets, .. } => {
+            // Snippets are sorted by position so the last one that starts at or before the cursor
+            // is the only one that can contain it
+            let idx = snippets.partition_point(|it| it.range.start() <= cursor);
+
+            idx.checked_sub(1)
+                .map(|idx| &snippets[idx])
+                // Inclusive range to ensure we detect the right snippet
+                .filter(|it| cursor <= it.range.end())
+                .map(|it| {
+                    features::create_completion_context(&it.cst, it.source_map.to_sql(cursor))
                 })
                 .unwrap_or(Vec::new())
         }
@@ -151,27 +193,162 @@ fn completion(
     Some(lsp::CompletionResponse::Array(completions))
 }
 
+fn code_action(
+    server: &mut BordLangServer,
+    params: lsp::CodeActionParams,
+) -> Option<lsp::CodeActionResponse> {
+    let uri = params.text_document.uri;
+    let Some(document) = server.vfs.files.get(&uri) else {
+        tracing::warn!("Received code action request for non-existent document: {uri}");
+        return None;
+    };
+
+    let Ok(range) = from_lsp::text_range(&document.line_index, params.range) else {
+        tracing::error!("Unable to convert lsp text range");
+        return None;
+    };
+
+    let actions = match &document.cst {
+        TextDocumentCstKind::FullSqlFile(incr_cst) => {
+            let source_map = SourceMap::default();
+            features::code_actions(
+                incr_cst,
+                &document.line_index,
+                &source_map,
+                &uri,
+                range,
+                &server.config.lints,
+            )
+        }
+        TextDocumentCstKind::NonSqlFile { snippets, .. } => snippets
+            .iter()
+            .filter(|it| it.range.intersect(range).is_some())
+            .flat_map(|it| {
+                let range = it.source_map.to_sql_range(range);
+                features::code_actions(
+                    &it.cst,
+                    &document.line_index,
+                    &it.source_map,
+                    &uri,
+                    range,
+                    &server.config.lints,
+                )
+            })
+            .collect(),
+    };
+
+    Some(actions)
+}
+
+fn goto_definition(
+    server: &mut BordLangServer,
+    params: lsp::GotoDefinitionParams,
+) -> Option<lsp::GotoDefinitionResponse> {
+    let doc_pos = params.text_document_position_params;
+    let uri = doc_pos.text_document.uri;
+    let Some(document) = server.vfs.files.get(&uri) else {
+        tracing::warn!("Received go to definition request for non-existent document: {uri}");
+        return None;
+    };
+
+    let Ok(cursor) = from_lsp::offset(
+        &document.line_index,
+        doc_pos.position.line,
+        doc_pos.position.character,
+    ) else {
+        tracing::error!("Unable to convert lsp text position");
+        return None;
+    };
+
+    let location = match &document.cst {
+        TextDocumentCstKind::FullSqlFile(incr_cst) => features::goto_definition(
+            incr_cst,
+            &document.line_index,
+            &SourceMap::default(),
+            &uri,
+            cursor,
+        ),
+        TextDocumentCstKind::NonSqlFile { snippets, .. } => {
+            let idx = snippets.partition_point(|it| it.range.start() <= cursor);
+
+            idx.checked_sub(1)
+                .map(|idx| &snippets[idx])
+                .filter(|it| cursor <= it.range.end())
+                .and_then(|it| {
+                    features::goto_definition(
+                        &it.cst,
+                        &document.line_index,
+                        &it.source_map,
+                        &uri,
+                        it.source_map.to_sql(cursor),
+                    )
+                })
+        }
+    };
+
+    location.map(lsp::GotoDefinitionResponse::Scalar)
+}
+
+fn named_queries(
+    server: &mut BordLangServer,
+    params: lsp_ext::NamedQueriesParams,
+) -> Vec<lsp_ext::NamedQuery> {
+    let uri = params.text_document.uri;
+    let Some(document) = server.vfs.files.get(&uri) else {
+        tracing::warn!("Received named queries request for non-existent document: {uri}");
+        return Vec::new();
+    };
+
+    match &document.cst {
+        TextDocumentCstKind::FullSqlFile(incr_cst) => {
+            features::named_queries(incr_cst, &document.line_index, &SourceMap::default())
+        }
+        TextDocumentCstKind::NonSqlFile { snippets, .. } => snippets
+            .iter()
+            .flat_map(|it| features::named_queries(&it.cst, &document.line_index, &it.source_map))
+            .collect(),
+    }
+}
+
 pub struct TickEvent;
 
 pub fn router(client: async_lsp::ClientSocket) -> Router<BordLangServer> {
     let mut router = async_lsp::router::Router::new(BordLangServer::new(client));
 
     router
-        .request::<req::Initialize, _>(|_, _| async move {
-            Ok(lsp::InitializeResult {
-                server_info: None,
-                capabilities: server_capabilities(),
-            })
+        .request::<req::Initialize, _>(|s, p| {
+            if let Some(options) = &p.initialization_options {
+                s.config.update_from_json(options);
+            }
+
+            async move {
+                Ok(lsp::InitializeResult {
+                    server_info: None,
+                    capabilities: server_capabilities(),
+                })
+            }
         })
         .notification::<not::Initialized>(|_, _| ControlFlow::Continue(()))
         .request::<req::Shutdown, _>(|_, _| async move { Ok(()) })
-        .notification::<not::DidChangeConfiguration>(|_, _| ControlFlow::Continue(()))
+        .notification::<not::DidChangeConfiguration>(did_change_configuration)
         .notification::<not::DidOpenTextDocument>(did_open_text_document)
         .notification::<not::DidChangeTextDocument>(did_change_text_document)
         .request::<req::Completion, _>(|s, p| {
             let completions = completion(s, p);
             async move { Ok(completions) }
         })
+        .request::<req::CodeActionRequest, _>(|s, p| {
+            let actions = code_action(s, p);
+            async move { Ok(actions) }
+        })
+        .request::<req::GotoDefinition, _>(|s, p| {
+            let location = goto_definition(s, p);
+            async move { Ok(location) }
+        })
+        .request::<lsp_ext::NamedQueries, _>(|s, p| {
+            let queries = named_queries(s, p);
+            async move { Ok(queries) }
+        })
         .notification::<not::DidCloseTextDocument>(did_close_text_document)
         .unhandled_notification(|_, _| ControlFlow::Continue(()));
 
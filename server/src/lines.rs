@@ -0,0 +1,176 @@
+use line_index::{LineCol, TextRange, TextSize, WideEncoding, WideLineCol};
+
+/// Maps offsets to (line, column) pairs and back. Unlike [`line_index::LineIndex`], it can be
+/// updated in place after an edit so the whole document does not need to be scanned on every
+/// keystroke.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineIndex {
+    /// Offset of the start of every line except the first one
+    newlines: Vec<TextSize>,
+    /// Characters that take up more than one byte (sorted by position)
+    wide_chars: Vec<WideChar>,
+    len: TextSize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct WideChar {
+    start: TextSize,
+    len: TextSize,
+}
+
+impl WideChar {
+    fn wide_len(&self, enc: WideEncoding) -> u32 {
+        match enc {
+            WideEncoding::Utf16 if self.len == TextSize::new(4) => 2,
+            _ => 1,
+        }
+    }
+}
+
+impl LineIndex {
+    pub fn new(text: &str) -> LineIndex {
+        LineIndex {
+            newlines: newlines(TextSize::new(0), text).collect(),
+            wide_chars: wide_chars(TextSize::new(0), text).collect(),
+            len: TextSize::of(text),
+        }
+    }
+
+    pub fn len(&self) -> TextSize {
+        self.len
+    }
+
+    /// Updates the index after the text in `range` was replaced by `new_text`
+    pub fn apply_edit(&mut self, range: TextRange, new_text: &str) {
+        let new_len = TextSize::of(new_text);
+        let shift = |it: TextSize| it - range.len() + new_len;
+
+        // Newlines in the replaced text start lines in `(start, end]`
+        let start = self.newlines.partition_point(|&it| it <= range.start());
+        let end = self.newlines.partition_point(|&it| it <= range.end());
+        self.newlines[end..]
+            .iter_mut()
+            .for_each(|it| *it = shift(*it));
+        self.newlines
+            .splice(start..end, newlines(range.start(), new_text));
+
+        let start = self
+            .wide_chars
+            .partition_point(|it| it.start < range.start());
+        let end = self.wide_chars.partition_point(|it| it.start < range.end());
+        self.wide_chars[end..]
+            .iter_mut()
+            .for_each(|it| it.start = shift(it.start));
+        self.wide_chars
+            .splice(start..end, wide_chars(range.start(), new_text));
+
+        self.len = shift(self.len);
+    }
+
+    pub fn try_line_col(&self, offset: TextSize) -> Option<LineCol> {
+        if offset > self.len {
+            return None;
+        }
+
+        let line = self.newlines.partition_point(|&it| it <= offset);
+        let col = offset - self.line_start(line as u32)?;
+
+        Some(LineCol {
+            line: line as u32,
+            col: col.into(),
+        })
+    }
+
+    pub fn offset(&self, line_col: LineCol) -> Option<TextSize> {
+        let offset = self.line_start(line_col.line)? + TextSize::from(line_col.col);
+
+        (offset <= self.len).then_some(offset)
+    }
+
+    pub fn to_utf8(&self, enc: WideEncoding, line_col: WideLineCol) -> Option<LineCol> {
+        let line_start = self.line_start(line_col.line)?;
+        let mut col = line_col.col;
+
+        for c in self.line_wide_chars(line_col.line) {
+            if u32::from(c.start - line_start) >= col {
+                break;
+            }
+
+            col += u32::from(c.len) - c.wide_len(enc);
+        }
+
+        Some(LineCol {
+            line: line_col.line,
+            col,
+        })
+    }
+
+    fn line_start(&self, line: u32) -> Option<TextSize> {
+        match line.checked_sub(1) {
+            None => Some(TextSize::new(0)),
+            Some(idx) => self.newlines.get(idx as usize).copied(),
+        }
+    }
+
+    fn line_wide_chars(&self, line: u32) -> &[WideChar] {
+        let start = self.line_start(line).unwrap_or(self.len);
+        let end = self.line_start(line + 1).unwrap_or(self.len);
+
+        let from = self.wide_chars.partition_point(|it| it.start < start);
+        let to = self.wide_chars.partition_point(|it| it.start < end);
+
+        &self.wide_chars[from..to]
+    }
+}
+
+fn newlines(offset: TextSize, text: &str) -> impl Iterator<Item = TextSize> + '_ {
+    text.match_indices('\n')
+        .map(move |(idx, _)| offset + TextSize::from(idx as u32 + 1))
+}
+
+fn wide_chars(offset: TextSize, text: &str) -> impl Iterator<Item = WideChar> + '_ {
+    text.char_indices()
+        .filter(|(_, c)| !c.is_ascii())
+        .map(move |(idx, c)| WideChar {
+            start: offset + TextSize::from(idx as u32),
+            len: TextSize::of(c),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edits_match_a_new_index() {
+        let mut text = String::from("SELECT 'é'\nFROM t;\n\nSELECT '😀';");
+        let mut index = LineIndex::new(&text);
+
+        let edits = [
+            (8, 10, "ab\nc"),
+            (0, 0, "\n"),
+            (3, 12, ""),
+            (14, 14, "😀\n😀"),
+        ];
+
+        for (start, end, new_text) in edits {
+            let range = TextRange::new(TextSize::new(start), TextSize::new(end));
+            text.replace_range(std::ops::Range::<usize>::from(range), new_text);
+            index.apply_edit(range, new_text);
+
+            assert_eq!(index, LineIndex::new(&text), "{text:?}");
+        }
+    }
+
+    #[test]
+    fn wide_columns_are_converted() {
+        let index = LineIndex::new("a\n😀b");
+
+        let line_col = index
+            .to_utf8(WideEncoding::Utf16, WideLineCol { line: 1, col: 2 })
+            .unwrap();
+        assert_eq!(line_col, LineCol { line: 1, col: 4 });
+        assert_eq!(index.offset(line_col), Some(TextSize::new(6)));
+        assert_eq!(index.try_line_col(TextSize::new(6)), Some(line_col));
+    }
+}
@@ -0,0 +1,49 @@
+//! Requests and notifications that are not part of the LSP specification
+
+use async_lsp::lsp_types as lsp;
+use serde::{Deserialize, Serialize};
+
+/// The sqlc-style named queries of a document (Ex: `-- name: GetUserById :one`), mainly for
+/// code generators
+pub enum NamedQueries {}
+
+impl lsp::request::Request for NamedQueries {
+    type Params = NamedQueriesParams;
+    type Result = Vec<NamedQuery>;
+    const METHOD: &'static str = "bord/namedQueries";
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NamedQueriesParams {
+    pub text_document: lsp::TextDocumentIdentifier,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NamedQuery {
+    pub name: String,
+    /// Ex: `:one`
+    pub command: String,
+    /// Range of the statement
+    pub range: lsp::Range,
+    pub sql: String,
+    pub params: Vec<QueryParam>,
+    pub columns: Vec<QueryColumn>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryParam {
+    /// `None` for positional parameters (`?` and `?NNN`)
+    pub name: Option<String>,
+    pub text: String,
+    pub range: lsp::Range,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryColumn {
+    pub name: String,
+    pub range: lsp::Range,
+}
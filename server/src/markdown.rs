@@ -0,0 +1,121 @@
+//! SQL in the fenced code blocks of Markdown documents:
+//!
+//! ````markdown
+//! ```sql
+//! SELECT * FROM users;
+//! ```
+//! ````
+
+use line_index::{TextRange, TextSize};
+
+/// Info strings (the word after the opening fence) of the blocks that hold SQL
+const SQL_INFO_STRINGS: &[&str] = &["sql", "sqlite"];
+
+/// Contents of the fenced code blocks tagged `sql` or `sqlite`, sorted by position. The contents
+/// start on the line after the opening fence and end before the closing fence (or at the end of
+/// the document if the block is never closed).
+pub(crate) fn sql_code_blocks(text: &str) -> Vec<TextRange> {
+    let mut blocks = Vec::new();
+    // The fence character, the length of the fence and where the contents start (if the block
+    // holds SQL). Other blocks are tracked as well so fences inside them are ignored.
+    let mut open_block: Option<(char, usize, Option<usize>)> = None;
+    let mut line_start = 0;
+
+    for line in text.split_inclusive('\n') {
+        let next_line_start = line_start + line.len();
+
+        match open_block {
+            Some((fence_char, fence_len, start)) => {
+                if closing_fence(line, fence_char, fence_len) {
+                    blocks.extend(start.map(|start| (start, line_start)));
+                    open_block = None;
+                }
+            }
+            None => {
+                if let Some((fence_char, fence_len, info)) = opening_fence(line) {
+                    let is_sql = info.split_whitespace().next().is_some_and(|lang| {
+                        SQL_INFO_STRINGS
+                            .iter()
+                            .any(|it| it.eq_ignore_ascii_case(lang))
+                    });
+
+                    open_block = Some((fence_char, fence_len, is_sql.then_some(next_line_start)));
+                }
+            }
+        }
+
+        line_start = next_line_start;
+    }
+
+    if let Some((_, _, Some(start))) = open_block {
+        blocks.push((start, text.len()));
+    }
+
+    blocks
+        .into_iter()
+        .map(|(start, end)| {
+            TextRange::new(TextSize::from(start as u32), TextSize::from(end as u32))
+        })
+        .collect()
+}
+
+/// The fence character, the length of the fence and the info string. Blocks are usually indented
+/// inside lists, so any indentation is accepted.
+fn opening_fence(line: &str) -> Option<(char, usize, &str)> {
+    let line = line.trim_start_matches([' ', '\t']);
+    let fence_char = line.chars().next().filter(|it| matches!(it, '`' | '~'))?;
+    let fence_len = line.len() - line.trim_start_matches(fence_char).len();
+    let info = line[fence_len..].trim();
+
+    // Backticks in the info string mean this is inline code
+    let is_fence = fence_len >= 3 && !(fence_char == '`' && info.contains('`'));
+
+    is_fence.then_some((fence_char, fence_len, info))
+}
+
+fn closing_fence(line: &str, fence_char: char, fence_len: usize) -> bool {
+    let line = line.trim_start_matches([' ', '\t']);
+    let rest = line.trim_start_matches(fence_char);
+
+    line.len() - rest.len() >= fence_len && rest.trim().is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block_texts(text: &str) -> Vec<&str> {
+        sql_code_blocks(text)
+            .into_iter()
+            .map(|it| &text[it])
+            .collect()
+    }
+
+    #[test]
+    fn sql_blocks_are_found() {
+        let text = "\
+# Runbook
+
+```sql
+SELECT 1;
+```
+
+```rust
+let q = \"```sql\";
+```
+
+- Clean up:
+  ~~~~ SQLite title=cleanup
+  DELETE FROM t;
+  ```
+  ~~~~
+
+```sql
+SELECT 2;";
+
+        assert_eq!(
+            block_texts(text),
+            ["SELECT 1;\n", "  DELETE FROM t;\n  ```\n", "SELECT 2;"]
+        );
+    }
+}
@@ -0,0 +1,236 @@
+//! Built-in patterns for finding SQL in the string literals of other languages. Every profile
+//! matches the calls that usually take SQL (Ex: `cursor.execute(...)` in Python) and knows how
+//! the string literals of its language are escaped.
+
+use hashbrown::HashMap;
+
+use crate::{
+    config::{MatchPattern, SqlExtractor},
+    source_map::EscapeStyle,
+};
+
+/// Names of the built-in profiles
+pub(crate) const PROFILES: &[&str] = &["rust", "python", "typescript", "go", "java", "markdown"];
+
+/// The profile that is enabled for each language id unless configured otherwise
+const DEFAULT_PROFILES: &[(&str, &str)] = &[
+    ("rust", "rust"),
+    ("python", "python"),
+    ("typescript", "typescript"),
+    ("typescriptreact", "typescript"),
+    ("javascript", "typescript"),
+    ("javascriptreact", "typescript"),
+    ("go", "go"),
+    ("java", "java"),
+    ("markdown", "markdown"),
+];
+
+const BACKSLASH_DOUBLE: EscapeStyle = EscapeStyle::Backslash { quote: '"' };
+const BACKSLASH_SINGLE: EscapeStyle = EscapeStyle::Backslash { quote: '\'' };
+
+// String literals. `NAME` is replaced with the name of the group that captures the contents
+const DOUBLE_QUOTED: &str = r#""(?<NAME>(?:\\.|[^"\\\n])*)""#;
+const SINGLE_QUOTED: &str = r#"'(?<NAME>(?:\\.|[^'\\\n])*)'"#;
+const BACKTICK_QUOTED: &str = r#"`(?<NAME>(?:\\[\s\S]|[^`\\])*)`"#;
+const TRIPLE_DOUBLE_QUOTED: &str = r#""""(?<NAME>(?:\\[\s\S]|[^\\])*?)""""#;
+const TRIPLE_SINGLE_QUOTED: &str = r#"'''(?<NAME>(?:\\[\s\S]|[^\\])*?)'''"#;
+
+pub(crate) fn default_extractors() -> HashMap<String, SqlExtractor> {
+    DEFAULT_PROFILES
+        .iter()
+        .filter_map(|(lang_id, name)| Some((lang_id.to_string(), profile(name)?)))
+        .collect()
+}
+
+/// Name of the profile that is enabled for the language id by default
+pub(crate) fn default_profile(lang_id: &str) -> Option<&'static str> {
+    DEFAULT_PROFILES
+        .iter()
+        .find(|(it, _)| *it == lang_id)
+        .map(|(_, name)| *name)
+}
+
+pub(crate) fn profile(name: &str) -> Option<SqlExtractor> {
+    let pattern = match name {
+        "rust" => MatchPattern::default().with_rule(
+            r#"sql!\(\s*(?:"?(?<n1>(?:\\.|[^"\\])*)"|r#"(?<n2>(?:\\.|[^"\\])*)"\#)\s*\)"#,
+            &[("n1", BACKSLASH_DOUBLE), ("n2", EscapeStyle::Raw)],
+        ),
+        // cursor.execute("..."), sqlalchemy.text("""...""") and pandas.read_sql("...")
+        "python" => MatchPattern::default().with_rule(
+            &call(
+                r"(?:\.execute(?:many|script)?|\btext|\bread_sql(?:_query)?)\(\s*",
+                &[
+                    format!("[rR]{}", literal(TRIPLE_DOUBLE_QUOTED, "raw_triple_double")),
+                    format!("[rR]{}", literal(TRIPLE_SINGLE_QUOTED, "raw_triple_single")),
+                    r#"[rR]"(?<raw_double>[^"\n]*)""#.to_owned(),
+                    r"[rR]'(?<raw_single>[^'\n]*)'".to_owned(),
+                    literal(TRIPLE_DOUBLE_QUOTED, "triple_double"),
+                    literal(TRIPLE_SINGLE_QUOTED, "triple_single"),
+                    literal(DOUBLE_QUOTED, "double"),
+                    literal(SINGLE_QUOTED, "single"),
+                ],
+            ),
+            &[
+                ("raw_triple_double", EscapeStyle::Raw),
+                ("raw_triple_single", EscapeStyle::Raw),
+                ("raw_double", EscapeStyle::Raw),
+                ("raw_single", EscapeStyle::Raw),
+                ("triple_double", BACKSLASH_DOUBLE),
+                ("triple_single", BACKSLASH_SINGLE),
+                ("double", BACKSLASH_DOUBLE),
+                ("single", BACKSLASH_SINGLE),
+            ],
+        ),
+        // sql`...` and db.query("...")
+        "typescript" => MatchPattern::default()
+            .with_rule(
+                &format!(r"\bsql\s*{}", literal(BACKTICK_QUOTED, "tagged")),
+                &[("tagged", EscapeStyle::Template)],
+            )
+            .with_rule(
+                &call(
+                    r"\.(?:query|execute|prepare|exec)\(\s*",
+                    &[
+                        literal(BACKTICK_QUOTED, "template"),
+                        literal(DOUBLE_QUOTED, "double"),
+                        literal(SINGLE_QUOTED, "single"),
+                    ],
+                ),
+                &[
+                    ("template", EscapeStyle::Template),
+                    ("double", BACKSLASH_DOUBLE),
+                    ("single", BACKSLASH_SINGLE),
+                ],
+            ),
+        // db.Query(`...`) and db.ExecContext(ctx, "...")
+        "go" => MatchPattern::default().with_rule(
+            &call(
+                r"\.(?:Query|QueryRow|Exec|Prepare)(?:Context)?\(\s*(?:\w+\s*,\s*)?",
+                &[
+                    "`(?<raw>[^`]*)`".to_owned(),
+                    literal(DOUBLE_QUOTED, "double"),
+                ],
+            ),
+            &[("raw", EscapeStyle::Raw), ("double", BACKSLASH_DOUBLE)],
+        ),
+        // @Query("""...""") and connection.prepareStatement("...")
+        "java" => MatchPattern::default().with_rule(
+            &call(
+                r"(?:@Query\(\s*(?:value\s*=\s*)?|\.(?:prepareStatement|prepareCall|executeQuery|executeUpdate|execute|createQuery|createNativeQuery)\(\s*)",
+                &[
+                    // The contents of a text block start on the line after the opening quotes
+                    r#""""[ \t]*\r?\n(?<text_block>(?:\\[\s\S]|[^\\])*?)""""#.to_owned(),
+                    literal(DOUBLE_QUOTED, "double"),
+                ],
+            ),
+            &[("text_block", BACKSLASH_DOUBLE), ("double", BACKSLASH_DOUBLE)],
+        ),
+        // Not a regex, see `crate::markdown`
+        "markdown" => return Some(SqlExtractor::Markdown),
+        _ => return None,
+    };
+
+    Some(SqlExtractor::Regex(pattern))
+}
+
+fn literal(pattern: &str, group_name: &str) -> String {
+    pattern.replace("NAME", group_name)
+}
+
+/// A call that takes one of the given string literals as its first argument
+fn call(prefix: &str, literals: &[String]) -> String {
+    format!("{prefix}(?:{})", literals.join("|"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find_sql(profile_name: &str, text: &str) -> Vec<(String, EscapeStyle)> {
+        profile(profile_name)
+            .unwrap()
+            .find_sql(text)
+            .into_iter()
+            .map(|(range, style)| (text[range].to_owned(), style))
+            .collect()
+    }
+
+    #[test]
+    fn profiles_find_sql() {
+        let python = r#"
+cur.execute("SELECT * FROM t WHERE a = \"b\"")
+cur.executemany('INSERT INTO t VALUES (?)', rows)
+session.execute(text("""
+    SELECT 1
+"""))
+pd.read_sql(r'SELECT \d', conn)
+print("SELECT 2")
+"#;
+        assert_eq!(
+            find_sql("python", python),
+            [
+                (
+                    r#"SELECT * FROM t WHERE a = \"b\""#.into(),
+                    BACKSLASH_DOUBLE
+                ),
+                ("INSERT INTO t VALUES (?)".into(), BACKSLASH_SINGLE),
+                ("\n    SELECT 1\n".into(), BACKSLASH_DOUBLE),
+                (r"SELECT \d".into(), EscapeStyle::Raw),
+            ]
+        );
+
+        let typescript = r#"
+const rows = await sql`SELECT * FROM t WHERE id = ${id}`;
+db.prepare("SELECT 1").get();
+"#;
+        assert_eq!(
+            find_sql("typescript", typescript),
+            [
+                (
+                    "SELECT * FROM t WHERE id = ${id}".into(),
+                    EscapeStyle::Template
+                ),
+                ("SELECT 1".into(), BACKSLASH_DOUBLE),
+            ]
+        );
+
+        let go = r#"
+rows, err := db.QueryContext(ctx, `SELECT "a" FROM t`)
+db.Exec("DELETE FROM t")
+db.Query(query)
+"#;
+        assert_eq!(
+            find_sql("go", go),
+            [
+                (r#"SELECT "a" FROM t"#.into(), EscapeStyle::Raw),
+                ("DELETE FROM t".into(), BACKSLASH_DOUBLE),
+            ]
+        );
+
+        let java = r#"
+@Query(value = """
+    SELECT u FROM users u
+    """)
+PreparedStatement stmt = conn.prepareStatement("UPDATE t SET a = ?");
+"#;
+        assert_eq!(
+            find_sql("java", java),
+            [
+                ("    SELECT u FROM users u\n    ".into(), BACKSLASH_DOUBLE),
+                ("UPDATE t SET a = ?".into(), BACKSLASH_DOUBLE),
+            ]
+        );
+    }
+
+    #[test]
+    fn every_profile_compiles() {
+        for name in PROFILES {
+            assert!(profile(name).is_some(), "{name}");
+        }
+
+        for (lang_id, _) in DEFAULT_PROFILES {
+            assert!(default_extractors().contains_key(*lang_id));
+        }
+    }
+}
@@ -0,0 +1,306 @@
+use bord_sqlite3_parser::TextEdit;
+use line_index::{TextRange, TextSize};
+
+/// How a host language escapes characters inside the string literal that holds the SQL
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EscapeStyle {
+    /// Nothing is escaped (Ex: Rust's `r#"..."#` or Go's backquoted strings)
+    Raw,
+    /// `\n`, `\"`, `\x41`, `\u{1F600}` and so on. `quote` is the character that ends the literal
+    Backslash { quote: char },
+    /// JavaScript template literals. Backslash escapes like [`EscapeStyle::Backslash`] and
+    /// `${...}` substitutions, which are replaced with `?` so they are checked as bind parameters
+    Template,
+}
+
+/// Maps offsets in the SQL decoded from a string literal to offsets in the document that
+/// contains the literal (and back). Offsets inside an escape sequence are mapped to its start.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SourceMap {
+    /// Where the SQL starts in the document
+    host_start: TextSize,
+    /// Escape sequences sorted by position. SQL ranges are relative to the start of the SQL and
+    /// host ranges are relative to the start of the document.
+    escapes: Vec<Escape>,
+    style: EscapeStyle,
+    /// Whether the SQL comes from a string literal rather than being the whole document
+    embedded: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Escape {
+    sql: TextRange,
+    host: TextRange,
+}
+
+impl Default for SourceMap {
+    /// The SQL is the whole document
+    fn default() -> Self {
+        SourceMap {
+            host_start: TextSize::new(0),
+            escapes: Vec::new(),
+            style: EscapeStyle::Raw,
+            embedded: false,
+        }
+    }
+}
+
+impl SourceMap {
+    pub(crate) fn is_embedded(&self) -> bool {
+        self.embedded
+    }
+
+    pub(crate) fn has_escapes(&self) -> bool {
+        !self.escapes.is_empty()
+    }
+
+    /// Moves the literal to `host_start` (Ex: when the text before it was edited)
+    pub(crate) fn move_to(&mut self, host_start: TextSize) {
+        for escape in &mut self.escapes {
+            escape.host = escape.host - self.host_start + host_start;
+        }
+
+        self.host_start = host_start;
+    }
+
+    pub(crate) fn to_host(&self, offset: TextSize) -> TextSize {
+        let idx = self.escapes.partition_point(|it| it.sql.start() <= offset);
+
+        match idx.checked_sub(1).map(|idx| self.escapes[idx]) {
+            None => self.host_start + offset,
+            Some(escape) if offset < escape.sql.end() => escape.host.start(),
+            Some(escape) => escape.host.end() + (offset - escape.sql.end()),
+        }
+    }
+
+    /// Escape sequences that are partially covered by the range are included in it
+    pub(crate) fn to_host_range(&self, range: TextRange) -> TextRange {
+        let idx = self
+            .escapes
+            .partition_point(|it| it.sql.start() < range.end());
+
+        let end = match idx.checked_sub(1).map(|idx| self.escapes[idx]) {
+            Some(escape) if range.end() < escape.sql.end() => escape.host.end(),
+            _ => self.to_host(range.end()),
+        };
+
+        TextRange::new(self.to_host(range.start()), end)
+    }
+
+    /// Offsets before the literal are mapped to its start
+    pub(crate) fn to_sql(&self, offset: TextSize) -> TextSize {
+        let offset = offset.max(self.host_start);
+        let idx = self.escapes.partition_point(|it| it.host.start() <= offset);
+
+        match idx.checked_sub(1).map(|idx| self.escapes[idx]) {
+            None => offset - self.host_start,
+            Some(escape) if offset < escape.host.end() => escape.sql.start(),
+            Some(escape) => escape.sql.end() + (offset - escape.host.end()),
+        }
+    }
+
+    pub(crate) fn to_sql_range(&self, range: TextRange) -> TextRange {
+        TextRange::new(self.to_sql(range.start()), self.to_sql(range.end()))
+    }
+
+    /// Edits to the SQL as edits to the document. The new text is escaped so it can be put
+    /// inside the literal.
+    pub(crate) fn to_host_edits(&self, edits: &[TextEdit]) -> Vec<TextEdit> {
+        edits
+            .iter()
+            .map(|edit| TextEdit {
+                range: self.to_host_range(edit.range),
+                new_text: self.escape(&edit.new_text),
+            })
+            .collect()
+    }
+
+    fn escape(&self, text: &str) -> String {
+        match self.style {
+            EscapeStyle::Raw => text.to_owned(),
+            EscapeStyle::Backslash { quote } => text
+                .replace('\\', "\\\\")
+                .replace(quote, &format!("\\{quote}")),
+            EscapeStyle::Template => text
+                .replace('\\', "\\\\")
+                .replace('`', "\\`")
+                .replace("${", "\\${"),
+        }
+    }
+}
+
+/// Decodes the contents of a string literal (without the quotes) that starts at `host_start`
+pub(crate) fn decode(text: &str, host_start: TextSize, style: EscapeStyle) -> (String, SourceMap) {
+    let mut sql = String::with_capacity(text.len());
+    let mut escapes = Vec::new();
+    let mut idx = 0;
+
+    while let Some(c) = text[idx..].chars().next() {
+        let escape = match style {
+            EscapeStyle::Template if text[idx..].starts_with("\\$") => Some((Some('$'), 2)),
+            EscapeStyle::Template if text[idx..].starts_with("${") => {
+                substitution_len(&text[idx..]).map(|len| (Some('?'), len))
+            }
+            EscapeStyle::Backslash { .. } | EscapeStyle::Template if c == '\\' => {
+                backslash_escape(&text[idx..])
+            }
+            _ => None,
+        };
+
+        match escape {
+            Some((decoded, host_len)) => {
+                let sql_start = TextSize::of(sql.as_str());
+                sql.extend(decoded);
+
+                escapes.push(Escape {
+                    sql: TextRange::new(sql_start, TextSize::of(sql.as_str())),
+                    host: TextRange::at(
+                        host_start + TextSize::from(idx as u32),
+                        TextSize::from(host_len as u32),
+                    ),
+                });
+                idx += host_len;
+            }
+            None => {
+                sql.push(c);
+                idx += c.len_utf8();
+            }
+        }
+    }
+
+    let source_map = SourceMap {
+        host_start,
+        escapes,
+        style,
+        embedded: true,
+    };
+
+    (sql, source_map)
+}
+
+/// The character a backslash escape stands for (`None` for line continuations) and the length of
+/// the escape. Unknown escapes are left as they are.
+fn backslash_escape(text: &str) -> Option<(Option<char>, usize)> {
+    let hex = |len: usize| {
+        let digits = text.get(2..2 + len)?;
+        if !digits.chars().all(|it| it.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let c = char::from_u32(u32::from_str_radix(digits, 16).ok()?)?;
+        Some((Some(c), 2 + len))
+    };
+
+    match text[1..].chars().next()? {
+        'n' => Some((Some('\n'), 2)),
+        'r' => Some((Some('\r'), 2)),
+        't' => Some((Some('\t'), 2)),
+        '0' => Some((Some('\0'), 2)),
+        c @ ('\\' | '\'' | '"' | '`') => Some((Some(c), 2)),
+        'x' => hex(2),
+        // Ex: `\u{1F600}`
+        'u' if text[2..].starts_with('{') => {
+            let end = text.find('}')?;
+            let c = char::from_u32(u32::from_str_radix(&text[3..end], 16).ok()?)?;
+            Some((Some(c), end + 1))
+        }
+        'u' => hex(4),
+        'U' => hex(8),
+        // The newline and the indentation of the next line are not part of the string
+        '\n' | '\r' => {
+            let rest = &text[1..];
+            Some((None, 1 + rest.len() - rest.trim_start().len()))
+        }
+        _ => None,
+    }
+}
+
+/// Length of the `${...}` substitution at the start of the text (`None` if it is never closed)
+fn substitution_len(text: &str) -> Option<usize> {
+    // The expression can have braces of its own (Ex: `${JSON.stringify({ a: 1 })}`)
+    let mut depth = 0;
+
+    for (idx, c) in text.char_indices().skip(2) {
+        match c {
+            '{' => depth += 1,
+            '}' if depth == 0 => return Some(idx + 1),
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RUST: EscapeStyle = EscapeStyle::Backslash { quote: '"' };
+
+    #[test]
+    fn escapes_are_decoded() {
+        let text = r#"SELECT \"a\" FROM t WHERE b = '\u{1F600}\x41' \
+            AND c = 1"#;
+        let (sql, _) = decode(text, TextSize::new(0), RUST);
+
+        assert_eq!(sql, "SELECT \"a\" FROM t WHERE b = '😀A' AND c = 1");
+
+        // Quotes are escaped in SQL strings, not the literal
+        let (sql, _) = decode("a = 'b''c'", TextSize::new(0), RUST);
+        assert_eq!(sql, "a = 'b''c'");
+
+        let (sql, _) = decode(r"\n \q", TextSize::new(0), EscapeStyle::Raw);
+        assert_eq!(sql, r"\n \q");
+    }
+
+    #[test]
+    fn template_substitutions_are_bind_parameters() {
+        // sql`SELECT * FROM t WHERE a = ${a} AND b = ${fn({ b })} AND c = '\${c}'`
+        let host_start = TextSize::new(4);
+        let text = r"SELECT * FROM t WHERE a = ${a} AND b = ${fn({ b })} AND c = '\${c}'";
+        let (sql, source_map) = decode(text, host_start, EscapeStyle::Template);
+
+        assert_eq!(sql, "SELECT * FROM t WHERE a = ? AND b = ? AND c = '${c}'");
+
+        let param = TextRange::at(TextSize::from(sql.find('?').unwrap() as u32), 1.into());
+        let host_range = source_map.to_host_range(param) - host_start;
+        assert_eq!(&text[host_range], "${a}");
+
+        let and = TextSize::from(sql.rfind("AND").unwrap() as u32);
+        assert_eq!(
+            source_map.to_host(and) - host_start,
+            TextSize::from(text.rfind("AND").unwrap() as u32)
+        );
+    }
+
+    #[test]
+    fn offsets_are_mapped_around_escapes() {
+        // let q = sql!("SELECT \"a\" FROM t");
+        let host_start = TextSize::new(14);
+        let (sql, source_map) = decode(r#"SELECT \"a\" FROM t"#, host_start, RUST);
+
+        let offset = |text: &str, pat: &str| TextSize::from(text.find(pat).unwrap() as u32);
+        let host_text = r#"let q = sql!("SELECT \"a\" FROM t");"#;
+
+        for pat in ["SELECT", "a", "FROM", " t"] {
+            let sql_offset = offset(&sql, pat);
+            let host_offset = offset(host_text, pat);
+
+            assert_eq!(source_map.to_host(sql_offset), host_offset, "{pat}");
+            assert_eq!(source_map.to_sql(host_offset), sql_offset, "{pat}");
+        }
+
+        // The identifier with its quotes
+        let range = TextRange::at(offset(&sql, "\"a\""), TextSize::of("\"a\""));
+        let host_range = source_map.to_host_range(range);
+        assert_eq!(&host_text[host_range], r#"\"a\""#);
+
+        let edits = source_map.to_host_edits(&[TextEdit {
+            range,
+            new_text: "\"b\"".into(),
+        }]);
+        assert_eq!(edits[0].range, host_range);
+        assert_eq!(edits[0].new_text, r#"\"b\""#);
+    }
+}
@@ -1,22 +1,53 @@
-use crate::{from_lsp, BordLangServer};
+use crate::{config::BordConfig, features, from_lsp, BordLangServer};
 use async_lsp::lsp_types::{self as lsp};
 use bord_sqlite3_parser::{
-    batch, incr, incremental_parse2, parse, parse_with_abs_pos, CstNodeTrait, CstTrait, TextPatch,
-    TextPatchKind,
+    batch, incr, incremental_parse_batch, parse_with_config, CstNodeTrait, CstTrait, ParserConfig,
+    TextPatch, TextPatchKind,
 };
-use line_index::LineIndex;
 use rusqlite::Connection;
+use std::hash::{Hash, Hasher};
 use text_size::{TextRange, TextSize};
 
+use crate::{
+    lines::LineIndex,
+    source_map::{self, EscapeStyle, SourceMap},
+};
+
 #[derive(Debug)]
 pub enum TextDocumentCstKind {
     FullSqlFile(incr::IncrSqlCst),
     NonSqlFile {
-        csts: Vec<batch::SqlCst>,
+        snippets: Vec<SqlSnippet>,
         lang_id: String,
     },
 }
 
+/// SQL embedded in a non-SQL file (Ex: the string passed to `sql!()` in Rust)
+#[derive(Debug)]
+pub struct SqlSnippet {
+    /// Position of the string literal's contents in the document
+    pub(crate) range: TextRange,
+    /// The CST of the decoded SQL. Its positions are relative to the start of the SQL and have
+    /// to be mapped with `source_map` before they are used with the document.
+    pub(crate) cst: incr::IncrSqlCst,
+    pub(crate) source_map: SourceMap,
+    /// How the string literal escapes characters
+    pub(crate) style: EscapeStyle,
+}
+
+impl SqlSnippet {
+    fn new(range: TextRange, text: &str, style: EscapeStyle, config: ParserConfig) -> Self {
+        let (sql, source_map) = source_map::decode(text, range.start(), style);
+
+        SqlSnippet {
+            range,
+            cst: parse_with_config(&sql, config),
+            source_map,
+            style,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct TextDocument {
     /// When we receive 'location' in text document via LSP, it is in
@@ -38,7 +69,7 @@ pub struct TextDocument {
 impl Default for TextDocumentCstKind {
     fn default() -> Self {
         TextDocumentCstKind::NonSqlFile {
-            csts: Vec::new(),
+            snippets: Vec::new(),
             lang_id: "".into(),
         }
     }
@@ -46,24 +77,22 @@ impl Default for TextDocumentCstKind {
 
 impl TextDocumentCstKind {
     pub fn new(server: &BordLangServer, lang_id: String, contents: &str) -> Self {
+        let config = server.config.parser;
+
         if lang_id == "sql" {
-            let cst = parse(&contents);
+            let cst = parse_with_config(&contents, config);
             TextDocumentCstKind::FullSqlFile(cst)
-        } else if let Some(match_pattern) = server.config.match_patterns.get(&lang_id) {
-            let csts = match_pattern
-                .match_on_haystack(&contents)
-                .map(|it| {
-                    parse_with_abs_pos::<batch::SqlCst>(
-                        TextSize::from(it.start() as u32),
-                        it.as_str(),
-                    )
-                })
+        } else if let Some(extractor) = server.config.extractors.get(&lang_id) {
+            let snippets = extractor
+                .find_sql(contents)
+                .into_iter()
+                .map(|(range, style)| SqlSnippet::new(range, &contents[range], style, config))
                 .collect();
 
-            TextDocumentCstKind::NonSqlFile { csts, lang_id }
+            TextDocumentCstKind::NonSqlFile { snippets, lang_id }
         } else {
             TextDocumentCstKind::NonSqlFile {
-                csts: Vec::new(),
+                snippets: Vec::new(),
                 lang_id,
             }
         }
@@ -96,121 +125,237 @@ impl TextDocument {
         // applied
         anyhow::ensure!(self.doc_version <= doc_version, "Unexpected doc version");
         self.doc_version = doc_version;
+        let config = server.config.parser;
 
         match &mut self.cst {
             TextDocumentCstKind::FullSqlFile(cst) => {
+                // Only needed to replay the edits if validation fails
+                let mut edit_trace = server
+                    .config
+                    .validate_incremental_parsing
+                    .then(EditTrace::default);
+
+                // Patches that were not applied to the CST yet. Their positions are relative to
+                // the text the CST was built from, so a multi-cursor edit only needs one reparse
+                let mut pending_patches = Vec::new();
+
                 for change in params.content_changes {
                     if let Some(lsp_range) = change.range {
                         let range = from_lsp::text_range(&self.line_index, lsp_range)?;
 
+                        let cst_range = match cst_range(&pending_patches, range) {
+                            Some(it) => it,
+                            None => {
+                                apply_patches(cst, &self.contents, &mut pending_patches, config);
+                                range
+                            }
+                        };
+
+                        if let Some(trace) = &mut edit_trace {
+                            trace.edits.push(TracedEdit {
+                                range,
+                                removed: self.contents[range].to_owned(),
+                                inserted: change.text.clone(),
+                            });
+                        }
+
                         self.contents
                             .replace_range(std::ops::Range::<usize>::from(range), &change.text);
+                        self.line_index.apply_edit(range, &change.text);
 
-                        let text_patch = TextPatch {
-                            relex_start: (),
-                            affected_node_byte_len: (),
-                            start: range.start(),
-                            size: TextSize::from(change.text.len() as u32),
-                            kind: if range.start() == range.end() {
-                                TextPatchKind::Insert
-                            } else {
-                                TextPatchKind::Replace {
-                                    end: range.end().into(),
-                                }
-                            },
-                        };
-
-                        let start = std::time::Instant::now();
-                        let text_patch = cst.updated_text_patch(text_patch);
-                        let relex_input = &self.contents[text_patch.relex_start.into()..];
-                        let new_cst = incremental_parse2(relex_input, text_patch);
-                        cst.merge_cst(new_cst, text_patch);
-                        tracing::info!("Incremental Parse Time: {}", start.elapsed().as_micros());
-                        // eprintln!("{cst}");
-                        let start = std::time::Instant::now();
-                        let batch_cst: batch::SqlCst = parse(&self.contents);
-                        eprintln!("Normal Parse Time: {}", start.elapsed().as_micros());
-                        // eprintln!("{batch_cst}");
-                        assert_eq!(cst.root().comparable(), batch_cst.root().comparable());
+                        let size = TextSize::of(change.text.as_str());
+                        add_pending_patch(&mut pending_patches, cst_range, size);
                     }
                     // No range indicates the given text represents the entire document
                     else {
                         self.contents = change.text;
+                        self.line_index = LineIndex::new(&self.contents);
+
+                        *cst = parse_with_config(&self.contents, config);
+                        pending_patches.clear();
 
-                        *cst = parse(&self.contents);
+                        // The CST no longer depends on the earlier edits
+                        edit_trace = edit_trace.map(|_| EditTrace::default());
                     }
+                }
+
+                apply_patches(cst, &self.contents, &mut pending_patches, config);
 
-                    // Rebuild index because a change may span multiple lines
-                    self.line_index = LineIndex::new(&self.contents);
+                if let Some(trace) = edit_trace {
+                    validate_incremental_parse(cst, &self.contents, &trace, config);
                 }
             }
-            TextDocumentCstKind::NonSqlFile { lang_id, .. } => {
+            TextDocumentCstKind::NonSqlFile { snippets, lang_id } => {
+                // Edits inside each snippet that were not applied to its CST yet (See
+                // `cst_range`). `None` once an edit crosses the boundary of the snippet or is made
+                // to a snippet with escape sequences, in which case the snippet is parsed again
+                let mut pending_patches = vec![Some(Vec::new()); snippets.len()];
+
+                // Edits that stay inside the contents of a snippet and do not add or remove
+                // delimiters cannot move the boundaries of any snippet. The document only has to
+                // be matched again after other edits (or after any edit if the delimiters are
+                // not known).
+                let mut rematch = !server
+                    .config
+                    .extractors
+                    .get(lang_id.as_str())
+                    .is_some_and(|it| it.has_known_delimiters());
+
                 for change in params.content_changes {
                     if let Some(lsp_range) = change.range {
                         let range = from_lsp::text_range(&self.line_index, lsp_range)?;
+                        let size = TextSize::of(change.text.as_str());
+
+                        let inside_snippet = snippets.iter().any(|it| {
+                            it.range.start() < range.start() && range.end() < it.range.end()
+                        });
+                        rematch |= !inside_snippet
+                            || has_delimiters(&change.text)
+                            || has_delimiters(&self.contents[range]);
+
+                        for (snippet, pending_patches) in
+                            snippets.iter_mut().zip(&mut pending_patches)
+                        {
+                            if range.end() < snippet.range.start() {
+                                snippet.range = snippet.range - range.len() + size;
+                            } else if snippet.range.contains_range(range) {
+                                let end = snippet.range.end() - range.len() + size;
+
+                                if snippet.source_map.has_escapes() {
+                                    *pending_patches = None;
+                                }
+
+                                if let Some(patches) = pending_patches {
+                                    // Without escapes, the SQL is the text of the snippet
+                                    let range = range - snippet.range.start();
+
+                                    let cst_range = match cst_range(patches, range) {
+                                        Some(it) => it,
+                                        None => {
+                                            let text = &self.contents[snippet.range];
+                                            apply_patches(&mut snippet.cst, text, patches, config);
+                                            range
+                                        }
+                                    };
+
+                                    add_pending_patch(patches, cst_range, size);
+                                }
+
+                                snippet.range = TextRange::new(snippet.range.start(), end);
+                            } else if range.start() <= snippet.range.end() {
+                                *pending_patches = None;
+                            }
+                        }
 
                         self.contents
                             .replace_range(std::ops::Range::<usize>::from(range), &change.text);
+                        self.line_index.apply_edit(range, &change.text);
                     }
                     // No range indicates the given text represents the entire document
                     else {
                         self.contents = change.text;
-                    };
+                        self.line_index = LineIndex::new(&self.contents);
 
-                    // Rebuild index because a change may span multiple lines
-                    self.line_index = LineIndex::new(&self.contents);
+                        snippets.clear();
+                        pending_patches.clear();
+                        rematch = true;
+                    };
                 }
 
-                self.cst = TextDocumentCstKind::new(server, lang_id.clone(), &self.contents)
+                let Some(extractor) = server.config.extractors.get(lang_id.as_str()) else {
+                    return Ok(());
+                };
+
+                // The edits may have created, removed or resized snippets, so we match again.
+                // Snippets that kept their position are reused (and reparsed incrementally if they
+                // have no escape sequences)
+                let found = if rematch {
+                    extractor.find_sql(&self.contents)
+                } else {
+                    snippets.iter().map(|it| (it.range, it.style)).collect()
+                };
+
+                let mut old_snippets = std::mem::take(snippets)
+                    .into_iter()
+                    .zip(pending_patches)
+                    .peekable();
+
+                *snippets = found
+                    .into_iter()
+                    .map(|(range, style)| {
+                        let text = &self.contents[range];
+
+                        while old_snippets
+                            .next_if(|(old, _)| old.range.start() < range.start())
+                            .is_some()
+                        {}
+
+                        match old_snippets
+                            .next_if(|(old, patches)| old.range == range && patches.is_some())
+                        {
+                            // Only the text before the snippet changed
+                            Some((mut snippet, Some(patches))) if patches.is_empty() => {
+                                snippet.source_map.move_to(range.start());
+                                snippet
+                            }
+                            old => {
+                                let (sql, source_map) =
+                                    source_map::decode(text, range.start(), style);
+
+                                match old {
+                                    Some((mut snippet, Some(mut patches)))
+                                        if !source_map.has_escapes() =>
+                                    {
+                                        apply_patches(&mut snippet.cst, &sql, &mut patches, config);
+                                        snippet.source_map = source_map;
+                                        snippet
+                                    }
+                                    _ => SqlSnippet {
+                                        range,
+                                        cst: parse_with_config(&sql, config),
+                                        source_map,
+                                        style,
+                                    },
+                                }
+                            }
+                        }
+                    })
+                    .collect();
             }
         }
 
         Ok(())
     }
 
-    pub fn update_errors(&mut self, conn: &Connection) -> anyhow::Result<()> {
+    /// Parses the document from scratch. Needed when the settings that the CST depends on
+    /// changed (Ex: `sqliteVersion` or `embeddedSql`)
+    pub fn reparse(&mut self, server: &BordLangServer) {
+        let lang_id = match &self.cst {
+            TextDocumentCstKind::FullSqlFile(_) => "sql".to_owned(),
+            TextDocumentCstKind::NonSqlFile { lang_id, .. } => lang_id.clone(),
+        };
+
+        self.cst = TextDocumentCstKind::new(server, lang_id, &self.contents);
+    }
+
+    pub fn update_errors(
+        &mut self,
+        uri: &lsp::Url,
+        config: &BordConfig,
+        conn: &Connection,
+    ) -> anyhow::Result<()> {
         let errors = match &self.cst {
-            TextDocumentCstKind::FullSqlFile(incr_sql_cst) => incr_sql_cst
-                .root()
-                .me_and_descendants()
-                .filter(|it| it.error().is_some())
-                .map(|it| {
-                    let mut range = from_lsp::node_lsp_range(&self.line_index, &it).unwrap();
-                    range.end = range.start;
-                    lsp::Diagnostic {
-                        range,
-                        severity: Some(lsp::DiagnosticSeverity::ERROR),
-                        message: format!("{:?}", it.error().unwrap()),
-                        source: Some("bordsql".into()),
-                        ..Default::default()
-                    }
-                })
-                .collect(),
-            TextDocumentCstKind::NonSqlFile { csts, lang_id } => csts
+            TextDocumentCstKind::FullSqlFile(incr_sql_cst) => features::diagnostics(
+                incr_sql_cst,
+                &self.line_index,
+                &SourceMap::default(),
+                uri,
+                config,
+            ),
+            TextDocumentCstKind::NonSqlFile { snippets, .. } => snippets
                 .iter()
                 .flat_map(|it| {
-                    it.root()
-                        .me_and_descendants()
-                        .filter(|it| it.error().is_some())
-                        .map(|it| {
-                            let range = from_lsp::lsp_range(
-                                &self.line_index,
-                                TextRange::new(
-                                    it.start_pos_skip_trivia(),
-                                    it.end_pos_skip_trivia(),
-                                )
-                                .into(),
-                            )
-                            .unwrap();
-
-                            lsp::Diagnostic {
-                                range,
-                                severity: Some(lsp::DiagnosticSeverity::ERROR),
-                                message: format!("{:?}", it.error().unwrap()),
-                                source: Some("bordsql".into()),
-                                ..Default::default()
-                            }
-                        })
+                    features::diagnostics(&it.cst, &self.line_index, &it.source_map, uri, config)
                 })
                 .collect(),
         };
@@ -220,3 +365,446 @@ impl TextDocument {
         Ok(())
     }
 }
+
+/// Whether the text could open, close or escape a string literal or a fenced code block (line
+/// breaks end single-line literals and start fences)
+fn has_delimiters(text: &str) -> bool {
+    text.contains(['"', '\'', '`', '~', '\\', '\n', '\r'])
+}
+
+/// The edits made to a SQL document since its CST was last validated. Each edit keeps the text it
+/// removed, so the text before the edits only has to be rebuilt when validation fails.
+#[derive(Debug, Default)]
+struct EditTrace {
+    edits: Vec<TracedEdit>,
+}
+
+#[derive(Debug)]
+struct TracedEdit {
+    /// Position in the text at the time of the edit
+    range: TextRange,
+    removed: String,
+    inserted: String,
+}
+
+impl EditTrace {
+    /// Undoes the edits, last to first
+    fn text_before(&self, text_after: &str) -> String {
+        let mut text = text_after.to_owned();
+
+        for edit in self.edits.iter().rev() {
+            let range = TextRange::at(edit.range.start(), TextSize::of(edit.inserted.as_str()));
+            text.replace_range(std::ops::Range::<usize>::from(range), &edit.removed);
+        }
+
+        text
+    }
+
+    /// Parses the text before the edits and reparses it incrementally after each edit
+    fn replay(&self, text_before: &str, config: ParserConfig) -> incr::IncrSqlCst {
+        let mut cst = parse_with_config(text_before, config);
+        let mut text = text_before.to_owned();
+
+        for edit in &self.edits {
+            text.replace_range(std::ops::Range::<usize>::from(edit.range), &edit.inserted);
+
+            let patch = TextPatch {
+                relex_start: (),
+                affected_node_byte_len: (),
+                start: edit.range.start(),
+                size: TextSize::of(edit.inserted.as_str()),
+                kind: if edit.range.is_empty() {
+                    TextPatchKind::Insert
+                } else {
+                    TextPatchKind::Replace {
+                        end: edit.range.end(),
+                    }
+                },
+            };
+            incremental_parse_batch(&mut cst, &text, &[patch], config);
+        }
+
+        cst
+    }
+}
+
+/// Compares the incrementally parsed CST with a full parse of `text`. On a mismatch the CST is
+/// parsed again and the edits are written to a file so they can be replayed. Returns whether the
+/// CST was right.
+fn validate_incremental_parse(
+    cst: &mut incr::IncrSqlCst,
+    text: &str,
+    trace: &EditTrace,
+    config: ParserConfig,
+) -> bool {
+    let batch_cst: batch::SqlCst = parse_with_config(text, config);
+
+    if cst.root().comparable() == batch_cst.root().comparable() {
+        return true;
+    }
+
+    let text_before = trace.text_before(text);
+    let hash = {
+        let mut hasher = std::hash::DefaultHasher::new();
+        text_before.hash(&mut hasher);
+        hasher.finish()
+    };
+    let reproduced =
+        trace.replay(&text_before, config).root().comparable() != batch_cst.root().comparable();
+
+    let path = std::env::temp_dir().join(format!("bord-incremental-parse-{hash:016x}.txt"));
+    let written = std::fs::write(&path, format!("{text_before:?}\n{:#?}", trace.edits));
+
+    // The document can be large, so only its hash and the edited ranges are logged
+    tracing::error!(
+        "Incremental parse does not match a full parse. Falling back to a full reparse. Text \
+         before the edits: {} bytes, hash {hash:016x}. Edits: {:?}. Replaying them one by one \
+         reproduces the mismatch: {reproduced}. Trace: {}",
+        text_before.len(),
+        trace
+            .edits
+            .iter()
+            .map(|it| (it.range, it.inserted.len()))
+            .collect::<Vec<_>>(),
+        match written {
+            Ok(()) => path.display().to_string(),
+            Err(err) => format!("could not be written to {} ({err})", path.display()),
+        }
+    );
+
+    *cst = parse_with_config(text, config);
+
+    false
+}
+
+/// Maps a range of the current text to the text the CST was built from. Returns `None` if the
+/// range touches one of the pending patches (the patches would have to be merged)
+fn cst_range(pending_patches: &[TextPatch<(), ()>], range: TextRange) -> Option<TextRange> {
+    // Bytes inserted and removed by the patches before the range
+    let (mut inserted, mut removed) = (TextSize::new(0), TextSize::new(0));
+
+    for patch in pending_patches {
+        let old_end = match patch.kind {
+            TextPatchKind::Insert => patch.start,
+            TextPatchKind::Replace { end } => end,
+        };
+        let new_start = patch.start - removed + inserted;
+        let new_end = new_start + patch.size;
+
+        if new_end < range.start() {
+            inserted += patch.size;
+            removed += old_end - patch.start;
+        } else if range.end() < new_start {
+            break;
+        } else {
+            return None;
+        }
+    }
+
+    Some(range + removed - inserted)
+}
+
+fn add_pending_patch(
+    pending_patches: &mut Vec<TextPatch<(), ()>>,
+    cst_range: TextRange,
+    size: TextSize,
+) {
+    let idx = pending_patches.partition_point(|it| it.start < cst_range.start());
+
+    pending_patches.insert(
+        idx,
+        TextPatch {
+            relex_start: (),
+            affected_node_byte_len: (),
+            start: cst_range.start(),
+            size,
+            kind: if cst_range.is_empty() {
+                TextPatchKind::Insert
+            } else {
+                TextPatchKind::Replace {
+                    end: cst_range.end(),
+                }
+            },
+        },
+    );
+}
+
+fn apply_patches(
+    cst: &mut incr::IncrSqlCst,
+    text: &str,
+    pending_patches: &mut Vec<TextPatch<(), ()>>,
+    config: ParserConfig,
+) {
+    if pending_patches.is_empty() {
+        return;
+    }
+
+    let start = std::time::Instant::now();
+    incremental_parse_batch(cst, text, pending_patches, config);
+    tracing::info!(
+        "Incremental Parse Time: {} ({} patches)",
+        start.elapsed().as_micros(),
+        pending_patches.len()
+    );
+
+    pending_patches.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(
+        doc: &TextDocument,
+        range: TextRange,
+        text: &str,
+    ) -> lsp::TextDocumentContentChangeEvent {
+        let range = from_lsp::lsp_range(&doc.line_index, range).unwrap();
+
+        lsp::TextDocumentContentChangeEvent {
+            range: Some(range),
+            range_length: None,
+            text: text.to_owned(),
+        }
+    }
+
+    fn open(server: &BordLangServer, uri: &lsp::Url, text: &str) -> TextDocument {
+        TextDocument::new(
+            server,
+            lsp::DidOpenTextDocumentParams {
+                text_document: lsp::TextDocumentItem {
+                    uri: uri.clone(),
+                    language_id: "rust".into(),
+                    version: 0,
+                    text: text.into(),
+                },
+            },
+        )
+    }
+
+    #[test]
+    fn snippets_are_updated_incrementally() {
+        let server = BordLangServer::new(async_lsp::ClientSocket::new_closed());
+        let uri = lsp::Url::parse("file:///main.rs").unwrap();
+
+        let mut doc = open(
+            &server,
+            &uri,
+            concat!(
+                "let a = sql!(\"SELECT a FROM t\");\n",
+                "let b = sql!(\"SELECT b FROM t WHERE b = 1\");\n",
+                "let c = sql!(\"SELECT c FROM t\");\n",
+                "let d = sql!(\"SELECT \\\"d\\\" FROM t\");\n",
+            ),
+        );
+
+        // Edits before, inside and across snippets
+        let edits = [
+            vec![("let a", "let aa")],
+            vec![
+                ("SELECT b", "SELECT bb"),
+                (" WHERE b = 1", ""),
+                ("SELECT c", "SELECT c, d"),
+            ],
+            vec![("SELECT a", "SELECT a, b")],
+            vec![("\");\nlet b", ";\nlet b")],
+            vec![
+                ("let c", "let cc"),
+                ("FROM t\");\nlet d", "FROM u\");\nlet d"),
+            ],
+            vec![(r#"\"d\""#, r#"\"dd\""#)],
+            vec![("dd", "ddd")],
+            vec![("SELECT bb", "SELECT bbb")],
+            vec![("SELECT a, b", "SELECT a, b\n")],
+            vec![("SELECT c, d", "SELECT c\", d")],
+        ];
+
+        for (version, edits) in (1..).zip(edits) {
+            // Applied from last to first (like multi-cursor edits) so the ranges stay valid
+            let content_changes = edits
+                .into_iter()
+                .rev()
+                .map(|(pat, text)| {
+                    let start = TextSize::from(doc.contents.find(pat).unwrap() as u32);
+                    change(&doc, TextRange::at(start, TextSize::of(pat)), text)
+                })
+                .collect();
+
+            doc.apply_changes(
+                &server,
+                version,
+                lsp::DidChangeTextDocumentParams {
+                    text_document: lsp::VersionedTextDocumentIdentifier {
+                        uri: uri.clone(),
+                        version,
+                    },
+                    content_changes,
+                },
+            )
+            .unwrap();
+
+            let TextDocumentCstKind::NonSqlFile { snippets, .. } = &doc.cst else {
+                unreachable!()
+            };
+            let TextDocumentCstKind::NonSqlFile {
+                snippets: expected, ..
+            } = TextDocumentCstKind::new(&server, "rust".into(), &doc.contents)
+            else {
+                unreachable!()
+            };
+
+            assert_eq!(snippets.len(), expected.len(), "{}", doc.contents);
+            for (snippet, expected) in snippets.iter().zip(&expected) {
+                assert_eq!(snippet.range, expected.range);
+                assert_eq!(snippet.source_map, expected.source_map);
+                assert_eq!(
+                    snippet.cst.root().comparable(),
+                    expected.cst.root().comparable()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn settings_changes_reparse_the_document() {
+        let mut server = BordLangServer::new(async_lsp::ClientSocket::new_closed());
+        let uri = lsp::Url::parse("file:///main.sql").unwrap();
+        let conn = Connection::open_in_memory().unwrap();
+
+        let mut doc = TextDocument::new(
+            &server,
+            lsp::DidOpenTextDocumentParams {
+                text_document: lsp::TextDocumentItem {
+                    uri: uri.clone(),
+                    language_id: "sql".into(),
+                    version: 0,
+                    text: "SELECT a FROM {{ ref('t') }};".into(),
+                },
+            },
+        );
+        doc.update_errors(&uri, &server.config, &conn).unwrap();
+        assert!(!doc.errors.is_empty());
+
+        // Template tags are only recognized by the lexer when they are turned on
+        server
+            .config
+            .update_from_json(&serde_json::json!({ "templates": true }));
+        doc.reparse(&server);
+        doc.update_errors(&uri, &server.config, &conn).unwrap();
+
+        assert!(doc.errors.is_empty(), "{:#?}", doc.errors);
+    }
+
+    #[test]
+    fn wrong_incremental_parses_fall_back_to_a_full_parse() {
+        let config = ParserConfig::default();
+        let text_before = "SELECT a FROM t;\nSELECT c FROM u;";
+        let text = "SELECT a, b FROM t;\nSELECT c FROM u WHERE c;";
+
+        let mut trace = EditTrace::default();
+        for (pos, removed, inserted) in [(8, "", ", b"), (35, "", " WHERE c")] {
+            trace.edits.push(TracedEdit {
+                range: TextRange::at(TextSize::new(pos), TextSize::of(removed)),
+                removed: removed.to_owned(),
+                inserted: inserted.to_owned(),
+            });
+        }
+
+        // A CST that missed the edits
+        let mut cst: incr::IncrSqlCst = parse_with_config(text_before, config);
+        assert!(!validate_incremental_parse(&mut cst, text, &trace, config));
+
+        let expected: batch::SqlCst = parse_with_config(text, config);
+        assert_eq!(cst.root().comparable(), expected.root().comparable());
+
+        assert_eq!(trace.text_before(text), text_before);
+        let replayed = trace.replay(text_before, config);
+        assert_eq!(replayed.root().to_text(), text);
+        assert!(validate_incremental_parse(&mut cst, text, &trace, config));
+    }
+
+    #[test]
+    fn template_substitutions_have_no_diagnostics() {
+        let server = BordLangServer::new(async_lsp::ClientSocket::new_closed());
+        let uri = lsp::Url::parse("file:///main.ts").unwrap();
+
+        let mut doc = TextDocument::new(
+            &server,
+            lsp::DidOpenTextDocumentParams {
+                text_document: lsp::TextDocumentItem {
+                    uri: uri.clone(),
+                    language_id: "typescript".into(),
+                    version: 0,
+                    text: "const rows = await sql`SELECT a FROM t WHERE id = ${id} AND b IN \
+                           (${ids.map((it) => { return it.id; })})`;"
+                        .into(),
+                },
+            },
+        );
+
+        let conn = Connection::open_in_memory().unwrap();
+        doc.update_errors(&uri, &server.config, &conn).unwrap();
+
+        assert!(doc.errors.is_empty(), "{:#?}", doc.errors);
+    }
+
+    #[test]
+    fn snippets_of_user_patterns_are_matched_after_every_edit() {
+        let mut server = BordLangServer::new(async_lsp::ClientSocket::new_closed());
+        server.config.update_from_json(&serde_json::json!({
+            "embeddedSql": {
+                "rust": {
+                    "profile": false,
+                    "patterns": [{ "regex": "query\\((?<sql>[^)]*)\\)" }]
+                }
+            }
+        }));
+        let uri = lsp::Url::parse("file:///main.rs").unwrap();
+
+        let mut doc = open(&server, &uri, "query(SELECT a FROM t WHERE b = 1);");
+
+        // The pattern ends at the `)`
+        let start = TextSize::from(doc.contents.find(" = 1").unwrap() as u32);
+        let content_changes = vec![change(&doc, TextRange::empty(start), ")")];
+        doc.apply_changes(
+            &server,
+            1,
+            lsp::DidChangeTextDocumentParams {
+                text_document: lsp::VersionedTextDocumentIdentifier {
+                    uri: uri.clone(),
+                    version: 1,
+                },
+                content_changes,
+            },
+        )
+        .unwrap();
+
+        let TextDocumentCstKind::NonSqlFile { snippets, .. } = &doc.cst else {
+            unreachable!()
+        };
+        let ranges: Vec<_> = snippets.iter().map(|it| &doc.contents[it.range]).collect();
+        assert_eq!(ranges, ["SELECT a FROM t WHERE b"]);
+    }
+
+    #[test]
+    fn snippet_diagnostics_are_mapped_through_escapes() {
+        let server = BordLangServer::new(async_lsp::ClientSocket::new_closed());
+        let uri = lsp::Url::parse("file:///main.rs").unwrap();
+
+        let text = r#"let q = sql!("SELECT \"a\"\n ! FROM t");"#;
+        let mut doc = open(&server, &uri, text);
+
+        let conn = Connection::open_in_memory().unwrap();
+        doc.update_errors(&uri, &server.config, &conn).unwrap();
+
+        let unknown_token = TextSize::from(text.rfind('!').unwrap() as u32);
+        let range = TextRange::at(unknown_token, TextSize::of("!"));
+        let range = from_lsp::lsp_range(&doc.line_index, range).unwrap();
+
+        assert!(
+            doc.errors.iter().any(|it| it.range == range),
+            "{:#?}",
+            doc.errors
+        );
+    }
+}
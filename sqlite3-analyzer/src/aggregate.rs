@@ -0,0 +1,373 @@
+//! Misuse of aggregate and window functions (Ex: `WHERE count(*) > 1`). Most of these are errors
+//! that SQLite only reports when the statement is prepared.
+
+use bord_sqlite3_parser::{CstNodeTrait, SqliteTreeKind};
+
+use crate::check::{first_token, trimmed_range, trimmed_text, unquoted, Finding};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FunctionKind {
+    Scalar,
+    /// Can also be used as a window function
+    Aggregate,
+    /// Can only be used as a window function (Ex: `row_number()`)
+    Window,
+}
+
+#[rustfmt::skip]
+const AGGREGATE_FUNCTIONS: &[&str] = &[
+    "avg", "count", "group_concat", "max", "min", "string_agg", "sum", "total",
+    "json_group_array", "jsonb_group_array", "json_group_object", "jsonb_group_object", "median",
+    "percentile", "percentile_cont", "percentile_disc",
+];
+
+#[rustfmt::skip]
+const WINDOW_FUNCTIONS: &[&str] = &[
+    "row_number", "rank", "dense_rank", "percent_rank", "cume_dist", "ntile", "lag", "lead",
+    "first_value", "last_value", "nth_value",
+];
+
+#[rustfmt::skip]
+const SCALAR_FUNCTIONS: &[&str] = &[
+    // Core functions
+    "abs", "changes", "char", "coalesce", "concat", "concat_ws", "format", "glob", "hex",
+    "ifnull", "iif", "instr", "last_insert_rowid", "length", "like", "likelihood", "likely",
+    "load_extension", "lower", "ltrim", "max", "min", "nullif", "octet_length", "printf", "quote",
+    "random", "randomblob", "replace", "round", "rtrim", "sign", "soundex",
+    "sqlite_compileoption_get", "sqlite_compileoption_used", "sqlite_offset", "sqlite_source_id",
+    "sqlite_version", "substr", "substring", "total_changes", "trim", "typeof", "unhex",
+    "unicode", "unlikely", "upper", "zeroblob",
+    // Date and time functions
+    "date", "time", "datetime", "julianday", "unixepoch", "strftime", "timediff",
+    // Math functions
+    "acos", "acosh", "asin", "asinh", "atan", "atan2", "atanh", "ceil", "ceiling", "cos", "cosh",
+    "degrees", "exp", "floor", "ln", "log", "log10", "log2", "mod", "pi", "pow", "power",
+    "radians", "sin", "sinh", "sqrt", "tan", "tanh", "trunc",
+    // JSON functions
+    "json", "jsonb", "json_array", "jsonb_array", "json_array_length", "json_error_position",
+    "json_extract", "jsonb_extract", "json_insert", "jsonb_insert", "json_object",
+    "jsonb_object", "json_patch", "jsonb_patch", "json_pretty", "json_quote", "json_remove",
+    "jsonb_remove", "json_replace", "jsonb_replace", "json_set", "jsonb_set", "json_type",
+    "json_valid",
+];
+
+/// Kind of the built-in function. `None` for functions we do not know about (Ex: application
+/// defined functions), which can be any of them.
+fn function_kind(name: &str, arg_count: usize) -> Option<FunctionKind> {
+    let name = name.to_ascii_lowercase();
+    let is = |functions: &[&str]| functions.contains(&name.as_str());
+
+    // `max(a, b)` and `min(a, b)` are scalar
+    if is(AGGREGATE_FUNCTIONS) && !(matches!(name.as_str(), "max" | "min") && arg_count > 1) {
+        Some(FunctionKind::Aggregate)
+    } else if is(WINDOW_FUNCTIONS) {
+        Some(FunctionKind::Window)
+    } else if is(SCALAR_FUNCTIONS) {
+        Some(FunctionKind::Scalar)
+    } else {
+        None
+    }
+}
+
+/// The name and kind of the function if the node is a function call
+fn function_call<'a, N: CstNodeTrait<'a>>(node: N) -> Option<(String, Option<FunctionKind>)> {
+    use SqliteTreeKind::*;
+
+    if node.tree()? != ExprFunc {
+        return None;
+    }
+
+    let name = first_token(node.find_children(FunctionName).next()?)?
+        .token()?
+        .text
+        .to_string();
+    let arg_count = node
+        .find_children(FuncArguments)
+        .next()
+        .and_then(|it| it.find_children(ArgExpr).next())
+        .map_or(0, |it| it.find_children(Expr).count());
+    let kind = function_kind(&name, arg_count);
+
+    Some((name, kind))
+}
+
+/// `count(*)` but not `count(*) OVER ()`
+fn is_aggregate_call<'a, N: CstNodeTrait<'a>>(node: N) -> bool {
+    matches!(
+        function_call(node),
+        Some((_, Some(FunctionKind::Aggregate)))
+    ) && node
+        .find_children(SqliteTreeKind::OverClause)
+        .next()
+        .is_none()
+}
+
+fn is_window_call<'a, N: CstNodeTrait<'a>>(node: N) -> bool {
+    node.tree() == Some(SqliteTreeKind::ExprFunc)
+        && node
+            .find_children(SqliteTreeKind::OverClause)
+            .next()
+            .is_some()
+}
+
+/// The clause of the query the expression is part of (Ex: `WhereClause`), or the aggregate call
+/// it is an argument of. Expressions in subqueries belong to the clauses of the subquery.
+fn context<'a, N: CstNodeTrait<'a>>(node: N) -> Option<N> {
+    use SqliteTreeKind::*;
+
+    node.ancestors().find(|it| match it.tree() {
+        // The condition of `FILTER (WHERE ...)` belongs to the aggregate call
+        Some(WhereClause) => it.parent().tree() != Some(FilterClause),
+        Some(ExprFunc) => is_aggregate_call(*it),
+        Some(
+            GroupByClause | HavingClause | ResultColumnList | OrderByClause | OverClause
+            | JoinConstraint | TraditionalSelect | ValuesSelect | SelectStmt | Statement,
+        ) => true,
+        _ => false,
+    })
+}
+
+pub(crate) fn aggregate_in_where<'a, N: CstNodeTrait<'a>>(node: N) -> Option<Finding> {
+    if !is_aggregate_call(node) || context(node)?.tree()? != SqliteTreeKind::WhereClause {
+        return None;
+    }
+
+    Some((
+        trimmed_range(node),
+        "aggregate functions are not allowed in WHERE, use HAVING instead".to_owned(),
+        None,
+    ))
+}
+
+pub(crate) fn nested_aggregate<'a, N: CstNodeTrait<'a>>(node: N) -> Option<Finding> {
+    if !is_aggregate_call(node) {
+        return None;
+    }
+
+    let outer = context(node).filter(|it| it.tree() == Some(SqliteTreeKind::ExprFunc))?;
+    let (outer_name, _) = function_call(outer)?;
+
+    Some((
+        trimmed_range(node),
+        format!("aggregate functions cannot be nested inside `{outer_name}()`"),
+        None,
+    ))
+}
+
+/// Columns of a grouped query that are neither grouped by nor aggregated
+pub(crate) fn bare_column<'a, N: CstNodeTrait<'a>>(node: N) -> Option<Finding> {
+    use SqliteTreeKind::*;
+
+    if node.tree()? != ExprColumnName {
+        return None;
+    }
+
+    let clause = context(node)?;
+    if !matches!(clause.tree()?, ResultColumnList | HavingClause) {
+        return None;
+    }
+
+    let select = clause.parent();
+    let result_columns: Vec<_> = select
+        .find_children(ResultColumnList)
+        .flat_map(|it| it.find_children(ResultColumn))
+        .flat_map(|it| it.valid_children().next())
+        .collect();
+
+    // `GROUP BY 1` and `GROUP BY alias` group by the expression of the result column
+    let mut group_by: Vec<_> = select
+        .find_children(GroupByClause)
+        .flat_map(|it| it.find_children(Expr))
+        .collect();
+    let referenced: Vec<_> = group_by
+        .iter()
+        .filter_map(|it| referenced_result_column(*it, &result_columns))
+        .collect();
+    group_by.extend(referenced);
+
+    let is_grouped = !group_by.is_empty()
+        || select.me_and_descendants().any(|it| {
+            is_aggregate_call(it)
+                && context(it).is_some_and(|ctx| {
+                    matches!(ctx.tree(), Some(ResultColumnList | HavingClause))
+                        && ctx.parent().equals(&select)
+                })
+        });
+
+    if !is_grouped {
+        return None;
+    }
+
+    let column = unquoted(&trimmed_text(node.find_children(ColumnName).next()?));
+
+    // The column itself or an expression containing it (Ex: `lower(name)`) is grouped by
+    let grouped_exprs: Vec<_> = group_by.iter().map(|it| normalized(*it)).collect();
+    let is_grouped_by = node
+        .ancestors()
+        .take_while(|it| !it.equals(&clause))
+        .filter(|it| it.tree() == Some(Expr))
+        .any(|it| grouped_exprs.contains(&normalized(it)))
+        || group_by.iter().any(|it| {
+            it.find_children(ExprColumnName)
+                .next()
+                .and_then(|it| it.find_children(ColumnName).next())
+                .is_some_and(|it| unquoted(&trimmed_text(it)).eq_ignore_ascii_case(&column))
+        });
+
+    // HAVING can refer to the aliases of the result columns
+    let is_alias = clause.tree() == Some(HavingClause)
+        && node.find_children(TableName).next().is_none()
+        && select
+            .find_children(ResultColumnList)
+            .flat_map(|it| it.me_and_descendants())
+            .filter(|it| it.tree() == Some(AliasName))
+            .any(|it| unquoted(&trimmed_text(it)).eq_ignore_ascii_case(&column));
+
+    if is_grouped_by || is_alias {
+        return None;
+    }
+
+    Some((
+        trimmed_range(node),
+        format!(
+            "`{column}` is not in GROUP BY or inside an aggregate function, its value comes from \
+            an arbitrary row of the group"
+        ),
+        None,
+    ))
+}
+
+/// The expression of the result column that `GROUP BY 2` or `GROUP BY alias` refers to
+fn referenced_result_column<'a, N: CstNodeTrait<'a>>(expr: N, result_columns: &[N]) -> Option<N> {
+    use SqliteTreeKind::*;
+
+    let result_column = if let Some(literal) = expr.find_children(ExprLit).next() {
+        let position: usize = first_token(literal)?.token()?.text.parse().ok()?;
+        *result_columns.get(position.checked_sub(1)?)?
+    } else {
+        let name = expr
+            .find_children(ExprColumnName)
+            .next()
+            .filter(|it| it.find_children(TableName).next().is_none())
+            .and_then(|it| it.find_children(ColumnName).next())?;
+        let name = unquoted(&trimmed_text(name));
+
+        *result_columns.iter().find(|it| {
+            it.find_children(WithAlias)
+                .next()
+                .and_then(|it| it.find_children(AliasName).next())
+                .is_some_and(|alias| unquoted(&trimmed_text(alias)).eq_ignore_ascii_case(&name))
+        })?
+    };
+
+    result_column.find_children(Expr).next()
+}
+
+pub(crate) fn having_without_aggregate<'a, N: CstNodeTrait<'a>>(node: N) -> Option<Finding> {
+    use SqliteTreeKind::*;
+
+    if node.tree()? != HavingClause {
+        return None;
+    }
+
+    let has_aggregate = node
+        .me_and_descendants()
+        .any(|it| is_aggregate_call(it) && context(it).is_some_and(|ctx| ctx.equals(&node)));
+
+    // The condition might refer to an aggregate through its alias (Ex: `HAVING total > 10`)
+    let aliases: Vec<_> = node
+        .parent()
+        .find_children(ResultColumnList)
+        .flat_map(|it| it.me_and_descendants())
+        .filter(|it| it.tree() == Some(AliasName))
+        .map(|it| unquoted(&trimmed_text(it)))
+        .collect();
+    let uses_alias = node.me_and_descendants().any(|it| {
+        it.tree() == Some(ExprColumnName)
+            && it.find_children(TableName).next().is_none()
+            && it.find_children(ColumnName).next().is_some_and(|name| {
+                let name = unquoted(&trimmed_text(name));
+                aliases
+                    .iter()
+                    .any(|alias| alias.eq_ignore_ascii_case(&name))
+            })
+    });
+
+    if has_aggregate || uses_alias || node.has_errors() {
+        return None;
+    }
+
+    Some((
+        trimmed_range(node),
+        "HAVING without an aggregate function, the condition belongs in WHERE".to_owned(),
+        None,
+    ))
+}
+
+pub(crate) fn misplaced_window_function<'a, N: CstNodeTrait<'a>>(node: N) -> Option<Finding> {
+    use SqliteTreeKind::*;
+
+    if !is_window_call(node) {
+        return None;
+    }
+
+    let is_allowed = context(node).is_some_and(|it| match it.tree() {
+        Some(ResultColumnList) => true,
+        // The ORDER BY of the query, not the one of a window definition or an aggregate
+        Some(OrderByClause) => it.parent().tree() == Some(SelectStmt),
+        _ => false,
+    });
+
+    if is_allowed {
+        return None;
+    }
+
+    let (name, _) = function_call(node)?;
+
+    Some((
+        trimmed_range(node),
+        format!(
+            "`{name}()` is a window function, which is only allowed in the result columns and \
+            ORDER BY"
+        ),
+        None,
+    ))
+}
+
+pub(crate) fn filter_on_non_aggregate<'a, N: CstNodeTrait<'a>>(node: N) -> Option<Finding> {
+    let (name, kind) = function_call(node)?;
+    let filter = node.find_children(SqliteTreeKind::FilterClause).next()?;
+
+    if matches!(kind?, FunctionKind::Aggregate) {
+        return None;
+    }
+
+    Some((
+        trimmed_range(filter),
+        format!("FILTER can only be used with aggregate functions and `{name}()` is not one"),
+        None,
+    ))
+}
+
+pub(crate) fn over_on_non_window_function<'a, N: CstNodeTrait<'a>>(node: N) -> Option<Finding> {
+    let (name, kind) = function_call(node)?;
+    let over = node.find_children(SqliteTreeKind::OverClause).next()?;
+
+    if kind? != FunctionKind::Scalar {
+        return None;
+    }
+
+    Some((
+        trimmed_range(over),
+        format!("`{name}()` is not a window function"),
+        None,
+    ))
+}
+
+/// The expression without whitespace and in lower case so `lower( Name )` matches `LOWER(name)`
+fn normalized<'a, N: CstNodeTrait<'a>>(expr: N) -> String {
+    expr.to_text()
+        .split_whitespace()
+        .collect::<String>()
+        .to_ascii_lowercase()
+}
@@ -0,0 +1,127 @@
+//! Refactorings offered at the cursor (Ex: extracting a subquery into a CTE). Unlike the fixes of
+//! [`crate::Lint`]s they do not correct anything, they write the same query in another way.
+
+use bord_sqlite3_parser::{CstNodeTrait, TextEdit};
+
+use crate::cte;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssistKind {
+    Extract,
+    Inline,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assist {
+    pub kind: AssistKind,
+    pub title: String,
+    pub edits: Vec<TextEdit>,
+}
+
+/// Assists for the token at the cursor (or the smallest node that covers the selection)
+pub fn assists<'a, N: CstNodeTrait<'a>>(node: N) -> Vec<Assist> {
+    [cte::extract_subquery_into_cte(node), cte::inline_cte(node)]
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use bord_sqlite3_parser::{batch, parse, CstTrait};
+    use text_size::{TextRange, TextSize};
+
+    use super::*;
+
+    /// `|` marks the cursor
+    fn apply_assist(text: &str, kind: AssistKind) -> Option<String> {
+        let cursor = TextSize::new(text.find('|').unwrap() as u32);
+        let text = text.replace('|', "");
+        let cst: batch::SqlCst = parse(&text);
+
+        let assist = assists(cst.covering_element(TextRange::empty(cursor)))
+            .into_iter()
+            .find(|it| it.kind == kind)?;
+
+        let mut text = text.clone();
+        for edit in assist.edits.iter().rev() {
+            text.replace_range(std::ops::Range::<usize>::from(edit.range), &edit.new_text);
+        }
+
+        Some(text)
+    }
+
+    #[test]
+    fn subqueries_are_extracted_into_ctes() {
+        assert_eq!(
+            apply_assist(
+                "SELECT * FROM t JOIN (SELECT a FROM |u) AS s ON s.a = t.a;",
+                AssistKind::Extract
+            )
+            .as_deref(),
+            Some("WITH s AS (SELECT a FROM u) SELECT * FROM t JOIN s ON s.a = t.a;")
+        );
+        assert_eq!(
+            apply_assist(
+                "WITH subquery AS (SELECT 1) SELECT * FROM subquery, |(SELECT 2);",
+                AssistKind::Extract
+            )
+            .as_deref(),
+            Some(
+                "WITH subquery AS (SELECT 1), subquery2 AS (SELECT 2) SELECT * FROM subquery, \
+                subquery2;"
+            )
+        );
+        assert_eq!(
+            apply_assist(
+                "WITH c AS (SELECT * FROM (SELECT 1) |u) SELECT * FROM c AS u;",
+                AssistKind::Extract
+            )
+            .as_deref(),
+            Some("WITH u AS (SELECT 1), c AS (SELECT * FROM u) SELECT * FROM c AS u;")
+        );
+        assert_eq!(
+            apply_assist(
+                "SELECT * FROM (SELECT 1) AS |t JOIN t AS x;",
+                AssistKind::Extract
+            )
+            .as_deref(),
+            Some("WITH t2 AS (SELECT 1) SELECT * FROM t2 AS t JOIN t AS x;")
+        );
+        assert_eq!(apply_assist("SELECT * FROM |t;", AssistKind::Extract), None);
+    }
+
+    #[test]
+    fn single_use_ctes_are_inlined() {
+        assert_eq!(
+            apply_assist(
+                "WITH |c AS (SELECT a FROM t) SELECT c.a FROM c;",
+                AssistKind::Inline
+            )
+            .as_deref(),
+            Some("SELECT c.a FROM (SELECT a FROM t) AS c;")
+        );
+        assert_eq!(
+            apply_assist(
+                "WITH a AS (SELECT 1), b AS (SELECT 2) SELECT * FROM a x WHERE 1 IN |b;",
+                AssistKind::Inline
+            )
+            .as_deref(),
+            Some("WITH a AS (SELECT 1) SELECT * FROM a x WHERE 1 IN (SELECT 2);")
+        );
+        assert_eq!(
+            apply_assist(
+                "WITH |c AS (SELECT 1) SELECT * FROM c, c;",
+                AssistKind::Inline
+            ),
+            None
+        );
+        assert_eq!(
+            apply_assist(
+                "WITH |c(x) AS (SELECT 1) SELECT * FROM c;",
+                AssistKind::Inline
+            ),
+            None
+        );
+    }
+}
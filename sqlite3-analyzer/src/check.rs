@@ -0,0 +1,277 @@
+//! The checks behind each [`crate::LintRule`] (except for the ones in [`crate::aggregate`]). Every
+//! check looks at a single node and returns what it found there.
+
+use bord_sqlite3_parser::{CstNodeTrait, SqliteTokenKind, SqliteTreeKind, TextEdit};
+use text_size::TextRange;
+
+use crate::LintFix;
+
+/// Range, message and fix of a lint
+pub(crate) type Finding = (TextRange, String, Option<LintFix>);
+
+pub(crate) fn update_delete_without_where<'a, N: CstNodeTrait<'a>>(node: N) -> Option<Finding> {
+    use SqliteTreeKind::*;
+
+    let (stmt, effect) = match node.tree()? {
+        UpdateStmt => ("UPDATE", "changes"),
+        DeleteStmt => ("DELETE", "deletes"),
+        _ => return None,
+    };
+
+    // LIMIT without WHERE is most likely deliberate (Ex: trimming a log table)
+    let is_filtered = node.children().any(|it| {
+        matches!(
+            it.tree(),
+            Some(WhereClause | UpdateStmtLimited | DeleteStmtLimited)
+        )
+    });
+
+    if is_filtered || node.has_errors() {
+        return None;
+    }
+
+    let table = node.find_children(QualifiedTableName).next()?;
+
+    Some((
+        TextRange::new(node.start_pos_skip_trivia(), table.end_pos_skip_trivia()),
+        format!("`{stmt}` without `WHERE` {effect} every row of the table"),
+        None,
+    ))
+}
+
+pub(crate) fn null_comparison<'a, N: CstNodeTrait<'a>>(node: N) -> Option<Finding> {
+    let replacement = match node.tree()? {
+        SqliteTreeKind::OpEq => "IS",
+        SqliteTreeKind::OpNotEq => "IS NOT",
+        _ => return None,
+    };
+
+    let null = node
+        .find_children(SqliteTreeKind::Expr)
+        .find_map(null_literal)?;
+    let op = node.children().find(|it| it.is_non_trivia_token())?;
+
+    // Respect the casing style of the user
+    let replacement = match null.token()?.text.chars().any(|it| it.is_ascii_uppercase()) {
+        true => replacement.to_owned(),
+        false => replacement.to_ascii_lowercase(),
+    };
+    let op_text = &op.token()?.text;
+
+    Some((
+        trimmed_range(node),
+        format!("comparing with NULL using `{op_text}` is never true, use `{replacement} NULL`"),
+        Some(LintFix {
+            title: format!("Replace `{op_text}` with `{replacement}`"),
+            edits: vec![TextEdit {
+                range: token_range(op),
+                new_text: spaced(op, &replacement),
+            }],
+        }),
+    ))
+}
+
+pub(crate) fn double_quoted_string<'a, N: CstNodeTrait<'a>>(node: N) -> Option<Finding> {
+    use SqliteTreeKind::*;
+
+    let name = match node.tree()? {
+        DefaultConstraintIden => first_token(node)?,
+        // Qualified names (Ex: `t."a"`) are always columns
+        ExprColumnName if node.children().all(|it| it.tree() != Some(TableName)) => {
+            first_token(node.find_children(ColumnName).next()?)?
+        }
+        _ => return None,
+    };
+
+    let text = &name.token()?.text;
+    let contents = text.strip_prefix('"')?.strip_suffix('"')?;
+
+    if node.tree() == Some(ExprColumnName) && has_columns_in_scope(node) {
+        return None;
+    }
+
+    let literal = format!("'{}'", contents.replace("\"\"", "\"").replace('\'', "''"));
+
+    Some((
+        token_range(name),
+        format!("{text} is not a column and is treated as the string {literal}"),
+        Some(LintFix {
+            title: format!("Replace with {literal}"),
+            edits: vec![TextEdit {
+                range: token_range(name),
+                new_text: literal,
+            }],
+        }),
+    ))
+}
+
+pub(crate) fn implicit_cross_join<'a, N: CstNodeTrait<'a>>(node: N) -> Option<Finding> {
+    if node.tree()? != SqliteTreeKind::JoinOperator {
+        return None;
+    }
+
+    let comma = node
+        .children()
+        .find(|it| it.token_kind() == Some(SqliteTokenKind::COMMA))?;
+
+    Some((
+        token_range(comma),
+        "implicit cross join, use `JOIN ... ON` to join the tables".to_owned(),
+        Some(LintFix {
+            title: "Replace `,` with `JOIN`".to_owned(),
+            edits: vec![TextEdit {
+                range: token_range(comma),
+                new_text: spaced(comma, "JOIN"),
+            }],
+        }),
+    ))
+}
+
+pub(crate) fn unneeded_autoincrement<'a, N: CstNodeTrait<'a>>(node: N) -> Option<Finding> {
+    if node.token_kind()? != SqliteTokenKind::KW_AUTOINCREMENT {
+        return None;
+    }
+
+    // No fix: without AUTOINCREMENT, the rowids of deleted rows can be reused, which only the
+    // user knows to be fine
+    Some((
+        token_range(node),
+        "AUTOINCREMENT is only needed if the rowids of deleted rows must never be reused"
+            .to_owned(),
+        None,
+    ))
+}
+
+pub(crate) fn select_star_in_view<'a, N: CstNodeTrait<'a>>(node: N) -> Option<Finding> {
+    use SqliteTreeKind::*;
+
+    if !matches!(node.tree()?, ResultColumnAll | ResultColumnTableAll) {
+        return None;
+    }
+
+    // Only the columns of the view itself (`*` in subqueries does not leak into the view)
+    let owner = node.ancestors().find(|it| {
+        !matches!(
+            it.tree(),
+            Some(
+                ResultColumn
+                    | ResultColumnList
+                    | TraditionalSelect
+                    | SelectCore
+                    | CompoundSelect
+                    | SelectStmt
+                    | SelectStmtWithCte
+            )
+        )
+    })?;
+
+    (owner.tree() == Some(CreateViewStmt)).then(|| {
+        (
+            trimmed_range(node),
+            "`*` in a view, list the columns explicitly".to_owned(),
+            None,
+        )
+    })
+}
+
+pub(crate) fn leading_wildcard_like<'a, N: CstNodeTrait<'a>>(node: N) -> Option<Finding> {
+    if !matches!(
+        node.tree()?,
+        SqliteTreeKind::OpLike | SqliteTreeKind::OpNotLike
+    ) {
+        return None;
+    }
+
+    let pattern = node
+        .find_children(SqliteTreeKind::Expr)
+        .nth(1)
+        .and_then(|it| it.find_children(SqliteTreeKind::ExprLit).next())
+        .and_then(first_token)?;
+
+    let text = &pattern.token()?.text;
+    if pattern.token_kind()? != SqliteTokenKind::STR_LIT
+        || !(text.starts_with("'%") || text.starts_with("'_"))
+    {
+        return None;
+    }
+
+    Some((
+        token_range(pattern),
+        "LIKE pattern starts with a wildcard and cannot use an index".to_owned(),
+        None,
+    ))
+}
+
+/// Whether an unqualified column name could refer to a column at this point. SQLite only turns
+/// double-quoted names into strings when they do not.
+fn has_columns_in_scope<'a, N: CstNodeTrait<'a>>(node: N) -> bool {
+    use SqliteTreeKind::*;
+
+    for ancestor in node.ancestors() {
+        match ancestor.tree() {
+            Some(TraditionalSelect) if ancestor.find_children(FromClause).next().is_some() => {
+                return true
+            }
+            // Can refer to the aliases of the result columns
+            Some(OrderByClause) => return true,
+            Some(
+                UpdateStmt | DeleteStmt | ReturningClause | UpsertClause | CreateTableStmt
+                | CreateIndexStmt | CreateTriggerStmt | AlterTableStmt,
+            ) => return true,
+            Some(Statement) => return false,
+            _ => {}
+        }
+    }
+
+    false
+}
+
+/// The expression if it is just `NULL`
+fn null_literal<'a, N: CstNodeTrait<'a>>(expr: N) -> Option<N> {
+    first_token(expr.find_children(SqliteTreeKind::ExprLit).next()?)
+        .filter(|it| it.token_kind() == Some(SqliteTokenKind::KW_NULL))
+}
+
+/// `text` with spaces added where the token it replaces touches its neighbours (Ex: `a=NULL`)
+fn spaced<'a, N: CstNodeTrait<'a>>(token: N, text: &str) -> String {
+    let touches_prev = token
+        .prev_token_skip_trivia()
+        .is_some_and(|it| it.end_pos() == token.start_pos());
+    let touches_next = token
+        .next_token_skip_trivia()
+        .is_some_and(|it| it.start_pos() == token.end_pos());
+
+    format!(
+        "{}{text}{}",
+        if touches_prev { " " } else { "" },
+        if touches_next { " " } else { "" }
+    )
+}
+
+pub(crate) fn first_token<'a, N: CstNodeTrait<'a>>(node: N) -> Option<N> {
+    node.me_and_descendants()
+        .find(|it| it.is_non_trivia_token())
+}
+
+fn token_range<'a, N: CstNodeTrait<'a>>(token: N) -> TextRange {
+    TextRange::new(token.start_pos(), token.end_pos())
+}
+
+pub(crate) fn trimmed_range<'a, N: CstNodeTrait<'a>>(node: N) -> TextRange {
+    TextRange::new(node.start_pos_skip_trivia(), node.end_pos_skip_trivia())
+}
+
+pub(crate) fn trimmed_text<'a, N: CstNodeTrait<'a>>(node: N) -> String {
+    node.to_text().trim().to_owned()
+}
+
+/// The name without its quotes (Ex: `"user id"`)
+pub(crate) fn unquoted(name: &str) -> String {
+    match name.chars().next() {
+        Some(quote @ ('"' | '`')) if name.len() > 1 => {
+            name[1..name.len() - 1].replace(&format!("{quote}{quote}"), &quote.to_string())
+        }
+        Some('[') if name.len() > 1 => name[1..name.len() - 1].to_owned(),
+        _ => name.to_owned(),
+    }
+}
@@ -0,0 +1,416 @@
+//! Common table expressions (`WITH c AS (...)`), the tables that refer to them and the
+//! refactorings that move a query in or out of a CTE.
+
+use bord_sqlite3_parser::{CstNodeTrait, CstRewriter, SqliteTokenKind, SqliteTreeKind, TextEdit};
+use text_size::TextRange;
+
+use crate::{
+    check::{trimmed_range, trimmed_text, unquoted, Finding},
+    Assist, AssistKind, LintFix,
+};
+
+/// The CTE (`c AS (...)`) that a table name (Ex: `FROM c` or `x IN c`) refers to, if any
+pub(crate) fn cte_definition<'a, N: CstNodeTrait<'a>>(name: N) -> Option<N> {
+    use SqliteTreeKind::*;
+
+    if name.tree()? != TableName || name.is_root() {
+        return None;
+    }
+
+    // Schema-qualified names always refer to real tables
+    let full_name = name.parent();
+    if full_name.tree() != Some(FullTableName)
+        || full_name.find_children(SchemaName).next().is_some()
+        || full_name.is_root()
+    {
+        return None;
+    }
+
+    let is_table_source = match full_name.parent().tree() {
+        Some(QualifiedTableName) => full_name.parent().parent().tree() == Some(TableOrSubquery),
+        Some(InTable) => true,
+        _ => false,
+    };
+    if !is_table_source {
+        return None;
+    }
+
+    let text = unquoted(&trimmed_text(name));
+
+    // Inner WITH clauses shadow the outer ones
+    name.ancestors()
+        .filter_map(|it| it.find_children(CteClause).next())
+        .flat_map(|it| it.find_children(CommonTableExpr))
+        .find(|it| cte_name(*it).is_some_and(|it| it.eq_ignore_ascii_case(&text)))
+}
+
+pub(crate) fn invalid_recursive_cte<'a, N: CstNodeTrait<'a>>(node: N) -> Option<Finding> {
+    use SqliteTreeKind::*;
+
+    let cte = cte_definition(node)?;
+    if !is_inside(node, cte) {
+        return None;
+    }
+
+    let name = trimmed_text(node);
+    let body = cte
+        .find_children(SelectStmtWithCte)
+        .next()?
+        .find_children(SelectStmt)
+        .next()?;
+    let select = node.ancestors().find(|it| it.tree() == Some(SelectStmt))?;
+    let arm = node.ancestors().find(|it| it.tree() == Some(SelectCore))?;
+
+    let message = if !select.equals(&body) {
+        format!("recursive reference to `{name}` in a subquery")
+    } else if body.find_children(CompoundSelect).next().is_none() {
+        format!(
+            "`{name}` refers to itself but is not a `UNION` of an initial SELECT and a recursive \
+            SELECT"
+        )
+    } else if arm.parent().equals(&body) {
+        format!("the initial SELECT of `{name}` cannot refer to `{name}`")
+    } else if arm
+        .parent()
+        .find_children(CompoundOperator)
+        .next()?
+        .find_children(UnionCompoundOperator)
+        .next()
+        .is_none()
+    {
+        format!("the recursive SELECT of `{name}` must be joined with `UNION` or `UNION ALL`")
+    } else if arm
+        .me_and_descendants()
+        .take_while(|it| !it.equals(&node))
+        .any(|it| cte_definition(it).is_some_and(|it| it.equals(&cte)))
+    {
+        format!("`{name}` can only be referenced once by a recursive SELECT")
+    } else {
+        return None;
+    };
+
+    Some((trimmed_range(node), message, None))
+}
+
+pub(crate) fn cte_column_count<'a, N: CstNodeTrait<'a>>(node: N) -> Option<Finding> {
+    use SqliteTreeKind::*;
+
+    if node.tree()? != CommonTableExpr || node.has_errors() {
+        return None;
+    }
+
+    let col_names = node.find_children(ColNameList).next()?;
+    let names = col_names.find_children(ColumnName).count();
+    let values = result_column_count(
+        node.find_children(SelectStmtWithCte)
+            .next()?
+            .find_children(SelectStmt)
+            .next()?,
+    )?;
+
+    if names == values {
+        return None;
+    }
+
+    let plural = |count: usize, word: &str| match count {
+        1 => format!("1 {word}"),
+        _ => format!("{count} {word}s"),
+    };
+
+    Some((
+        trimmed_range(col_names),
+        format!(
+            "`{}` has {} but its SELECT returns {}",
+            trimmed_text(node.find_children(CteName).next()?),
+            plural(names, "column name"),
+            plural(values, "column"),
+        ),
+        None,
+    ))
+}
+
+/// `MATERIALIZED` hints that do nothing or that make the query slower
+pub(crate) fn materialized_hint<'a, N: CstNodeTrait<'a>>(node: N) -> Option<Finding> {
+    if node.tree()? != SqliteTreeKind::MaterializedCte || node.is_root() {
+        return None;
+    }
+
+    let cte = node.parent();
+    let name = trimmed_text(cte.find_children(SqliteTreeKind::CteName).next()?);
+    let hint = trimmed_text(node);
+    let is_not = node
+        .children()
+        .any(|it| it.token_kind() == Some(SqliteTokenKind::KW_NOT));
+
+    let (uses, recursive_uses) =
+        cte_references(cte).fold((0, 0), |(uses, recursive), it| match is_inside(it, cte) {
+            true => (uses, recursive + 1),
+            false => (uses + 1, recursive),
+        });
+
+    let message = if recursive_uses > 0 {
+        format!("recursive CTEs are always materialized, `{hint}` has no effect")
+    } else if is_not && uses > 1 {
+        format!("`{name}` is used {uses} times and `{hint}` runs its query again for every use")
+    } else {
+        return None;
+    };
+
+    let start = node
+        .prev_token_skip_trivia()
+        .map_or(node.start_pos(), |it| it.end_pos());
+
+    Some((
+        trimmed_range(node),
+        message,
+        Some(LintFix {
+            title: format!("Remove `{hint}`"),
+            edits: vec![TextEdit {
+                range: TextRange::new(start, node.end_pos_skip_trivia()),
+                new_text: String::new(),
+            }],
+        }),
+    ))
+}
+
+pub(crate) fn unused_cte<'a, N: CstNodeTrait<'a>>(node: N) -> Option<Finding> {
+    if node.tree()? != SqliteTreeKind::CommonTableExpr || node.is_root() {
+        return None;
+    }
+
+    // A reference could be hidden in the part that failed to parse
+    let clause = node.parent();
+    if clause.is_root() || clause.parent().has_errors() {
+        return None;
+    }
+
+    if cte_references(node).any(|it| !is_inside(it, node)) {
+        return None;
+    }
+
+    let name = node.find_children(SqliteTreeKind::CteName).next()?;
+    let mut rewriter = CstRewriter::new(clause.parent());
+    remove_cte(&mut rewriter, node);
+
+    Some((
+        trimmed_range(name),
+        format!("CTE `{}` is never used", trimmed_text(name)),
+        rewriter.edits().ok().map(|edits| LintFix {
+            title: format!("Remove `{}`", trimmed_text(name)),
+            edits,
+        }),
+    ))
+}
+
+/// `FROM (SELECT ...) AS s` -> `WITH s AS (SELECT ...) ... FROM s`
+pub(crate) fn extract_subquery_into_cte<'a, N: CstNodeTrait<'a>>(node: N) -> Option<Assist> {
+    use SqliteTreeKind::*;
+
+    let subquery = std::iter::once(node).chain(node.ancestors()).find(|it| {
+        it.tree() == Some(TableOrSubquery) && it.find_children(SelectStmtWithCte).next().is_some()
+    })?;
+    if subquery.has_errors() {
+        return None;
+    }
+
+    // The CTE goes in the closest WITH clause that can see everything the subquery can see. A
+    // subquery in the body of a CTE becomes a CTE of the same clause, right before it.
+    let mut before = None;
+    let target = subquery.ancestors().find(|it| match it.tree() {
+        Some(CommonTableExpr) => {
+            before = Some(*it);
+            false
+        }
+        Some(StatementWithCte) => true,
+        Some(SelectStmtWithCte) => {
+            it.find_children(CteClause).next().is_some()
+                || it.is_root()
+                || it.parent().tree() != Some(CommonTableExpr)
+        }
+        _ => false,
+    })?;
+
+    let alias = subquery
+        .find_children(WithAlias)
+        .next()
+        .and_then(|it| it.find_children(AliasName).next())
+        .map(trimmed_text);
+    let name = unused_name(target, alias.as_deref().unwrap_or("subquery"));
+
+    let body = trimmed_text(subquery.find_children(SelectStmtWithCte).next()?);
+    let definition = format!("{name} AS ({body})");
+
+    let mut rewriter = CstRewriter::new(target);
+    match (before, target.find_children(CteClause).next()) {
+        (Some(cte), _) => rewriter.insert_before(cte, format!("{definition}, ")),
+        (None, Some(clause)) => rewriter.insert_after(clause, format!(", {definition}")),
+        (None, None) => rewriter.insert_before(target, format!("WITH {definition} ")),
+    }
+
+    let r_paren = subquery
+        .children()
+        .find(|it| it.token_kind() == Some(SqliteTokenKind::R_PAREN))?;
+    match alias {
+        // `(SELECT ...) AS s` -> `s`
+        Some(alias) if alias == name => rewriter.replace(subquery, name),
+        // `(SELECT ...) AS s` -> `s2 AS s`
+        _ => rewriter.edit(
+            TextRange::new(subquery.start_pos_skip_trivia(), r_paren.end_pos()),
+            name,
+        ),
+    }
+
+    Some(Assist {
+        kind: AssistKind::Extract,
+        title: "Extract subquery into CTE".to_owned(),
+        edits: rewriter.edits().ok()?,
+    })
+}
+
+/// `WITH c AS (SELECT ...) ... FROM c` -> `... FROM (SELECT ...) AS c`. Only offered for CTEs
+/// that are used once and do not rename their columns.
+pub(crate) fn inline_cte<'a, N: CstNodeTrait<'a>>(node: N) -> Option<Assist> {
+    use SqliteTreeKind::*;
+
+    let cte = std::iter::once(node)
+        .chain(node.ancestors())
+        .find_map(|it| match it.tree() {
+            Some(CteName) if !it.is_root() => Some(it.parent()),
+            Some(TableName) => cte_definition(it),
+            _ => None,
+        })?;
+
+    if cte.has_errors() || cte.find_children(ColNameList).next().is_some() || cte.parent().is_root()
+    {
+        return None;
+    }
+
+    let mut references = cte_references(cte);
+    let (Some(reference), None) = (references.next(), references.next()) else {
+        return None;
+    };
+    if is_inside(reference, cte) {
+        return None;
+    }
+
+    let name = trimmed_text(reference);
+    let body = trimmed_text(cte.find_children(SelectStmtWithCte).next()?);
+    let full_name = reference.parent();
+
+    // Columns can be qualified with the name of the CTE, so it stays as the alias
+    let has_alias = full_name.parent().tree() == Some(InTable)
+        || full_name.parent().find_children(WithAlias).next().is_some();
+    let subquery = match has_alias {
+        true => format!("({body})"),
+        false => format!("({body}) AS {name}"),
+    };
+
+    let mut rewriter = CstRewriter::new(cte.parent().parent());
+    remove_cte(&mut rewriter, cte);
+    rewriter.replace(full_name, subquery);
+
+    Some(Assist {
+        kind: AssistKind::Inline,
+        title: format!("Inline CTE `{name}`"),
+        edits: rewriter.edits().ok()?,
+    })
+}
+
+fn cte_name<'a, N: CstNodeTrait<'a>>(cte: N) -> Option<String> {
+    cte.find_children(SqliteTreeKind::CteName)
+        .next()
+        .map(|it| unquoted(&trimmed_text(it)))
+}
+
+/// Every table name in the statement (or subquery) that owns the WITH clause of the CTE that
+/// refers to it, including the references of a recursive CTE to itself
+fn cte_references<'a, N: CstNodeTrait<'a>>(cte: N) -> impl Iterator<Item = N> + use<'a, N> {
+    let owner = cte.parent().parent();
+
+    owner
+        .me_and_descendants()
+        .filter(move |it| cte_definition(*it).is_some_and(|it| it.equals(&cte)))
+}
+
+fn is_inside<'a, N: CstNodeTrait<'a>>(node: N, ancestor: N) -> bool {
+    node.ancestors().any(|it| it.equals(&ancestor))
+}
+
+/// Number of columns returned by the first SELECT, unless it uses `*`
+fn result_column_count<'a, N: CstNodeTrait<'a>>(select: N) -> Option<usize> {
+    use SqliteTreeKind::*;
+
+    let core = select.find_children(SelectCore).next()?;
+
+    if let Some(values) = core.find_children(ValuesSelect).next() {
+        return Some(
+            values
+                .find_children(ExprList)
+                .next()?
+                .find_children(Expr)
+                .count(),
+        );
+    }
+
+    let columns = core
+        .find_children(TraditionalSelect)
+        .next()?
+        .find_children(ResultColumnList)
+        .next()?;
+    let has_star = columns.find_children(ResultColumn).any(|it| {
+        it.find_children(ResultColumnAll).next().is_some()
+            || it.find_children(ResultColumnTableAll).next().is_some()
+    });
+
+    (!has_star).then(|| columns.find_children(ResultColumn).count())
+}
+
+/// `base`, or `base` followed by a number if the statement already uses that name for a table or
+/// CTE
+fn unused_name<'a, N: CstNodeTrait<'a>>(node: N, base: &str) -> String {
+    use SqliteTreeKind::*;
+
+    let statement = std::iter::once(node)
+        .chain(node.ancestors())
+        .find(|it| it.tree() == Some(Statement))
+        .unwrap_or(node);
+    let is_taken = |name: &str| {
+        statement.me_and_descendants().any(|it| {
+            let is_table = match it.tree() {
+                Some(CteName) => true,
+                Some(TableName) => !it.is_root() && it.parent().tree() == Some(FullTableName),
+                _ => false,
+            };
+
+            is_table && unquoted(&trimmed_text(it)).eq_ignore_ascii_case(&unquoted(name))
+        })
+    };
+
+    std::iter::once(base.to_owned())
+        .chain((2..).map(|it| format!("{base}{it}")))
+        .find(|it| !is_taken(it))
+        .unwrap()
+}
+
+/// Removes the CTE, and the whole WITH clause if it was the only one
+fn remove_cte<'a, N: CstNodeTrait<'a>>(rewriter: &mut CstRewriter, cte: N) {
+    let clause = cte.parent();
+
+    if clause
+        .find_children(SqliteTreeKind::CommonTableExpr)
+        .count()
+        > 1
+    {
+        rewriter.delete(cte);
+        return;
+    }
+
+    // `WITH c AS (...) SELECT` -> `SELECT`
+    let end = clause
+        .next_token_skip_trivia()
+        .map_or(clause.end_pos(), |it| it.start_pos());
+    rewriter.edit(
+        TextRange::new(clause.start_pos_skip_trivia(), end),
+        String::new(),
+    );
+}
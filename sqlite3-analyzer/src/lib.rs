@@ -1,2 +1,10 @@
-mod resolve;
+mod aggregate;
+mod assist;
 mod check;
+mod cte;
+mod lint;
+mod window;
+
+pub use assist::{assists, Assist, AssistKind};
+pub use lint::{lint, Lint, LintConfig, LintFix, LintRule, Severity};
+pub use window::window_definition;
@@ -0,0 +1,624 @@
+//! Rule-based checks for SQL that is valid but most likely not what the user meant (Ex: `WHERE a
+//! = NULL`). Unlike parse errors, every rule can be turned off or given another severity per
+//! workspace (See [`LintConfig`]).
+
+use ahash::HashMap;
+use bord_sqlite3_parser::{CstNodeTrait, TextEdit};
+use text_size::TextRange;
+
+use crate::{aggregate, check, cte, window};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LintRule {
+    UpdateDeleteWithoutWhere,
+    NullComparison,
+    DoubleQuotedString,
+    ImplicitCrossJoin,
+    UnneededAutoincrement,
+    SelectStarInView,
+    LeadingWildcardLike,
+    AggregateInWhere,
+    NestedAggregate,
+    BareColumn,
+    HavingWithoutAggregate,
+    MisplacedWindowFunction,
+    FilterOnNonAggregate,
+    OverOnNonWindowFunction,
+    UndefinedWindow,
+    DuplicateWindow,
+    WindowInheritance,
+    InvalidRecursiveCte,
+    CteColumnCount,
+    MaterializedHint,
+    UnusedCte,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Error,
+    Warning,
+    Info,
+    Hint,
+}
+
+/// Severity of each rule. Rules that are not configured use their default severity.
+#[derive(Debug, Clone, Default)]
+pub struct LintConfig {
+    /// `None` if the rule is turned off
+    rules: HashMap<LintRule, Option<Severity>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lint {
+    pub rule: LintRule,
+    pub severity: Severity,
+    pub range: TextRange,
+    pub message: String,
+    pub fix: Option<LintFix>,
+}
+
+/// Edits that make the lint go away without changing what the user meant
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintFix {
+    pub title: String,
+    pub edits: Vec<TextEdit>,
+}
+
+impl LintRule {
+    pub const ALL: [LintRule; 21] = [
+        LintRule::UpdateDeleteWithoutWhere,
+        LintRule::NullComparison,
+        LintRule::DoubleQuotedString,
+        LintRule::ImplicitCrossJoin,
+        LintRule::UnneededAutoincrement,
+        LintRule::SelectStarInView,
+        LintRule::LeadingWildcardLike,
+        LintRule::AggregateInWhere,
+        LintRule::NestedAggregate,
+        LintRule::BareColumn,
+        LintRule::HavingWithoutAggregate,
+        LintRule::MisplacedWindowFunction,
+        LintRule::FilterOnNonAggregate,
+        LintRule::OverOnNonWindowFunction,
+        LintRule::UndefinedWindow,
+        LintRule::DuplicateWindow,
+        LintRule::WindowInheritance,
+        LintRule::InvalidRecursiveCte,
+        LintRule::CteColumnCount,
+        LintRule::MaterializedHint,
+        LintRule::UnusedCte,
+    ];
+
+    /// Stable identifier used in the settings and in `bord-ignore` comments
+    pub const fn id(&self) -> &'static str {
+        match self {
+            LintRule::UpdateDeleteWithoutWhere => "update-delete-without-where",
+            LintRule::NullComparison => "null-comparison",
+            LintRule::DoubleQuotedString => "double-quoted-string",
+            LintRule::ImplicitCrossJoin => "implicit-cross-join",
+            LintRule::UnneededAutoincrement => "unneeded-autoincrement",
+            LintRule::SelectStarInView => "select-star-in-view",
+            LintRule::LeadingWildcardLike => "leading-wildcard-like",
+            LintRule::AggregateInWhere => "aggregate-in-where",
+            LintRule::NestedAggregate => "nested-aggregate",
+            LintRule::BareColumn => "bare-column",
+            LintRule::HavingWithoutAggregate => "having-without-aggregate",
+            LintRule::MisplacedWindowFunction => "misplaced-window-function",
+            LintRule::FilterOnNonAggregate => "filter-on-non-aggregate",
+            LintRule::OverOnNonWindowFunction => "over-on-non-window-function",
+            LintRule::UndefinedWindow => "undefined-window",
+            LintRule::DuplicateWindow => "duplicate-window",
+            LintRule::WindowInheritance => "window-inheritance",
+            LintRule::InvalidRecursiveCte => "invalid-recursive-cte",
+            LintRule::CteColumnCount => "cte-column-count",
+            LintRule::MaterializedHint => "materialized-hint",
+            LintRule::UnusedCte => "unused-cte",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<LintRule> {
+        LintRule::ALL.into_iter().find(|it| it.id() == id)
+    }
+
+    pub const fn default_severity(&self) -> Severity {
+        match self {
+            LintRule::UpdateDeleteWithoutWhere
+            | LintRule::NullComparison
+            | LintRule::DoubleQuotedString
+            | LintRule::SelectStarInView
+            | LintRule::BareColumn
+            | LintRule::HavingWithoutAggregate
+            | LintRule::UnusedCte => Severity::Warning,
+            LintRule::ImplicitCrossJoin
+            | LintRule::LeadingWildcardLike
+            | LintRule::MaterializedHint => Severity::Info,
+            // Often intended, see the docs
+            LintRule::UnneededAutoincrement => Severity::Hint,
+            // SQLite refuses to run these
+            LintRule::AggregateInWhere
+            | LintRule::NestedAggregate
+            | LintRule::MisplacedWindowFunction
+            | LintRule::FilterOnNonAggregate
+            | LintRule::OverOnNonWindowFunction
+            | LintRule::UndefinedWindow
+            | LintRule::DuplicateWindow
+            | LintRule::WindowInheritance
+            | LintRule::InvalidRecursiveCte
+            | LintRule::CteColumnCount => Severity::Error,
+        }
+    }
+
+    /// What the rule looks for and why
+    pub const fn docs(&self) -> &'static str {
+        match self {
+            LintRule::UpdateDeleteWithoutWhere => {
+                "`UPDATE` and `DELETE` without a `WHERE` clause change every row of the table. \
+                Add `WHERE true` if that is intended."
+            }
+            LintRule::NullComparison => {
+                "`= NULL` and `!= NULL` are always `NULL` (and therefore never true) because NULL \
+                is not equal or unequal to anything. Use `IS NULL` and `IS NOT NULL` instead."
+            }
+            LintRule::DoubleQuotedString => {
+                "Double quotes are for identifiers. When a double-quoted identifier does not name \
+                a column, SQLite silently treats it as a string literal, which stops working as \
+                soon as a column with that name is added (or when SQLite is built without \
+                SQLITE_DQS). Use single quotes for strings."
+            }
+            LintRule::ImplicitCrossJoin => {
+                "Tables separated by commas in `FROM` are joined with every row of each other \
+                unless the `WHERE` clause relates them, which is easy to forget. Use `JOIN ... ON` \
+                to keep the join condition next to the table."
+            }
+            LintRule::UnneededAutoincrement => {
+                "`INTEGER PRIMARY KEY` columns already get a unique rowid. `AUTOINCREMENT` only \
+                prevents the rowids of deleted rows from being reused and costs an extra table \
+                lookup on every insert. Keep it if anything relies on rowids never being reused \
+                (Ex: they are stored elsewhere or sent to other systems). See \
+                https://www.sqlite.org/autoinc.html"
+            }
+            LintRule::SelectStarInView => {
+                "The columns of a view that uses `*` change whenever the tables it selects from \
+                change. List the columns explicitly."
+            }
+            LintRule::LeadingWildcardLike => {
+                "`LIKE` patterns that start with `%` or `_` cannot use an index, so every row of \
+                the table is scanned."
+            }
+            LintRule::AggregateInWhere => {
+                "`WHERE` filters rows before they are grouped, so it cannot use aggregate \
+                functions. Conditions on aggregates go in `HAVING`."
+            }
+            LintRule::NestedAggregate => {
+                "An aggregate function cannot take the result of another aggregate function as \
+                its argument (Ex: `max(count(*))`). Aggregate in a subquery first."
+            }
+            LintRule::BareColumn => {
+                "In a query with `GROUP BY` or aggregate functions, a column that is neither \
+                grouped by nor aggregated takes its value from an arbitrary row of the group. \
+                SQLite allows this (other databases do not) but it is rarely intended."
+            }
+            LintRule::HavingWithoutAggregate => {
+                "`HAVING` filters groups. A condition that does not use an aggregate function can \
+                filter the rows in `WHERE` instead, before they are grouped."
+            }
+            LintRule::MisplacedWindowFunction => {
+                "Window functions are computed after the rows are filtered and grouped, so they \
+                can only be used in the result columns and in the `ORDER BY` of the query."
+            }
+            LintRule::FilterOnNonAggregate => {
+                "`FILTER (WHERE ...)` picks the rows an aggregate function sees and can only be \
+                used with aggregate functions."
+            }
+            LintRule::OverOnNonWindowFunction => {
+                "`OVER` can only be used with aggregate functions and built-in window functions \
+                (Ex: `row_number()`), not with scalar functions."
+            }
+            LintRule::UndefinedWindow => {
+                "`OVER w` and `OVER (w ...)` refer to a window defined in the `WINDOW` clause of \
+                the same `SELECT`."
+            }
+            LintRule::DuplicateWindow => {
+                "Every window in a `WINDOW` clause needs a different name."
+            }
+            LintRule::WindowInheritance => {
+                "A window based on another one (Ex: `OVER (w ORDER BY a)`) cannot have its own \
+                `PARTITION BY`, cannot replace the `ORDER BY` of the base window and cannot be \
+                based on a window with a frame specification."
+            }
+            LintRule::InvalidRecursiveCte => {
+                "A CTE that refers to itself must be an initial SELECT followed by `UNION` or \
+                `UNION ALL` and a recursive SELECT. Only the recursive SELECT can refer to the CTE, \
+                once, in its `FROM` clause (not in a subquery)."
+            }
+            LintRule::CteColumnCount => {
+                "The column names after the name of a CTE (Ex: `WITH c(a, b) AS (...)`) must \
+                match the number of columns returned by its SELECT."
+            }
+            LintRule::MaterializedHint => {
+                "Recursive CTEs are always materialized, so `MATERIALIZED` and `NOT MATERIALIZED` \
+                do nothing. `NOT MATERIALIZED` on a CTE that is used more than once runs its query \
+                again for every use."
+            }
+            LintRule::UnusedCte => {
+                "A CTE that is not used by the query only makes it harder to read."
+            }
+        }
+    }
+}
+
+impl Severity {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+            Severity::Hint => "hint",
+        }
+    }
+}
+
+impl std::str::FromStr for Severity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let severity = match s.to_ascii_lowercase().as_str() {
+            "error" => Severity::Error,
+            "warning" | "warn" => Severity::Warning,
+            "info" | "information" => Severity::Info,
+            "hint" => Severity::Hint,
+            _ => {
+                return Err(format!(
+                    "Invalid severity `{s}` (expected `error`, `warning`, `info`, `hint` or `off`)"
+                ))
+            }
+        };
+
+        Ok(severity)
+    }
+}
+
+impl LintConfig {
+    /// `None` if the rule is turned off
+    pub fn severity(&self, rule: LintRule) -> Option<Severity> {
+        match self.rules.get(&rule) {
+            Some(severity) => *severity,
+            None => Some(rule.default_severity()),
+        }
+    }
+
+    /// `None` turns the rule off
+    pub fn set_severity(&mut self, rule: LintRule, severity: Option<Severity>) {
+        self.rules.insert(rule, severity);
+    }
+
+    /// Ex: `("null-comparison", "error")` or `("implicit-cross-join", "off")`
+    pub fn configure(&mut self, id: &str, severity: &str) -> Result<(), String> {
+        let rule = LintRule::from_id(id).ok_or_else(|| format!("Unknown lint rule `{id}`"))?;
+
+        let severity = match severity {
+            "off" | "allow" => None,
+            _ => Some(severity.parse()?),
+        };
+
+        self.set_severity(rule, severity);
+
+        Ok(())
+    }
+}
+
+/// Runs every rule that is turned on against the tree
+pub fn lint<'a, N: CstNodeTrait<'a>>(root: N, config: &LintConfig) -> Vec<Lint> {
+    let rules: Vec<_> = LintRule::ALL
+        .into_iter()
+        .filter_map(|rule| Some((rule, config.severity(rule)?)))
+        .collect();
+
+    let mut lints = Vec::new();
+
+    for node in root.me_and_descendants() {
+        for &(rule, severity) in &rules {
+            let found = match rule {
+                LintRule::UpdateDeleteWithoutWhere => check::update_delete_without_where(node),
+                LintRule::NullComparison => check::null_comparison(node),
+                LintRule::DoubleQuotedString => check::double_quoted_string(node),
+                LintRule::ImplicitCrossJoin => check::implicit_cross_join(node),
+                LintRule::UnneededAutoincrement => check::unneeded_autoincrement(node),
+                LintRule::SelectStarInView => check::select_star_in_view(node),
+                LintRule::LeadingWildcardLike => check::leading_wildcard_like(node),
+                LintRule::AggregateInWhere => aggregate::aggregate_in_where(node),
+                LintRule::NestedAggregate => aggregate::nested_aggregate(node),
+                LintRule::BareColumn => aggregate::bare_column(node),
+                LintRule::HavingWithoutAggregate => aggregate::having_without_aggregate(node),
+                LintRule::MisplacedWindowFunction => aggregate::misplaced_window_function(node),
+                LintRule::FilterOnNonAggregate => aggregate::filter_on_non_aggregate(node),
+                LintRule::OverOnNonWindowFunction => aggregate::over_on_non_window_function(node),
+                LintRule::UndefinedWindow => window::undefined_window(node),
+                LintRule::DuplicateWindow => window::duplicate_window(node),
+                LintRule::WindowInheritance => window::window_inheritance(node),
+                LintRule::InvalidRecursiveCte => cte::invalid_recursive_cte(node),
+                LintRule::CteColumnCount => cte::cte_column_count(node),
+                LintRule::MaterializedHint => cte::materialized_hint(node),
+                LintRule::UnusedCte => cte::unused_cte(node),
+            };
+
+            lints.extend(found.map(|(range, message, fix)| Lint {
+                rule,
+                severity,
+                range,
+                message,
+                fix,
+            }));
+        }
+    }
+
+    lints
+}
+
+#[cfg(test)]
+mod tests {
+    use bord_sqlite3_parser::{batch, parse, CstNodeTrait, CstTrait};
+
+    use super::*;
+    use crate::{check::trimmed_range, window_definition};
+
+    fn lint_text(text: &str, config: &LintConfig) -> Vec<(&'static str, String)> {
+        let cst: batch::SqlCst = parse(text);
+
+        lint(cst.root(), config)
+            .into_iter()
+            .map(|it| (it.rule.id(), text[it.range].to_owned()))
+            .collect()
+    }
+
+    fn apply_fixes(text: &str) -> String {
+        let cst: batch::SqlCst = parse(text);
+        let mut edits: Vec<_> = lint(cst.root(), &LintConfig::default())
+            .into_iter()
+            .flat_map(|it| it.fix)
+            .flat_map(|it| it.edits)
+            .collect();
+        edits.sort_by_key(|it| std::cmp::Reverse(it.range.start()));
+
+        let mut text = text.to_owned();
+        for edit in edits {
+            text.replace_range(std::ops::Range::<usize>::from(edit.range), &edit.new_text);
+        }
+
+        text
+    }
+
+    #[test]
+    fn rules_find_suspicious_sql() {
+        let text = "\
+UPDATE t SET a = 1;
+DELETE FROM t WHERE a;
+DELETE FROM t LIMIT 10;
+SELECT a = NULL, b != null, c IS NULL, d LIKE '%x', e NOT LIKE 'x%' FROM a, b JOIN c;
+SELECT \"s\", t.\"a\" FROM t ORDER BY \"s\";
+SELECT \"s\";
+CREATE TABLE t(id INTEGER PRIMARY KEY AUTOINCREMENT, b DEFAULT \"x\");
+CREATE VIEW v AS SELECT t.*, (SELECT count(*) FROM (SELECT * FROM u)) FROM t;
+INSERT INTO t VALUES (\"it's\");";
+
+        assert_eq!(
+            lint_text(text, &LintConfig::default()),
+            [
+                ("update-delete-without-where", "UPDATE t".to_owned()),
+                ("null-comparison", "a = NULL".to_owned()),
+                ("null-comparison", "b != null".to_owned()),
+                ("leading-wildcard-like", "'%x'".to_owned()),
+                ("implicit-cross-join", ",".to_owned()),
+                ("double-quoted-string", "\"s\"".to_owned()),
+                ("unneeded-autoincrement", "AUTOINCREMENT".to_owned()),
+                ("double-quoted-string", "\"x\"".to_owned()),
+                ("select-star-in-view", "t.*".to_owned()),
+                ("double-quoted-string", "\"it's\"".to_owned()),
+            ]
+        );
+
+        let mut config = LintConfig::default();
+        config.configure("null-comparison", "off").unwrap();
+        config.configure("implicit-cross-join", "error").unwrap();
+        assert!(config.configure("no-such-rule", "error").is_err());
+        assert!(config.configure("null-comparison", "loud").is_err());
+
+        let lints: Vec<_> = lint_text("SELECT a = NULL FROM a, b;", &config);
+        assert_eq!(lints, [("implicit-cross-join", ",".to_owned())]);
+        assert_eq!(
+            config.severity(LintRule::ImplicitCrossJoin),
+            Some(Severity::Error)
+        );
+    }
+
+    #[test]
+    fn fixes_keep_the_meaning() {
+        assert_eq!(
+            apply_fixes("SELECT a=NULL, b <> null FROM a,b, c;"),
+            "SELECT a IS NULL, b is not null FROM a JOIN b JOIN c;"
+        );
+        // Removing AUTOINCREMENT would let rowids be reused
+        assert_eq!(
+            apply_fixes(
+                "CREATE TABLE t(id INTEGER PRIMARY KEY AUTOINCREMENT, b DEFAULT \"it's\");"
+            ),
+            "CREATE TABLE t(id INTEGER PRIMARY KEY AUTOINCREMENT, b DEFAULT 'it''s');"
+        );
+    }
+
+    /// Only the given rules turned on
+    fn only(rules: &[LintRule]) -> LintConfig {
+        let mut config = LintConfig::default();
+        for rule in LintRule::ALL {
+            config.set_severity(rule, rules.contains(&rule).then(|| rule.default_severity()));
+        }
+
+        config
+    }
+
+    #[test]
+    fn aggregate_and_window_misuse_is_found() {
+        let text = "\
+SELECT a FROM t WHERE count(*) > 1 AND a IN (SELECT max(b) FROM u);
+SELECT max(count(*)), sum(count(*)) OVER () FROM t GROUP BY a;
+SELECT count(*) FILTER (WHERE sum(b) > 0) FROM t;";
+        assert_eq!(
+            lint_text(
+                text,
+                &only(&[LintRule::AggregateInWhere, LintRule::NestedAggregate])
+            ),
+            [
+                ("aggregate-in-where", "count(*)".to_owned()),
+                ("nested-aggregate", "count(*)".to_owned()),
+                ("nested-aggregate", "sum(b)".to_owned()),
+            ]
+        );
+
+        let text = "\
+SELECT a, b, lower(c), max(d), e + 1 AS n, count(*) FROM t GROUP BY t.a, lower(c) HAVING n > 1;
+SELECT b, count(*) FROM t;
+SELECT b, (SELECT count(*) FROM u WHERE u.b = b) FROM t;
+SELECT a FROM t GROUP BY a HAVING a > 1;
+SELECT a, count(*) AS total FROM t GROUP BY a HAVING total > 1 AND count(b) > 1;
+SELECT a, lower(b) AS lb, c, count(*) FROM t GROUP BY 1, lb;";
+        assert_eq!(
+            lint_text(
+                text,
+                &only(&[LintRule::BareColumn, LintRule::HavingWithoutAggregate])
+            ),
+            [
+                ("bare-column", "b".to_owned()),
+                ("bare-column", "e".to_owned()),
+                ("bare-column", "b".to_owned()),
+                ("having-without-aggregate", "HAVING a > 1".to_owned()),
+                ("bare-column", "c".to_owned()),
+            ]
+        );
+
+        let text = "\
+SELECT row_number() OVER (ORDER BY a) FROM t ORDER BY rank() OVER ();
+SELECT a FROM t WHERE row_number() OVER () > 1;
+SELECT abs(a) OVER (), max(a, b) OVER (), my_func(a) OVER (), sum(a) OVER () FROM t;
+SELECT lower(a) FILTER (WHERE a), count(*) FILTER (WHERE a), my_agg(a) FILTER (WHERE a) FROM t;";
+        assert_eq!(
+            lint_text(
+                text,
+                &only(&[
+                    LintRule::MisplacedWindowFunction,
+                    LintRule::FilterOnNonAggregate,
+                    LintRule::OverOnNonWindowFunction
+                ])
+            ),
+            [
+                (
+                    "misplaced-window-function",
+                    "row_number() OVER ()".to_owned()
+                ),
+                ("over-on-non-window-function", "OVER ()".to_owned()),
+                ("over-on-non-window-function", "OVER ()".to_owned()),
+                ("filter-on-non-aggregate", "FILTER (WHERE a)".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn window_names_are_checked() {
+        let text = "\
+SELECT sum(a) OVER w, sum(a) OVER (w ORDER BY b), sum(a) OVER nope, sum(a) OVER (nope)
+FROM t
+WINDOW w AS (PARTITION BY a), \"W\" AS (ORDER BY b), w2 AS (w PARTITION BY b),
+    w3 AS (ROWS 1 PRECEDING), w4 AS (w3)
+ORDER BY sum(a) OVER w4;
+SELECT sum(a) OVER (w ORDER BY c) FROM t WINDOW w AS (ORDER BY b)
+UNION ALL
+SELECT sum(a) OVER w FROM t WINDOW w AS ()
+ORDER BY sum(a) OVER w;";
+
+        assert_eq!(
+            lint_text(text, &LintConfig::default()),
+            [
+                ("undefined-window", "nope".to_owned()),
+                ("undefined-window", "nope".to_owned()),
+                ("duplicate-window", "\"W\"".to_owned()),
+                ("window-inheritance", "PARTITION BY b".to_owned()),
+                ("window-inheritance", "w3".to_owned()),
+                ("window-inheritance", "ORDER BY c".to_owned()),
+            ]
+        );
+
+        let cst: batch::SqlCst = parse(text);
+        let definitions: Vec<_> = cst
+            .root()
+            .me_and_descendants()
+            .filter_map(|it| Some((it, window_definition(it)?)))
+            .map(|(name, it)| (&text[trimmed_range(name)], &text[trimmed_range(it)]))
+            .collect();
+
+        assert_eq!(
+            definitions,
+            [
+                ("w", "w AS (PARTITION BY a)"),
+                ("w", "w AS (PARTITION BY a)"),
+                ("w", "w AS (PARTITION BY a)"),
+                ("w3", "w3 AS (ROWS 1 PRECEDING)"),
+                ("w4", "w4 AS (w3)"),
+                ("w", "w AS (ORDER BY b)"),
+                ("w", "w AS ()"),
+                ("w", "w AS ()"),
+            ]
+        );
+    }
+
+    #[test]
+    fn ctes_are_checked() {
+        let text = "\
+WITH RECURSIVE
+    c(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM c LIMIT 5),
+    d(x, y) AS (SELECT 1),
+    e AS MATERIALIZED (SELECT 1),
+    unused AS (SELECT * FROM c)
+SELECT * FROM c JOIN d JOIN e;
+WITH c AS (SELECT * FROM c) SELECT * FROM c;
+WITH c AS (SELECT x FROM c UNION SELECT 1) SELECT * FROM c;
+WITH c AS (SELECT 1 EXCEPT SELECT x FROM c) SELECT * FROM c;
+WITH c AS (SELECT 1 UNION SELECT a.x FROM c AS a JOIN c AS b) SELECT * FROM c;
+WITH c AS (SELECT 1 UNION SELECT (SELECT x FROM c)) SELECT * FROM c;
+WITH c(x) AS NOT MATERIALIZED (SELECT 1 UNION SELECT x FROM c),
+    d AS NOT MATERIALIZED (VALUES (1, 2))
+SELECT * FROM d WHERE 1 IN d;";
+
+        assert_eq!(
+            lint_text(
+                text,
+                &only(&[
+                    LintRule::InvalidRecursiveCte,
+                    LintRule::CteColumnCount,
+                    LintRule::MaterializedHint,
+                    LintRule::UnusedCte
+                ])
+            ),
+            [
+                ("cte-column-count", "(x, y)".to_owned()),
+                ("unused-cte", "unused".to_owned()),
+                ("invalid-recursive-cte", "c".to_owned()),
+                ("invalid-recursive-cte", "c".to_owned()),
+                ("invalid-recursive-cte", "c".to_owned()),
+                ("invalid-recursive-cte", "c".to_owned()),
+                ("invalid-recursive-cte", "c".to_owned()),
+                ("unused-cte", "c".to_owned()),
+                ("materialized-hint", "NOT MATERIALIZED".to_owned()),
+                ("materialized-hint", "NOT MATERIALIZED".to_owned()),
+            ]
+        );
+
+        assert_eq!(
+            apply_fixes("WITH a AS (SELECT 1), b AS (SELECT 2) SELECT * FROM b;"),
+            "WITH b AS (SELECT 2) SELECT * FROM b;"
+        );
+        assert_eq!(apply_fixes("WITH a AS (SELECT 1)\nSELECT 2;"), "SELECT 2;");
+        assert_eq!(
+            apply_fixes("WITH c AS NOT MATERIALIZED (SELECT 1) SELECT * FROM c JOIN c AS d;"),
+            "WITH c AS (SELECT 1) SELECT * FROM c JOIN c AS d;"
+        );
+    }
+}
@@ -0,0 +1,142 @@
+//! Named windows (`WINDOW w AS (...)`) and the places that refer to them: `OVER w` and the base
+//! window of `OVER (w ORDER BY a)`.
+
+use bord_sqlite3_parser::{CstNodeTrait, SqliteTreeKind};
+
+use crate::check::{trimmed_range, trimmed_text, unquoted, Finding};
+
+/// The definition (`w AS (...)` in the WINDOW clause) of the window that the name refers to. The
+/// name is either the `WindowName` of an OVER clause or a `WindowBaseName`.
+pub fn window_definition<'a, N: CstNodeTrait<'a>>(name: N) -> Option<N> {
+    if !is_window_reference(name) {
+        return None;
+    }
+
+    let text = unquoted(&trimmed_text(name));
+
+    window_definitions(scope(name)?)
+        .find(|it| definition_name(*it).is_some_and(|it| it.eq_ignore_ascii_case(&text)))
+}
+
+pub(crate) fn undefined_window<'a, N: CstNodeTrait<'a>>(node: N) -> Option<Finding> {
+    if !is_window_reference(node) || node.has_errors() || window_definition(node).is_some() {
+        return None;
+    }
+
+    // Nothing to check against if the name is not used inside a SELECT
+    scope(node)?;
+
+    Some((
+        trimmed_range(node),
+        format!("no window named `{}`", trimmed_text(node)),
+        None,
+    ))
+}
+
+pub(crate) fn duplicate_window<'a, N: CstNodeTrait<'a>>(node: N) -> Option<Finding> {
+    if node.tree()? != SqliteTreeKind::WindowFunction {
+        return None;
+    }
+
+    let name = definition_name(node)?;
+    let is_duplicate = node
+        .left_siblings()
+        .filter(|it| it.tree() == Some(SqliteTreeKind::WindowFunction))
+        .any(|it| definition_name(it).is_some_and(|it| it.eq_ignore_ascii_case(&name)));
+
+    if !is_duplicate {
+        return None;
+    }
+
+    Some((
+        trimmed_range(node.find_children(SqliteTreeKind::WindowName).next()?),
+        format!("window `{name}` is already defined"),
+        None,
+    ))
+}
+
+/// A window that is based on another one can only add an ORDER BY (if the base window does not
+/// have one) and a frame specification (Ex: `OVER (w ROWS 1 PRECEDING)`)
+pub(crate) fn window_inheritance<'a, N: CstNodeTrait<'a>>(node: N) -> Option<Finding> {
+    use SqliteTreeKind::*;
+
+    if node.tree()? != WindowBaseName {
+        return None;
+    }
+
+    let base = window_definition(node)?.find_children(WindowDef).next()?;
+    let derived = node.parent();
+    let name = trimmed_text(node);
+
+    let (range, message) =
+        if let Some(partition_by) = derived.find_children(WindowPartitionByClause).next() {
+            (
+                trimmed_range(partition_by),
+                format!("cannot override the PARTITION BY of window `{name}`"),
+            )
+        } else if let (Some(order_by), Some(_)) = (
+            derived.find_children(OrderByClause).next(),
+            base.find_children(OrderByClause).next(),
+        ) {
+            (
+                trimmed_range(order_by),
+                format!("cannot override the ORDER BY of window `{name}`"),
+            )
+        } else if base.find_children(FrameSpec).next().is_some() {
+            (
+                trimmed_range(node),
+                format!(
+                    "window `{name}` has a frame specification and cannot be used as a base window"
+                ),
+            )
+        } else {
+            return None;
+        };
+
+    Some((range, message, None))
+}
+
+/// `OVER w` or the `w` in `OVER (w ORDER BY a)`
+fn is_window_reference<'a, N: CstNodeTrait<'a>>(node: N) -> bool {
+    match node.tree() {
+        Some(SqliteTreeKind::WindowName) => {
+            !node.is_root() && node.parent().tree() == Some(SqliteTreeKind::OverClause)
+        }
+        Some(SqliteTreeKind::WindowBaseName) => true,
+        _ => false,
+    }
+}
+
+/// The SELECT whose WINDOW clause the names used at this point refer to. Names in the ORDER BY
+/// of a compound SELECT refer to the windows of its last SELECT.
+fn scope<'a, N: CstNodeTrait<'a>>(node: N) -> Option<N> {
+    use SqliteTreeKind::*;
+
+    node.ancestors().find_map(|it| match it.tree() {
+        Some(TraditionalSelect) => Some(Some(it)),
+        Some(SelectStmt) => Some(
+            it.find_children(CompoundSelect)
+                .last()
+                .and_then(|it| it.find_children(SelectCore).next())
+                .or_else(|| it.find_children(SelectCore).next())
+                .and_then(|it| it.find_children(TraditionalSelect).next()),
+        ),
+        Some(Statement) => Some(None),
+        _ => None,
+    })?
+}
+
+fn window_definitions<'a, N: CstNodeTrait<'a>>(select: N) -> impl Iterator<Item = N> + use<'a, N> {
+    select
+        .find_children(SqliteTreeKind::WindowClause)
+        .next()
+        .into_iter()
+        .flat_map(|it| it.find_children(SqliteTreeKind::WindowFunction))
+}
+
+fn definition_name<'a, N: CstNodeTrait<'a>>(definition: N) -> Option<String> {
+    definition
+        .find_children(SqliteTreeKind::WindowName)
+        .next()
+        .map(|it| unquoted(&trimmed_text(it)))
+}
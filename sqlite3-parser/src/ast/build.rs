@@ -0,0 +1,475 @@
+use std::collections::{HashMap, HashSet};
+use std::marker::PhantomData;
+
+use enumset::EnumSet;
+
+use crate::grammar::common::{JOIN_KEYWORDS, LITERAL_VALUE, NUMERIC_LIT};
+use crate::ungram::{
+    builder_field, builder_fields, collect_builder_fields, flag_name, is_variable_token,
+    remove_opt, rep_labels, BuilderFieldKind, Rule, UNGRAMMAR,
+};
+use crate::{
+    parse, parse_fragment, CstTrait, FragmentKind, ParserConfig, SqliteLexer, SqliteTokenKind,
+    SqliteVersion,
+};
+
+/// SQL text of the typed node `T`, produced by a builder (Ex: `SelectStmt::build()`). The
+/// builders follow the grammar in `sqlite.ungram` and the text is expected to parse without
+/// errors. Builders only produce text: the CST comes from parsing it (See [`Built::to_cst`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Built<T> {
+    text: String,
+    node: &'static str,
+    /// Operator expressions are wrapped in parentheses when used inside another operator, so
+    /// that the text does not depend on operator precedence
+    is_operator: bool,
+    _node: PhantomData<fn() -> T>,
+}
+
+impl<T> Built<T> {
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn into_text(self) -> String {
+        self.text
+    }
+
+    /// Parses the text (the CST is not built directly from the builder calls). Nodes that are not
+    /// statements are parsed with [`parse_fragment`] when possible.
+    pub fn to_cst<CST: CstTrait>(&self) -> CST {
+        let fragment_kind = match self.node {
+            "TypeName" => Some(FragmentKind::TypeName),
+            "ColumnDef" => Some(FragmentKind::ColumnDef),
+            "SelectStmt" | "SelectStmtWithCte" => Some(FragmentKind::Select),
+            "OrderByClause" | "OrderingTermList" => Some(FragmentKind::OrderBy),
+            node if node.starts_with("Expr") || node.starts_with("Op") => Some(FragmentKind::Expr),
+            _ => None,
+        };
+
+        match fragment_kind {
+            Some(kind) => parse_fragment(kind, &self.text),
+            None => parse(&self.text),
+        }
+    }
+
+    /// The same text as a node that contains `T` (Ex: an `OpAdd` as an `Expr`)
+    pub(crate) fn convert<U>(self, node: &'static str) -> Built<U> {
+        Built {
+            text: self.text,
+            node,
+            is_operator: self.is_operator,
+            _node: PhantomData,
+        }
+    }
+
+    /// A node made of a single token whose text is fixed by the grammar (Ex: `KW_NULL`)
+    pub(crate) fn fixed_token(node: &'static str, token_name: &str) -> Self {
+        Self::new(node, token_text(token_name).to_owned())
+    }
+
+    /// A node made of a single token whose text is given by the user (Ex: `INT_LIT`)
+    pub(crate) fn token(
+        node: &'static str,
+        token_name: &str,
+        text: &str,
+    ) -> Result<Self, BuildError> {
+        Ok(Self::new(node, token_value(token_name, text)?))
+    }
+
+    fn new(node: &'static str, text: String) -> Self {
+        Built {
+            text,
+            node,
+            is_operator: node.starts_with("Op"),
+            _node: PhantomData,
+        }
+    }
+}
+
+impl<T> std::fmt::Display for Built<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BuildError {
+    /// A field required by the grammar was not given
+    MissingField { node: &'static str, field: String },
+    /// A field was given but the grammar has no place for it (Ex: both alternatives of an
+    /// alternation were given)
+    UnusedField { node: &'static str, field: String },
+    /// The text is not a valid token of the expected kind
+    InvalidToken { expected: String, text: String },
+}
+
+impl std::fmt::Display for BuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuildError::MissingField { node, field } => {
+                write!(f, "{node} is missing the field `{field}`")
+            }
+            BuildError::UnusedField { node, field } => {
+                write!(f, "{node} has no place for the field `{field}`")
+            }
+            BuildError::InvalidToken { expected, text } => {
+                write!(f, "`{text}` is not a valid {expected}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// Builds the text of an ungrammar node from the values of its fields (See
+/// [`crate::ungram::builder_fields`]). The generated builders (Ex: `SelectStmt::build()`) are
+/// typed wrappers around this.
+#[derive(Debug, Clone)]
+pub struct NodeBuilder {
+    node: &'static str,
+    values: HashMap<String, Vec<String>>,
+    flags: HashSet<String>,
+}
+
+impl NodeBuilder {
+    pub fn new(node: &'static str) -> Self {
+        Self {
+            node,
+            values: HashMap::new(),
+            flags: HashSet::new(),
+        }
+    }
+
+    /// Sets a node field. Repeated fields are appended to.
+    pub fn node<T>(&mut self, field: &str, value: Built<T>) {
+        let text = if self.node.starts_with("Op") && value.is_operator {
+            format!("({})", value.text)
+        } else {
+            value.text
+        };
+
+        self.values.entry(field.to_owned()).or_default().push(text);
+    }
+
+    /// Sets a token field. The text is checked when the node is built.
+    pub fn token(&mut self, field: &str, text: &str) {
+        self.values
+            .entry(field.to_owned())
+            .or_default()
+            .push(text.to_owned());
+    }
+
+    pub fn flag(&mut self, field: &str) {
+        self.flags.insert(field.to_owned());
+    }
+
+    pub fn finish<T>(self) -> Result<Built<T>, BuildError> {
+        let node = UNGRAMMAR
+            .get_by_name(self.node)
+            .unwrap_or_else(|| panic!("{} is not an ungrammar node", self.node));
+
+        let mut renderer = Renderer {
+            builder: &self,
+            rep_labels: rep_labels(&node.rule),
+            cursors: HashMap::new(),
+            used_flags: HashSet::new(),
+            pieces: Vec::new(),
+        };
+        renderer.render(&node.rule)?;
+
+        let unused_field = builder_fields(self.node).into_iter().find(|field| {
+            let given = self.values.get(&field.name).map_or(0, |it| it.len());
+            given > renderer.cursor(&field.name)
+                || (self.flags.contains(&field.name) && !renderer.used_flags.contains(&field.name))
+        });
+
+        if let Some(field) = unused_field {
+            return Err(BuildError::UnusedField {
+                node: self.node,
+                field: field.name,
+            });
+        }
+
+        Ok(Built::new(self.node, join_pieces(&renderer.pieces)))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PieceKind {
+    Keyword,
+    Symbol,
+    Other,
+}
+
+struct Renderer<'b> {
+    builder: &'b NodeBuilder,
+    rep_labels: HashMap<String, String>,
+    cursors: HashMap<String, usize>,
+    used_flags: HashSet<String>,
+    pieces: Vec<(String, PieceKind)>,
+}
+
+impl Renderer<'_> {
+    fn render(&mut self, rule: &Rule) -> Result<(), BuildError> {
+        if let Some(field) = builder_field(rule, &self.rep_labels) {
+            let rule = match rule {
+                Rule::Labeled { rule, .. } => rule.as_ref(),
+                rule => rule,
+            };
+
+            if matches!(rule, Rule::Opt(_)) && !self.is_given(&field.name) {
+                return Ok(());
+            }
+
+            return match (remove_opt(rule), field.kind) {
+                (Rule::Rep(inner), _) => {
+                    while self.is_given(&field.name) {
+                        let cursor = self.cursor(&field.name);
+                        self.render(inner)?;
+
+                        if self.cursor(&field.name) == cursor {
+                            break;
+                        }
+                    }
+
+                    Ok(())
+                }
+                (Rule::Token(token_id), BuilderFieldKind::Flag) => {
+                    self.used_flags.insert(field.name);
+                    self.push_token(UNGRAMMAR.get_token(*token_id));
+                    Ok(())
+                }
+                (_, BuilderFieldKind::Token(token_name)) => {
+                    let text = self.take(&field.name)?;
+                    let text = token_value(&token_name, &text)?;
+                    self.pieces.push((text, PieceKind::Other));
+                    Ok(())
+                }
+                _ => {
+                    let text = self.take(&field.name)?;
+                    self.pieces.push((text, PieceKind::Other));
+                    Ok(())
+                }
+            };
+        }
+
+        match rule {
+            Rule::Token(token_id) => {
+                self.push_token(UNGRAMMAR.get_token(*token_id));
+                Ok(())
+            }
+            Rule::Labeled { rule, .. } => self.render(rule),
+            Rule::Seq(items) => items.iter().try_for_each(|it| self.render(it)),
+            Rule::Opt(inner) => {
+                if let Some(flag) = flag_name(rule) {
+                    if self.builder.flags.contains(&flag) && self.used_flags.insert(flag) {
+                        self.render(inner)?;
+                    }
+                    Ok(())
+                } else if self.any_given(inner) {
+                    self.render(inner)
+                } else {
+                    Ok(())
+                }
+            }
+            Rule::Rep(inner) => {
+                while self.any_given(inner) {
+                    let pieces_len = self.pieces.len();
+                    self.render(inner)?;
+
+                    if self.pieces.len() == pieces_len {
+                        break;
+                    }
+                }
+
+                Ok(())
+            }
+            Rule::Alt(alts) => self.render_alt(alts),
+            Rule::Node(_) => unreachable!("Nodes are always fields"),
+        }
+    }
+
+    /// Picks the alternative that uses the most given fields (the first one on ties). Fixed
+    /// tokens whose flag is not given (Ex: `','` in `JoinOperator`) are picked last.
+    fn render_alt(&mut self, alts: &[Rule]) -> Result<(), BuildError> {
+        let mut candidates: Vec<(usize, bool, &Rule)> = alts
+            .iter()
+            .map(|alt| match flag_name(alt) {
+                Some(flag) => {
+                    let is_given = self.is_given(&flag);
+                    (usize::from(is_given), !is_given, alt)
+                }
+                None => (self.given_count(alt), false, alt),
+            })
+            .collect();
+        candidates.sort_by_key(|(count, is_fallback, _)| (std::cmp::Reverse(*count), *is_fallback));
+
+        let mut first_err = None;
+        for (_, _, alt) in candidates {
+            let cursors = self.cursors.clone();
+            let used_flags = self.used_flags.clone();
+            let pieces_len = self.pieces.len();
+
+            let result = match flag_name(alt) {
+                Some(flag) => {
+                    self.used_flags.insert(flag);
+                    self.render_tokens(alt);
+                    Ok(())
+                }
+                None => self.render(alt),
+            };
+
+            match result {
+                Ok(()) => return Ok(()),
+                Err(err) => {
+                    self.cursors = cursors;
+                    self.used_flags = used_flags;
+                    self.pieces.truncate(pieces_len);
+                    first_err.get_or_insert(err);
+                }
+            }
+        }
+
+        Err(first_err.expect("Alternations have at least one alternative"))
+    }
+
+    fn render_tokens(&mut self, rule: &Rule) {
+        match rule {
+            Rule::Token(token_id) => self.push_token(UNGRAMMAR.get_token(*token_id)),
+            Rule::Seq(items) => items.iter().for_each(|it| self.render_tokens(it)),
+            Rule::Opt(inner) => self.render_tokens(inner),
+            _ => {}
+        }
+    }
+
+    fn push_token(&mut self, token_name: &str) {
+        let kind = if token_name.starts_with("KW_") {
+            PieceKind::Keyword
+        } else {
+            PieceKind::Symbol
+        };
+
+        self.pieces.push((token_text(token_name).to_owned(), kind));
+    }
+
+    fn take(&mut self, field: &str) -> Result<String, BuildError> {
+        let cursor = self.cursor(field);
+
+        match self.builder.values.get(field).and_then(|it| it.get(cursor)) {
+            Some(value) => {
+                self.cursors.insert(field.to_owned(), cursor + 1);
+                Ok(value.clone())
+            }
+            None => Err(self.missing(field.to_owned())),
+        }
+    }
+
+    fn cursor(&self, field: &str) -> usize {
+        self.cursors.get(field).copied().unwrap_or(0)
+    }
+
+    fn is_given(&self, field: &str) -> bool {
+        let given = self.builder.values.get(field).map_or(0, |it| it.len());
+
+        given > self.cursor(field)
+            || (self.builder.flags.contains(field) && !self.used_flags.contains(field))
+    }
+
+    fn given_count(&self, rule: &Rule) -> usize {
+        let mut fields = Vec::new();
+        collect_fields(rule, &self.rep_labels, &mut fields);
+
+        fields.iter().filter(|it| self.is_given(it)).count()
+    }
+
+    fn any_given(&self, rule: &Rule) -> bool {
+        self.given_count(rule) > 0
+    }
+
+    fn missing(&self, field: String) -> BuildError {
+        BuildError::MissingField {
+            node: self.builder.node,
+            field,
+        }
+    }
+}
+
+/// Names of the fields used by the rule
+fn collect_fields(rule: &Rule, rep_labels: &HashMap<String, String>, fields: &mut Vec<String>) {
+    let mut builder_fields = Vec::new();
+    collect_builder_fields(rule, rep_labels, &mut builder_fields);
+
+    fields.extend(builder_fields.into_iter().map(|it| it.name));
+}
+
+/// Text of a token that is fixed by the grammar (Ex: `SELECT` for `KW_SELECT`)
+fn token_text(token_name: &str) -> &str {
+    token_name.strip_prefix("KW_").unwrap_or(token_name)
+}
+
+/// Checks that the text is a single token that can be used in place of the given ungrammar
+/// token. Names that are not valid identifiers (Ex: keywords) are quoted.
+fn token_value(token_name: &str, text: &str) -> Result<String, BuildError> {
+    use SqliteTokenKind::*;
+
+    let iden_set = ParserConfig::default().iden_set();
+    let (allowed, quote_if_invalid): (EnumSet<SqliteTokenKind>, bool) = match token_name {
+        "$NAME" | "$CONSTRAINT_NAME" => (iden_set | KW_INDEXED | JOIN_KEYWORDS | STR_LIT, true),
+        "$COLLATION_NAME" => (iden_set | STR_LIT, true),
+        "IDEN" => (iden_set, true),
+        "$NUMERIC_LIT" => (NUMERIC_LIT, false),
+        "$LITERAL_VALUE" => (LITERAL_VALUE, false),
+        "$ANY_JOIN_KW" => (JOIN_KEYWORDS, false),
+        "PARAM" => (PARAM.into(), false),
+        "INT_LIT" => (INT_LIT.into(), false),
+        "HEX_LIT" => (HEX_LIT.into(), false),
+        "REAL_LIT" => (REAL_LIT.into(), false),
+        "STR_LIT" => (STR_LIT.into(), false),
+        "BLOB_LIT" => (BLOB_LIT.into(), false),
+        _ => unreachable!("{token_name} is not a variable token"),
+    };
+    debug_assert!(is_variable_token(token_name));
+
+    let mut tokens = SqliteLexer::new(text, SqliteVersion::LATEST);
+    let is_valid = match (tokens.next(), tokens.next()) {
+        (Some(token), None) => token.error.is_none() && allowed.contains(token.kind),
+        _ => false,
+    };
+
+    if is_valid {
+        Ok(text.to_owned())
+    } else if quote_if_invalid {
+        Ok(format!("\"{}\"", text.replace('"', "\"\"")))
+    } else {
+        Err(BuildError::InvalidToken {
+            expected: token_name.trim_start_matches('$').to_owned(),
+            text: text.to_owned(),
+        })
+    }
+}
+
+fn join_pieces(pieces: &[(String, PieceKind)]) -> String {
+    let mut text = String::new();
+    let mut prev: Option<&(String, PieceKind)> = None;
+
+    for piece in pieces {
+        let (piece_text, _) = piece;
+
+        let needs_space = match prev {
+            None => false,
+            Some(_) if matches!(piece_text.as_str(), "," | ")" | "." | ";") => false,
+            Some((prev_text, _)) if matches!(prev_text.as_str(), "(" | ".") => false,
+            // Function calls and column lists (Ex: `count(*)`, `users(id)`)
+            Some((_, prev_kind)) => piece_text != "(" || *prev_kind != PieceKind::Other,
+        };
+
+        if needs_space {
+            text.push(' ');
+        }
+        text.push_str(piece_text);
+        prev = Some(piece);
+    }
+
+    text
+}
@@ -0,0 +1,5894 @@
+#![allow(clippy::should_implement_trait)]
+use super::*;
+pub struct AddColumnBuilder(NodeBuilder);
+impl AddColumn<()> {
+    pub fn build() -> AddColumnBuilder {
+        AddColumnBuilder(NodeBuilder::new("AddColumn"))
+    }
+}
+impl AddColumnBuilder {
+    pub fn column(mut self) -> Self {
+        self.0.flag("column");
+        self
+    }
+    pub fn column_def(mut self, value: impl Into<Built<ColumnDef<()>>>) -> Self {
+        self.0.node("column_def", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<AddColumn<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct AliasNameBuilder(NodeBuilder);
+impl AliasName<()> {
+    pub fn build() -> AliasNameBuilder {
+        AliasNameBuilder(NodeBuilder::new("AliasName"))
+    }
+    pub fn new(value: &str) -> Result<Built<AliasName<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl AliasNameBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<AliasName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct AlterTableStmtBuilder(NodeBuilder);
+impl AlterTableStmt<()> {
+    pub fn build() -> AlterTableStmtBuilder {
+        AlterTableStmtBuilder(NodeBuilder::new("AlterTableStmt"))
+    }
+}
+impl AlterTableStmtBuilder {
+    pub fn full_table_name(mut self, value: impl Into<Built<FullTableName<()>>>) -> Self {
+        self.0.node("full_table_name", value.into());
+        self
+    }
+    pub fn alter_table_kind(mut self, value: impl Into<Built<AlterTableKind<()>>>) -> Self {
+        self.0.node("alter_table_kind", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<AlterTableStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct AnalyzeStmtBuilder(NodeBuilder);
+impl AnalyzeStmt<()> {
+    pub fn build() -> AnalyzeStmtBuilder {
+        AnalyzeStmtBuilder(NodeBuilder::new("AnalyzeStmt"))
+    }
+}
+impl AnalyzeStmtBuilder {
+    pub fn analyze_target(mut self, value: impl Into<Built<AnalyzeTarget<()>>>) -> Self {
+        self.0.node("analyze_target", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<AnalyzeStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct AnyValidNameBuilder(NodeBuilder);
+impl AnyValidName<()> {
+    pub fn build() -> AnyValidNameBuilder {
+        AnyValidNameBuilder(NodeBuilder::new("AnyValidName"))
+    }
+    pub fn new(value: &str) -> Result<Built<AnyValidName<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl AnyValidNameBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<AnyValidName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ArgExprBuilder(NodeBuilder);
+impl ArgExpr<()> {
+    pub fn build() -> ArgExprBuilder {
+        ArgExprBuilder(NodeBuilder::new("ArgExpr"))
+    }
+}
+impl ArgExprBuilder {
+    pub fn distinct(mut self) -> Self {
+        self.0.flag("distinct");
+        self
+    }
+    pub fn items(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("items", value.into());
+        self
+    }
+    pub fn order_by_clause(mut self, value: impl Into<Built<OrderByClause<()>>>) -> Self {
+        self.0.node("order_by_clause", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ArgExpr<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ArgStarBuilder(NodeBuilder);
+impl ArgStar<()> {
+    pub fn build() -> ArgStarBuilder {
+        ArgStarBuilder(NodeBuilder::new("ArgStar"))
+    }
+}
+impl ArgStarBuilder {
+    pub fn finish(self) -> Result<Built<ArgStar<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct AttachDbStmtBuilder(NodeBuilder);
+impl AttachDbStmt<()> {
+    pub fn build() -> AttachDbStmtBuilder {
+        AttachDbStmtBuilder(NodeBuilder::new("AttachDbStmt"))
+    }
+}
+impl AttachDbStmtBuilder {
+    pub fn database(mut self) -> Self {
+        self.0.flag("database");
+        self
+    }
+    pub fn file_name_expr(mut self, value: impl Into<Built<FileNameExpr<()>>>) -> Self {
+        self.0.node("file_name_expr", value.into());
+        self
+    }
+    pub fn schema_name_expr(mut self, value: impl Into<Built<SchemaNameExpr<()>>>) -> Self {
+        self.0.node("schema_name_expr", value.into());
+        self
+    }
+    pub fn password_expr(mut self, value: impl Into<Built<PasswordExpr<()>>>) -> Self {
+        self.0.node("password_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<AttachDbStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct BeginStmtBuilder(NodeBuilder);
+impl BeginStmt<()> {
+    pub fn build() -> BeginStmtBuilder {
+        BeginStmtBuilder(NodeBuilder::new("BeginStmt"))
+    }
+}
+impl BeginStmtBuilder {
+    pub fn begin_stmt_kind(mut self, value: impl Into<Built<BeginStmtKind<()>>>) -> Self {
+        self.0.node("begin_stmt_kind", value.into());
+        self
+    }
+    pub fn transaction(mut self) -> Self {
+        self.0.flag("transaction");
+        self
+    }
+    pub fn finish(self) -> Result<Built<BeginStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct CaseElseClauseBuilder(NodeBuilder);
+impl CaseElseClause<()> {
+    pub fn build() -> CaseElseClauseBuilder {
+        CaseElseClauseBuilder(NodeBuilder::new("CaseElseClause"))
+    }
+}
+impl CaseElseClauseBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<CaseElseClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct CaseTargetExprBuilder(NodeBuilder);
+impl CaseTargetExpr<()> {
+    pub fn build() -> CaseTargetExprBuilder {
+        CaseTargetExprBuilder(NodeBuilder::new("CaseTargetExpr"))
+    }
+}
+impl CaseTargetExprBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<CaseTargetExpr<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct CaseWhenClauseBuilder(NodeBuilder);
+impl CaseWhenClause<()> {
+    pub fn build() -> CaseWhenClauseBuilder {
+        CaseWhenClauseBuilder(NodeBuilder::new("CaseWhenClause"))
+    }
+}
+impl CaseWhenClauseBuilder {
+    pub fn when_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("when_expr", value.into());
+        self
+    }
+    pub fn then_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("then_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<CaseWhenClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct CaseWhenClauseListBuilder(NodeBuilder);
+impl CaseWhenClauseList<()> {
+    pub fn build() -> CaseWhenClauseListBuilder {
+        CaseWhenClauseListBuilder(NodeBuilder::new("CaseWhenClauseList"))
+    }
+}
+impl CaseWhenClauseListBuilder {
+    pub fn items(mut self, value: impl Into<Built<CaseWhenClause<()>>>) -> Self {
+        self.0.node("items", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<CaseWhenClauseList<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct CheckConstraintBuilder(NodeBuilder);
+impl CheckConstraint<()> {
+    pub fn build() -> CheckConstraintBuilder {
+        CheckConstraintBuilder(NodeBuilder::new("CheckConstraint"))
+    }
+}
+impl CheckConstraintBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<CheckConstraint<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ColNameListBuilder(NodeBuilder);
+impl ColNameList<()> {
+    pub fn build() -> ColNameListBuilder {
+        ColNameListBuilder(NodeBuilder::new("ColNameList"))
+    }
+}
+impl ColNameListBuilder {
+    pub fn items(mut self, value: impl Into<Built<ColumnName<()>>>) -> Self {
+        self.0.node("items", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ColNameList<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct CollationBuilder(NodeBuilder);
+impl Collation<()> {
+    pub fn build() -> CollationBuilder {
+        CollationBuilder(NodeBuilder::new("Collation"))
+    }
+}
+impl CollationBuilder {
+    pub fn collation_name(mut self, value: impl Into<Built<CollationName<()>>>) -> Self {
+        self.0.node("collation_name", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<Collation<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct CollationNameBuilder(NodeBuilder);
+impl CollationName<()> {
+    pub fn build() -> CollationNameBuilder {
+        CollationNameBuilder(NodeBuilder::new("CollationName"))
+    }
+    pub fn new(value: &str) -> Result<Built<CollationName<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl CollationNameBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<CollationName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ColumnConstraintBuilder(NodeBuilder);
+impl ColumnConstraint<()> {
+    pub fn build() -> ColumnConstraintBuilder {
+        ColumnConstraintBuilder(NodeBuilder::new("ColumnConstraint"))
+    }
+}
+impl ColumnConstraintBuilder {
+    pub fn column_constraint_name(
+        mut self,
+        value: impl Into<Built<ColumnConstraintName<()>>>,
+    ) -> Self {
+        self.0.node("column_constraint_name", value.into());
+        self
+    }
+    pub fn constraint_type(mut self, value: impl Into<Built<ConstraintType<()>>>) -> Self {
+        self.0.node("constraint_type", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ColumnConstraint<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ColumnConstraintNameBuilder(NodeBuilder);
+impl ColumnConstraintName<()> {
+    pub fn build() -> ColumnConstraintNameBuilder {
+        ColumnConstraintNameBuilder(NodeBuilder::new("ColumnConstraintName"))
+    }
+}
+impl ColumnConstraintNameBuilder {
+    pub fn constraint_name(mut self, value: impl Into<Built<ConstraintName<()>>>) -> Self {
+        self.0.node("constraint_name", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ColumnConstraintName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ColumnDefBuilder(NodeBuilder);
+impl ColumnDef<()> {
+    pub fn build() -> ColumnDefBuilder {
+        ColumnDefBuilder(NodeBuilder::new("ColumnDef"))
+    }
+}
+impl ColumnDefBuilder {
+    pub fn column_name(mut self, value: impl Into<Built<ColumnName<()>>>) -> Self {
+        self.0.node("column_name", value.into());
+        self
+    }
+    pub fn type_name(mut self, value: impl Into<Built<TypeName<()>>>) -> Self {
+        self.0.node("type_name", value.into());
+        self
+    }
+    pub fn constraints(mut self, value: impl Into<Built<ColumnConstraint<()>>>) -> Self {
+        self.0.node("constraints", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ColumnDef<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ColumnGeneratedBuilder(NodeBuilder);
+impl ColumnGenerated<()> {
+    pub fn build() -> ColumnGeneratedBuilder {
+        ColumnGeneratedBuilder(NodeBuilder::new("ColumnGenerated"))
+    }
+}
+impl ColumnGeneratedBuilder {
+    pub fn generated_always(mut self) -> Self {
+        self.0.flag("generated_always");
+        self
+    }
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn column_generated_kind(
+        mut self,
+        value: impl Into<Built<ColumnGeneratedKind<()>>>,
+    ) -> Self {
+        self.0.node("column_generated_kind", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ColumnGenerated<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ColumnNameBuilder(NodeBuilder);
+impl ColumnName<()> {
+    pub fn build() -> ColumnNameBuilder {
+        ColumnNameBuilder(NodeBuilder::new("ColumnName"))
+    }
+    pub fn new(value: &str) -> Result<Built<ColumnName<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl ColumnNameBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<ColumnName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct CommitStmtBuilder(NodeBuilder);
+impl CommitStmt<()> {
+    pub fn build() -> CommitStmtBuilder {
+        CommitStmtBuilder(NodeBuilder::new("CommitStmt"))
+    }
+}
+impl CommitStmtBuilder {
+    pub fn commit_start_kw(mut self, value: impl Into<Built<CommitStartKw<()>>>) -> Self {
+        self.0.node("commit_start_kw", value.into());
+        self
+    }
+    pub fn transaction(mut self) -> Self {
+        self.0.flag("transaction");
+        self
+    }
+    pub fn finish(self) -> Result<Built<CommitStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct CommonTableExprBuilder(NodeBuilder);
+impl CommonTableExpr<()> {
+    pub fn build() -> CommonTableExprBuilder {
+        CommonTableExprBuilder(NodeBuilder::new("CommonTableExpr"))
+    }
+}
+impl CommonTableExprBuilder {
+    pub fn cte_name(mut self, value: impl Into<Built<CteName<()>>>) -> Self {
+        self.0.node("cte_name", value.into());
+        self
+    }
+    pub fn col_name_list(mut self, value: impl Into<Built<ColNameList<()>>>) -> Self {
+        self.0.node("col_name_list", value.into());
+        self
+    }
+    pub fn materialized_cte(mut self, value: impl Into<Built<MaterializedCte<()>>>) -> Self {
+        self.0.node("materialized_cte", value.into());
+        self
+    }
+    pub fn select_stmt_with_cte(mut self, value: impl Into<Built<SelectStmtWithCte<()>>>) -> Self {
+        self.0.node("select_stmt_with_cte", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<CommonTableExpr<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct CompoundSelectBuilder(NodeBuilder);
+impl CompoundSelect<()> {
+    pub fn build() -> CompoundSelectBuilder {
+        CompoundSelectBuilder(NodeBuilder::new("CompoundSelect"))
+    }
+}
+impl CompoundSelectBuilder {
+    pub fn compound_operator(mut self, value: impl Into<Built<CompoundOperator<()>>>) -> Self {
+        self.0.node("compound_operator", value.into());
+        self
+    }
+    pub fn select_core(mut self, value: impl Into<Built<SelectCore<()>>>) -> Self {
+        self.0.node("select_core", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<CompoundSelect<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ConflictClauseBuilder(NodeBuilder);
+impl ConflictClause<()> {
+    pub fn build() -> ConflictClauseBuilder {
+        ConflictClauseBuilder(NodeBuilder::new("ConflictClause"))
+    }
+}
+impl ConflictClauseBuilder {
+    pub fn conflict_action(mut self, value: impl Into<Built<ConflictAction<()>>>) -> Self {
+        self.0.node("conflict_action", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ConflictClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ConstraintNameBuilder(NodeBuilder);
+impl ConstraintName<()> {
+    pub fn build() -> ConstraintNameBuilder {
+        ConstraintNameBuilder(NodeBuilder::new("ConstraintName"))
+    }
+    pub fn new(value: &str) -> Result<Built<ConstraintName<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl ConstraintNameBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<ConstraintName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct CreateIndexStmtBuilder(NodeBuilder);
+impl CreateIndexStmt<()> {
+    pub fn build() -> CreateIndexStmtBuilder {
+        CreateIndexStmtBuilder(NodeBuilder::new("CreateIndexStmt"))
+    }
+}
+impl CreateIndexStmtBuilder {
+    pub fn unique(mut self) -> Self {
+        self.0.flag("unique");
+        self
+    }
+    pub fn if_not_exists(mut self, value: impl Into<Built<IfNotExists<()>>>) -> Self {
+        self.0.node("if_not_exists", value.into());
+        self
+    }
+    pub fn full_index_name(mut self, value: impl Into<Built<FullIndexName<()>>>) -> Self {
+        self.0.node("full_index_name", value.into());
+        self
+    }
+    pub fn table_name(mut self, value: impl Into<Built<TableName<()>>>) -> Self {
+        self.0.node("table_name", value.into());
+        self
+    }
+    pub fn indexed_col_list(mut self, value: impl Into<Built<IndexedColList<()>>>) -> Self {
+        self.0.node("indexed_col_list", value.into());
+        self
+    }
+    pub fn where_clause(mut self, value: impl Into<Built<WhereClause<()>>>) -> Self {
+        self.0.node("where_clause", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<CreateIndexStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct CreateTableSelectBuilder(NodeBuilder);
+impl CreateTableSelect<()> {
+    pub fn build() -> CreateTableSelectBuilder {
+        CreateTableSelectBuilder(NodeBuilder::new("CreateTableSelect"))
+    }
+}
+impl CreateTableSelectBuilder {
+    pub fn select_stmt_with_cte(mut self, value: impl Into<Built<SelectStmtWithCte<()>>>) -> Self {
+        self.0.node("select_stmt_with_cte", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<CreateTableSelect<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct CreateTableStmtBuilder(NodeBuilder);
+impl CreateTableStmt<()> {
+    pub fn build() -> CreateTableStmtBuilder {
+        CreateTableStmtBuilder(NodeBuilder::new("CreateTableStmt"))
+    }
+}
+impl CreateTableStmtBuilder {
+    pub fn temporary(mut self, value: impl Into<Built<Temporary<()>>>) -> Self {
+        self.0.node("temporary", value.into());
+        self
+    }
+    pub fn if_not_exists(mut self, value: impl Into<Built<IfNotExists<()>>>) -> Self {
+        self.0.node("if_not_exists", value.into());
+        self
+    }
+    pub fn full_table_name(mut self, value: impl Into<Built<FullTableName<()>>>) -> Self {
+        self.0.node("full_table_name", value.into());
+        self
+    }
+    pub fn table_columns(mut self, value: impl Into<Built<TableColumns<()>>>) -> Self {
+        self.0.node("table_columns", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<CreateTableStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct CreateTriggerStmtBuilder(NodeBuilder);
+impl CreateTriggerStmt<()> {
+    pub fn build() -> CreateTriggerStmtBuilder {
+        CreateTriggerStmtBuilder(NodeBuilder::new("CreateTriggerStmt"))
+    }
+}
+impl CreateTriggerStmtBuilder {
+    pub fn temporary(mut self, value: impl Into<Built<Temporary<()>>>) -> Self {
+        self.0.node("temporary", value.into());
+        self
+    }
+    pub fn if_not_exists(mut self, value: impl Into<Built<IfNotExists<()>>>) -> Self {
+        self.0.node("if_not_exists", value.into());
+        self
+    }
+    pub fn full_trigger_name(mut self, value: impl Into<Built<FullTriggerName<()>>>) -> Self {
+        self.0.node("full_trigger_name", value.into());
+        self
+    }
+    pub fn trigger_when(mut self, value: impl Into<Built<TriggerWhen<()>>>) -> Self {
+        self.0.node("trigger_when", value.into());
+        self
+    }
+    pub fn trigger_action_kind(mut self, value: impl Into<Built<TriggerActionKind<()>>>) -> Self {
+        self.0.node("trigger_action_kind", value.into());
+        self
+    }
+    pub fn full_table_name(mut self, value: impl Into<Built<FullTableName<()>>>) -> Self {
+        self.0.node("full_table_name", value.into());
+        self
+    }
+    pub fn trigger_for_each_row(mut self, value: impl Into<Built<TriggerForEachRow<()>>>) -> Self {
+        self.0.node("trigger_for_each_row", value.into());
+        self
+    }
+    pub fn trigger_when_expr(mut self, value: impl Into<Built<TriggerWhenExpr<()>>>) -> Self {
+        self.0.node("trigger_when_expr", value.into());
+        self
+    }
+    pub fn trigger_body_stmt_list(
+        mut self,
+        value: impl Into<Built<TriggerBodyStmtList<()>>>,
+    ) -> Self {
+        self.0.node("trigger_body_stmt_list", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<CreateTriggerStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct CreateViewStmtBuilder(NodeBuilder);
+impl CreateViewStmt<()> {
+    pub fn build() -> CreateViewStmtBuilder {
+        CreateViewStmtBuilder(NodeBuilder::new("CreateViewStmt"))
+    }
+}
+impl CreateViewStmtBuilder {
+    pub fn temporary(mut self, value: impl Into<Built<Temporary<()>>>) -> Self {
+        self.0.node("temporary", value.into());
+        self
+    }
+    pub fn if_not_exists(mut self, value: impl Into<Built<IfNotExists<()>>>) -> Self {
+        self.0.node("if_not_exists", value.into());
+        self
+    }
+    pub fn full_view_name(mut self, value: impl Into<Built<FullViewName<()>>>) -> Self {
+        self.0.node("full_view_name", value.into());
+        self
+    }
+    pub fn col_name_list(mut self, value: impl Into<Built<ColNameList<()>>>) -> Self {
+        self.0.node("col_name_list", value.into());
+        self
+    }
+    pub fn select_stmt_with_cte(mut self, value: impl Into<Built<SelectStmtWithCte<()>>>) -> Self {
+        self.0.node("select_stmt_with_cte", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<CreateViewStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct CreateVirtualTableStmtBuilder(NodeBuilder);
+impl CreateVirtualTableStmt<()> {
+    pub fn build() -> CreateVirtualTableStmtBuilder {
+        CreateVirtualTableStmtBuilder(NodeBuilder::new("CreateVirtualTableStmt"))
+    }
+}
+impl CreateVirtualTableStmtBuilder {
+    pub fn if_not_exists(mut self, value: impl Into<Built<IfNotExists<()>>>) -> Self {
+        self.0.node("if_not_exists", value.into());
+        self
+    }
+    pub fn full_table_name(mut self, value: impl Into<Built<FullTableName<()>>>) -> Self {
+        self.0.node("full_table_name", value.into());
+        self
+    }
+    pub fn module_name(mut self, value: impl Into<Built<ModuleName<()>>>) -> Self {
+        self.0.node("module_name", value.into());
+        self
+    }
+    pub fn module_arg_list(mut self, value: impl Into<Built<ModuleArgList<()>>>) -> Self {
+        self.0.node("module_arg_list", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<CreateVirtualTableStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct CteClauseBuilder(NodeBuilder);
+impl CteClause<()> {
+    pub fn build() -> CteClauseBuilder {
+        CteClauseBuilder(NodeBuilder::new("CteClause"))
+    }
+}
+impl CteClauseBuilder {
+    pub fn recursive(mut self) -> Self {
+        self.0.flag("recursive");
+        self
+    }
+    pub fn expressions(mut self, value: impl Into<Built<CommonTableExpr<()>>>) -> Self {
+        self.0.node("expressions", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<CteClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct CteNameBuilder(NodeBuilder);
+impl CteName<()> {
+    pub fn build() -> CteNameBuilder {
+        CteNameBuilder(NodeBuilder::new("CteName"))
+    }
+    pub fn new(value: &str) -> Result<Built<CteName<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl CteNameBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<CteName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct DbNameExprBuilder(NodeBuilder);
+impl DbNameExpr<()> {
+    pub fn build() -> DbNameExprBuilder {
+        DbNameExprBuilder(NodeBuilder::new("DbNameExpr"))
+    }
+}
+impl DbNameExprBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<DbNameExpr<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct DefaultConstraintBuilder(NodeBuilder);
+impl DefaultConstraint<()> {
+    pub fn build() -> DefaultConstraintBuilder {
+        DefaultConstraintBuilder(NodeBuilder::new("DefaultConstraint"))
+    }
+}
+impl DefaultConstraintBuilder {
+    pub fn default_value_kind(mut self, value: impl Into<Built<DefaultValueKind<()>>>) -> Self {
+        self.0.node("default_value_kind", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<DefaultConstraint<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct DefaultConstraintExprBuilder(NodeBuilder);
+impl DefaultConstraintExpr<()> {
+    pub fn build() -> DefaultConstraintExprBuilder {
+        DefaultConstraintExprBuilder(NodeBuilder::new("DefaultConstraintExpr"))
+    }
+}
+impl DefaultConstraintExprBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<DefaultConstraintExpr<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct DefaultConstraintIdenBuilder(NodeBuilder);
+impl DefaultConstraintIden<()> {
+    pub fn build() -> DefaultConstraintIdenBuilder {
+        DefaultConstraintIdenBuilder(NodeBuilder::new("DefaultConstraintIden"))
+    }
+    pub fn new(value: &str) -> Result<Built<DefaultConstraintIden<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl DefaultConstraintIdenBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<DefaultConstraintIden<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct DefaultConstraintLiteralBuilder(NodeBuilder);
+impl DefaultConstraintLiteral<()> {
+    pub fn build() -> DefaultConstraintLiteralBuilder {
+        DefaultConstraintLiteralBuilder(NodeBuilder::new("DefaultConstraintLiteral"))
+    }
+}
+impl DefaultConstraintLiteralBuilder {
+    pub fn plus_or_minus(mut self, value: impl Into<Built<PlusOrMinus<()>>>) -> Self {
+        self.0.node("plus_or_minus", value.into());
+        self
+    }
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<DefaultConstraintLiteral<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct DeleteStmtBuilder(NodeBuilder);
+impl DeleteStmt<()> {
+    pub fn build() -> DeleteStmtBuilder {
+        DeleteStmtBuilder(NodeBuilder::new("DeleteStmt"))
+    }
+}
+impl DeleteStmtBuilder {
+    pub fn qualified_table_name(mut self, value: impl Into<Built<QualifiedTableName<()>>>) -> Self {
+        self.0.node("qualified_table_name", value.into());
+        self
+    }
+    pub fn where_clause(mut self, value: impl Into<Built<WhereClause<()>>>) -> Self {
+        self.0.node("where_clause", value.into());
+        self
+    }
+    pub fn returning_clause(mut self, value: impl Into<Built<ReturningClause<()>>>) -> Self {
+        self.0.node("returning_clause", value.into());
+        self
+    }
+    pub fn delete_stmt_limited(mut self, value: impl Into<Built<DeleteStmtLimited<()>>>) -> Self {
+        self.0.node("delete_stmt_limited", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<DeleteStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct DeleteStmtLimitedBuilder(NodeBuilder);
+impl DeleteStmtLimited<()> {
+    pub fn build() -> DeleteStmtLimitedBuilder {
+        DeleteStmtLimitedBuilder(NodeBuilder::new("DeleteStmtLimited"))
+    }
+}
+impl DeleteStmtLimitedBuilder {
+    pub fn order_by_clause(mut self, value: impl Into<Built<OrderByClause<()>>>) -> Self {
+        self.0.node("order_by_clause", value.into());
+        self
+    }
+    pub fn limit_clause(mut self, value: impl Into<Built<LimitClause<()>>>) -> Self {
+        self.0.node("limit_clause", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<DeleteStmtLimited<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct DetachStmtBuilder(NodeBuilder);
+impl DetachStmt<()> {
+    pub fn build() -> DetachStmtBuilder {
+        DetachStmtBuilder(NodeBuilder::new("DetachStmt"))
+    }
+}
+impl DetachStmtBuilder {
+    pub fn database(mut self) -> Self {
+        self.0.flag("database");
+        self
+    }
+    pub fn db_name_expr(mut self, value: impl Into<Built<DbNameExpr<()>>>) -> Self {
+        self.0.node("db_name_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<DetachStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct DropColumnBuilder(NodeBuilder);
+impl DropColumn<()> {
+    pub fn build() -> DropColumnBuilder {
+        DropColumnBuilder(NodeBuilder::new("DropColumn"))
+    }
+}
+impl DropColumnBuilder {
+    pub fn column(mut self) -> Self {
+        self.0.flag("column");
+        self
+    }
+    pub fn column_name(mut self, value: impl Into<Built<ColumnName<()>>>) -> Self {
+        self.0.node("column_name", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<DropColumn<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct DropIndexStmtBuilder(NodeBuilder);
+impl DropIndexStmt<()> {
+    pub fn build() -> DropIndexStmtBuilder {
+        DropIndexStmtBuilder(NodeBuilder::new("DropIndexStmt"))
+    }
+}
+impl DropIndexStmtBuilder {
+    pub fn if_exists(mut self) -> Self {
+        self.0.flag("if_exists");
+        self
+    }
+    pub fn full_index_name(mut self, value: impl Into<Built<FullIndexName<()>>>) -> Self {
+        self.0.node("full_index_name", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<DropIndexStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct DropTableStmtBuilder(NodeBuilder);
+impl DropTableStmt<()> {
+    pub fn build() -> DropTableStmtBuilder {
+        DropTableStmtBuilder(NodeBuilder::new("DropTableStmt"))
+    }
+}
+impl DropTableStmtBuilder {
+    pub fn if_exists(mut self) -> Self {
+        self.0.flag("if_exists");
+        self
+    }
+    pub fn full_table_name(mut self, value: impl Into<Built<FullTableName<()>>>) -> Self {
+        self.0.node("full_table_name", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<DropTableStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct DropTriggerStmtBuilder(NodeBuilder);
+impl DropTriggerStmt<()> {
+    pub fn build() -> DropTriggerStmtBuilder {
+        DropTriggerStmtBuilder(NodeBuilder::new("DropTriggerStmt"))
+    }
+}
+impl DropTriggerStmtBuilder {
+    pub fn if_exists(mut self) -> Self {
+        self.0.flag("if_exists");
+        self
+    }
+    pub fn full_trigger_name(mut self, value: impl Into<Built<FullTriggerName<()>>>) -> Self {
+        self.0.node("full_trigger_name", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<DropTriggerStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct DropViewStmtBuilder(NodeBuilder);
+impl DropViewStmt<()> {
+    pub fn build() -> DropViewStmtBuilder {
+        DropViewStmtBuilder(NodeBuilder::new("DropViewStmt"))
+    }
+}
+impl DropViewStmtBuilder {
+    pub fn if_exists(mut self) -> Self {
+        self.0.flag("if_exists");
+        self
+    }
+    pub fn full_view_name(mut self, value: impl Into<Built<FullViewName<()>>>) -> Self {
+        self.0.node("full_view_name", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<DropViewStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct EmptyableExprListBuilder(NodeBuilder);
+impl EmptyableExprList<()> {
+    pub fn build() -> EmptyableExprListBuilder {
+        EmptyableExprListBuilder(NodeBuilder::new("EmptyableExprList"))
+    }
+}
+impl EmptyableExprListBuilder {
+    pub fn items(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("items", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<EmptyableExprList<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ExplainClauseBuilder(NodeBuilder);
+impl ExplainClause<()> {
+    pub fn build() -> ExplainClauseBuilder {
+        ExplainClauseBuilder(NodeBuilder::new("ExplainClause"))
+    }
+}
+impl ExplainClauseBuilder {
+    pub fn query_plan(mut self) -> Self {
+        self.0.flag("query_plan");
+        self
+    }
+    pub fn finish(self) -> Result<Built<ExplainClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ExprBindParamBuilder(NodeBuilder);
+impl ExprBindParam<()> {
+    pub fn build() -> ExprBindParamBuilder {
+        ExprBindParamBuilder(NodeBuilder::new("ExprBindParam"))
+    }
+    pub fn new(value: &str) -> Result<Built<ExprBindParam<()>>, BuildError> {
+        Self::build().param(value).finish()
+    }
+}
+impl ExprBindParamBuilder {
+    pub fn param(mut self, value: &str) -> Self {
+        self.0.token("param", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<ExprBindParam<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ExprCaseBuilder(NodeBuilder);
+impl ExprCase<()> {
+    pub fn build() -> ExprCaseBuilder {
+        ExprCaseBuilder(NodeBuilder::new("ExprCase"))
+    }
+}
+impl ExprCaseBuilder {
+    pub fn case_target_expr(mut self, value: impl Into<Built<CaseTargetExpr<()>>>) -> Self {
+        self.0.node("case_target_expr", value.into());
+        self
+    }
+    pub fn case_when_clause_list(
+        mut self,
+        value: impl Into<Built<CaseWhenClauseList<()>>>,
+    ) -> Self {
+        self.0.node("case_when_clause_list", value.into());
+        self
+    }
+    pub fn case_else_clause(mut self, value: impl Into<Built<CaseElseClause<()>>>) -> Self {
+        self.0.node("case_else_clause", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ExprCase<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ExprCastBuilder(NodeBuilder);
+impl ExprCast<()> {
+    pub fn build() -> ExprCastBuilder {
+        ExprCastBuilder(NodeBuilder::new("ExprCast"))
+    }
+}
+impl ExprCastBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn type_name(mut self, value: impl Into<Built<TypeName<()>>>) -> Self {
+        self.0.node("type_name", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ExprCast<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ExprColumnNameBuilder(NodeBuilder);
+impl ExprColumnName<()> {
+    pub fn build() -> ExprColumnNameBuilder {
+        ExprColumnNameBuilder(NodeBuilder::new("ExprColumnName"))
+    }
+}
+impl ExprColumnNameBuilder {
+    pub fn schema_name(mut self, value: impl Into<Built<SchemaName<()>>>) -> Self {
+        self.0.node("schema_name", value.into());
+        self
+    }
+    pub fn table_name(mut self, value: impl Into<Built<TableName<()>>>) -> Self {
+        self.0.node("table_name", value.into());
+        self
+    }
+    pub fn column_name(mut self, value: impl Into<Built<ColumnName<()>>>) -> Self {
+        self.0.node("column_name", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ExprColumnName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ExprExistsSelectBuilder(NodeBuilder);
+impl ExprExistsSelect<()> {
+    pub fn build() -> ExprExistsSelectBuilder {
+        ExprExistsSelectBuilder(NodeBuilder::new("ExprExistsSelect"))
+    }
+}
+impl ExprExistsSelectBuilder {
+    pub fn not(mut self) -> Self {
+        self.0.flag("not");
+        self
+    }
+    pub fn exists(mut self) -> Self {
+        self.0.flag("exists");
+        self
+    }
+    pub fn select_stmt_with_cte(mut self, value: impl Into<Built<SelectStmtWithCte<()>>>) -> Self {
+        self.0.node("select_stmt_with_cte", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ExprExistsSelect<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ExprFuncBuilder(NodeBuilder);
+impl ExprFunc<()> {
+    pub fn build() -> ExprFuncBuilder {
+        ExprFuncBuilder(NodeBuilder::new("ExprFunc"))
+    }
+}
+impl ExprFuncBuilder {
+    pub fn function_name(mut self, value: impl Into<Built<FunctionName<()>>>) -> Self {
+        self.0.node("function_name", value.into());
+        self
+    }
+    pub fn func_arguments(mut self, value: impl Into<Built<FuncArguments<()>>>) -> Self {
+        self.0.node("func_arguments", value.into());
+        self
+    }
+    pub fn filter_clause(mut self, value: impl Into<Built<FilterClause<()>>>) -> Self {
+        self.0.node("filter_clause", value.into());
+        self
+    }
+    pub fn over_clause(mut self, value: impl Into<Built<OverClause<()>>>) -> Self {
+        self.0.node("over_clause", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ExprFunc<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ExprListBuilder(NodeBuilder);
+impl ExprList<()> {
+    pub fn build() -> ExprListBuilder {
+        ExprListBuilder(NodeBuilder::new("ExprList"))
+    }
+}
+impl ExprListBuilder {
+    pub fn items(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("items", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ExprList<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ExprParenBuilder(NodeBuilder);
+impl ExprParen<()> {
+    pub fn build() -> ExprParenBuilder {
+        ExprParenBuilder(NodeBuilder::new("ExprParen"))
+    }
+}
+impl ExprParenBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ExprParen<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ExprSelectBuilder(NodeBuilder);
+impl ExprSelect<()> {
+    pub fn build() -> ExprSelectBuilder {
+        ExprSelectBuilder(NodeBuilder::new("ExprSelect"))
+    }
+}
+impl ExprSelectBuilder {
+    pub fn select_stmt_with_cte(mut self, value: impl Into<Built<SelectStmtWithCte<()>>>) -> Self {
+        self.0.node("select_stmt_with_cte", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ExprSelect<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FileBuilder(NodeBuilder);
+impl File<()> {
+    pub fn build() -> FileBuilder {
+        FileBuilder(NodeBuilder::new("File"))
+    }
+}
+impl FileBuilder {
+    pub fn statements(mut self, value: impl Into<Built<Statement<()>>>) -> Self {
+        self.0.node("statements", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<File<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FileNameExprBuilder(NodeBuilder);
+impl FileNameExpr<()> {
+    pub fn build() -> FileNameExprBuilder {
+        FileNameExprBuilder(NodeBuilder::new("FileNameExpr"))
+    }
+}
+impl FileNameExprBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<FileNameExpr<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FilterClauseBuilder(NodeBuilder);
+impl FilterClause<()> {
+    pub fn build() -> FilterClauseBuilder {
+        FilterClauseBuilder(NodeBuilder::new("FilterClause"))
+    }
+}
+impl FilterClauseBuilder {
+    pub fn where_clause(mut self, value: impl Into<Built<WhereClause<()>>>) -> Self {
+        self.0.node("where_clause", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<FilterClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FkCascadeBuilder(NodeBuilder);
+impl FkCascade<()> {
+    pub fn build() -> FkCascadeBuilder {
+        FkCascadeBuilder(NodeBuilder::new("FkCascade"))
+    }
+}
+impl FkCascadeBuilder {
+    pub fn finish(self) -> Result<Built<FkCascade<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FkClauseBuilder(NodeBuilder);
+impl FkClause<()> {
+    pub fn build() -> FkClauseBuilder {
+        FkClauseBuilder(NodeBuilder::new("FkClause"))
+    }
+}
+impl FkClauseBuilder {
+    pub fn table_name(mut self, value: impl Into<Built<TableName<()>>>) -> Self {
+        self.0.node("table_name", value.into());
+        self
+    }
+    pub fn col_name_list(mut self, value: impl Into<Built<ColNameList<()>>>) -> Self {
+        self.0.node("col_name_list", value.into());
+        self
+    }
+    pub fn fk_actions(mut self, value: impl Into<Built<FkViolateAction<()>>>) -> Self {
+        self.0.node("fk_actions", value.into());
+        self
+    }
+    pub fn fk_deferrable(mut self, value: impl Into<Built<FkDeferrable<()>>>) -> Self {
+        self.0.node("fk_deferrable", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<FkClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FkDeferrableBuilder(NodeBuilder);
+impl FkDeferrable<()> {
+    pub fn build() -> FkDeferrableBuilder {
+        FkDeferrableBuilder(NodeBuilder::new("FkDeferrable"))
+    }
+}
+impl FkDeferrableBuilder {
+    pub fn not(mut self) -> Self {
+        self.0.flag("not");
+        self
+    }
+    pub fn defer_kind(mut self, value: impl Into<Built<DeferKind<()>>>) -> Self {
+        self.0.node("defer_kind", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<FkDeferrable<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FkMatchActionBuilder(NodeBuilder);
+impl FkMatchAction<()> {
+    pub fn build() -> FkMatchActionBuilder {
+        FkMatchActionBuilder(NodeBuilder::new("FkMatchAction"))
+    }
+}
+impl FkMatchActionBuilder {
+    pub fn any_valid_name(mut self, value: impl Into<Built<AnyValidName<()>>>) -> Self {
+        self.0.node("any_valid_name", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<FkMatchAction<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FkNoActionBuilder(NodeBuilder);
+impl FkNoAction<()> {
+    pub fn build() -> FkNoActionBuilder {
+        FkNoActionBuilder(NodeBuilder::new("FkNoAction"))
+    }
+}
+impl FkNoActionBuilder {
+    pub fn finish(self) -> Result<Built<FkNoAction<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FkOnActionBuilder(NodeBuilder);
+impl FkOnAction<()> {
+    pub fn build() -> FkOnActionBuilder {
+        FkOnActionBuilder(NodeBuilder::new("FkOnAction"))
+    }
+}
+impl FkOnActionBuilder {
+    pub fn fk_fail_kind(mut self, value: impl Into<Built<FkFailKind<()>>>) -> Self {
+        self.0.node("fk_fail_kind", value.into());
+        self
+    }
+    pub fn fk_action(mut self, value: impl Into<Built<FkAction<()>>>) -> Self {
+        self.0.node("fk_action", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<FkOnAction<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FkRestrictBuilder(NodeBuilder);
+impl FkRestrict<()> {
+    pub fn build() -> FkRestrictBuilder {
+        FkRestrictBuilder(NodeBuilder::new("FkRestrict"))
+    }
+}
+impl FkRestrictBuilder {
+    pub fn finish(self) -> Result<Built<FkRestrict<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FkSetDefaultBuilder(NodeBuilder);
+impl FkSetDefault<()> {
+    pub fn build() -> FkSetDefaultBuilder {
+        FkSetDefaultBuilder(NodeBuilder::new("FkSetDefault"))
+    }
+}
+impl FkSetDefaultBuilder {
+    pub fn finish(self) -> Result<Built<FkSetDefault<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FkSetNullBuilder(NodeBuilder);
+impl FkSetNull<()> {
+    pub fn build() -> FkSetNullBuilder {
+        FkSetNullBuilder(NodeBuilder::new("FkSetNull"))
+    }
+}
+impl FkSetNullBuilder {
+    pub fn finish(self) -> Result<Built<FkSetNull<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FkViolateActionBuilder(NodeBuilder);
+impl FkViolateAction<()> {
+    pub fn build() -> FkViolateActionBuilder {
+        FkViolateActionBuilder(NodeBuilder::new("FkViolateAction"))
+    }
+}
+impl FkViolateActionBuilder {
+    pub fn fk_on_or_match(mut self, value: impl Into<Built<FkOnOrMatch<()>>>) -> Self {
+        self.0.node("fk_on_or_match", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<FkViolateAction<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FrameSpecBuilder(NodeBuilder);
+impl FrameSpec<()> {
+    pub fn build() -> FrameSpecBuilder {
+        FrameSpecBuilder(NodeBuilder::new("FrameSpec"))
+    }
+}
+impl FrameSpecBuilder {
+    pub fn range(mut self, value: impl Into<Built<Range<()>>>) -> Self {
+        self.0.node("range", value.into());
+        self
+    }
+    pub fn frame_spec_kind(mut self, value: impl Into<Built<FrameSpecKind<()>>>) -> Self {
+        self.0.node("frame_spec_kind", value.into());
+        self
+    }
+    pub fn frame_spec_exclude_clause(
+        mut self,
+        value: impl Into<Built<FrameSpecExcludeClause<()>>>,
+    ) -> Self {
+        self.0.node("frame_spec_exclude_clause", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<FrameSpec<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FrameSpecBetweenClauseBuilder(NodeBuilder);
+impl FrameSpecBetweenClause<()> {
+    pub fn build() -> FrameSpecBetweenClauseBuilder {
+        FrameSpecBetweenClauseBuilder(NodeBuilder::new("FrameSpecBetweenClause"))
+    }
+}
+impl FrameSpecBetweenClauseBuilder {
+    pub fn frame_spec_between_left(
+        mut self,
+        value: impl Into<Built<FrameSpecBetweenLeft<()>>>,
+    ) -> Self {
+        self.0.node("frame_spec_between_left", value.into());
+        self
+    }
+    pub fn frame_spec_between_right(
+        mut self,
+        value: impl Into<Built<FrameSpecBetweenRight<()>>>,
+    ) -> Self {
+        self.0.node("frame_spec_between_right", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<FrameSpecBetweenClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FrameSpecCurrentRowBuilder(NodeBuilder);
+impl FrameSpecCurrentRow<()> {
+    pub fn build() -> FrameSpecCurrentRowBuilder {
+        FrameSpecCurrentRowBuilder(NodeBuilder::new("FrameSpecCurrentRow"))
+    }
+}
+impl FrameSpecCurrentRowBuilder {
+    pub fn finish(self) -> Result<Built<FrameSpecCurrentRow<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FrameSpecExcludeClauseBuilder(NodeBuilder);
+impl FrameSpecExcludeClause<()> {
+    pub fn build() -> FrameSpecExcludeClauseBuilder {
+        FrameSpecExcludeClauseBuilder(NodeBuilder::new("FrameSpecExcludeClause"))
+    }
+}
+impl FrameSpecExcludeClauseBuilder {
+    pub fn frame_spec_exclude_kind(
+        mut self,
+        value: impl Into<Built<FrameSpecExcludeKind<()>>>,
+    ) -> Self {
+        self.0.node("frame_spec_exclude_kind", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<FrameSpecExcludeClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FrameSpecFollowingBuilder(NodeBuilder);
+impl FrameSpecFollowing<()> {
+    pub fn build() -> FrameSpecFollowingBuilder {
+        FrameSpecFollowingBuilder(NodeBuilder::new("FrameSpecFollowing"))
+    }
+}
+impl FrameSpecFollowingBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<FrameSpecFollowing<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FrameSpecNoOthersBuilder(NodeBuilder);
+impl FrameSpecNoOthers<()> {
+    pub fn build() -> FrameSpecNoOthersBuilder {
+        FrameSpecNoOthersBuilder(NodeBuilder::new("FrameSpecNoOthers"))
+    }
+}
+impl FrameSpecNoOthersBuilder {
+    pub fn finish(self) -> Result<Built<FrameSpecNoOthers<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FrameSpecPrecedingBuilder(NodeBuilder);
+impl FrameSpecPreceding<()> {
+    pub fn build() -> FrameSpecPrecedingBuilder {
+        FrameSpecPrecedingBuilder(NodeBuilder::new("FrameSpecPreceding"))
+    }
+}
+impl FrameSpecPrecedingBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<FrameSpecPreceding<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FrameSpecUnboundedFollowingBuilder(NodeBuilder);
+impl FrameSpecUnboundedFollowing<()> {
+    pub fn build() -> FrameSpecUnboundedFollowingBuilder {
+        FrameSpecUnboundedFollowingBuilder(NodeBuilder::new("FrameSpecUnboundedFollowing"))
+    }
+}
+impl FrameSpecUnboundedFollowingBuilder {
+    pub fn finish(self) -> Result<Built<FrameSpecUnboundedFollowing<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FrameSpecUnboundedPrecedingBuilder(NodeBuilder);
+impl FrameSpecUnboundedPreceding<()> {
+    pub fn build() -> FrameSpecUnboundedPrecedingBuilder {
+        FrameSpecUnboundedPrecedingBuilder(NodeBuilder::new("FrameSpecUnboundedPreceding"))
+    }
+}
+impl FrameSpecUnboundedPrecedingBuilder {
+    pub fn finish(self) -> Result<Built<FrameSpecUnboundedPreceding<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FromClauseBuilder(NodeBuilder);
+impl FromClause<()> {
+    pub fn build() -> FromClauseBuilder {
+        FromClauseBuilder(NodeBuilder::new("FromClause"))
+    }
+}
+impl FromClauseBuilder {
+    pub fn from_clause_value(mut self, value: impl Into<Built<FromClauseValue<()>>>) -> Self {
+        self.0.node("from_clause_value", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<FromClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FromClauseTableValueFunctionBuilder(NodeBuilder);
+impl FromClauseTableValueFunction<()> {
+    pub fn build() -> FromClauseTableValueFunctionBuilder {
+        FromClauseTableValueFunctionBuilder(NodeBuilder::new("FromClauseTableValueFunction"))
+    }
+}
+impl FromClauseTableValueFunctionBuilder {
+    pub fn full_table_function_name(
+        mut self,
+        value: impl Into<Built<FullTableFunctionName<()>>>,
+    ) -> Self {
+        self.0.node("full_table_function_name", value.into());
+        self
+    }
+    pub fn emptyable_expr_list(mut self, value: impl Into<Built<EmptyableExprList<()>>>) -> Self {
+        self.0.node("emptyable_expr_list", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<FromClauseTableValueFunction<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FullIndexNameBuilder(NodeBuilder);
+impl FullIndexName<()> {
+    pub fn build() -> FullIndexNameBuilder {
+        FullIndexNameBuilder(NodeBuilder::new("FullIndexName"))
+    }
+}
+impl FullIndexNameBuilder {
+    pub fn schema_name(mut self, value: impl Into<Built<SchemaName<()>>>) -> Self {
+        self.0.node("schema_name", value.into());
+        self
+    }
+    pub fn index_name(mut self, value: impl Into<Built<IndexName<()>>>) -> Self {
+        self.0.node("index_name", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<FullIndexName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FullPragmaNameBuilder(NodeBuilder);
+impl FullPragmaName<()> {
+    pub fn build() -> FullPragmaNameBuilder {
+        FullPragmaNameBuilder(NodeBuilder::new("FullPragmaName"))
+    }
+}
+impl FullPragmaNameBuilder {
+    pub fn schema_name(mut self, value: impl Into<Built<SchemaName<()>>>) -> Self {
+        self.0.node("schema_name", value.into());
+        self
+    }
+    pub fn pragma(mut self, value: impl Into<Built<PragmaName<()>>>) -> Self {
+        self.0.node("pragma", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<FullPragmaName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FullTableFunctionNameBuilder(NodeBuilder);
+impl FullTableFunctionName<()> {
+    pub fn build() -> FullTableFunctionNameBuilder {
+        FullTableFunctionNameBuilder(NodeBuilder::new("FullTableFunctionName"))
+    }
+}
+impl FullTableFunctionNameBuilder {
+    pub fn schema_name(mut self, value: impl Into<Built<SchemaName<()>>>) -> Self {
+        self.0.node("schema_name", value.into());
+        self
+    }
+    pub fn table_func(mut self, value: impl Into<Built<TableFunctionName<()>>>) -> Self {
+        self.0.node("table_func", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<FullTableFunctionName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FullTableNameBuilder(NodeBuilder);
+impl FullTableName<()> {
+    pub fn build() -> FullTableNameBuilder {
+        FullTableNameBuilder(NodeBuilder::new("FullTableName"))
+    }
+}
+impl FullTableNameBuilder {
+    pub fn schema_name(mut self, value: impl Into<Built<SchemaName<()>>>) -> Self {
+        self.0.node("schema_name", value.into());
+        self
+    }
+    pub fn table(mut self, value: impl Into<Built<TableName<()>>>) -> Self {
+        self.0.node("table", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<FullTableName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FullTriggerNameBuilder(NodeBuilder);
+impl FullTriggerName<()> {
+    pub fn build() -> FullTriggerNameBuilder {
+        FullTriggerNameBuilder(NodeBuilder::new("FullTriggerName"))
+    }
+}
+impl FullTriggerNameBuilder {
+    pub fn schema_name(mut self, value: impl Into<Built<SchemaName<()>>>) -> Self {
+        self.0.node("schema_name", value.into());
+        self
+    }
+    pub fn trigger(mut self, value: impl Into<Built<TriggerName<()>>>) -> Self {
+        self.0.node("trigger", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<FullTriggerName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FullViewNameBuilder(NodeBuilder);
+impl FullViewName<()> {
+    pub fn build() -> FullViewNameBuilder {
+        FullViewNameBuilder(NodeBuilder::new("FullViewName"))
+    }
+}
+impl FullViewNameBuilder {
+    pub fn schema_name(mut self, value: impl Into<Built<SchemaName<()>>>) -> Self {
+        self.0.node("schema_name", value.into());
+        self
+    }
+    pub fn view_name(mut self, value: impl Into<Built<ViewName<()>>>) -> Self {
+        self.0.node("view_name", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<FullViewName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct FunctionNameBuilder(NodeBuilder);
+impl FunctionName<()> {
+    pub fn build() -> FunctionNameBuilder {
+        FunctionNameBuilder(NodeBuilder::new("FunctionName"))
+    }
+    pub fn new(value: &str) -> Result<Built<FunctionName<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl FunctionNameBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<FunctionName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct GroupByClauseBuilder(NodeBuilder);
+impl GroupByClause<()> {
+    pub fn build() -> GroupByClauseBuilder {
+        GroupByClauseBuilder(NodeBuilder::new("GroupByClause"))
+    }
+}
+impl GroupByClauseBuilder {
+    pub fn items(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("items", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<GroupByClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct HavingClauseBuilder(NodeBuilder);
+impl HavingClause<()> {
+    pub fn build() -> HavingClauseBuilder {
+        HavingClauseBuilder(NodeBuilder::new("HavingClause"))
+    }
+}
+impl HavingClauseBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<HavingClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct IfNotExistsBuilder(NodeBuilder);
+impl IfNotExists<()> {
+    pub fn build() -> IfNotExistsBuilder {
+        IfNotExistsBuilder(NodeBuilder::new("IfNotExists"))
+    }
+}
+impl IfNotExistsBuilder {
+    pub fn finish(self) -> Result<Built<IfNotExists<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct InTableBuilder(NodeBuilder);
+impl InTable<()> {
+    pub fn build() -> InTableBuilder {
+        InTableBuilder(NodeBuilder::new("InTable"))
+    }
+}
+impl InTableBuilder {
+    pub fn full_table_name(mut self, value: impl Into<Built<FullTableName<()>>>) -> Self {
+        self.0.node("full_table_name", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<InTable<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct InTableFuncBuilder(NodeBuilder);
+impl InTableFunc<()> {
+    pub fn build() -> InTableFuncBuilder {
+        InTableFuncBuilder(NodeBuilder::new("InTableFunc"))
+    }
+}
+impl InTableFuncBuilder {
+    pub fn full_table_function_name(
+        mut self,
+        value: impl Into<Built<FullTableFunctionName<()>>>,
+    ) -> Self {
+        self.0.node("full_table_function_name", value.into());
+        self
+    }
+    pub fn items(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("items", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<InTableFunc<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct IndexNameBuilder(NodeBuilder);
+impl IndexName<()> {
+    pub fn build() -> IndexNameBuilder {
+        IndexNameBuilder(NodeBuilder::new("IndexName"))
+    }
+    pub fn new(value: &str) -> Result<Built<IndexName<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl IndexNameBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<IndexName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct IndexedColBuilder(NodeBuilder);
+impl IndexedCol<()> {
+    pub fn build() -> IndexedColBuilder {
+        IndexedColBuilder(NodeBuilder::new("IndexedCol"))
+    }
+}
+impl IndexedColBuilder {
+    pub fn index_column(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("index_column", value.into());
+        self
+    }
+    pub fn collation(mut self, value: impl Into<Built<Collation<()>>>) -> Self {
+        self.0.node("collation", value.into());
+        self
+    }
+    pub fn order(mut self, value: impl Into<Built<Order<()>>>) -> Self {
+        self.0.node("order", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<IndexedCol<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct IndexedColListBuilder(NodeBuilder);
+impl IndexedColList<()> {
+    pub fn build() -> IndexedColListBuilder {
+        IndexedColListBuilder(NodeBuilder::new("IndexedColList"))
+    }
+}
+impl IndexedColListBuilder {
+    pub fn items(mut self, value: impl Into<Built<IndexedCol<()>>>) -> Self {
+        self.0.node("items", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<IndexedColList<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct InsertDefaultValuesClauseBuilder(NodeBuilder);
+impl InsertDefaultValuesClause<()> {
+    pub fn build() -> InsertDefaultValuesClauseBuilder {
+        InsertDefaultValuesClauseBuilder(NodeBuilder::new("InsertDefaultValuesClause"))
+    }
+}
+impl InsertDefaultValuesClauseBuilder {
+    pub fn finish(self) -> Result<Built<InsertDefaultValuesClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct InsertOrActionBuilder(NodeBuilder);
+impl InsertOrAction<()> {
+    pub fn build() -> InsertOrActionBuilder {
+        InsertOrActionBuilder(NodeBuilder::new("InsertOrAction"))
+    }
+}
+impl InsertOrActionBuilder {
+    pub fn conflict_action(mut self, value: impl Into<Built<ConflictAction<()>>>) -> Self {
+        self.0.node("conflict_action", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<InsertOrAction<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct InsertSelectClauseBuilder(NodeBuilder);
+impl InsertSelectClause<()> {
+    pub fn build() -> InsertSelectClauseBuilder {
+        InsertSelectClauseBuilder(NodeBuilder::new("InsertSelectClause"))
+    }
+}
+impl InsertSelectClauseBuilder {
+    pub fn select_stmt_with_cte(mut self, value: impl Into<Built<SelectStmtWithCte<()>>>) -> Self {
+        self.0.node("select_stmt_with_cte", value.into());
+        self
+    }
+    pub fn upsert_clauses(mut self, value: impl Into<Built<UpsertClause<()>>>) -> Self {
+        self.0.node("upsert_clauses", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<InsertSelectClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct InsertStmtBuilder(NodeBuilder);
+impl InsertStmt<()> {
+    pub fn build() -> InsertStmtBuilder {
+        InsertStmtBuilder(NodeBuilder::new("InsertStmt"))
+    }
+}
+impl InsertStmtBuilder {
+    pub fn insert_stmt_kind(mut self, value: impl Into<Built<InsertStmtKind<()>>>) -> Self {
+        self.0.node("insert_stmt_kind", value.into());
+        self
+    }
+    pub fn full_table_name(mut self, value: impl Into<Built<FullTableName<()>>>) -> Self {
+        self.0.node("full_table_name", value.into());
+        self
+    }
+    pub fn with_alias(mut self, value: impl Into<Built<WithAlias<()>>>) -> Self {
+        self.0.node("with_alias", value.into());
+        self
+    }
+    pub fn col_name_list(mut self, value: impl Into<Built<ColNameList<()>>>) -> Self {
+        self.0.node("col_name_list", value.into());
+        self
+    }
+    pub fn insert_value_kind(mut self, value: impl Into<Built<InsertValueKind<()>>>) -> Self {
+        self.0.node("insert_value_kind", value.into());
+        self
+    }
+    pub fn returning_clause(mut self, value: impl Into<Built<ReturningClause<()>>>) -> Self {
+        self.0.node("returning_clause", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<InsertStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct InsertValuesClauseBuilder(NodeBuilder);
+impl InsertValuesClause<()> {
+    pub fn build() -> InsertValuesClauseBuilder {
+        InsertValuesClauseBuilder(NodeBuilder::new("InsertValuesClause"))
+    }
+}
+impl InsertValuesClauseBuilder {
+    pub fn expr_lists(mut self, value: impl Into<Built<ExprList<()>>>) -> Self {
+        self.0.node("expr_lists", value.into());
+        self
+    }
+    pub fn upsert_clauses(mut self, value: impl Into<Built<UpsertClause<()>>>) -> Self {
+        self.0.node("upsert_clauses", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<InsertValuesClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct JoinClauseBuilder(NodeBuilder);
+impl JoinClause<()> {
+    pub fn build() -> JoinClauseBuilder {
+        JoinClauseBuilder(NodeBuilder::new("JoinClause"))
+    }
+}
+impl JoinClauseBuilder {
+    pub fn left_operand(mut self, value: impl Into<Built<FromClauseValue<()>>>) -> Self {
+        self.0.node("left_operand", value.into());
+        self
+    }
+    pub fn join_operator(mut self, value: impl Into<Built<JoinOperator<()>>>) -> Self {
+        self.0.node("join_operator", value.into());
+        self
+    }
+    pub fn right_operand(mut self, value: impl Into<Built<FromClauseValue<()>>>) -> Self {
+        self.0.node("right_operand", value.into());
+        self
+    }
+    pub fn join_constraint(mut self, value: impl Into<Built<JoinConstraint<()>>>) -> Self {
+        self.0.node("join_constraint", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<JoinClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct JoinOperatorBuilder(NodeBuilder);
+impl JoinOperator<()> {
+    pub fn build() -> JoinOperatorBuilder {
+        JoinOperatorBuilder(NodeBuilder::new("JoinOperator"))
+    }
+}
+impl JoinOperatorBuilder {
+    pub fn comma(mut self) -> Self {
+        self.0.flag("comma");
+        self
+    }
+    pub fn any_join_kw(mut self, value: &str) -> Self {
+        self.0.token("any_join_kw", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<JoinOperator<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct LimitClauseBuilder(NodeBuilder);
+impl LimitClause<()> {
+    pub fn build() -> LimitClauseBuilder {
+        LimitClauseBuilder(NodeBuilder::new("LimitClause"))
+    }
+}
+impl LimitClauseBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn offset(mut self, value: impl Into<Built<Offset<()>>>) -> Self {
+        self.0.node("offset", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<LimitClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct MaterializedCteBuilder(NodeBuilder);
+impl MaterializedCte<()> {
+    pub fn build() -> MaterializedCteBuilder {
+        MaterializedCteBuilder(NodeBuilder::new("MaterializedCte"))
+    }
+}
+impl MaterializedCteBuilder {
+    pub fn not(mut self) -> Self {
+        self.0.flag("not");
+        self
+    }
+    pub fn finish(self) -> Result<Built<MaterializedCte<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ModuleArgListBuilder(NodeBuilder);
+impl ModuleArgList<()> {
+    pub fn build() -> ModuleArgListBuilder {
+        ModuleArgListBuilder(NodeBuilder::new("ModuleArgList"))
+    }
+}
+impl ModuleArgListBuilder {
+    pub fn items(mut self, value: impl Into<Built<ModuleArg<()>>>) -> Self {
+        self.0.node("items", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ModuleArgList<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ModuleNameBuilder(NodeBuilder);
+impl ModuleName<()> {
+    pub fn build() -> ModuleNameBuilder {
+        ModuleNameBuilder(NodeBuilder::new("ModuleName"))
+    }
+    pub fn new(value: &str) -> Result<Built<ModuleName<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl ModuleNameBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<ModuleName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct NewColumnNameBuilder(NodeBuilder);
+impl NewColumnName<()> {
+    pub fn build() -> NewColumnNameBuilder {
+        NewColumnNameBuilder(NodeBuilder::new("NewColumnName"))
+    }
+    pub fn new(value: &str) -> Result<Built<NewColumnName<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl NewColumnNameBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<NewColumnName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct NewTableNameBuilder(NodeBuilder);
+impl NewTableName<()> {
+    pub fn build() -> NewTableNameBuilder {
+        NewTableNameBuilder(NodeBuilder::new("NewTableName"))
+    }
+    pub fn new(value: &str) -> Result<Built<NewTableName<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl NewTableNameBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<NewTableName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct NullConstraintBuilder(NodeBuilder);
+impl NullConstraint<()> {
+    pub fn build() -> NullConstraintBuilder {
+        NullConstraintBuilder(NodeBuilder::new("NullConstraint"))
+    }
+}
+impl NullConstraintBuilder {
+    pub fn is_not_null(mut self) -> Self {
+        self.0.flag("is_not_null");
+        self
+    }
+    pub fn conflict_clause(mut self, value: impl Into<Built<ConflictClause<()>>>) -> Self {
+        self.0.node("conflict_clause", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<NullConstraint<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OffsetBuilder(NodeBuilder);
+impl Offset<()> {
+    pub fn build() -> OffsetBuilder {
+        OffsetBuilder(NodeBuilder::new("Offset"))
+    }
+}
+impl OffsetBuilder {
+    pub fn int_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("int_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<Offset<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OnConstraintBuilder(NodeBuilder);
+impl OnConstraint<()> {
+    pub fn build() -> OnConstraintBuilder {
+        OnConstraintBuilder(NodeBuilder::new("OnConstraint"))
+    }
+}
+impl OnConstraintBuilder {
+    pub fn on_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("on_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OnConstraint<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpAddBuilder(NodeBuilder);
+impl OpAdd<()> {
+    pub fn build() -> OpAddBuilder {
+        OpAddBuilder(NodeBuilder::new("OpAdd"))
+    }
+}
+impl OpAddBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpAdd<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpAndBuilder(NodeBuilder);
+impl OpAnd<()> {
+    pub fn build() -> OpAndBuilder {
+        OpAndBuilder(NodeBuilder::new("OpAnd"))
+    }
+}
+impl OpAndBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpAnd<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpBetweenAndBuilder(NodeBuilder);
+impl OpBetweenAnd<()> {
+    pub fn build() -> OpBetweenAndBuilder {
+        OpBetweenAndBuilder(NodeBuilder::new("OpBetweenAnd"))
+    }
+}
+impl OpBetweenAndBuilder {
+    pub fn target_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("target_expr", value.into());
+        self
+    }
+    pub fn low_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("low_expr", value.into());
+        self
+    }
+    pub fn high_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("high_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpBetweenAnd<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpBinAndBuilder(NodeBuilder);
+impl OpBinAnd<()> {
+    pub fn build() -> OpBinAndBuilder {
+        OpBinAndBuilder(NodeBuilder::new("OpBinAnd"))
+    }
+}
+impl OpBinAndBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpBinAnd<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpBinComplementBuilder(NodeBuilder);
+impl OpBinComplement<()> {
+    pub fn build() -> OpBinComplementBuilder {
+        OpBinComplementBuilder(NodeBuilder::new("OpBinComplement"))
+    }
+}
+impl OpBinComplementBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpBinComplement<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpBinLShiftBuilder(NodeBuilder);
+impl OpBinLShift<()> {
+    pub fn build() -> OpBinLShiftBuilder {
+        OpBinLShiftBuilder(NodeBuilder::new("OpBinLShift"))
+    }
+}
+impl OpBinLShiftBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpBinLShift<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpBinOrBuilder(NodeBuilder);
+impl OpBinOr<()> {
+    pub fn build() -> OpBinOrBuilder {
+        OpBinOrBuilder(NodeBuilder::new("OpBinOr"))
+    }
+}
+impl OpBinOrBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpBinOr<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpBinRShiftBuilder(NodeBuilder);
+impl OpBinRShift<()> {
+    pub fn build() -> OpBinRShiftBuilder {
+        OpBinRShiftBuilder(NodeBuilder::new("OpBinRShift"))
+    }
+}
+impl OpBinRShiftBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpBinRShift<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpCollateBuilder(NodeBuilder);
+impl OpCollate<()> {
+    pub fn build() -> OpCollateBuilder {
+        OpCollateBuilder(NodeBuilder::new("OpCollate"))
+    }
+}
+impl OpCollateBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn collation(mut self, value: impl Into<Built<Collation<()>>>) -> Self {
+        self.0.node("collation", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpCollate<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpConcatBuilder(NodeBuilder);
+impl OpConcat<()> {
+    pub fn build() -> OpConcatBuilder {
+        OpConcatBuilder(NodeBuilder::new("OpConcat"))
+    }
+}
+impl OpConcatBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpConcat<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpDivideBuilder(NodeBuilder);
+impl OpDivide<()> {
+    pub fn build() -> OpDivideBuilder {
+        OpDivideBuilder(NodeBuilder::new("OpDivide"))
+    }
+}
+impl OpDivideBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpDivide<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpEqBuilder(NodeBuilder);
+impl OpEq<()> {
+    pub fn build() -> OpEqBuilder {
+        OpEqBuilder(NodeBuilder::new("OpEq"))
+    }
+}
+impl OpEqBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn eq_sql(mut self) -> Self {
+        self.0.flag("eq_sql");
+        self
+    }
+    pub fn eq(mut self) -> Self {
+        self.0.flag("eq");
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpEq<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpEscapeBuilder(NodeBuilder);
+impl OpEscape<()> {
+    pub fn build() -> OpEscapeBuilder {
+        OpEscapeBuilder(NodeBuilder::new("OpEscape"))
+    }
+}
+impl OpEscapeBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpEscape<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpExtractOneBuilder(NodeBuilder);
+impl OpExtractOne<()> {
+    pub fn build() -> OpExtractOneBuilder {
+        OpExtractOneBuilder(NodeBuilder::new("OpExtractOne"))
+    }
+}
+impl OpExtractOneBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpExtractOne<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpExtractTwoBuilder(NodeBuilder);
+impl OpExtractTwo<()> {
+    pub fn build() -> OpExtractTwoBuilder {
+        OpExtractTwoBuilder(NodeBuilder::new("OpExtractTwo"))
+    }
+}
+impl OpExtractTwoBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpExtractTwo<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpGTBuilder(NodeBuilder);
+impl OpGT<()> {
+    pub fn build() -> OpGTBuilder {
+        OpGTBuilder(NodeBuilder::new("OpGT"))
+    }
+}
+impl OpGTBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpGT<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpGTEBuilder(NodeBuilder);
+impl OpGTE<()> {
+    pub fn build() -> OpGTEBuilder {
+        OpGTEBuilder(NodeBuilder::new("OpGTE"))
+    }
+}
+impl OpGTEBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpGTE<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpGlobBuilder(NodeBuilder);
+impl OpGlob<()> {
+    pub fn build() -> OpGlobBuilder {
+        OpGlobBuilder(NodeBuilder::new("OpGlob"))
+    }
+}
+impl OpGlobBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpGlob<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpInBuilder(NodeBuilder);
+impl OpIn<()> {
+    pub fn build() -> OpInBuilder {
+        OpInBuilder(NodeBuilder::new("OpIn"))
+    }
+}
+impl OpInBuilder {
+    pub fn lhs(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs", value.into());
+        self
+    }
+    pub fn in_expr_kind(mut self, value: impl Into<Built<InExprKind<()>>>) -> Self {
+        self.0.node("in_expr_kind", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpIn<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpIsBuilder(NodeBuilder);
+impl OpIs<()> {
+    pub fn build() -> OpIsBuilder {
+        OpIsBuilder(NodeBuilder::new("OpIs"))
+    }
+}
+impl OpIsBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpIs<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpIsDistinctFromBuilder(NodeBuilder);
+impl OpIsDistinctFrom<()> {
+    pub fn build() -> OpIsDistinctFromBuilder {
+        OpIsDistinctFromBuilder(NodeBuilder::new("OpIsDistinctFrom"))
+    }
+}
+impl OpIsDistinctFromBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpIsDistinctFrom<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpIsNotBuilder(NodeBuilder);
+impl OpIsNot<()> {
+    pub fn build() -> OpIsNotBuilder {
+        OpIsNotBuilder(NodeBuilder::new("OpIsNot"))
+    }
+}
+impl OpIsNotBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpIsNot<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpIsNotDistinctFromBuilder(NodeBuilder);
+impl OpIsNotDistinctFrom<()> {
+    pub fn build() -> OpIsNotDistinctFromBuilder {
+        OpIsNotDistinctFromBuilder(NodeBuilder::new("OpIsNotDistinctFrom"))
+    }
+}
+impl OpIsNotDistinctFromBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpIsNotDistinctFrom<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpIsNullBuilder(NodeBuilder);
+impl OpIsNull<()> {
+    pub fn build() -> OpIsNullBuilder {
+        OpIsNullBuilder(NodeBuilder::new("OpIsNull"))
+    }
+}
+impl OpIsNullBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpIsNull<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpLTBuilder(NodeBuilder);
+impl OpLT<()> {
+    pub fn build() -> OpLTBuilder {
+        OpLTBuilder(NodeBuilder::new("OpLT"))
+    }
+}
+impl OpLTBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpLT<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpLTEBuilder(NodeBuilder);
+impl OpLTE<()> {
+    pub fn build() -> OpLTEBuilder {
+        OpLTEBuilder(NodeBuilder::new("OpLTE"))
+    }
+}
+impl OpLTEBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpLTE<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpLikeBuilder(NodeBuilder);
+impl OpLike<()> {
+    pub fn build() -> OpLikeBuilder {
+        OpLikeBuilder(NodeBuilder::new("OpLike"))
+    }
+}
+impl OpLikeBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn op_escape(mut self, value: impl Into<Built<OpEscape<()>>>) -> Self {
+        self.0.node("op_escape", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpLike<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpMatchBuilder(NodeBuilder);
+impl OpMatch<()> {
+    pub fn build() -> OpMatchBuilder {
+        OpMatchBuilder(NodeBuilder::new("OpMatch"))
+    }
+}
+impl OpMatchBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpMatch<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpModulusBuilder(NodeBuilder);
+impl OpModulus<()> {
+    pub fn build() -> OpModulusBuilder {
+        OpModulusBuilder(NodeBuilder::new("OpModulus"))
+    }
+}
+impl OpModulusBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpModulus<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpMultiplyBuilder(NodeBuilder);
+impl OpMultiply<()> {
+    pub fn build() -> OpMultiplyBuilder {
+        OpMultiplyBuilder(NodeBuilder::new("OpMultiply"))
+    }
+}
+impl OpMultiplyBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpMultiply<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpNotBuilder(NodeBuilder);
+impl OpNot<()> {
+    pub fn build() -> OpNotBuilder {
+        OpNotBuilder(NodeBuilder::new("OpNot"))
+    }
+}
+impl OpNotBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpNot<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpNotBetweenAndBuilder(NodeBuilder);
+impl OpNotBetweenAnd<()> {
+    pub fn build() -> OpNotBetweenAndBuilder {
+        OpNotBetweenAndBuilder(NodeBuilder::new("OpNotBetweenAnd"))
+    }
+}
+impl OpNotBetweenAndBuilder {
+    pub fn target_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("target_expr", value.into());
+        self
+    }
+    pub fn low_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("low_expr", value.into());
+        self
+    }
+    pub fn high_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("high_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpNotBetweenAnd<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpNotEqBuilder(NodeBuilder);
+impl OpNotEq<()> {
+    pub fn build() -> OpNotEqBuilder {
+        OpNotEqBuilder(NodeBuilder::new("OpNotEq"))
+    }
+}
+impl OpNotEqBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn not_eq(mut self) -> Self {
+        self.0.flag("not_eq");
+        self
+    }
+    pub fn not_eq_sql(mut self) -> Self {
+        self.0.flag("not_eq_sql");
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpNotEq<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpNotGlobBuilder(NodeBuilder);
+impl OpNotGlob<()> {
+    pub fn build() -> OpNotGlobBuilder {
+        OpNotGlobBuilder(NodeBuilder::new("OpNotGlob"))
+    }
+}
+impl OpNotGlobBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpNotGlob<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpNotInBuilder(NodeBuilder);
+impl OpNotIn<()> {
+    pub fn build() -> OpNotInBuilder {
+        OpNotInBuilder(NodeBuilder::new("OpNotIn"))
+    }
+}
+impl OpNotInBuilder {
+    pub fn lhs(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs", value.into());
+        self
+    }
+    pub fn in_expr_kind(mut self, value: impl Into<Built<InExprKind<()>>>) -> Self {
+        self.0.node("in_expr_kind", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpNotIn<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpNotLikeBuilder(NodeBuilder);
+impl OpNotLike<()> {
+    pub fn build() -> OpNotLikeBuilder {
+        OpNotLikeBuilder(NodeBuilder::new("OpNotLike"))
+    }
+}
+impl OpNotLikeBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn escape(mut self, value: &str) -> Self {
+        self.0.token("escape", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpNotLike<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpNotMatchBuilder(NodeBuilder);
+impl OpNotMatch<()> {
+    pub fn build() -> OpNotMatchBuilder {
+        OpNotMatchBuilder(NodeBuilder::new("OpNotMatch"))
+    }
+}
+impl OpNotMatchBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpNotMatch<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpNotNullBuilder(NodeBuilder);
+impl OpNotNull<()> {
+    pub fn build() -> OpNotNullBuilder {
+        OpNotNullBuilder(NodeBuilder::new("OpNotNull"))
+    }
+}
+impl OpNotNullBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpNotNull<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpNotRegexpBuilder(NodeBuilder);
+impl OpNotRegexp<()> {
+    pub fn build() -> OpNotRegexpBuilder {
+        OpNotRegexpBuilder(NodeBuilder::new("OpNotRegexp"))
+    }
+}
+impl OpNotRegexpBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpNotRegexp<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpNotSpaceNullBuilder(NodeBuilder);
+impl OpNotSpaceNull<()> {
+    pub fn build() -> OpNotSpaceNullBuilder {
+        OpNotSpaceNullBuilder(NodeBuilder::new("OpNotSpaceNull"))
+    }
+}
+impl OpNotSpaceNullBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpNotSpaceNull<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpOrBuilder(NodeBuilder);
+impl OpOr<()> {
+    pub fn build() -> OpOrBuilder {
+        OpOrBuilder(NodeBuilder::new("OpOr"))
+    }
+}
+impl OpOrBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpOr<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpRegexpBuilder(NodeBuilder);
+impl OpRegexp<()> {
+    pub fn build() -> OpRegexpBuilder {
+        OpRegexpBuilder(NodeBuilder::new("OpRegexp"))
+    }
+}
+impl OpRegexpBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpRegexp<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpSubtractBuilder(NodeBuilder);
+impl OpSubtract<()> {
+    pub fn build() -> OpSubtractBuilder {
+        OpSubtractBuilder(NodeBuilder::new("OpSubtract"))
+    }
+}
+impl OpSubtractBuilder {
+    pub fn lhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("lhs_expr", value.into());
+        self
+    }
+    pub fn rhs_expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("rhs_expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpSubtract<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpUnaryMinusBuilder(NodeBuilder);
+impl OpUnaryMinus<()> {
+    pub fn build() -> OpUnaryMinusBuilder {
+        OpUnaryMinusBuilder(NodeBuilder::new("OpUnaryMinus"))
+    }
+}
+impl OpUnaryMinusBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpUnaryMinus<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OpUnaryPlusBuilder(NodeBuilder);
+impl OpUnaryPlus<()> {
+    pub fn build() -> OpUnaryPlusBuilder {
+        OpUnaryPlusBuilder(NodeBuilder::new("OpUnaryPlus"))
+    }
+}
+impl OpUnaryPlusBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OpUnaryPlus<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OrderByClauseBuilder(NodeBuilder);
+impl OrderByClause<()> {
+    pub fn build() -> OrderByClauseBuilder {
+        OrderByClauseBuilder(NodeBuilder::new("OrderByClause"))
+    }
+}
+impl OrderByClauseBuilder {
+    pub fn ordering_term_list(mut self, value: impl Into<Built<OrderingTermList<()>>>) -> Self {
+        self.0.node("ordering_term_list", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OrderByClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OrderingTermBuilder(NodeBuilder);
+impl OrderingTerm<()> {
+    pub fn build() -> OrderingTermBuilder {
+        OrderingTermBuilder(NodeBuilder::new("OrderingTerm"))
+    }
+}
+impl OrderingTermBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn collation(mut self, value: impl Into<Built<Collation<()>>>) -> Self {
+        self.0.node("collation", value.into());
+        self
+    }
+    pub fn order(mut self, value: impl Into<Built<Order<()>>>) -> Self {
+        self.0.node("order", value.into());
+        self
+    }
+    pub fn nulls_position(mut self, value: impl Into<Built<NullsPosition<()>>>) -> Self {
+        self.0.node("nulls_position", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OrderingTerm<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OrderingTermListBuilder(NodeBuilder);
+impl OrderingTermList<()> {
+    pub fn build() -> OrderingTermListBuilder {
+        OrderingTermListBuilder(NodeBuilder::new("OrderingTermList"))
+    }
+}
+impl OrderingTermListBuilder {
+    pub fn items(mut self, value: impl Into<Built<OrderingTerm<()>>>) -> Self {
+        self.0.node("items", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OrderingTermList<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct OverClauseBuilder(NodeBuilder);
+impl OverClause<()> {
+    pub fn build() -> OverClauseBuilder {
+        OverClauseBuilder(NodeBuilder::new("OverClause"))
+    }
+}
+impl OverClauseBuilder {
+    pub fn over_clause_kind(mut self, value: impl Into<Built<OverClauseKind<()>>>) -> Self {
+        self.0.node("over_clause_kind", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<OverClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct PasswordExprBuilder(NodeBuilder);
+impl PasswordExpr<()> {
+    pub fn build() -> PasswordExprBuilder {
+        PasswordExprBuilder(NodeBuilder::new("PasswordExpr"))
+    }
+}
+impl PasswordExprBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<PasswordExpr<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct PragmaNameBuilder(NodeBuilder);
+impl PragmaName<()> {
+    pub fn build() -> PragmaNameBuilder {
+        PragmaNameBuilder(NodeBuilder::new("PragmaName"))
+    }
+    pub fn new(value: &str) -> Result<Built<PragmaName<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl PragmaNameBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<PragmaName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct PragmaStmtBuilder(NodeBuilder);
+impl PragmaStmt<()> {
+    pub fn build() -> PragmaStmtBuilder {
+        PragmaStmtBuilder(NodeBuilder::new("PragmaStmt"))
+    }
+}
+impl PragmaStmtBuilder {
+    pub fn full_pragma_name(mut self, value: impl Into<Built<FullPragmaName<()>>>) -> Self {
+        self.0.node("full_pragma_name", value.into());
+        self
+    }
+    pub fn pragma_value(mut self, value: impl Into<Built<PragmaValue<()>>>) -> Self {
+        self.0.node("pragma_value", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<PragmaStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct PragmaValueNameBuilder(NodeBuilder);
+impl PragmaValueName<()> {
+    pub fn build() -> PragmaValueNameBuilder {
+        PragmaValueNameBuilder(NodeBuilder::new("PragmaValueName"))
+    }
+    pub fn new(value: &str) -> Result<Built<PragmaValueName<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl PragmaValueNameBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<PragmaValueName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct PrimaryConstraintBuilder(NodeBuilder);
+impl PrimaryConstraint<()> {
+    pub fn build() -> PrimaryConstraintBuilder {
+        PrimaryConstraintBuilder(NodeBuilder::new("PrimaryConstraint"))
+    }
+}
+impl PrimaryConstraintBuilder {
+    pub fn order(mut self, value: impl Into<Built<Order<()>>>) -> Self {
+        self.0.node("order", value.into());
+        self
+    }
+    pub fn conflict_clause(mut self, value: impl Into<Built<ConflictClause<()>>>) -> Self {
+        self.0.node("conflict_clause", value.into());
+        self
+    }
+    pub fn auto_increment(mut self) -> Self {
+        self.0.flag("auto_increment");
+        self
+    }
+    pub fn finish(self) -> Result<Built<PrimaryConstraint<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct QualifiedTableNameBuilder(NodeBuilder);
+impl QualifiedTableName<()> {
+    pub fn build() -> QualifiedTableNameBuilder {
+        QualifiedTableNameBuilder(NodeBuilder::new("QualifiedTableName"))
+    }
+}
+impl QualifiedTableNameBuilder {
+    pub fn full_table_name(mut self, value: impl Into<Built<FullTableName<()>>>) -> Self {
+        self.0.node("full_table_name", value.into());
+        self
+    }
+    pub fn with_alias(mut self, value: impl Into<Built<WithAlias<()>>>) -> Self {
+        self.0.node("with_alias", value.into());
+        self
+    }
+    pub fn index_details(mut self, value: impl Into<Built<IndexDetails<()>>>) -> Self {
+        self.0.node("index_details", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<QualifiedTableName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct RaiseActionAbortBuilder(NodeBuilder);
+impl RaiseActionAbort<()> {
+    pub fn build() -> RaiseActionAbortBuilder {
+        RaiseActionAbortBuilder(NodeBuilder::new("RaiseActionAbort"))
+    }
+}
+impl RaiseActionAbortBuilder {
+    pub fn raise_func_err_message(
+        mut self,
+        value: impl Into<Built<RaiseFuncErrMessage<()>>>,
+    ) -> Self {
+        self.0.node("raise_func_err_message", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<RaiseActionAbort<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct RaiseActionFailBuilder(NodeBuilder);
+impl RaiseActionFail<()> {
+    pub fn build() -> RaiseActionFailBuilder {
+        RaiseActionFailBuilder(NodeBuilder::new("RaiseActionFail"))
+    }
+}
+impl RaiseActionFailBuilder {
+    pub fn raise_func_err_message(
+        mut self,
+        value: impl Into<Built<RaiseFuncErrMessage<()>>>,
+    ) -> Self {
+        self.0.node("raise_func_err_message", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<RaiseActionFail<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct RaiseActionRollBackBuilder(NodeBuilder);
+impl RaiseActionRollBack<()> {
+    pub fn build() -> RaiseActionRollBackBuilder {
+        RaiseActionRollBackBuilder(NodeBuilder::new("RaiseActionRollBack"))
+    }
+}
+impl RaiseActionRollBackBuilder {
+    pub fn raise_func_err_message(
+        mut self,
+        value: impl Into<Built<RaiseFuncErrMessage<()>>>,
+    ) -> Self {
+        self.0.node("raise_func_err_message", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<RaiseActionRollBack<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct RaiseFuncBuilder(NodeBuilder);
+impl RaiseFunc<()> {
+    pub fn build() -> RaiseFuncBuilder {
+        RaiseFuncBuilder(NodeBuilder::new("RaiseFunc"))
+    }
+}
+impl RaiseFuncBuilder {
+    pub fn raise_action(mut self, value: impl Into<Built<RaiseAction<()>>>) -> Self {
+        self.0.node("raise_action", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<RaiseFunc<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct RaiseFuncErrMessageBuilder(NodeBuilder);
+impl RaiseFuncErrMessage<()> {
+    pub fn build() -> RaiseFuncErrMessageBuilder {
+        RaiseFuncErrMessageBuilder(NodeBuilder::new("RaiseFuncErrMessage"))
+    }
+    pub fn new(value: &str) -> Result<Built<RaiseFuncErrMessage<()>>, BuildError> {
+        Self::build().str_lit(value).finish()
+    }
+}
+impl RaiseFuncErrMessageBuilder {
+    pub fn str_lit(mut self, value: &str) -> Self {
+        self.0.token("str_lit", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<RaiseFuncErrMessage<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ReIndexStmtBuilder(NodeBuilder);
+impl ReIndexStmt<()> {
+    pub fn build() -> ReIndexStmtBuilder {
+        ReIndexStmtBuilder(NodeBuilder::new("ReIndexStmt"))
+    }
+}
+impl ReIndexStmtBuilder {
+    pub fn target(mut self, value: impl Into<Built<Target<()>>>) -> Self {
+        self.0.node("target", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ReIndexStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ReleaseStmtBuilder(NodeBuilder);
+impl ReleaseStmt<()> {
+    pub fn build() -> ReleaseStmtBuilder {
+        ReleaseStmtBuilder(NodeBuilder::new("ReleaseStmt"))
+    }
+}
+impl ReleaseStmtBuilder {
+    pub fn savepoint(mut self) -> Self {
+        self.0.flag("savepoint");
+        self
+    }
+    pub fn savepoint_name(mut self, value: impl Into<Built<SavepointName<()>>>) -> Self {
+        self.0.node("savepoint_name", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ReleaseStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct RenameColumnBuilder(NodeBuilder);
+impl RenameColumn<()> {
+    pub fn build() -> RenameColumnBuilder {
+        RenameColumnBuilder(NodeBuilder::new("RenameColumn"))
+    }
+}
+impl RenameColumnBuilder {
+    pub fn column(mut self) -> Self {
+        self.0.flag("column");
+        self
+    }
+    pub fn old_name(mut self, value: impl Into<Built<ColumnName<()>>>) -> Self {
+        self.0.node("old_name", value.into());
+        self
+    }
+    pub fn new_name(mut self, value: impl Into<Built<NewColumnName<()>>>) -> Self {
+        self.0.node("new_name", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<RenameColumn<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct RenameTableBuilder(NodeBuilder);
+impl RenameTable<()> {
+    pub fn build() -> RenameTableBuilder {
+        RenameTableBuilder(NodeBuilder::new("RenameTable"))
+    }
+}
+impl RenameTableBuilder {
+    pub fn table_name(mut self, value: impl Into<Built<TableName<()>>>) -> Self {
+        self.0.node("table_name", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<RenameTable<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ResultColumnAllBuilder(NodeBuilder);
+impl ResultColumnAll<()> {
+    pub fn build() -> ResultColumnAllBuilder {
+        ResultColumnAllBuilder(NodeBuilder::new("ResultColumnAll"))
+    }
+}
+impl ResultColumnAllBuilder {
+    pub fn finish(self) -> Result<Built<ResultColumnAll<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ResultColumnExprBuilder(NodeBuilder);
+impl ResultColumnExpr<()> {
+    pub fn build() -> ResultColumnExprBuilder {
+        ResultColumnExprBuilder(NodeBuilder::new("ResultColumnExpr"))
+    }
+}
+impl ResultColumnExprBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn with_alias(mut self, value: impl Into<Built<WithAlias<()>>>) -> Self {
+        self.0.node("with_alias", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ResultColumnExpr<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ResultColumnListBuilder(NodeBuilder);
+impl ResultColumnList<()> {
+    pub fn build() -> ResultColumnListBuilder {
+        ResultColumnListBuilder(NodeBuilder::new("ResultColumnList"))
+    }
+}
+impl ResultColumnListBuilder {
+    pub fn items(mut self, value: impl Into<Built<ResultColumn<()>>>) -> Self {
+        self.0.node("items", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ResultColumnList<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ResultColumnTableAllBuilder(NodeBuilder);
+impl ResultColumnTableAll<()> {
+    pub fn build() -> ResultColumnTableAllBuilder {
+        ResultColumnTableAllBuilder(NodeBuilder::new("ResultColumnTableAll"))
+    }
+}
+impl ResultColumnTableAllBuilder {
+    pub fn table_name(mut self, value: impl Into<Built<TableName<()>>>) -> Self {
+        self.0.node("table_name", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ResultColumnTableAll<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ReturningClauseBuilder(NodeBuilder);
+impl ReturningClause<()> {
+    pub fn build() -> ReturningClauseBuilder {
+        ReturningClauseBuilder(NodeBuilder::new("ReturningClause"))
+    }
+}
+impl ReturningClauseBuilder {
+    pub fn columns(mut self, value: impl Into<Built<ReturningClauseKind<()>>>) -> Self {
+        self.0.node("columns", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ReturningClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ReturningClauseExprBuilder(NodeBuilder);
+impl ReturningClauseExpr<()> {
+    pub fn build() -> ReturningClauseExprBuilder {
+        ReturningClauseExprBuilder(NodeBuilder::new("ReturningClauseExpr"))
+    }
+}
+impl ReturningClauseExprBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn r#as(mut self) -> Self {
+        self.0.flag("as");
+        self
+    }
+    pub fn col_alias(mut self, value: impl Into<Built<AliasName<()>>>) -> Self {
+        self.0.node("col_alias", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ReturningClauseExpr<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct RollbackStmtBuilder(NodeBuilder);
+impl RollbackStmt<()> {
+    pub fn build() -> RollbackStmtBuilder {
+        RollbackStmtBuilder(NodeBuilder::new("RollbackStmt"))
+    }
+}
+impl RollbackStmtBuilder {
+    pub fn transaction(mut self) -> Self {
+        self.0.flag("transaction");
+        self
+    }
+    pub fn savepoint(mut self) -> Self {
+        self.0.flag("savepoint");
+        self
+    }
+    pub fn savepoint_name(mut self, value: impl Into<Built<SavepointName<()>>>) -> Self {
+        self.0.node("savepoint_name", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<RollbackStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct SavepointNameBuilder(NodeBuilder);
+impl SavepointName<()> {
+    pub fn build() -> SavepointNameBuilder {
+        SavepointNameBuilder(NodeBuilder::new("SavepointName"))
+    }
+    pub fn new(value: &str) -> Result<Built<SavepointName<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl SavepointNameBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<SavepointName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct SavepointStmtBuilder(NodeBuilder);
+impl SavepointStmt<()> {
+    pub fn build() -> SavepointStmtBuilder {
+        SavepointStmtBuilder(NodeBuilder::new("SavepointStmt"))
+    }
+}
+impl SavepointStmtBuilder {
+    pub fn savepoint_name(mut self, value: impl Into<Built<SavepointName<()>>>) -> Self {
+        self.0.node("savepoint_name", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<SavepointStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct SchemaNameBuilder(NodeBuilder);
+impl SchemaName<()> {
+    pub fn build() -> SchemaNameBuilder {
+        SchemaNameBuilder(NodeBuilder::new("SchemaName"))
+    }
+    pub fn new(value: &str) -> Result<Built<SchemaName<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl SchemaNameBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<SchemaName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct SchemaNameExprBuilder(NodeBuilder);
+impl SchemaNameExpr<()> {
+    pub fn build() -> SchemaNameExprBuilder {
+        SchemaNameExprBuilder(NodeBuilder::new("SchemaNameExpr"))
+    }
+}
+impl SchemaNameExprBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<SchemaNameExpr<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct SchemaOrIdxOrTableNameBuilder(NodeBuilder);
+impl SchemaOrIdxOrTableName<()> {
+    pub fn build() -> SchemaOrIdxOrTableNameBuilder {
+        SchemaOrIdxOrTableNameBuilder(NodeBuilder::new("SchemaOrIdxOrTableName"))
+    }
+    pub fn new(value: &str) -> Result<Built<SchemaOrIdxOrTableName<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl SchemaOrIdxOrTableNameBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<SchemaOrIdxOrTableName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct SelectStmtBuilder(NodeBuilder);
+impl SelectStmt<()> {
+    pub fn build() -> SelectStmtBuilder {
+        SelectStmtBuilder(NodeBuilder::new("SelectStmt"))
+    }
+}
+impl SelectStmtBuilder {
+    pub fn select_core(mut self, value: impl Into<Built<SelectCore<()>>>) -> Self {
+        self.0.node("select_core", value.into());
+        self
+    }
+    pub fn compound_selects(mut self, value: impl Into<Built<CompoundSelect<()>>>) -> Self {
+        self.0.node("compound_selects", value.into());
+        self
+    }
+    pub fn order_by_clause(mut self, value: impl Into<Built<OrderByClause<()>>>) -> Self {
+        self.0.node("order_by_clause", value.into());
+        self
+    }
+    pub fn limit_clause(mut self, value: impl Into<Built<LimitClause<()>>>) -> Self {
+        self.0.node("limit_clause", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<SelectStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct SelectStmtWithCteBuilder(NodeBuilder);
+impl SelectStmtWithCte<()> {
+    pub fn build() -> SelectStmtWithCteBuilder {
+        SelectStmtWithCteBuilder(NodeBuilder::new("SelectStmtWithCte"))
+    }
+}
+impl SelectStmtWithCteBuilder {
+    pub fn cte_clause(mut self, value: impl Into<Built<CteClause<()>>>) -> Self {
+        self.0.node("cte_clause", value.into());
+        self
+    }
+    pub fn select_stmt(mut self, value: impl Into<Built<SelectStmt<()>>>) -> Self {
+        self.0.node("select_stmt", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<SelectStmtWithCte<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct SetColumnExprBuilder(NodeBuilder);
+impl SetColumnExpr<()> {
+    pub fn build() -> SetColumnExprBuilder {
+        SetColumnExprBuilder(NodeBuilder::new("SetColumnExpr"))
+    }
+}
+impl SetColumnExprBuilder {
+    pub fn set_column_kind(mut self, value: impl Into<Built<SetColumnKind<()>>>) -> Self {
+        self.0.node("set_column_kind", value.into());
+        self
+    }
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<SetColumnExpr<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct SignedNumberBuilder(NodeBuilder);
+impl SignedNumber<()> {
+    pub fn build() -> SignedNumberBuilder {
+        SignedNumberBuilder(NodeBuilder::new("SignedNumber"))
+    }
+}
+impl SignedNumberBuilder {
+    pub fn plus_or_minus(mut self, value: impl Into<Built<PlusOrMinus<()>>>) -> Self {
+        self.0.node("plus_or_minus", value.into());
+        self
+    }
+    pub fn number(mut self, value: &str) -> Self {
+        self.0.token("number", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<SignedNumber<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct StatementBuilder(NodeBuilder);
+impl Statement<()> {
+    pub fn build() -> StatementBuilder {
+        StatementBuilder(NodeBuilder::new("Statement"))
+    }
+}
+impl StatementBuilder {
+    pub fn explain_clause(mut self, value: impl Into<Built<ExplainClause<()>>>) -> Self {
+        self.0.node("explain_clause", value.into());
+        self
+    }
+    pub fn statement_kind(mut self, value: impl Into<Built<StatementKind<()>>>) -> Self {
+        self.0.node("statement_kind", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<Statement<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct StatementWithCteBuilder(NodeBuilder);
+impl StatementWithCte<()> {
+    pub fn build() -> StatementWithCteBuilder {
+        StatementWithCteBuilder(NodeBuilder::new("StatementWithCte"))
+    }
+}
+impl StatementWithCteBuilder {
+    pub fn cte_clause(mut self, value: impl Into<Built<CteClause<()>>>) -> Self {
+        self.0.node("cte_clause", value.into());
+        self
+    }
+    pub fn cte_prependable(mut self, value: impl Into<Built<CtePrependable<()>>>) -> Self {
+        self.0.node("cte_prependable", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<StatementWithCte<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TableConstraintBuilder(NodeBuilder);
+impl TableConstraint<()> {
+    pub fn build() -> TableConstraintBuilder {
+        TableConstraintBuilder(NodeBuilder::new("TableConstraint"))
+    }
+}
+impl TableConstraintBuilder {
+    pub fn constraint_name(mut self, value: impl Into<Built<ConstraintName<()>>>) -> Self {
+        self.0.node("constraint_name", value.into());
+        self
+    }
+    pub fn table_constraint_kind(
+        mut self,
+        value: impl Into<Built<TableConstraintKind<()>>>,
+    ) -> Self {
+        self.0.node("table_constraint_kind", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<TableConstraint<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TableDetailsBuilder(NodeBuilder);
+impl TableDetails<()> {
+    pub fn build() -> TableDetailsBuilder {
+        TableDetailsBuilder(NodeBuilder::new("TableDetails"))
+    }
+}
+impl TableDetailsBuilder {
+    pub fn columns(mut self, value: impl Into<Built<ColumnDef<()>>>) -> Self {
+        self.0.node("columns", value.into());
+        self
+    }
+    pub fn table_constraints(mut self, value: impl Into<Built<TableConstraint<()>>>) -> Self {
+        self.0.node("table_constraints", value.into());
+        self
+    }
+    pub fn table_options_list(mut self, value: impl Into<Built<TableOptionsList<()>>>) -> Self {
+        self.0.node("table_options_list", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<TableDetails<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TableFkConstraintBuilder(NodeBuilder);
+impl TableFkConstraint<()> {
+    pub fn build() -> TableFkConstraintBuilder {
+        TableFkConstraintBuilder(NodeBuilder::new("TableFkConstraint"))
+    }
+}
+impl TableFkConstraintBuilder {
+    pub fn col_name_list(mut self, value: impl Into<Built<ColNameList<()>>>) -> Self {
+        self.0.node("col_name_list", value.into());
+        self
+    }
+    pub fn fk_clause(mut self, value: impl Into<Built<FkClause<()>>>) -> Self {
+        self.0.node("fk_clause", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<TableFkConstraint<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TableFunctionNameBuilder(NodeBuilder);
+impl TableFunctionName<()> {
+    pub fn build() -> TableFunctionNameBuilder {
+        TableFunctionNameBuilder(NodeBuilder::new("TableFunctionName"))
+    }
+    pub fn new(value: &str) -> Result<Built<TableFunctionName<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl TableFunctionNameBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<TableFunctionName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TableNameBuilder(NodeBuilder);
+impl TableName<()> {
+    pub fn build() -> TableNameBuilder {
+        TableNameBuilder(NodeBuilder::new("TableName"))
+    }
+    pub fn new(value: &str) -> Result<Built<TableName<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl TableNameBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<TableName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TableNameIndexedByBuilder(NodeBuilder);
+impl TableNameIndexedBy<()> {
+    pub fn build() -> TableNameIndexedByBuilder {
+        TableNameIndexedByBuilder(NodeBuilder::new("TableNameIndexedBy"))
+    }
+}
+impl TableNameIndexedByBuilder {
+    pub fn index_name(mut self, value: impl Into<Built<IndexName<()>>>) -> Self {
+        self.0.node("index_name", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<TableNameIndexedBy<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TableNameNotIndexedBuilder(NodeBuilder);
+impl TableNameNotIndexed<()> {
+    pub fn build() -> TableNameNotIndexedBuilder {
+        TableNameNotIndexedBuilder(NodeBuilder::new("TableNameNotIndexed"))
+    }
+}
+impl TableNameNotIndexedBuilder {
+    pub fn finish(self) -> Result<Built<TableNameNotIndexed<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TableOptWithoutRowIdBuilder(NodeBuilder);
+impl TableOptWithoutRowId<()> {
+    pub fn build() -> TableOptWithoutRowIdBuilder {
+        TableOptWithoutRowIdBuilder(NodeBuilder::new("TableOptWithoutRowId"))
+    }
+}
+impl TableOptWithoutRowIdBuilder {
+    pub fn finish(self) -> Result<Built<TableOptWithoutRowId<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TableOptionsListBuilder(NodeBuilder);
+impl TableOptionsList<()> {
+    pub fn build() -> TableOptionsListBuilder {
+        TableOptionsListBuilder(NodeBuilder::new("TableOptionsList"))
+    }
+}
+impl TableOptionsListBuilder {
+    pub fn items(mut self, value: impl Into<Built<TableOptions<()>>>) -> Self {
+        self.0.node("items", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<TableOptionsList<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TableOrIdxNameWithSchemaBuilder(NodeBuilder);
+impl TableOrIdxNameWithSchema<()> {
+    pub fn build() -> TableOrIdxNameWithSchemaBuilder {
+        TableOrIdxNameWithSchemaBuilder(NodeBuilder::new("TableOrIdxNameWithSchema"))
+    }
+}
+impl TableOrIdxNameWithSchemaBuilder {
+    pub fn schema_name(mut self, value: impl Into<Built<SchemaName<()>>>) -> Self {
+        self.0.node("schema_name", value.into());
+        self
+    }
+    pub fn table_or_index_name(mut self, value: impl Into<Built<TableOrIndexName<()>>>) -> Self {
+        self.0.node("table_or_index_name", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<TableOrIdxNameWithSchema<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TableOrIdxOrCollationNameBuilder(NodeBuilder);
+impl TableOrIdxOrCollationName<()> {
+    pub fn build() -> TableOrIdxOrCollationNameBuilder {
+        TableOrIdxOrCollationNameBuilder(NodeBuilder::new("TableOrIdxOrCollationName"))
+    }
+    pub fn new(value: &str) -> Result<Built<TableOrIdxOrCollationName<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl TableOrIdxOrCollationNameBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<TableOrIdxOrCollationName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TableOrIndexNameBuilder(NodeBuilder);
+impl TableOrIndexName<()> {
+    pub fn build() -> TableOrIndexNameBuilder {
+        TableOrIndexNameBuilder(NodeBuilder::new("TableOrIndexName"))
+    }
+    pub fn new(value: &str) -> Result<Built<TableOrIndexName<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl TableOrIndexNameBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<TableOrIndexName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TableOrSubqueryBuilder(NodeBuilder);
+impl TableOrSubquery<()> {
+    pub fn build() -> TableOrSubqueryBuilder {
+        TableOrSubqueryBuilder(NodeBuilder::new("TableOrSubquery"))
+    }
+}
+impl TableOrSubqueryBuilder {
+    pub fn qualified_table_name(mut self, value: impl Into<Built<QualifiedTableName<()>>>) -> Self {
+        self.0.node("qualified_table_name", value.into());
+        self
+    }
+    pub fn from_clause_table_value_function(
+        mut self,
+        value: impl Into<Built<FromClauseTableValueFunction<()>>>,
+    ) -> Self {
+        self.0
+            .node("from_clause_table_value_function", value.into());
+        self
+    }
+    pub fn select_stmt_with_cte(mut self, value: impl Into<Built<SelectStmtWithCte<()>>>) -> Self {
+        self.0.node("select_stmt_with_cte", value.into());
+        self
+    }
+    pub fn join_clause(mut self, value: impl Into<Built<JoinClause<()>>>) -> Self {
+        self.0.node("join_clause", value.into());
+        self
+    }
+    pub fn table_or_subquery(mut self, value: impl Into<Built<TableOrSubquery<()>>>) -> Self {
+        self.0.node("table_or_subquery", value.into());
+        self
+    }
+    pub fn with_alias(mut self, value: impl Into<Built<WithAlias<()>>>) -> Self {
+        self.0.node("with_alias", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<TableOrSubquery<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TablePkConstraintBuilder(NodeBuilder);
+impl TablePkConstraint<()> {
+    pub fn build() -> TablePkConstraintBuilder {
+        TablePkConstraintBuilder(NodeBuilder::new("TablePkConstraint"))
+    }
+}
+impl TablePkConstraintBuilder {
+    pub fn columns(mut self, value: impl Into<Built<IndexedCol<()>>>) -> Self {
+        self.0.node("columns", value.into());
+        self
+    }
+    pub fn auto_increment(mut self) -> Self {
+        self.0.flag("auto_increment");
+        self
+    }
+    pub fn conflict_clause(mut self, value: impl Into<Built<ConflictClause<()>>>) -> Self {
+        self.0.node("conflict_clause", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<TablePkConstraint<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TableUqConstraintBuilder(NodeBuilder);
+impl TableUqConstraint<()> {
+    pub fn build() -> TableUqConstraintBuilder {
+        TableUqConstraintBuilder(NodeBuilder::new("TableUqConstraint"))
+    }
+}
+impl TableUqConstraintBuilder {
+    pub fn indexed_col_list(mut self, value: impl Into<Built<IndexedColList<()>>>) -> Self {
+        self.0.node("indexed_col_list", value.into());
+        self
+    }
+    pub fn conflict_clause(mut self, value: impl Into<Built<ConflictClause<()>>>) -> Self {
+        self.0.node("conflict_clause", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<TableUqConstraint<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TraditionalSelectBuilder(NodeBuilder);
+impl TraditionalSelect<()> {
+    pub fn build() -> TraditionalSelectBuilder {
+        TraditionalSelectBuilder(NodeBuilder::new("TraditionalSelect"))
+    }
+}
+impl TraditionalSelectBuilder {
+    pub fn select_distinct(mut self, value: impl Into<Built<SelectDistinct<()>>>) -> Self {
+        self.0.node("select_distinct", value.into());
+        self
+    }
+    pub fn result_column_list(mut self, value: impl Into<Built<ResultColumnList<()>>>) -> Self {
+        self.0.node("result_column_list", value.into());
+        self
+    }
+    pub fn from_clause(mut self, value: impl Into<Built<FromClause<()>>>) -> Self {
+        self.0.node("from_clause", value.into());
+        self
+    }
+    pub fn where_clause(mut self, value: impl Into<Built<WhereClause<()>>>) -> Self {
+        self.0.node("where_clause", value.into());
+        self
+    }
+    pub fn group_by_clause(mut self, value: impl Into<Built<GroupByClause<()>>>) -> Self {
+        self.0.node("group_by_clause", value.into());
+        self
+    }
+    pub fn having_clause(mut self, value: impl Into<Built<HavingClause<()>>>) -> Self {
+        self.0.node("having_clause", value.into());
+        self
+    }
+    pub fn window_clause(mut self, value: impl Into<Built<WindowClause<()>>>) -> Self {
+        self.0.node("window_clause", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<TraditionalSelect<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TriggerBodyStmtListBuilder(NodeBuilder);
+impl TriggerBodyStmtList<()> {
+    pub fn build() -> TriggerBodyStmtListBuilder {
+        TriggerBodyStmtListBuilder(NodeBuilder::new("TriggerBodyStmtList"))
+    }
+}
+impl TriggerBodyStmtListBuilder {
+    pub fn items(mut self, value: impl Into<Built<TriggerBodyStmt<()>>>) -> Self {
+        self.0.node("items", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<TriggerBodyStmtList<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TriggerForEachRowBuilder(NodeBuilder);
+impl TriggerForEachRow<()> {
+    pub fn build() -> TriggerForEachRowBuilder {
+        TriggerForEachRowBuilder(NodeBuilder::new("TriggerForEachRow"))
+    }
+}
+impl TriggerForEachRowBuilder {
+    pub fn finish(self) -> Result<Built<TriggerForEachRow<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TriggerInsteadOfBuilder(NodeBuilder);
+impl TriggerInsteadOf<()> {
+    pub fn build() -> TriggerInsteadOfBuilder {
+        TriggerInsteadOfBuilder(NodeBuilder::new("TriggerInsteadOf"))
+    }
+}
+impl TriggerInsteadOfBuilder {
+    pub fn finish(self) -> Result<Built<TriggerInsteadOf<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TriggerNameBuilder(NodeBuilder);
+impl TriggerName<()> {
+    pub fn build() -> TriggerNameBuilder {
+        TriggerNameBuilder(NodeBuilder::new("TriggerName"))
+    }
+    pub fn new(value: &str) -> Result<Built<TriggerName<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl TriggerNameBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<TriggerName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TriggerUpdateActionBuilder(NodeBuilder);
+impl TriggerUpdateAction<()> {
+    pub fn build() -> TriggerUpdateActionBuilder {
+        TriggerUpdateActionBuilder(NodeBuilder::new("TriggerUpdateAction"))
+    }
+}
+impl TriggerUpdateActionBuilder {
+    pub fn trigger_update_affect_cols(
+        mut self,
+        value: impl Into<Built<TriggerUpdateAffectCols<()>>>,
+    ) -> Self {
+        self.0.node("trigger_update_affect_cols", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<TriggerUpdateAction<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TriggerUpdateAffectColsBuilder(NodeBuilder);
+impl TriggerUpdateAffectCols<()> {
+    pub fn build() -> TriggerUpdateAffectColsBuilder {
+        TriggerUpdateAffectColsBuilder(NodeBuilder::new("TriggerUpdateAffectCols"))
+    }
+}
+impl TriggerUpdateAffectColsBuilder {
+    pub fn columns(mut self, value: impl Into<Built<ColumnName<()>>>) -> Self {
+        self.0.node("columns", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<TriggerUpdateAffectCols<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TriggerWhenExprBuilder(NodeBuilder);
+impl TriggerWhenExpr<()> {
+    pub fn build() -> TriggerWhenExprBuilder {
+        TriggerWhenExprBuilder(NodeBuilder::new("TriggerWhenExpr"))
+    }
+}
+impl TriggerWhenExprBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<TriggerWhenExpr<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TypeNameBuilder(NodeBuilder);
+impl TypeName<()> {
+    pub fn build() -> TypeNameBuilder {
+        TypeNameBuilder(NodeBuilder::new("TypeName"))
+    }
+}
+impl TypeNameBuilder {
+    pub fn words(mut self, value: impl Into<Built<TypeNameWord<()>>>) -> Self {
+        self.0.node("words", value.into());
+        self
+    }
+    pub fn lhs_signed_number(mut self, value: impl Into<Built<SignedNumber<()>>>) -> Self {
+        self.0.node("lhs_signed_number", value.into());
+        self
+    }
+    pub fn rhs_signed_number(mut self, value: impl Into<Built<SignedNumber<()>>>) -> Self {
+        self.0.node("rhs_signed_number", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<TypeName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct TypeNameWordBuilder(NodeBuilder);
+impl TypeNameWord<()> {
+    pub fn build() -> TypeNameWordBuilder {
+        TypeNameWordBuilder(NodeBuilder::new("TypeNameWord"))
+    }
+    pub fn new(value: &str) -> Result<Built<TypeNameWord<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl TypeNameWordBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<TypeNameWord<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct UnionCompoundOperatorBuilder(NodeBuilder);
+impl UnionCompoundOperator<()> {
+    pub fn build() -> UnionCompoundOperatorBuilder {
+        UnionCompoundOperatorBuilder(NodeBuilder::new("UnionCompoundOperator"))
+    }
+}
+impl UnionCompoundOperatorBuilder {
+    pub fn all(mut self) -> Self {
+        self.0.flag("all");
+        self
+    }
+    pub fn finish(self) -> Result<Built<UnionCompoundOperator<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct UniqueConstraintBuilder(NodeBuilder);
+impl UniqueConstraint<()> {
+    pub fn build() -> UniqueConstraintBuilder {
+        UniqueConstraintBuilder(NodeBuilder::new("UniqueConstraint"))
+    }
+}
+impl UniqueConstraintBuilder {
+    pub fn conflict_clause(mut self, value: impl Into<Built<ConflictClause<()>>>) -> Self {
+        self.0.node("conflict_clause", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<UniqueConstraint<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct UpdateStmtBuilder(NodeBuilder);
+impl UpdateStmt<()> {
+    pub fn build() -> UpdateStmtBuilder {
+        UpdateStmtBuilder(NodeBuilder::new("UpdateStmt"))
+    }
+}
+impl UpdateStmtBuilder {
+    pub fn conflict_action(mut self, value: impl Into<Built<ConflictAction<()>>>) -> Self {
+        self.0.node("conflict_action", value.into());
+        self
+    }
+    pub fn qualified_table_name(mut self, value: impl Into<Built<QualifiedTableName<()>>>) -> Self {
+        self.0.node("qualified_table_name", value.into());
+        self
+    }
+    pub fn set_expressions(mut self, value: impl Into<Built<SetColumnExpr<()>>>) -> Self {
+        self.0.node("set_expressions", value.into());
+        self
+    }
+    pub fn from_clause(mut self, value: impl Into<Built<FromClause<()>>>) -> Self {
+        self.0.node("from_clause", value.into());
+        self
+    }
+    pub fn where_clause(mut self, value: impl Into<Built<WhereClause<()>>>) -> Self {
+        self.0.node("where_clause", value.into());
+        self
+    }
+    pub fn returning_clause(mut self, value: impl Into<Built<ReturningClause<()>>>) -> Self {
+        self.0.node("returning_clause", value.into());
+        self
+    }
+    pub fn update_stmt_limited(mut self, value: impl Into<Built<UpdateStmtLimited<()>>>) -> Self {
+        self.0.node("update_stmt_limited", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<UpdateStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct UpdateStmtLimitedBuilder(NodeBuilder);
+impl UpdateStmtLimited<()> {
+    pub fn build() -> UpdateStmtLimitedBuilder {
+        UpdateStmtLimitedBuilder(NodeBuilder::new("UpdateStmtLimited"))
+    }
+}
+impl UpdateStmtLimitedBuilder {
+    pub fn order_by_clause(mut self, value: impl Into<Built<OrderByClause<()>>>) -> Self {
+        self.0.node("order_by_clause", value.into());
+        self
+    }
+    pub fn limit_clause(mut self, value: impl Into<Built<LimitClause<()>>>) -> Self {
+        self.0.node("limit_clause", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<UpdateStmtLimited<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct UpsertClauseBuilder(NodeBuilder);
+impl UpsertClause<()> {
+    pub fn build() -> UpsertClauseBuilder {
+        UpsertClauseBuilder(NodeBuilder::new("UpsertClause"))
+    }
+}
+impl UpsertClauseBuilder {
+    pub fn upsert_clause_conflict_target(
+        mut self,
+        value: impl Into<Built<UpsertClauseConflictTarget<()>>>,
+    ) -> Self {
+        self.0.node("upsert_clause_conflict_target", value.into());
+        self
+    }
+    pub fn upsert_clause_action(mut self, value: impl Into<Built<UpsertClauseAction<()>>>) -> Self {
+        self.0.node("upsert_clause_action", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<UpsertClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct UpsertClauseConflictTargetBuilder(NodeBuilder);
+impl UpsertClauseConflictTarget<()> {
+    pub fn build() -> UpsertClauseConflictTargetBuilder {
+        UpsertClauseConflictTargetBuilder(NodeBuilder::new("UpsertClauseConflictTarget"))
+    }
+}
+impl UpsertClauseConflictTargetBuilder {
+    pub fn indexed_col_list(mut self, value: impl Into<Built<IndexedColList<()>>>) -> Self {
+        self.0.node("indexed_col_list", value.into());
+        self
+    }
+    pub fn where_clause(mut self, value: impl Into<Built<WhereClause<()>>>) -> Self {
+        self.0.node("where_clause", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<UpsertClauseConflictTarget<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct UpsertDoUpdateBuilder(NodeBuilder);
+impl UpsertDoUpdate<()> {
+    pub fn build() -> UpsertDoUpdateBuilder {
+        UpsertDoUpdateBuilder(NodeBuilder::new("UpsertDoUpdate"))
+    }
+}
+impl UpsertDoUpdateBuilder {
+    pub fn set_expressions(mut self, value: impl Into<Built<SetColumnExpr<()>>>) -> Self {
+        self.0.node("set_expressions", value.into());
+        self
+    }
+    pub fn where_clause(mut self, value: impl Into<Built<WhereClause<()>>>) -> Self {
+        self.0.node("where_clause", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<UpsertDoUpdate<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct UsingConstraintBuilder(NodeBuilder);
+impl UsingConstraint<()> {
+    pub fn build() -> UsingConstraintBuilder {
+        UsingConstraintBuilder(NodeBuilder::new("UsingConstraint"))
+    }
+}
+impl UsingConstraintBuilder {
+    pub fn col_name_list(mut self, value: impl Into<Built<ColNameList<()>>>) -> Self {
+        self.0.node("col_name_list", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<UsingConstraint<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct VacuumStmtBuilder(NodeBuilder);
+impl VacuumStmt<()> {
+    pub fn build() -> VacuumStmtBuilder {
+        VacuumStmtBuilder(NodeBuilder::new("VacuumStmt"))
+    }
+}
+impl VacuumStmtBuilder {
+    pub fn schema_name(mut self, value: impl Into<Built<SchemaName<()>>>) -> Self {
+        self.0.node("schema_name", value.into());
+        self
+    }
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<VacuumStmt<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ValuesClauseBuilder(NodeBuilder);
+impl ValuesClause<()> {
+    pub fn build() -> ValuesClauseBuilder {
+        ValuesClauseBuilder(NodeBuilder::new("ValuesClause"))
+    }
+}
+impl ValuesClauseBuilder {
+    pub fn expr_list(mut self, value: impl Into<Built<ExprList<()>>>) -> Self {
+        self.0.node("expr_list", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ValuesClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ValuesSelectBuilder(NodeBuilder);
+impl ValuesSelect<()> {
+    pub fn build() -> ValuesSelectBuilder {
+        ValuesSelectBuilder(NodeBuilder::new("ValuesSelect"))
+    }
+}
+impl ValuesSelectBuilder {
+    pub fn expr_lists(mut self, value: impl Into<Built<ExprList<()>>>) -> Self {
+        self.0.node("expr_lists", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<ValuesSelect<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct ViewNameBuilder(NodeBuilder);
+impl ViewName<()> {
+    pub fn build() -> ViewNameBuilder {
+        ViewNameBuilder(NodeBuilder::new("ViewName"))
+    }
+    pub fn new(value: &str) -> Result<Built<ViewName<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl ViewNameBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<ViewName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct WhereClauseBuilder(NodeBuilder);
+impl WhereClause<()> {
+    pub fn build() -> WhereClauseBuilder {
+        WhereClauseBuilder(NodeBuilder::new("WhereClause"))
+    }
+}
+impl WhereClauseBuilder {
+    pub fn expr(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("expr", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<WhereClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct WindowBaseNameBuilder(NodeBuilder);
+impl WindowBaseName<()> {
+    pub fn build() -> WindowBaseNameBuilder {
+        WindowBaseNameBuilder(NodeBuilder::new("WindowBaseName"))
+    }
+    pub fn new(value: &str) -> Result<Built<WindowBaseName<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl WindowBaseNameBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<WindowBaseName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct WindowClauseBuilder(NodeBuilder);
+impl WindowClause<()> {
+    pub fn build() -> WindowClauseBuilder {
+        WindowClauseBuilder(NodeBuilder::new("WindowClause"))
+    }
+}
+impl WindowClauseBuilder {
+    pub fn functions(mut self, value: impl Into<Built<WindowFunction<()>>>) -> Self {
+        self.0.node("functions", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<WindowClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct WindowDefBuilder(NodeBuilder);
+impl WindowDef<()> {
+    pub fn build() -> WindowDefBuilder {
+        WindowDefBuilder(NodeBuilder::new("WindowDef"))
+    }
+}
+impl WindowDefBuilder {
+    pub fn window_base_name(mut self, value: impl Into<Built<WindowBaseName<()>>>) -> Self {
+        self.0.node("window_base_name", value.into());
+        self
+    }
+    pub fn window_partition_by_clause(
+        mut self,
+        value: impl Into<Built<WindowPartitionByClause<()>>>,
+    ) -> Self {
+        self.0.node("window_partition_by_clause", value.into());
+        self
+    }
+    pub fn order_by_clause(mut self, value: impl Into<Built<OrderByClause<()>>>) -> Self {
+        self.0.node("order_by_clause", value.into());
+        self
+    }
+    pub fn frame_spec(mut self, value: impl Into<Built<FrameSpec<()>>>) -> Self {
+        self.0.node("frame_spec", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<WindowDef<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct WindowFunctionBuilder(NodeBuilder);
+impl WindowFunction<()> {
+    pub fn build() -> WindowFunctionBuilder {
+        WindowFunctionBuilder(NodeBuilder::new("WindowFunction"))
+    }
+}
+impl WindowFunctionBuilder {
+    pub fn window_name(mut self, value: impl Into<Built<WindowName<()>>>) -> Self {
+        self.0.node("window_name", value.into());
+        self
+    }
+    pub fn window_def(mut self, value: impl Into<Built<WindowDef<()>>>) -> Self {
+        self.0.node("window_def", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<WindowFunction<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct WindowNameBuilder(NodeBuilder);
+impl WindowName<()> {
+    pub fn build() -> WindowNameBuilder {
+        WindowNameBuilder(NodeBuilder::new("WindowName"))
+    }
+    pub fn new(value: &str) -> Result<Built<WindowName<()>>, BuildError> {
+        Self::build().value(value).finish()
+    }
+}
+impl WindowNameBuilder {
+    pub fn value(mut self, value: &str) -> Self {
+        self.0.token("value", value);
+        self
+    }
+    pub fn finish(self) -> Result<Built<WindowName<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct WindowPartitionByClauseBuilder(NodeBuilder);
+impl WindowPartitionByClause<()> {
+    pub fn build() -> WindowPartitionByClauseBuilder {
+        WindowPartitionByClauseBuilder(NodeBuilder::new("WindowPartitionByClause"))
+    }
+}
+impl WindowPartitionByClauseBuilder {
+    pub fn items(mut self, value: impl Into<Built<Expr<()>>>) -> Self {
+        self.0.node("items", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<WindowPartitionByClause<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+pub struct WithAliasBuilder(NodeBuilder);
+impl WithAlias<()> {
+    pub fn build() -> WithAliasBuilder {
+        WithAliasBuilder(NodeBuilder::new("WithAlias"))
+    }
+}
+impl WithAliasBuilder {
+    pub fn r#as(mut self) -> Self {
+        self.0.flag("as");
+        self
+    }
+    pub fn alias_name(mut self, value: impl Into<Built<AliasName<()>>>) -> Self {
+        self.0.node("alias_name", value.into());
+        self
+    }
+    pub fn finish(self) -> Result<Built<WithAlias<()>>, BuildError> {
+        self.0.finish()
+    }
+}
+impl From<Built<AddColumn<()>>> for Built<AlterTableKind<()>> {
+    fn from(value: Built<AddColumn<()>>) -> Self {
+        value.convert("AlterTableKind")
+    }
+}
+impl From<Built<DropColumn<()>>> for Built<AlterTableKind<()>> {
+    fn from(value: Built<DropColumn<()>>) -> Self {
+        value.convert("AlterTableKind")
+    }
+}
+impl From<Built<RenameColumn<()>>> for Built<AlterTableKind<()>> {
+    fn from(value: Built<RenameColumn<()>>) -> Self {
+        value.convert("AlterTableKind")
+    }
+}
+impl From<Built<RenameTable<()>>> for Built<AlterTableKind<()>> {
+    fn from(value: Built<RenameTable<()>>) -> Self {
+        value.convert("AlterTableKind")
+    }
+}
+impl From<Built<SchemaOrIdxOrTableName<()>>> for Built<AnalyzeTarget<()>> {
+    fn from(value: Built<SchemaOrIdxOrTableName<()>>) -> Self {
+        value.convert("AnalyzeTarget")
+    }
+}
+impl From<Built<TableOrIdxNameWithSchema<()>>> for Built<AnalyzeTarget<()>> {
+    fn from(value: Built<TableOrIdxNameWithSchema<()>>) -> Self {
+        value.convert("AnalyzeTarget")
+    }
+}
+impl BeginStmtKind<()> {
+    pub fn build_kw_deferred() -> Built<BeginStmtKind<()>> {
+        Built::fixed_token("BeginStmtKind", "KW_DEFERRED")
+    }
+    pub fn build_kw_immediate() -> Built<BeginStmtKind<()>> {
+        Built::fixed_token("BeginStmtKind", "KW_IMMEDIATE")
+    }
+    pub fn build_kw_exclusive() -> Built<BeginStmtKind<()>> {
+        Built::fixed_token("BeginStmtKind", "KW_EXCLUSIVE")
+    }
+}
+impl ColumnGeneratedKind<()> {
+    pub fn build_kw_stored() -> Built<ColumnGeneratedKind<()>> {
+        Built::fixed_token("ColumnGeneratedKind", "KW_STORED")
+    }
+    pub fn build_kw_virtual() -> Built<ColumnGeneratedKind<()>> {
+        Built::fixed_token("ColumnGeneratedKind", "KW_VIRTUAL")
+    }
+}
+impl CommitStartKw<()> {
+    pub fn build_kw_commit() -> Built<CommitStartKw<()>> {
+        Built::fixed_token("CommitStartKw", "KW_COMMIT")
+    }
+    pub fn build_kw_end() -> Built<CommitStartKw<()>> {
+        Built::fixed_token("CommitStartKw", "KW_END")
+    }
+}
+impl From<Built<UnionCompoundOperator<()>>> for Built<CompoundOperator<()>> {
+    fn from(value: Built<UnionCompoundOperator<()>>) -> Self {
+        value.convert("CompoundOperator")
+    }
+}
+impl CompoundOperator<()> {
+    pub fn build_kw_intersect() -> Built<CompoundOperator<()>> {
+        Built::fixed_token("CompoundOperator", "KW_INTERSECT")
+    }
+    pub fn build_kw_except() -> Built<CompoundOperator<()>> {
+        Built::fixed_token("CompoundOperator", "KW_EXCEPT")
+    }
+}
+impl ConflictAction<()> {
+    pub fn build_kw_rollback() -> Built<ConflictAction<()>> {
+        Built::fixed_token("ConflictAction", "KW_ROLLBACK")
+    }
+    pub fn build_kw_abort() -> Built<ConflictAction<()>> {
+        Built::fixed_token("ConflictAction", "KW_ABORT")
+    }
+    pub fn build_kw_fail() -> Built<ConflictAction<()>> {
+        Built::fixed_token("ConflictAction", "KW_FAIL")
+    }
+    pub fn build_kw_ignore() -> Built<ConflictAction<()>> {
+        Built::fixed_token("ConflictAction", "KW_IGNORE")
+    }
+    pub fn build_kw_replace() -> Built<ConflictAction<()>> {
+        Built::fixed_token("ConflictAction", "KW_REPLACE")
+    }
+}
+impl From<Built<CheckConstraint<()>>> for Built<ConstraintType<()>> {
+    fn from(value: Built<CheckConstraint<()>>) -> Self {
+        value.convert("ConstraintType")
+    }
+}
+impl From<Built<Collation<()>>> for Built<ConstraintType<()>> {
+    fn from(value: Built<Collation<()>>) -> Self {
+        value.convert("ConstraintType")
+    }
+}
+impl From<Built<ColumnGenerated<()>>> for Built<ConstraintType<()>> {
+    fn from(value: Built<ColumnGenerated<()>>) -> Self {
+        value.convert("ConstraintType")
+    }
+}
+impl From<Built<DefaultConstraint<()>>> for Built<ConstraintType<()>> {
+    fn from(value: Built<DefaultConstraint<()>>) -> Self {
+        value.convert("ConstraintType")
+    }
+}
+impl From<Built<FkClause<()>>> for Built<ConstraintType<()>> {
+    fn from(value: Built<FkClause<()>>) -> Self {
+        value.convert("ConstraintType")
+    }
+}
+impl From<Built<NullConstraint<()>>> for Built<ConstraintType<()>> {
+    fn from(value: Built<NullConstraint<()>>) -> Self {
+        value.convert("ConstraintType")
+    }
+}
+impl From<Built<PrimaryConstraint<()>>> for Built<ConstraintType<()>> {
+    fn from(value: Built<PrimaryConstraint<()>>) -> Self {
+        value.convert("ConstraintType")
+    }
+}
+impl From<Built<UniqueConstraint<()>>> for Built<ConstraintType<()>> {
+    fn from(value: Built<UniqueConstraint<()>>) -> Self {
+        value.convert("ConstraintType")
+    }
+}
+impl From<Built<DeleteStmt<()>>> for Built<CtePrependable<()>> {
+    fn from(value: Built<DeleteStmt<()>>) -> Self {
+        value.convert("CtePrependable")
+    }
+}
+impl From<Built<InsertStmt<()>>> for Built<CtePrependable<()>> {
+    fn from(value: Built<InsertStmt<()>>) -> Self {
+        value.convert("CtePrependable")
+    }
+}
+impl From<Built<SelectStmt<()>>> for Built<CtePrependable<()>> {
+    fn from(value: Built<SelectStmt<()>>) -> Self {
+        value.convert("CtePrependable")
+    }
+}
+impl From<Built<UpdateStmt<()>>> for Built<CtePrependable<()>> {
+    fn from(value: Built<UpdateStmt<()>>) -> Self {
+        value.convert("CtePrependable")
+    }
+}
+impl From<Built<DefaultConstraintExpr<()>>> for Built<DefaultValueKind<()>> {
+    fn from(value: Built<DefaultConstraintExpr<()>>) -> Self {
+        value.convert("DefaultValueKind")
+    }
+}
+impl From<Built<DefaultConstraintIden<()>>> for Built<DefaultValueKind<()>> {
+    fn from(value: Built<DefaultConstraintIden<()>>) -> Self {
+        value.convert("DefaultValueKind")
+    }
+}
+impl From<Built<DefaultConstraintLiteral<()>>> for Built<DefaultValueKind<()>> {
+    fn from(value: Built<DefaultConstraintLiteral<()>>) -> Self {
+        value.convert("DefaultValueKind")
+    }
+}
+impl DeferKind<()> {
+    pub fn build_kw_deferred() -> Built<DeferKind<()>> {
+        Built::fixed_token("DeferKind", "KW_DEFERRED")
+    }
+    pub fn build_kw_immediate() -> Built<DeferKind<()>> {
+        Built::fixed_token("DeferKind", "KW_IMMEDIATE")
+    }
+}
+impl From<Built<ExprBindParam<()>>> for Built<Expr<()>> {
+    fn from(value: Built<ExprBindParam<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<ExprCase<()>>> for Built<Expr<()>> {
+    fn from(value: Built<ExprCase<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<ExprCast<()>>> for Built<Expr<()>> {
+    fn from(value: Built<ExprCast<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<ExprColumnName<()>>> for Built<Expr<()>> {
+    fn from(value: Built<ExprColumnName<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<ExprExistsSelect<()>>> for Built<Expr<()>> {
+    fn from(value: Built<ExprExistsSelect<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<ExprFunc<()>>> for Built<Expr<()>> {
+    fn from(value: Built<ExprFunc<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<ExprInfix<()>>> for Built<Expr<()>> {
+    fn from(value: Built<ExprInfix<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<ExprList<()>>> for Built<Expr<()>> {
+    fn from(value: Built<ExprList<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<ExprLit<()>>> for Built<Expr<()>> {
+    fn from(value: Built<ExprLit<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<ExprParen<()>>> for Built<Expr<()>> {
+    fn from(value: Built<ExprParen<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<ExprPostfix<()>>> for Built<Expr<()>> {
+    fn from(value: Built<ExprPostfix<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<ExprPrefix<()>>> for Built<Expr<()>> {
+    fn from(value: Built<ExprPrefix<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<ExprSelect<()>>> for Built<Expr<()>> {
+    fn from(value: Built<ExprSelect<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpAdd<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpAdd<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpAnd<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpAnd<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpBetweenAnd<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpBetweenAnd<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpBinAnd<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpBinAnd<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpBinComplement<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpBinComplement<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpBinLShift<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpBinLShift<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpBinOr<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpBinOr<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpBinRShift<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpBinRShift<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpCollate<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpCollate<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpConcat<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpConcat<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpDivide<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpDivide<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpEq<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpEq<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpExtractOne<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpExtractOne<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpExtractTwo<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpExtractTwo<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpGT<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpGT<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpGTE<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpGTE<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpGlob<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpGlob<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpIn<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpIn<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpIs<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpIs<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpIsDistinctFrom<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpIsDistinctFrom<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpIsNot<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpIsNot<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpIsNotDistinctFrom<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpIsNotDistinctFrom<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpIsNull<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpIsNull<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpLT<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpLT<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpLTE<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpLTE<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpLike<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpLike<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpMatch<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpMatch<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpModulus<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpModulus<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpMultiply<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpMultiply<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpNot<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpNot<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpNotBetweenAnd<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpNotBetweenAnd<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpNotEq<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpNotEq<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpNotGlob<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpNotGlob<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpNotIn<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpNotIn<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpNotLike<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpNotLike<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpNotMatch<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpNotMatch<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpNotNull<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpNotNull<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpNotRegexp<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpNotRegexp<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpNotSpaceNull<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpNotSpaceNull<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpOr<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpOr<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpRegexp<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpRegexp<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpSubtract<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpSubtract<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpUnaryMinus<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpUnaryMinus<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpUnaryPlus<()>>> for Built<Expr<()>> {
+    fn from(value: Built<OpUnaryPlus<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<RaiseFunc<()>>> for Built<Expr<()>> {
+    fn from(value: Built<RaiseFunc<()>>) -> Self {
+        value.convert("Expr")
+    }
+}
+impl From<Built<OpAdd<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpAdd<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpAnd<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpAnd<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpBetweenAnd<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpBetweenAnd<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpBinAnd<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpBinAnd<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpBinLShift<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpBinLShift<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpBinOr<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpBinOr<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpBinRShift<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpBinRShift<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpConcat<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpConcat<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpDivide<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpDivide<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpEq<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpEq<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpExtractOne<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpExtractOne<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpExtractTwo<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpExtractTwo<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpGT<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpGT<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpGTE<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpGTE<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpGlob<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpGlob<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpIn<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpIn<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpIs<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpIs<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpIsDistinctFrom<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpIsDistinctFrom<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpIsNot<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpIsNot<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpIsNotDistinctFrom<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpIsNotDistinctFrom<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpLT<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpLT<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpLTE<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpLTE<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpLike<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpLike<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpMatch<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpMatch<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpModulus<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpModulus<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpMultiply<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpMultiply<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpNotBetweenAnd<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpNotBetweenAnd<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpNotEq<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpNotEq<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpNotGlob<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpNotGlob<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpNotIn<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpNotIn<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpNotLike<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpNotLike<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpNotMatch<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpNotMatch<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpNotRegexp<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpNotRegexp<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpOr<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpOr<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpRegexp<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpRegexp<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl From<Built<OpSubtract<()>>> for Built<ExprInfix<()>> {
+    fn from(value: Built<OpSubtract<()>>) -> Self {
+        value.convert("ExprInfix")
+    }
+}
+impl ExprLit<()> {
+    pub fn build_int_lit(text: &str) -> Result<Built<ExprLit<()>>, BuildError> {
+        Built::token("ExprLit", "INT_LIT", text)
+    }
+    pub fn build_hex_lit(text: &str) -> Result<Built<ExprLit<()>>, BuildError> {
+        Built::token("ExprLit", "HEX_LIT", text)
+    }
+    pub fn build_str_lit(text: &str) -> Result<Built<ExprLit<()>>, BuildError> {
+        Built::token("ExprLit", "STR_LIT", text)
+    }
+    pub fn build_real_lit(text: &str) -> Result<Built<ExprLit<()>>, BuildError> {
+        Built::token("ExprLit", "REAL_LIT", text)
+    }
+    pub fn build_blob_lit(text: &str) -> Result<Built<ExprLit<()>>, BuildError> {
+        Built::token("ExprLit", "BLOB_LIT", text)
+    }
+    pub fn build_kw_null() -> Built<ExprLit<()>> {
+        Built::fixed_token("ExprLit", "KW_NULL")
+    }
+    pub fn build_kw_current_time() -> Built<ExprLit<()>> {
+        Built::fixed_token("ExprLit", "KW_CURRENT_TIME")
+    }
+    pub fn build_kw_current_date() -> Built<ExprLit<()>> {
+        Built::fixed_token("ExprLit", "KW_CURRENT_DATE")
+    }
+    pub fn build_kw_current_timestamp() -> Built<ExprLit<()>> {
+        Built::fixed_token("ExprLit", "KW_CURRENT_TIMESTAMP")
+    }
+}
+impl From<Built<OpCollate<()>>> for Built<ExprPostfix<()>> {
+    fn from(value: Built<OpCollate<()>>) -> Self {
+        value.convert("ExprPostfix")
+    }
+}
+impl From<Built<OpIsNull<()>>> for Built<ExprPostfix<()>> {
+    fn from(value: Built<OpIsNull<()>>) -> Self {
+        value.convert("ExprPostfix")
+    }
+}
+impl From<Built<OpNotNull<()>>> for Built<ExprPostfix<()>> {
+    fn from(value: Built<OpNotNull<()>>) -> Self {
+        value.convert("ExprPostfix")
+    }
+}
+impl From<Built<OpNotSpaceNull<()>>> for Built<ExprPostfix<()>> {
+    fn from(value: Built<OpNotSpaceNull<()>>) -> Self {
+        value.convert("ExprPostfix")
+    }
+}
+impl From<Built<OpBinComplement<()>>> for Built<ExprPrefix<()>> {
+    fn from(value: Built<OpBinComplement<()>>) -> Self {
+        value.convert("ExprPrefix")
+    }
+}
+impl From<Built<OpNot<()>>> for Built<ExprPrefix<()>> {
+    fn from(value: Built<OpNot<()>>) -> Self {
+        value.convert("ExprPrefix")
+    }
+}
+impl From<Built<OpUnaryMinus<()>>> for Built<ExprPrefix<()>> {
+    fn from(value: Built<OpUnaryMinus<()>>) -> Self {
+        value.convert("ExprPrefix")
+    }
+}
+impl From<Built<OpUnaryPlus<()>>> for Built<ExprPrefix<()>> {
+    fn from(value: Built<OpUnaryPlus<()>>) -> Self {
+        value.convert("ExprPrefix")
+    }
+}
+impl From<Built<FkCascade<()>>> for Built<FkAction<()>> {
+    fn from(value: Built<FkCascade<()>>) -> Self {
+        value.convert("FkAction")
+    }
+}
+impl From<Built<FkNoAction<()>>> for Built<FkAction<()>> {
+    fn from(value: Built<FkNoAction<()>>) -> Self {
+        value.convert("FkAction")
+    }
+}
+impl From<Built<FkRestrict<()>>> for Built<FkAction<()>> {
+    fn from(value: Built<FkRestrict<()>>) -> Self {
+        value.convert("FkAction")
+    }
+}
+impl From<Built<FkSetDefault<()>>> for Built<FkAction<()>> {
+    fn from(value: Built<FkSetDefault<()>>) -> Self {
+        value.convert("FkAction")
+    }
+}
+impl From<Built<FkSetNull<()>>> for Built<FkAction<()>> {
+    fn from(value: Built<FkSetNull<()>>) -> Self {
+        value.convert("FkAction")
+    }
+}
+impl FkFailKind<()> {
+    pub fn build_kw_delete() -> Built<FkFailKind<()>> {
+        Built::fixed_token("FkFailKind", "KW_DELETE")
+    }
+    pub fn build_kw_update() -> Built<FkFailKind<()>> {
+        Built::fixed_token("FkFailKind", "KW_UPDATE")
+    }
+}
+impl From<Built<FkMatchAction<()>>> for Built<FkOnOrMatch<()>> {
+    fn from(value: Built<FkMatchAction<()>>) -> Self {
+        value.convert("FkOnOrMatch")
+    }
+}
+impl From<Built<FkOnAction<()>>> for Built<FkOnOrMatch<()>> {
+    fn from(value: Built<FkOnAction<()>>) -> Self {
+        value.convert("FkOnOrMatch")
+    }
+}
+impl From<Built<FrameSpecCurrentRow<()>>> for Built<FrameSpecBetweenLeft<()>> {
+    fn from(value: Built<FrameSpecCurrentRow<()>>) -> Self {
+        value.convert("FrameSpecBetweenLeft")
+    }
+}
+impl From<Built<FrameSpecFollowing<()>>> for Built<FrameSpecBetweenLeft<()>> {
+    fn from(value: Built<FrameSpecFollowing<()>>) -> Self {
+        value.convert("FrameSpecBetweenLeft")
+    }
+}
+impl From<Built<FrameSpecPreceding<()>>> for Built<FrameSpecBetweenLeft<()>> {
+    fn from(value: Built<FrameSpecPreceding<()>>) -> Self {
+        value.convert("FrameSpecBetweenLeft")
+    }
+}
+impl From<Built<FrameSpecUnboundedPreceding<()>>> for Built<FrameSpecBetweenLeft<()>> {
+    fn from(value: Built<FrameSpecUnboundedPreceding<()>>) -> Self {
+        value.convert("FrameSpecBetweenLeft")
+    }
+}
+impl From<Built<FrameSpecCurrentRow<()>>> for Built<FrameSpecBetweenRight<()>> {
+    fn from(value: Built<FrameSpecCurrentRow<()>>) -> Self {
+        value.convert("FrameSpecBetweenRight")
+    }
+}
+impl From<Built<FrameSpecFollowing<()>>> for Built<FrameSpecBetweenRight<()>> {
+    fn from(value: Built<FrameSpecFollowing<()>>) -> Self {
+        value.convert("FrameSpecBetweenRight")
+    }
+}
+impl From<Built<FrameSpecPreceding<()>>> for Built<FrameSpecBetweenRight<()>> {
+    fn from(value: Built<FrameSpecPreceding<()>>) -> Self {
+        value.convert("FrameSpecBetweenRight")
+    }
+}
+impl From<Built<FrameSpecUnboundedFollowing<()>>> for Built<FrameSpecBetweenRight<()>> {
+    fn from(value: Built<FrameSpecUnboundedFollowing<()>>) -> Self {
+        value.convert("FrameSpecBetweenRight")
+    }
+}
+impl From<Built<FrameSpecCurrentRow<()>>> for Built<FrameSpecExcludeKind<()>> {
+    fn from(value: Built<FrameSpecCurrentRow<()>>) -> Self {
+        value.convert("FrameSpecExcludeKind")
+    }
+}
+impl From<Built<FrameSpecNoOthers<()>>> for Built<FrameSpecExcludeKind<()>> {
+    fn from(value: Built<FrameSpecNoOthers<()>>) -> Self {
+        value.convert("FrameSpecExcludeKind")
+    }
+}
+impl FrameSpecExcludeKind<()> {
+    pub fn build_kw_group() -> Built<FrameSpecExcludeKind<()>> {
+        Built::fixed_token("FrameSpecExcludeKind", "KW_GROUP")
+    }
+    pub fn build_kw_ties() -> Built<FrameSpecExcludeKind<()>> {
+        Built::fixed_token("FrameSpecExcludeKind", "KW_TIES")
+    }
+}
+impl From<Built<FrameSpecBetweenClause<()>>> for Built<FrameSpecKind<()>> {
+    fn from(value: Built<FrameSpecBetweenClause<()>>) -> Self {
+        value.convert("FrameSpecKind")
+    }
+}
+impl From<Built<FrameSpecCurrentRow<()>>> for Built<FrameSpecKind<()>> {
+    fn from(value: Built<FrameSpecCurrentRow<()>>) -> Self {
+        value.convert("FrameSpecKind")
+    }
+}
+impl From<Built<FrameSpecPreceding<()>>> for Built<FrameSpecKind<()>> {
+    fn from(value: Built<FrameSpecPreceding<()>>) -> Self {
+        value.convert("FrameSpecKind")
+    }
+}
+impl From<Built<FrameSpecUnboundedPreceding<()>>> for Built<FrameSpecKind<()>> {
+    fn from(value: Built<FrameSpecUnboundedPreceding<()>>) -> Self {
+        value.convert("FrameSpecKind")
+    }
+}
+impl From<Built<JoinClause<()>>> for Built<FromClauseValue<()>> {
+    fn from(value: Built<JoinClause<()>>) -> Self {
+        value.convert("FromClauseValue")
+    }
+}
+impl From<Built<TableOrSubquery<()>>> for Built<FromClauseValue<()>> {
+    fn from(value: Built<TableOrSubquery<()>>) -> Self {
+        value.convert("FromClauseValue")
+    }
+}
+impl From<Built<ArgExpr<()>>> for Built<FuncArguments<()>> {
+    fn from(value: Built<ArgExpr<()>>) -> Self {
+        value.convert("FuncArguments")
+    }
+}
+impl From<Built<ArgStar<()>>> for Built<FuncArguments<()>> {
+    fn from(value: Built<ArgStar<()>>) -> Self {
+        value.convert("FuncArguments")
+    }
+}
+impl From<Built<EmptyableExprList<()>>> for Built<InExprKind<()>> {
+    fn from(value: Built<EmptyableExprList<()>>) -> Self {
+        value.convert("InExprKind")
+    }
+}
+impl From<Built<ExprSelect<()>>> for Built<InExprKind<()>> {
+    fn from(value: Built<ExprSelect<()>>) -> Self {
+        value.convert("InExprKind")
+    }
+}
+impl From<Built<InTable<()>>> for Built<InExprKind<()>> {
+    fn from(value: Built<InTable<()>>) -> Self {
+        value.convert("InExprKind")
+    }
+}
+impl From<Built<InTableFunc<()>>> for Built<InExprKind<()>> {
+    fn from(value: Built<InTableFunc<()>>) -> Self {
+        value.convert("InExprKind")
+    }
+}
+impl From<Built<TableNameIndexedBy<()>>> for Built<IndexDetails<()>> {
+    fn from(value: Built<TableNameIndexedBy<()>>) -> Self {
+        value.convert("IndexDetails")
+    }
+}
+impl From<Built<TableNameNotIndexed<()>>> for Built<IndexDetails<()>> {
+    fn from(value: Built<TableNameNotIndexed<()>>) -> Self {
+        value.convert("IndexDetails")
+    }
+}
+impl From<Built<InsertOrAction<()>>> for Built<InsertStmtKind<()>> {
+    fn from(value: Built<InsertOrAction<()>>) -> Self {
+        value.convert("InsertStmtKind")
+    }
+}
+impl InsertStmtKind<()> {
+    pub fn build_kw_replace() -> Built<InsertStmtKind<()>> {
+        Built::fixed_token("InsertStmtKind", "KW_REPLACE")
+    }
+}
+impl From<Built<InsertDefaultValuesClause<()>>> for Built<InsertValueKind<()>> {
+    fn from(value: Built<InsertDefaultValuesClause<()>>) -> Self {
+        value.convert("InsertValueKind")
+    }
+}
+impl From<Built<InsertSelectClause<()>>> for Built<InsertValueKind<()>> {
+    fn from(value: Built<InsertSelectClause<()>>) -> Self {
+        value.convert("InsertValueKind")
+    }
+}
+impl From<Built<InsertValuesClause<()>>> for Built<InsertValueKind<()>> {
+    fn from(value: Built<InsertValuesClause<()>>) -> Self {
+        value.convert("InsertValueKind")
+    }
+}
+impl From<Built<OnConstraint<()>>> for Built<JoinConstraint<()>> {
+    fn from(value: Built<OnConstraint<()>>) -> Self {
+        value.convert("JoinConstraint")
+    }
+}
+impl From<Built<UsingConstraint<()>>> for Built<JoinConstraint<()>> {
+    fn from(value: Built<UsingConstraint<()>>) -> Self {
+        value.convert("JoinConstraint")
+    }
+}
+impl ModuleArg<()> {
+    pub fn build_str_lit(text: &str) -> Result<Built<ModuleArg<()>>, BuildError> {
+        Built::token("ModuleArg", "STR_LIT", text)
+    }
+    pub fn build_int_lit(text: &str) -> Result<Built<ModuleArg<()>>, BuildError> {
+        Built::token("ModuleArg", "INT_LIT", text)
+    }
+    pub fn build_real_lit(text: &str) -> Result<Built<ModuleArg<()>>, BuildError> {
+        Built::token("ModuleArg", "REAL_LIT", text)
+    }
+    pub fn build_hex_lit(text: &str) -> Result<Built<ModuleArg<()>>, BuildError> {
+        Built::token("ModuleArg", "HEX_LIT", text)
+    }
+}
+impl NullsPosition<()> {
+    pub fn build_kw_first() -> Built<NullsPosition<()>> {
+        Built::fixed_token("NullsPosition", "KW_FIRST")
+    }
+    pub fn build_kw_last() -> Built<NullsPosition<()>> {
+        Built::fixed_token("NullsPosition", "KW_LAST")
+    }
+}
+impl Order<()> {
+    pub fn build_kw_asc() -> Built<Order<()>> {
+        Built::fixed_token("Order", "KW_ASC")
+    }
+    pub fn build_kw_desc() -> Built<Order<()>> {
+        Built::fixed_token("Order", "KW_DESC")
+    }
+}
+impl From<Built<WindowDef<()>>> for Built<OverClauseKind<()>> {
+    fn from(value: Built<WindowDef<()>>) -> Self {
+        value.convert("OverClauseKind")
+    }
+}
+impl From<Built<WindowName<()>>> for Built<OverClauseKind<()>> {
+    fn from(value: Built<WindowName<()>>) -> Self {
+        value.convert("OverClauseKind")
+    }
+}
+impl PlusOrMinus<()> {
+    pub fn build_plus() -> Built<PlusOrMinus<()>> {
+        Built::fixed_token("PlusOrMinus", "+")
+    }
+    pub fn build_minus() -> Built<PlusOrMinus<()>> {
+        Built::fixed_token("PlusOrMinus", "-")
+    }
+}
+impl From<Built<PragmaValueName<()>>> for Built<PragmaValue<()>> {
+    fn from(value: Built<PragmaValueName<()>>) -> Self {
+        value.convert("PragmaValue")
+    }
+}
+impl From<Built<SignedNumber<()>>> for Built<PragmaValue<()>> {
+    fn from(value: Built<SignedNumber<()>>) -> Self {
+        value.convert("PragmaValue")
+    }
+}
+impl PragmaValue<()> {
+    pub fn build_kw_on() -> Built<PragmaValue<()>> {
+        Built::fixed_token("PragmaValue", "KW_ON")
+    }
+    pub fn build_kw_delete() -> Built<PragmaValue<()>> {
+        Built::fixed_token("PragmaValue", "KW_DELETE")
+    }
+    pub fn build_kw_default() -> Built<PragmaValue<()>> {
+        Built::fixed_token("PragmaValue", "KW_DEFAULT")
+    }
+}
+impl From<Built<RaiseActionAbort<()>>> for Built<RaiseAction<()>> {
+    fn from(value: Built<RaiseActionAbort<()>>) -> Self {
+        value.convert("RaiseAction")
+    }
+}
+impl From<Built<RaiseActionFail<()>>> for Built<RaiseAction<()>> {
+    fn from(value: Built<RaiseActionFail<()>>) -> Self {
+        value.convert("RaiseAction")
+    }
+}
+impl From<Built<RaiseActionRollBack<()>>> for Built<RaiseAction<()>> {
+    fn from(value: Built<RaiseActionRollBack<()>>) -> Self {
+        value.convert("RaiseAction")
+    }
+}
+impl RaiseAction<()> {
+    pub fn build_kw_ignore() -> Built<RaiseAction<()>> {
+        Built::fixed_token("RaiseAction", "KW_IGNORE")
+    }
+}
+impl Range<()> {
+    pub fn build_kw_range() -> Built<Range<()>> {
+        Built::fixed_token("Range", "KW_RANGE")
+    }
+    pub fn build_kw_rows() -> Built<Range<()>> {
+        Built::fixed_token("Range", "KW_ROWS")
+    }
+    pub fn build_kw_groups() -> Built<Range<()>> {
+        Built::fixed_token("Range", "KW_GROUPS")
+    }
+}
+impl From<Built<ResultColumnAll<()>>> for Built<ResultColumn<()>> {
+    fn from(value: Built<ResultColumnAll<()>>) -> Self {
+        value.convert("ResultColumn")
+    }
+}
+impl From<Built<ResultColumnExpr<()>>> for Built<ResultColumn<()>> {
+    fn from(value: Built<ResultColumnExpr<()>>) -> Self {
+        value.convert("ResultColumn")
+    }
+}
+impl From<Built<ResultColumnTableAll<()>>> for Built<ResultColumn<()>> {
+    fn from(value: Built<ResultColumnTableAll<()>>) -> Self {
+        value.convert("ResultColumn")
+    }
+}
+impl From<Built<ReturningClauseExpr<()>>> for Built<ReturningClauseKind<()>> {
+    fn from(value: Built<ReturningClauseExpr<()>>) -> Self {
+        value.convert("ReturningClauseKind")
+    }
+}
+impl ReturningClauseKind<()> {
+    pub fn build_star() -> Built<ReturningClauseKind<()>> {
+        Built::fixed_token("ReturningClauseKind", "*")
+    }
+}
+impl From<Built<TraditionalSelect<()>>> for Built<SelectCore<()>> {
+    fn from(value: Built<TraditionalSelect<()>>) -> Self {
+        value.convert("SelectCore")
+    }
+}
+impl From<Built<ValuesSelect<()>>> for Built<SelectCore<()>> {
+    fn from(value: Built<ValuesSelect<()>>) -> Self {
+        value.convert("SelectCore")
+    }
+}
+impl SelectDistinct<()> {
+    pub fn build_kw_distinct() -> Built<SelectDistinct<()>> {
+        Built::fixed_token("SelectDistinct", "KW_DISTINCT")
+    }
+    pub fn build_kw_all() -> Built<SelectDistinct<()>> {
+        Built::fixed_token("SelectDistinct", "KW_ALL")
+    }
+}
+impl From<Built<ColNameList<()>>> for Built<SetColumnKind<()>> {
+    fn from(value: Built<ColNameList<()>>) -> Self {
+        value.convert("SetColumnKind")
+    }
+}
+impl From<Built<ColumnName<()>>> for Built<SetColumnKind<()>> {
+    fn from(value: Built<ColumnName<()>>) -> Self {
+        value.convert("SetColumnKind")
+    }
+}
+impl From<Built<AlterTableStmt<()>>> for Built<StatementKind<()>> {
+    fn from(value: Built<AlterTableStmt<()>>) -> Self {
+        value.convert("StatementKind")
+    }
+}
+impl From<Built<AnalyzeStmt<()>>> for Built<StatementKind<()>> {
+    fn from(value: Built<AnalyzeStmt<()>>) -> Self {
+        value.convert("StatementKind")
+    }
+}
+impl From<Built<AttachDbStmt<()>>> for Built<StatementKind<()>> {
+    fn from(value: Built<AttachDbStmt<()>>) -> Self {
+        value.convert("StatementKind")
+    }
+}
+impl From<Built<BeginStmt<()>>> for Built<StatementKind<()>> {
+    fn from(value: Built<BeginStmt<()>>) -> Self {
+        value.convert("StatementKind")
+    }
+}
+impl From<Built<CommitStmt<()>>> for Built<StatementKind<()>> {
+    fn from(value: Built<CommitStmt<()>>) -> Self {
+        value.convert("StatementKind")
+    }
+}
+impl From<Built<CreateIndexStmt<()>>> for Built<StatementKind<()>> {
+    fn from(value: Built<CreateIndexStmt<()>>) -> Self {
+        value.convert("StatementKind")
+    }
+}
+impl From<Built<CreateTableStmt<()>>> for Built<StatementKind<()>> {
+    fn from(value: Built<CreateTableStmt<()>>) -> Self {
+        value.convert("StatementKind")
+    }
+}
+impl From<Built<CreateTriggerStmt<()>>> for Built<StatementKind<()>> {
+    fn from(value: Built<CreateTriggerStmt<()>>) -> Self {
+        value.convert("StatementKind")
+    }
+}
+impl From<Built<CreateViewStmt<()>>> for Built<StatementKind<()>> {
+    fn from(value: Built<CreateViewStmt<()>>) -> Self {
+        value.convert("StatementKind")
+    }
+}
+impl From<Built<CreateVirtualTableStmt<()>>> for Built<StatementKind<()>> {
+    fn from(value: Built<CreateVirtualTableStmt<()>>) -> Self {
+        value.convert("StatementKind")
+    }
+}
+impl From<Built<DetachStmt<()>>> for Built<StatementKind<()>> {
+    fn from(value: Built<DetachStmt<()>>) -> Self {
+        value.convert("StatementKind")
+    }
+}
+impl From<Built<DropIndexStmt<()>>> for Built<StatementKind<()>> {
+    fn from(value: Built<DropIndexStmt<()>>) -> Self {
+        value.convert("StatementKind")
+    }
+}
+impl From<Built<DropTableStmt<()>>> for Built<StatementKind<()>> {
+    fn from(value: Built<DropTableStmt<()>>) -> Self {
+        value.convert("StatementKind")
+    }
+}
+impl From<Built<DropTriggerStmt<()>>> for Built<StatementKind<()>> {
+    fn from(value: Built<DropTriggerStmt<()>>) -> Self {
+        value.convert("StatementKind")
+    }
+}
+impl From<Built<DropViewStmt<()>>> for Built<StatementKind<()>> {
+    fn from(value: Built<DropViewStmt<()>>) -> Self {
+        value.convert("StatementKind")
+    }
+}
+impl From<Built<PragmaStmt<()>>> for Built<StatementKind<()>> {
+    fn from(value: Built<PragmaStmt<()>>) -> Self {
+        value.convert("StatementKind")
+    }
+}
+impl From<Built<ReIndexStmt<()>>> for Built<StatementKind<()>> {
+    fn from(value: Built<ReIndexStmt<()>>) -> Self {
+        value.convert("StatementKind")
+    }
+}
+impl From<Built<ReleaseStmt<()>>> for Built<StatementKind<()>> {
+    fn from(value: Built<ReleaseStmt<()>>) -> Self {
+        value.convert("StatementKind")
+    }
+}
+impl From<Built<RollbackStmt<()>>> for Built<StatementKind<()>> {
+    fn from(value: Built<RollbackStmt<()>>) -> Self {
+        value.convert("StatementKind")
+    }
+}
+impl From<Built<SavepointStmt<()>>> for Built<StatementKind<()>> {
+    fn from(value: Built<SavepointStmt<()>>) -> Self {
+        value.convert("StatementKind")
+    }
+}
+impl From<Built<StatementNoCte<()>>> for Built<StatementKind<()>> {
+    fn from(value: Built<StatementNoCte<()>>) -> Self {
+        value.convert("StatementKind")
+    }
+}
+impl From<Built<StatementWithCte<()>>> for Built<StatementKind<()>> {
+    fn from(value: Built<StatementWithCte<()>>) -> Self {
+        value.convert("StatementKind")
+    }
+}
+impl From<Built<VacuumStmt<()>>> for Built<StatementKind<()>> {
+    fn from(value: Built<VacuumStmt<()>>) -> Self {
+        value.convert("StatementKind")
+    }
+}
+impl From<Built<AlterTableStmt<()>>> for Built<StatementNoCte<()>> {
+    fn from(value: Built<AlterTableStmt<()>>) -> Self {
+        value.convert("StatementNoCte")
+    }
+}
+impl From<Built<AnalyzeStmt<()>>> for Built<StatementNoCte<()>> {
+    fn from(value: Built<AnalyzeStmt<()>>) -> Self {
+        value.convert("StatementNoCte")
+    }
+}
+impl From<Built<AttachDbStmt<()>>> for Built<StatementNoCte<()>> {
+    fn from(value: Built<AttachDbStmt<()>>) -> Self {
+        value.convert("StatementNoCte")
+    }
+}
+impl From<Built<BeginStmt<()>>> for Built<StatementNoCte<()>> {
+    fn from(value: Built<BeginStmt<()>>) -> Self {
+        value.convert("StatementNoCte")
+    }
+}
+impl From<Built<CommitStmt<()>>> for Built<StatementNoCte<()>> {
+    fn from(value: Built<CommitStmt<()>>) -> Self {
+        value.convert("StatementNoCte")
+    }
+}
+impl From<Built<CreateIndexStmt<()>>> for Built<StatementNoCte<()>> {
+    fn from(value: Built<CreateIndexStmt<()>>) -> Self {
+        value.convert("StatementNoCte")
+    }
+}
+impl From<Built<CreateTableStmt<()>>> for Built<StatementNoCte<()>> {
+    fn from(value: Built<CreateTableStmt<()>>) -> Self {
+        value.convert("StatementNoCte")
+    }
+}
+impl From<Built<CreateTriggerStmt<()>>> for Built<StatementNoCte<()>> {
+    fn from(value: Built<CreateTriggerStmt<()>>) -> Self {
+        value.convert("StatementNoCte")
+    }
+}
+impl From<Built<CreateViewStmt<()>>> for Built<StatementNoCte<()>> {
+    fn from(value: Built<CreateViewStmt<()>>) -> Self {
+        value.convert("StatementNoCte")
+    }
+}
+impl From<Built<CreateVirtualTableStmt<()>>> for Built<StatementNoCte<()>> {
+    fn from(value: Built<CreateVirtualTableStmt<()>>) -> Self {
+        value.convert("StatementNoCte")
+    }
+}
+impl From<Built<DetachStmt<()>>> for Built<StatementNoCte<()>> {
+    fn from(value: Built<DetachStmt<()>>) -> Self {
+        value.convert("StatementNoCte")
+    }
+}
+impl From<Built<DropIndexStmt<()>>> for Built<StatementNoCte<()>> {
+    fn from(value: Built<DropIndexStmt<()>>) -> Self {
+        value.convert("StatementNoCte")
+    }
+}
+impl From<Built<DropTableStmt<()>>> for Built<StatementNoCte<()>> {
+    fn from(value: Built<DropTableStmt<()>>) -> Self {
+        value.convert("StatementNoCte")
+    }
+}
+impl From<Built<DropTriggerStmt<()>>> for Built<StatementNoCte<()>> {
+    fn from(value: Built<DropTriggerStmt<()>>) -> Self {
+        value.convert("StatementNoCte")
+    }
+}
+impl From<Built<DropViewStmt<()>>> for Built<StatementNoCte<()>> {
+    fn from(value: Built<DropViewStmt<()>>) -> Self {
+        value.convert("StatementNoCte")
+    }
+}
+impl From<Built<PragmaStmt<()>>> for Built<StatementNoCte<()>> {
+    fn from(value: Built<PragmaStmt<()>>) -> Self {
+        value.convert("StatementNoCte")
+    }
+}
+impl From<Built<ReIndexStmt<()>>> for Built<StatementNoCte<()>> {
+    fn from(value: Built<ReIndexStmt<()>>) -> Self {
+        value.convert("StatementNoCte")
+    }
+}
+impl From<Built<ReleaseStmt<()>>> for Built<StatementNoCte<()>> {
+    fn from(value: Built<ReleaseStmt<()>>) -> Self {
+        value.convert("StatementNoCte")
+    }
+}
+impl From<Built<RollbackStmt<()>>> for Built<StatementNoCte<()>> {
+    fn from(value: Built<RollbackStmt<()>>) -> Self {
+        value.convert("StatementNoCte")
+    }
+}
+impl From<Built<SavepointStmt<()>>> for Built<StatementNoCte<()>> {
+    fn from(value: Built<SavepointStmt<()>>) -> Self {
+        value.convert("StatementNoCte")
+    }
+}
+impl From<Built<VacuumStmt<()>>> for Built<StatementNoCte<()>> {
+    fn from(value: Built<VacuumStmt<()>>) -> Self {
+        value.convert("StatementNoCte")
+    }
+}
+impl From<Built<CreateTableSelect<()>>> for Built<TableColumns<()>> {
+    fn from(value: Built<CreateTableSelect<()>>) -> Self {
+        value.convert("TableColumns")
+    }
+}
+impl From<Built<TableDetails<()>>> for Built<TableColumns<()>> {
+    fn from(value: Built<TableDetails<()>>) -> Self {
+        value.convert("TableColumns")
+    }
+}
+impl From<Built<CheckConstraint<()>>> for Built<TableConstraintKind<()>> {
+    fn from(value: Built<CheckConstraint<()>>) -> Self {
+        value.convert("TableConstraintKind")
+    }
+}
+impl From<Built<TableFkConstraint<()>>> for Built<TableConstraintKind<()>> {
+    fn from(value: Built<TableFkConstraint<()>>) -> Self {
+        value.convert("TableConstraintKind")
+    }
+}
+impl From<Built<TablePkConstraint<()>>> for Built<TableConstraintKind<()>> {
+    fn from(value: Built<TablePkConstraint<()>>) -> Self {
+        value.convert("TableConstraintKind")
+    }
+}
+impl From<Built<TableUqConstraint<()>>> for Built<TableConstraintKind<()>> {
+    fn from(value: Built<TableUqConstraint<()>>) -> Self {
+        value.convert("TableConstraintKind")
+    }
+}
+impl From<Built<TableOptWithoutRowId<()>>> for Built<TableOptions<()>> {
+    fn from(value: Built<TableOptWithoutRowId<()>>) -> Self {
+        value.convert("TableOptions")
+    }
+}
+impl TableOptions<()> {
+    pub fn build_kw_strict() -> Built<TableOptions<()>> {
+        Built::fixed_token("TableOptions", "KW_STRICT")
+    }
+}
+impl From<Built<TableOrIdxNameWithSchema<()>>> for Built<Target<()>> {
+    fn from(value: Built<TableOrIdxNameWithSchema<()>>) -> Self {
+        value.convert("Target")
+    }
+}
+impl From<Built<TableOrIdxOrCollationName<()>>> for Built<Target<()>> {
+    fn from(value: Built<TableOrIdxOrCollationName<()>>) -> Self {
+        value.convert("Target")
+    }
+}
+impl Temporary<()> {
+    pub fn build_kw_temp() -> Built<Temporary<()>> {
+        Built::fixed_token("Temporary", "KW_TEMP")
+    }
+    pub fn build_kw_temporary() -> Built<Temporary<()>> {
+        Built::fixed_token("Temporary", "KW_TEMPORARY")
+    }
+}
+impl From<Built<TriggerUpdateAction<()>>> for Built<TriggerActionKind<()>> {
+    fn from(value: Built<TriggerUpdateAction<()>>) -> Self {
+        value.convert("TriggerActionKind")
+    }
+}
+impl TriggerActionKind<()> {
+    pub fn build_kw_delete() -> Built<TriggerActionKind<()>> {
+        Built::fixed_token("TriggerActionKind", "KW_DELETE")
+    }
+    pub fn build_kw_insert() -> Built<TriggerActionKind<()>> {
+        Built::fixed_token("TriggerActionKind", "KW_INSERT")
+    }
+}
+impl From<Built<DeleteStmt<()>>> for Built<TriggerBodyStmt<()>> {
+    fn from(value: Built<DeleteStmt<()>>) -> Self {
+        value.convert("TriggerBodyStmt")
+    }
+}
+impl From<Built<InsertStmt<()>>> for Built<TriggerBodyStmt<()>> {
+    fn from(value: Built<InsertStmt<()>>) -> Self {
+        value.convert("TriggerBodyStmt")
+    }
+}
+impl From<Built<SelectStmtWithCte<()>>> for Built<TriggerBodyStmt<()>> {
+    fn from(value: Built<SelectStmtWithCte<()>>) -> Self {
+        value.convert("TriggerBodyStmt")
+    }
+}
+impl From<Built<UpdateStmt<()>>> for Built<TriggerBodyStmt<()>> {
+    fn from(value: Built<UpdateStmt<()>>) -> Self {
+        value.convert("TriggerBodyStmt")
+    }
+}
+impl From<Built<TriggerInsteadOf<()>>> for Built<TriggerWhen<()>> {
+    fn from(value: Built<TriggerInsteadOf<()>>) -> Self {
+        value.convert("TriggerWhen")
+    }
+}
+impl TriggerWhen<()> {
+    pub fn build_kw_before() -> Built<TriggerWhen<()>> {
+        Built::fixed_token("TriggerWhen", "KW_BEFORE")
+    }
+    pub fn build_kw_after() -> Built<TriggerWhen<()>> {
+        Built::fixed_token("TriggerWhen", "KW_AFTER")
+    }
+}
+impl From<Built<UpsertDoUpdate<()>>> for Built<UpsertClauseAction<()>> {
+    fn from(value: Built<UpsertDoUpdate<()>>) -> Self {
+        value.convert("UpsertClauseAction")
+    }
+}
+impl UpsertClauseAction<()> {
+    pub fn build_kw_nothing() -> Built<UpsertClauseAction<()>> {
+        Built::fixed_token("UpsertClauseAction", "KW_NOTHING")
+    }
+}
@@ -591,7 +591,7 @@ impl<'a, N: CstNodeTrait<'a>> ReturningClauseKind<N> {
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::STAR,
                     ..
-                }) => Some(Self::STAR(node)),
+                }) => Some(Self::STAR(child)),
                 CstNodeDataKind::Tree(TreeKind::ReturningClauseExpr, _) => {
                     Some(Self::ReturningClauseExpr(ReturningClauseExpr::cast(child)?))
                 }
@@ -673,7 +673,7 @@ impl<'a, N: CstNodeTrait<'a>> InsertStmtKind<N> {
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::KW_REPLACE,
                     ..
-                }) => Some(Self::KW_REPLACE(node)),
+                }) => Some(Self::KW_REPLACE(child)),
                 CstNodeDataKind::Tree(TreeKind::InsertOrAction, _) => {
                     Some(Self::InsertOrAction(InsertOrAction::cast(child)?))
                 }
@@ -1176,11 +1176,11 @@ impl<'a, N: CstNodeTrait<'a>> Order<N> {
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::KW_ASC,
                     ..
-                }) => Some(Self::KW_ASC(node)),
+                }) => Some(Self::KW_ASC(child)),
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::KW_DESC,
                     ..
-                }) => Some(Self::KW_DESC(node)),
+                }) => Some(Self::KW_DESC(child)),
                 _ => None,
             })
             .next()
@@ -1290,39 +1290,39 @@ impl<'a, N: CstNodeTrait<'a>> ExprLit<N> {
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::INT_LIT,
                     ..
-                }) => Some(Self::INT_LIT(node)),
+                }) => Some(Self::INT_LIT(child)),
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::HEX_LIT,
                     ..
-                }) => Some(Self::HEX_LIT(node)),
+                }) => Some(Self::HEX_LIT(child)),
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::STR_LIT,
                     ..
-                }) => Some(Self::STR_LIT(node)),
+                }) => Some(Self::STR_LIT(child)),
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::REAL_LIT,
                     ..
-                }) => Some(Self::REAL_LIT(node)),
+                }) => Some(Self::REAL_LIT(child)),
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::BLOB_LIT,
                     ..
-                }) => Some(Self::BLOB_LIT(node)),
+                }) => Some(Self::BLOB_LIT(child)),
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::KW_NULL,
                     ..
-                }) => Some(Self::KW_NULL(node)),
+                }) => Some(Self::KW_NULL(child)),
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::KW_CURRENT_TIME,
                     ..
-                }) => Some(Self::KW_CURRENT_TIME(node)),
+                }) => Some(Self::KW_CURRENT_TIME(child)),
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::KW_CURRENT_DATE,
                     ..
-                }) => Some(Self::KW_CURRENT_DATE(node)),
+                }) => Some(Self::KW_CURRENT_DATE(child)),
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::KW_CURRENT_TIMESTAMP,
                     ..
-                }) => Some(Self::KW_CURRENT_TIMESTAMP(node)),
+                }) => Some(Self::KW_CURRENT_TIMESTAMP(child)),
                 _ => None,
             })
             .next()
@@ -1413,11 +1413,11 @@ impl<'a, N: CstNodeTrait<'a>> TriggerActionKind<N> {
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::KW_DELETE,
                     ..
-                }) => Some(Self::KW_DELETE(node)),
+                }) => Some(Self::KW_DELETE(child)),
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::KW_INSERT,
                     ..
-                }) => Some(Self::KW_INSERT(node)),
+                }) => Some(Self::KW_INSERT(child)),
                 CstNodeDataKind::Tree(TreeKind::TriggerUpdateAction, _) => {
                     Some(Self::TriggerUpdateAction(TriggerUpdateAction::cast(child)?))
                 }
@@ -1806,11 +1806,11 @@ impl<'a, N: CstNodeTrait<'a>> CompoundOperator<N> {
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::KW_INTERSECT,
                     ..
-                }) => Some(Self::KW_INTERSECT(node)),
+                }) => Some(Self::KW_INTERSECT(child)),
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::KW_EXCEPT,
                     ..
-                }) => Some(Self::KW_EXCEPT(node)),
+                }) => Some(Self::KW_EXCEPT(child)),
                 _ => None,
             })
             .next()
@@ -1900,7 +1900,7 @@ impl<'a, N: CstNodeTrait<'a>> TableOptions<N> {
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::KW_STRICT,
                     ..
-                }) => Some(Self::KW_STRICT(node)),
+                }) => Some(Self::KW_STRICT(child)),
                 _ => None,
             })
             .next()
@@ -2714,11 +2714,11 @@ impl<'a, N: CstNodeTrait<'a>> ColumnGeneratedKind<N> {
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::KW_STORED,
                     ..
-                }) => Some(Self::KW_STORED(node)),
+                }) => Some(Self::KW_STORED(child)),
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::KW_VIRTUAL,
                     ..
-                }) => Some(Self::KW_VIRTUAL(node)),
+                }) => Some(Self::KW_VIRTUAL(child)),
                 _ => None,
             })
             .next()
@@ -2761,7 +2761,7 @@ impl<'a, N: CstNodeTrait<'a>> RaiseAction<N> {
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::KW_IGNORE,
                     ..
-                }) => Some(Self::KW_IGNORE(node)),
+                }) => Some(Self::KW_IGNORE(child)),
                 CstNodeDataKind::Tree(TreeKind::RaiseActionRollBack, _) => {
                     Some(Self::RaiseActionRollBack(RaiseActionRollBack::cast(child)?))
                 }
@@ -2834,15 +2834,15 @@ impl<'a, N: CstNodeTrait<'a>> PragmaValue<N> {
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::KW_ON,
                     ..
-                }) => Some(Self::KW_ON(node)),
+                }) => Some(Self::KW_ON(child)),
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::KW_DELETE,
                     ..
-                }) => Some(Self::KW_DELETE(node)),
+                }) => Some(Self::KW_DELETE(child)),
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::KW_DEFAULT,
                     ..
-                }) => Some(Self::KW_DEFAULT(node)),
+                }) => Some(Self::KW_DEFAULT(child)),
                 _ => None,
             })
             .next()
@@ -2906,19 +2906,19 @@ impl<'a, N: CstNodeTrait<'a>> ModuleArg<N> {
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::STR_LIT,
                     ..
-                }) => Some(Self::STR_LIT(node)),
+                }) => Some(Self::STR_LIT(child)),
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::INT_LIT,
                     ..
-                }) => Some(Self::INT_LIT(node)),
+                }) => Some(Self::INT_LIT(child)),
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::REAL_LIT,
                     ..
-                }) => Some(Self::REAL_LIT(node)),
+                }) => Some(Self::REAL_LIT(child)),
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::HEX_LIT,
                     ..
-                }) => Some(Self::HEX_LIT(node)),
+                }) => Some(Self::HEX_LIT(child)),
                 _ => None,
             })
             .next()
@@ -3014,23 +3014,23 @@ impl<'a, N: CstNodeTrait<'a>> ConflictAction<N> {
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::KW_ROLLBACK,
                     ..
-                }) => Some(Self::KW_ROLLBACK(node)),
+                }) => Some(Self::KW_ROLLBACK(child)),
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::KW_ABORT,
                     ..
-                }) => Some(Self::KW_ABORT(node)),
+                }) => Some(Self::KW_ABORT(child)),
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::KW_FAIL,
                     ..
-                }) => Some(Self::KW_FAIL(node)),
+                }) => Some(Self::KW_FAIL(child)),
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::KW_IGNORE,
                     ..
-                }) => Some(Self::KW_IGNORE(node)),
+                }) => Some(Self::KW_IGNORE(child)),
                 CstNodeDataKind::Token(SqliteToken {
                     kind: TokenKind::KW_REPLACE,
                     ..
-                }) => Some(Self::KW_REPLACE(node)),
+                }) => Some(Self::KW_REPLACE(child)),
                 _ => None,
             })
             .next()
@@ -4068,7 +4068,6 @@ impl<'a, N: CstNodeTrait<'a>> WindowPartitionByClause<N> {
     pub fn items(&self) -> impl Iterator<Item = Expr<N>> + use<'_, 'a, N> {
         self.inner
             .find_children(SqliteTreeKind::Expr)
-            .flat_map(|it| it.children())
             .flat_map(Expr::cast)
     }
 }
@@ -5092,7 +5091,6 @@ impl<'a, N: CstNodeTrait<'a>> ExprList<N> {
     pub fn items(&self) -> impl Iterator<Item = Expr<N>> + use<'_, 'a, N> {
         self.inner
             .find_children(SqliteTreeKind::Expr)
-            .flat_map(|it| it.children())
             .flat_map(Expr::cast)
     }
 }
@@ -5333,7 +5331,6 @@ impl<'a, N: CstNodeTrait<'a>> TriggerBodyStmtList<N> {
     pub fn items(&self) -> impl Iterator<Item = TriggerBodyStmt<N>> + use<'_, 'a, N> {
         self.inner
             .find_children(SqliteTreeKind::TriggerBodyStmt)
-            .flat_map(|it| it.children())
             .flat_map(TriggerBodyStmt::cast)
     }
 }
@@ -5763,7 +5760,6 @@ impl<'a, N: CstNodeTrait<'a>> TableOptionsList<N> {
     pub fn items(&self) -> impl Iterator<Item = TableOptions<N>> + use<'_, 'a, N> {
         self.inner
             .find_children(SqliteTreeKind::TableOptions)
-            .flat_map(|it| it.children())
             .flat_map(TableOptions::cast)
     }
 }
@@ -6669,7 +6665,6 @@ impl<'a, N: CstNodeTrait<'a>> GroupByClause<N> {
     pub fn items(&self) -> impl Iterator<Item = Expr<N>> + use<'_, 'a, N> {
         self.inner
             .find_children(SqliteTreeKind::Expr)
-            .flat_map(|it| it.children())
             .flat_map(Expr::cast)
     }
 }
@@ -8047,7 +8042,6 @@ impl<'a, N: CstNodeTrait<'a>> ReturningClause<N> {
     pub fn columns(&self) -> impl Iterator<Item = ReturningClauseKind<N>> + use<'_, 'a, N> {
         self.inner
             .find_children(SqliteTreeKind::ReturningClauseKind)
-            .flat_map(|it| it.children())
             .flat_map(ReturningClauseKind::cast)
     }
 }
@@ -8123,7 +8117,6 @@ impl<'a, N: CstNodeTrait<'a>> InTableFunc<N> {
     pub fn items(&self) -> impl Iterator<Item = Expr<N>> + use<'_, 'a, N> {
         self.inner
             .find_children(SqliteTreeKind::Expr)
-            .flat_map(|it| it.children())
             .flat_map(Expr::cast)
     }
 }
@@ -9227,7 +9220,6 @@ impl<'a, N: CstNodeTrait<'a>> ResultColumnList<N> {
     pub fn items(&self) -> impl Iterator<Item = ResultColumn<N>> + use<'_, 'a, N> {
         self.inner
             .find_children(SqliteTreeKind::ResultColumn)
-            .flat_map(|it| it.children())
             .flat_map(ResultColumn::cast)
     }
 }
@@ -9359,7 +9351,6 @@ impl<'a, N: CstNodeTrait<'a>> ArgExpr<N> {
     pub fn items(&self) -> impl Iterator<Item = Expr<N>> + use<'_, 'a, N> {
         self.inner
             .find_children(SqliteTreeKind::Expr)
-            .flat_map(|it| it.children())
             .flat_map(Expr::cast)
     }
     pub fn order_by_clause(&self) -> Option<OrderByClause<N>> {
@@ -9502,7 +9493,6 @@ impl<'a, N: CstNodeTrait<'a>> EmptyableExprList<N> {
     pub fn items(&self) -> impl Iterator<Item = Expr<N>> + use<'_, 'a, N> {
         self.inner
             .find_children(SqliteTreeKind::Expr)
-            .flat_map(|it| it.children())
             .flat_map(Expr::cast)
     }
 }
@@ -9963,7 +9953,6 @@ impl<'a, N: CstNodeTrait<'a>> ModuleArgList<N> {
     pub fn items(&self) -> impl Iterator<Item = ModuleArg<N>> + use<'_, 'a, N> {
         self.inner
             .find_children(SqliteTreeKind::ModuleArg)
-            .flat_map(|it| it.children())
             .flat_map(ModuleArg::cast)
     }
 }
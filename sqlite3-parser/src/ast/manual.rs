@@ -223,7 +223,7 @@ pub enum JoinOperatorKind {
 
 impl<'a, N: CstNodeTrait<'a>> JoinOperator<N> {
     pub fn cast(node: N) -> Option<Self> {
-        if node.tree() == Some(SqliteTreeKind::JoinClause) {
+        if node.tree() == Some(SqliteTreeKind::JoinOperator) {
             Some(Self { inner: node })
         } else {
             None
@@ -1,8 +1,18 @@
+mod build;
+mod builder;
 mod compare;
 mod generated;
 mod manual;
+mod visitor;
 
+pub use build::{BuildError, Built, NodeBuilder};
+pub use builder::*;
 pub use generated::*;
+pub use manual::{
+    JoinClause, JoinOperator, JoinOperatorKind, Offset, PragmaStmt, TableOrSubquery,
+    TableOrSubqueryKind,
+};
+pub use visitor::*;
 
 #[test]
 fn test_create_table_ast() {
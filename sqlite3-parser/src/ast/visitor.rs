@@ -0,0 +1,5202 @@
+#![allow(unused_variables)]
+use super::*;
+use crate::{CstNodeTrait, SqliteTreeKind};
+/// Traverses the typed AST. Every `visit_*` method calls the matching `walk_*` function by
+/// default, which in turn visits the children of the node. Override a method to act on a
+/// node and call the `walk_*` function from it to keep descending.
+pub trait Visitor<'a, N: CstNodeTrait<'a>> {
+    /// Called for tokens that are exposed by the typed AST (Ex: names and literals)
+    fn visit_token(&mut self, token: N) {}
+    fn visit_add_column(&mut self, node: &AddColumn<N>) {
+        walk_add_column(self, node)
+    }
+    fn visit_alias_name(&mut self, node: &AliasName<N>) {
+        walk_alias_name(self, node)
+    }
+    fn visit_alter_table_kind(&mut self, node: &AlterTableKind<N>) {
+        walk_alter_table_kind(self, node)
+    }
+    fn visit_alter_table_stmt(&mut self, node: &AlterTableStmt<N>) {
+        walk_alter_table_stmt(self, node)
+    }
+    fn visit_analyze_stmt(&mut self, node: &AnalyzeStmt<N>) {
+        walk_analyze_stmt(self, node)
+    }
+    fn visit_analyze_target(&mut self, node: &AnalyzeTarget<N>) {
+        walk_analyze_target(self, node)
+    }
+    fn visit_any_valid_name(&mut self, node: &AnyValidName<N>) {
+        walk_any_valid_name(self, node)
+    }
+    fn visit_arg_expr(&mut self, node: &ArgExpr<N>) {
+        walk_arg_expr(self, node)
+    }
+    fn visit_arg_star(&mut self, node: &ArgStar<N>) {
+        walk_arg_star(self, node)
+    }
+    fn visit_attach_db_stmt(&mut self, node: &AttachDbStmt<N>) {
+        walk_attach_db_stmt(self, node)
+    }
+    fn visit_begin_stmt(&mut self, node: &BeginStmt<N>) {
+        walk_begin_stmt(self, node)
+    }
+    fn visit_begin_stmt_kind(&mut self, node: &BeginStmtKind<N>) {
+        walk_begin_stmt_kind(self, node)
+    }
+    fn visit_case_else_clause(&mut self, node: &CaseElseClause<N>) {
+        walk_case_else_clause(self, node)
+    }
+    fn visit_case_target_expr(&mut self, node: &CaseTargetExpr<N>) {
+        walk_case_target_expr(self, node)
+    }
+    fn visit_case_when_clause(&mut self, node: &CaseWhenClause<N>) {
+        walk_case_when_clause(self, node)
+    }
+    fn visit_case_when_clause_list(&mut self, node: &CaseWhenClauseList<N>) {
+        walk_case_when_clause_list(self, node)
+    }
+    fn visit_check_constraint(&mut self, node: &CheckConstraint<N>) {
+        walk_check_constraint(self, node)
+    }
+    fn visit_col_name_list(&mut self, node: &ColNameList<N>) {
+        walk_col_name_list(self, node)
+    }
+    fn visit_collation(&mut self, node: &Collation<N>) {
+        walk_collation(self, node)
+    }
+    fn visit_collation_name(&mut self, node: &CollationName<N>) {
+        walk_collation_name(self, node)
+    }
+    fn visit_column_constraint(&mut self, node: &ColumnConstraint<N>) {
+        walk_column_constraint(self, node)
+    }
+    fn visit_column_constraint_name(&mut self, node: &ColumnConstraintName<N>) {
+        walk_column_constraint_name(self, node)
+    }
+    fn visit_column_def(&mut self, node: &ColumnDef<N>) {
+        walk_column_def(self, node)
+    }
+    fn visit_column_generated(&mut self, node: &ColumnGenerated<N>) {
+        walk_column_generated(self, node)
+    }
+    fn visit_column_generated_kind(&mut self, node: &ColumnGeneratedKind<N>) {
+        walk_column_generated_kind(self, node)
+    }
+    fn visit_column_name(&mut self, node: &ColumnName<N>) {
+        walk_column_name(self, node)
+    }
+    fn visit_commit_start_kw(&mut self, node: &CommitStartKw<N>) {
+        walk_commit_start_kw(self, node)
+    }
+    fn visit_commit_stmt(&mut self, node: &CommitStmt<N>) {
+        walk_commit_stmt(self, node)
+    }
+    fn visit_common_table_expr(&mut self, node: &CommonTableExpr<N>) {
+        walk_common_table_expr(self, node)
+    }
+    fn visit_compound_operator(&mut self, node: &CompoundOperator<N>) {
+        walk_compound_operator(self, node)
+    }
+    fn visit_compound_select(&mut self, node: &CompoundSelect<N>) {
+        walk_compound_select(self, node)
+    }
+    fn visit_conflict_action(&mut self, node: &ConflictAction<N>) {
+        walk_conflict_action(self, node)
+    }
+    fn visit_conflict_clause(&mut self, node: &ConflictClause<N>) {
+        walk_conflict_clause(self, node)
+    }
+    fn visit_constraint_name(&mut self, node: &ConstraintName<N>) {
+        walk_constraint_name(self, node)
+    }
+    fn visit_constraint_type(&mut self, node: &ConstraintType<N>) {
+        walk_constraint_type(self, node)
+    }
+    fn visit_create_index_stmt(&mut self, node: &CreateIndexStmt<N>) {
+        walk_create_index_stmt(self, node)
+    }
+    fn visit_create_table_select(&mut self, node: &CreateTableSelect<N>) {
+        walk_create_table_select(self, node)
+    }
+    fn visit_create_table_stmt(&mut self, node: &CreateTableStmt<N>) {
+        walk_create_table_stmt(self, node)
+    }
+    fn visit_create_trigger_stmt(&mut self, node: &CreateTriggerStmt<N>) {
+        walk_create_trigger_stmt(self, node)
+    }
+    fn visit_create_view_stmt(&mut self, node: &CreateViewStmt<N>) {
+        walk_create_view_stmt(self, node)
+    }
+    fn visit_create_virtual_table_stmt(&mut self, node: &CreateVirtualTableStmt<N>) {
+        walk_create_virtual_table_stmt(self, node)
+    }
+    fn visit_cte_clause(&mut self, node: &CteClause<N>) {
+        walk_cte_clause(self, node)
+    }
+    fn visit_cte_name(&mut self, node: &CteName<N>) {
+        walk_cte_name(self, node)
+    }
+    fn visit_cte_prependable(&mut self, node: &CtePrependable<N>) {
+        walk_cte_prependable(self, node)
+    }
+    fn visit_db_name_expr(&mut self, node: &DbNameExpr<N>) {
+        walk_db_name_expr(self, node)
+    }
+    fn visit_default_constraint(&mut self, node: &DefaultConstraint<N>) {
+        walk_default_constraint(self, node)
+    }
+    fn visit_default_constraint_expr(&mut self, node: &DefaultConstraintExpr<N>) {
+        walk_default_constraint_expr(self, node)
+    }
+    fn visit_default_constraint_iden(&mut self, node: &DefaultConstraintIden<N>) {
+        walk_default_constraint_iden(self, node)
+    }
+    fn visit_default_constraint_literal(&mut self, node: &DefaultConstraintLiteral<N>) {
+        walk_default_constraint_literal(self, node)
+    }
+    fn visit_default_value_kind(&mut self, node: &DefaultValueKind<N>) {
+        walk_default_value_kind(self, node)
+    }
+    fn visit_defer_kind(&mut self, node: &DeferKind<N>) {
+        walk_defer_kind(self, node)
+    }
+    fn visit_delete_stmt(&mut self, node: &DeleteStmt<N>) {
+        walk_delete_stmt(self, node)
+    }
+    fn visit_delete_stmt_limited(&mut self, node: &DeleteStmtLimited<N>) {
+        walk_delete_stmt_limited(self, node)
+    }
+    fn visit_detach_stmt(&mut self, node: &DetachStmt<N>) {
+        walk_detach_stmt(self, node)
+    }
+    fn visit_drop_column(&mut self, node: &DropColumn<N>) {
+        walk_drop_column(self, node)
+    }
+    fn visit_drop_index_stmt(&mut self, node: &DropIndexStmt<N>) {
+        walk_drop_index_stmt(self, node)
+    }
+    fn visit_drop_table_stmt(&mut self, node: &DropTableStmt<N>) {
+        walk_drop_table_stmt(self, node)
+    }
+    fn visit_drop_trigger_stmt(&mut self, node: &DropTriggerStmt<N>) {
+        walk_drop_trigger_stmt(self, node)
+    }
+    fn visit_drop_view_stmt(&mut self, node: &DropViewStmt<N>) {
+        walk_drop_view_stmt(self, node)
+    }
+    fn visit_emptyable_expr_list(&mut self, node: &EmptyableExprList<N>) {
+        walk_emptyable_expr_list(self, node)
+    }
+    fn visit_explain_clause(&mut self, node: &ExplainClause<N>) {
+        walk_explain_clause(self, node)
+    }
+    fn visit_expr(&mut self, node: &Expr<N>) {
+        walk_expr(self, node)
+    }
+    fn visit_expr_bind_param(&mut self, node: &ExprBindParam<N>) {
+        walk_expr_bind_param(self, node)
+    }
+    fn visit_expr_case(&mut self, node: &ExprCase<N>) {
+        walk_expr_case(self, node)
+    }
+    fn visit_expr_cast(&mut self, node: &ExprCast<N>) {
+        walk_expr_cast(self, node)
+    }
+    fn visit_expr_column_name(&mut self, node: &ExprColumnName<N>) {
+        walk_expr_column_name(self, node)
+    }
+    fn visit_expr_exists_select(&mut self, node: &ExprExistsSelect<N>) {
+        walk_expr_exists_select(self, node)
+    }
+    fn visit_expr_func(&mut self, node: &ExprFunc<N>) {
+        walk_expr_func(self, node)
+    }
+    fn visit_expr_infix(&mut self, node: &ExprInfix<N>) {
+        walk_expr_infix(self, node)
+    }
+    fn visit_expr_list(&mut self, node: &ExprList<N>) {
+        walk_expr_list(self, node)
+    }
+    fn visit_expr_lit(&mut self, node: &ExprLit<N>) {
+        walk_expr_lit(self, node)
+    }
+    fn visit_expr_paren(&mut self, node: &ExprParen<N>) {
+        walk_expr_paren(self, node)
+    }
+    fn visit_expr_postfix(&mut self, node: &ExprPostfix<N>) {
+        walk_expr_postfix(self, node)
+    }
+    fn visit_expr_prefix(&mut self, node: &ExprPrefix<N>) {
+        walk_expr_prefix(self, node)
+    }
+    fn visit_expr_select(&mut self, node: &ExprSelect<N>) {
+        walk_expr_select(self, node)
+    }
+    fn visit_file(&mut self, node: &File<N>) {
+        walk_file(self, node)
+    }
+    fn visit_file_name_expr(&mut self, node: &FileNameExpr<N>) {
+        walk_file_name_expr(self, node)
+    }
+    fn visit_filter_clause(&mut self, node: &FilterClause<N>) {
+        walk_filter_clause(self, node)
+    }
+    fn visit_fk_action(&mut self, node: &FkAction<N>) {
+        walk_fk_action(self, node)
+    }
+    fn visit_fk_cascade(&mut self, node: &FkCascade<N>) {
+        walk_fk_cascade(self, node)
+    }
+    fn visit_fk_clause(&mut self, node: &FkClause<N>) {
+        walk_fk_clause(self, node)
+    }
+    fn visit_fk_deferrable(&mut self, node: &FkDeferrable<N>) {
+        walk_fk_deferrable(self, node)
+    }
+    fn visit_fk_fail_kind(&mut self, node: &FkFailKind<N>) {
+        walk_fk_fail_kind(self, node)
+    }
+    fn visit_fk_match_action(&mut self, node: &FkMatchAction<N>) {
+        walk_fk_match_action(self, node)
+    }
+    fn visit_fk_no_action(&mut self, node: &FkNoAction<N>) {
+        walk_fk_no_action(self, node)
+    }
+    fn visit_fk_on_action(&mut self, node: &FkOnAction<N>) {
+        walk_fk_on_action(self, node)
+    }
+    fn visit_fk_on_or_match(&mut self, node: &FkOnOrMatch<N>) {
+        walk_fk_on_or_match(self, node)
+    }
+    fn visit_fk_restrict(&mut self, node: &FkRestrict<N>) {
+        walk_fk_restrict(self, node)
+    }
+    fn visit_fk_set_default(&mut self, node: &FkSetDefault<N>) {
+        walk_fk_set_default(self, node)
+    }
+    fn visit_fk_set_null(&mut self, node: &FkSetNull<N>) {
+        walk_fk_set_null(self, node)
+    }
+    fn visit_fk_violate_action(&mut self, node: &FkViolateAction<N>) {
+        walk_fk_violate_action(self, node)
+    }
+    fn visit_frame_spec(&mut self, node: &FrameSpec<N>) {
+        walk_frame_spec(self, node)
+    }
+    fn visit_frame_spec_between_clause(&mut self, node: &FrameSpecBetweenClause<N>) {
+        walk_frame_spec_between_clause(self, node)
+    }
+    fn visit_frame_spec_between_left(&mut self, node: &FrameSpecBetweenLeft<N>) {
+        walk_frame_spec_between_left(self, node)
+    }
+    fn visit_frame_spec_between_right(&mut self, node: &FrameSpecBetweenRight<N>) {
+        walk_frame_spec_between_right(self, node)
+    }
+    fn visit_frame_spec_current_row(&mut self, node: &FrameSpecCurrentRow<N>) {
+        walk_frame_spec_current_row(self, node)
+    }
+    fn visit_frame_spec_exclude_clause(&mut self, node: &FrameSpecExcludeClause<N>) {
+        walk_frame_spec_exclude_clause(self, node)
+    }
+    fn visit_frame_spec_exclude_kind(&mut self, node: &FrameSpecExcludeKind<N>) {
+        walk_frame_spec_exclude_kind(self, node)
+    }
+    fn visit_frame_spec_following(&mut self, node: &FrameSpecFollowing<N>) {
+        walk_frame_spec_following(self, node)
+    }
+    fn visit_frame_spec_kind(&mut self, node: &FrameSpecKind<N>) {
+        walk_frame_spec_kind(self, node)
+    }
+    fn visit_frame_spec_no_others(&mut self, node: &FrameSpecNoOthers<N>) {
+        walk_frame_spec_no_others(self, node)
+    }
+    fn visit_frame_spec_preceding(&mut self, node: &FrameSpecPreceding<N>) {
+        walk_frame_spec_preceding(self, node)
+    }
+    fn visit_frame_spec_unbounded_following(&mut self, node: &FrameSpecUnboundedFollowing<N>) {
+        walk_frame_spec_unbounded_following(self, node)
+    }
+    fn visit_frame_spec_unbounded_preceding(&mut self, node: &FrameSpecUnboundedPreceding<N>) {
+        walk_frame_spec_unbounded_preceding(self, node)
+    }
+    fn visit_from_clause(&mut self, node: &FromClause<N>) {
+        walk_from_clause(self, node)
+    }
+    fn visit_from_clause_table_value_function(&mut self, node: &FromClauseTableValueFunction<N>) {
+        walk_from_clause_table_value_function(self, node)
+    }
+    fn visit_from_clause_value(&mut self, node: &FromClauseValue<N>) {
+        walk_from_clause_value(self, node)
+    }
+    fn visit_full_index_name(&mut self, node: &FullIndexName<N>) {
+        walk_full_index_name(self, node)
+    }
+    fn visit_full_pragma_name(&mut self, node: &FullPragmaName<N>) {
+        walk_full_pragma_name(self, node)
+    }
+    fn visit_full_table_function_name(&mut self, node: &FullTableFunctionName<N>) {
+        walk_full_table_function_name(self, node)
+    }
+    fn visit_full_table_name(&mut self, node: &FullTableName<N>) {
+        walk_full_table_name(self, node)
+    }
+    fn visit_full_trigger_name(&mut self, node: &FullTriggerName<N>) {
+        walk_full_trigger_name(self, node)
+    }
+    fn visit_full_view_name(&mut self, node: &FullViewName<N>) {
+        walk_full_view_name(self, node)
+    }
+    fn visit_func_arguments(&mut self, node: &FuncArguments<N>) {
+        walk_func_arguments(self, node)
+    }
+    fn visit_function_name(&mut self, node: &FunctionName<N>) {
+        walk_function_name(self, node)
+    }
+    fn visit_group_by_clause(&mut self, node: &GroupByClause<N>) {
+        walk_group_by_clause(self, node)
+    }
+    fn visit_having_clause(&mut self, node: &HavingClause<N>) {
+        walk_having_clause(self, node)
+    }
+    fn visit_if_not_exists(&mut self, node: &IfNotExists<N>) {
+        walk_if_not_exists(self, node)
+    }
+    fn visit_in_expr_kind(&mut self, node: &InExprKind<N>) {
+        walk_in_expr_kind(self, node)
+    }
+    fn visit_in_table(&mut self, node: &InTable<N>) {
+        walk_in_table(self, node)
+    }
+    fn visit_in_table_func(&mut self, node: &InTableFunc<N>) {
+        walk_in_table_func(self, node)
+    }
+    fn visit_index_details(&mut self, node: &IndexDetails<N>) {
+        walk_index_details(self, node)
+    }
+    fn visit_index_name(&mut self, node: &IndexName<N>) {
+        walk_index_name(self, node)
+    }
+    fn visit_indexed_col(&mut self, node: &IndexedCol<N>) {
+        walk_indexed_col(self, node)
+    }
+    fn visit_indexed_col_list(&mut self, node: &IndexedColList<N>) {
+        walk_indexed_col_list(self, node)
+    }
+    fn visit_insert_default_values_clause(&mut self, node: &InsertDefaultValuesClause<N>) {
+        walk_insert_default_values_clause(self, node)
+    }
+    fn visit_insert_or_action(&mut self, node: &InsertOrAction<N>) {
+        walk_insert_or_action(self, node)
+    }
+    fn visit_insert_select_clause(&mut self, node: &InsertSelectClause<N>) {
+        walk_insert_select_clause(self, node)
+    }
+    fn visit_insert_stmt(&mut self, node: &InsertStmt<N>) {
+        walk_insert_stmt(self, node)
+    }
+    fn visit_insert_stmt_kind(&mut self, node: &InsertStmtKind<N>) {
+        walk_insert_stmt_kind(self, node)
+    }
+    fn visit_insert_value_kind(&mut self, node: &InsertValueKind<N>) {
+        walk_insert_value_kind(self, node)
+    }
+    fn visit_insert_values_clause(&mut self, node: &InsertValuesClause<N>) {
+        walk_insert_values_clause(self, node)
+    }
+    fn visit_join_clause(&mut self, node: &JoinClause<N>) {
+        walk_join_clause(self, node)
+    }
+    fn visit_join_constraint(&mut self, node: &JoinConstraint<N>) {
+        walk_join_constraint(self, node)
+    }
+    fn visit_join_operator(&mut self, node: &JoinOperator<N>) {
+        walk_join_operator(self, node)
+    }
+    fn visit_limit_clause(&mut self, node: &LimitClause<N>) {
+        walk_limit_clause(self, node)
+    }
+    fn visit_materialized_cte(&mut self, node: &MaterializedCte<N>) {
+        walk_materialized_cte(self, node)
+    }
+    fn visit_module_arg(&mut self, node: &ModuleArg<N>) {
+        walk_module_arg(self, node)
+    }
+    fn visit_module_arg_list(&mut self, node: &ModuleArgList<N>) {
+        walk_module_arg_list(self, node)
+    }
+    fn visit_module_name(&mut self, node: &ModuleName<N>) {
+        walk_module_name(self, node)
+    }
+    fn visit_new_column_name(&mut self, node: &NewColumnName<N>) {
+        walk_new_column_name(self, node)
+    }
+    fn visit_new_table_name(&mut self, node: &NewTableName<N>) {
+        walk_new_table_name(self, node)
+    }
+    fn visit_null_constraint(&mut self, node: &NullConstraint<N>) {
+        walk_null_constraint(self, node)
+    }
+    fn visit_nulls_position(&mut self, node: &NullsPosition<N>) {
+        walk_nulls_position(self, node)
+    }
+    fn visit_offset(&mut self, node: &Offset<N>) {
+        walk_offset(self, node)
+    }
+    fn visit_on_constraint(&mut self, node: &OnConstraint<N>) {
+        walk_on_constraint(self, node)
+    }
+    fn visit_op_add(&mut self, node: &OpAdd<N>) {
+        walk_op_add(self, node)
+    }
+    fn visit_op_and(&mut self, node: &OpAnd<N>) {
+        walk_op_and(self, node)
+    }
+    fn visit_op_between_and(&mut self, node: &OpBetweenAnd<N>) {
+        walk_op_between_and(self, node)
+    }
+    fn visit_op_bin_and(&mut self, node: &OpBinAnd<N>) {
+        walk_op_bin_and(self, node)
+    }
+    fn visit_op_bin_complement(&mut self, node: &OpBinComplement<N>) {
+        walk_op_bin_complement(self, node)
+    }
+    fn visit_op_bin_l_shift(&mut self, node: &OpBinLShift<N>) {
+        walk_op_bin_l_shift(self, node)
+    }
+    fn visit_op_bin_or(&mut self, node: &OpBinOr<N>) {
+        walk_op_bin_or(self, node)
+    }
+    fn visit_op_bin_r_shift(&mut self, node: &OpBinRShift<N>) {
+        walk_op_bin_r_shift(self, node)
+    }
+    fn visit_op_collate(&mut self, node: &OpCollate<N>) {
+        walk_op_collate(self, node)
+    }
+    fn visit_op_concat(&mut self, node: &OpConcat<N>) {
+        walk_op_concat(self, node)
+    }
+    fn visit_op_divide(&mut self, node: &OpDivide<N>) {
+        walk_op_divide(self, node)
+    }
+    fn visit_op_eq(&mut self, node: &OpEq<N>) {
+        walk_op_eq(self, node)
+    }
+    fn visit_op_escape(&mut self, node: &OpEscape<N>) {
+        walk_op_escape(self, node)
+    }
+    fn visit_op_extract_one(&mut self, node: &OpExtractOne<N>) {
+        walk_op_extract_one(self, node)
+    }
+    fn visit_op_extract_two(&mut self, node: &OpExtractTwo<N>) {
+        walk_op_extract_two(self, node)
+    }
+    fn visit_op_gt(&mut self, node: &OpGT<N>) {
+        walk_op_gt(self, node)
+    }
+    fn visit_op_gte(&mut self, node: &OpGTE<N>) {
+        walk_op_gte(self, node)
+    }
+    fn visit_op_glob(&mut self, node: &OpGlob<N>) {
+        walk_op_glob(self, node)
+    }
+    fn visit_op_in(&mut self, node: &OpIn<N>) {
+        walk_op_in(self, node)
+    }
+    fn visit_op_is(&mut self, node: &OpIs<N>) {
+        walk_op_is(self, node)
+    }
+    fn visit_op_is_distinct_from(&mut self, node: &OpIsDistinctFrom<N>) {
+        walk_op_is_distinct_from(self, node)
+    }
+    fn visit_op_is_not(&mut self, node: &OpIsNot<N>) {
+        walk_op_is_not(self, node)
+    }
+    fn visit_op_is_not_distinct_from(&mut self, node: &OpIsNotDistinctFrom<N>) {
+        walk_op_is_not_distinct_from(self, node)
+    }
+    fn visit_op_is_null(&mut self, node: &OpIsNull<N>) {
+        walk_op_is_null(self, node)
+    }
+    fn visit_op_lt(&mut self, node: &OpLT<N>) {
+        walk_op_lt(self, node)
+    }
+    fn visit_op_lte(&mut self, node: &OpLTE<N>) {
+        walk_op_lte(self, node)
+    }
+    fn visit_op_like(&mut self, node: &OpLike<N>) {
+        walk_op_like(self, node)
+    }
+    fn visit_op_match(&mut self, node: &OpMatch<N>) {
+        walk_op_match(self, node)
+    }
+    fn visit_op_modulus(&mut self, node: &OpModulus<N>) {
+        walk_op_modulus(self, node)
+    }
+    fn visit_op_multiply(&mut self, node: &OpMultiply<N>) {
+        walk_op_multiply(self, node)
+    }
+    fn visit_op_not(&mut self, node: &OpNot<N>) {
+        walk_op_not(self, node)
+    }
+    fn visit_op_not_between_and(&mut self, node: &OpNotBetweenAnd<N>) {
+        walk_op_not_between_and(self, node)
+    }
+    fn visit_op_not_eq(&mut self, node: &OpNotEq<N>) {
+        walk_op_not_eq(self, node)
+    }
+    fn visit_op_not_glob(&mut self, node: &OpNotGlob<N>) {
+        walk_op_not_glob(self, node)
+    }
+    fn visit_op_not_in(&mut self, node: &OpNotIn<N>) {
+        walk_op_not_in(self, node)
+    }
+    fn visit_op_not_like(&mut self, node: &OpNotLike<N>) {
+        walk_op_not_like(self, node)
+    }
+    fn visit_op_not_match(&mut self, node: &OpNotMatch<N>) {
+        walk_op_not_match(self, node)
+    }
+    fn visit_op_not_null(&mut self, node: &OpNotNull<N>) {
+        walk_op_not_null(self, node)
+    }
+    fn visit_op_not_regexp(&mut self, node: &OpNotRegexp<N>) {
+        walk_op_not_regexp(self, node)
+    }
+    fn visit_op_not_space_null(&mut self, node: &OpNotSpaceNull<N>) {
+        walk_op_not_space_null(self, node)
+    }
+    fn visit_op_or(&mut self, node: &OpOr<N>) {
+        walk_op_or(self, node)
+    }
+    fn visit_op_regexp(&mut self, node: &OpRegexp<N>) {
+        walk_op_regexp(self, node)
+    }
+    fn visit_op_subtract(&mut self, node: &OpSubtract<N>) {
+        walk_op_subtract(self, node)
+    }
+    fn visit_op_unary_minus(&mut self, node: &OpUnaryMinus<N>) {
+        walk_op_unary_minus(self, node)
+    }
+    fn visit_op_unary_plus(&mut self, node: &OpUnaryPlus<N>) {
+        walk_op_unary_plus(self, node)
+    }
+    fn visit_order(&mut self, node: &Order<N>) {
+        walk_order(self, node)
+    }
+    fn visit_order_by_clause(&mut self, node: &OrderByClause<N>) {
+        walk_order_by_clause(self, node)
+    }
+    fn visit_ordering_term(&mut self, node: &OrderingTerm<N>) {
+        walk_ordering_term(self, node)
+    }
+    fn visit_ordering_term_list(&mut self, node: &OrderingTermList<N>) {
+        walk_ordering_term_list(self, node)
+    }
+    fn visit_over_clause(&mut self, node: &OverClause<N>) {
+        walk_over_clause(self, node)
+    }
+    fn visit_over_clause_kind(&mut self, node: &OverClauseKind<N>) {
+        walk_over_clause_kind(self, node)
+    }
+    fn visit_password_expr(&mut self, node: &PasswordExpr<N>) {
+        walk_password_expr(self, node)
+    }
+    fn visit_plus_or_minus(&mut self, node: &PlusOrMinus<N>) {
+        walk_plus_or_minus(self, node)
+    }
+    fn visit_pragma_name(&mut self, node: &PragmaName<N>) {
+        walk_pragma_name(self, node)
+    }
+    fn visit_pragma_stmt(&mut self, node: &PragmaStmt<N>) {
+        walk_pragma_stmt(self, node)
+    }
+    fn visit_pragma_value(&mut self, node: &PragmaValue<N>) {
+        walk_pragma_value(self, node)
+    }
+    fn visit_pragma_value_name(&mut self, node: &PragmaValueName<N>) {
+        walk_pragma_value_name(self, node)
+    }
+    fn visit_primary_constraint(&mut self, node: &PrimaryConstraint<N>) {
+        walk_primary_constraint(self, node)
+    }
+    fn visit_qualified_table_name(&mut self, node: &QualifiedTableName<N>) {
+        walk_qualified_table_name(self, node)
+    }
+    fn visit_raise_action(&mut self, node: &RaiseAction<N>) {
+        walk_raise_action(self, node)
+    }
+    fn visit_raise_action_abort(&mut self, node: &RaiseActionAbort<N>) {
+        walk_raise_action_abort(self, node)
+    }
+    fn visit_raise_action_fail(&mut self, node: &RaiseActionFail<N>) {
+        walk_raise_action_fail(self, node)
+    }
+    fn visit_raise_action_roll_back(&mut self, node: &RaiseActionRollBack<N>) {
+        walk_raise_action_roll_back(self, node)
+    }
+    fn visit_raise_func(&mut self, node: &RaiseFunc<N>) {
+        walk_raise_func(self, node)
+    }
+    fn visit_raise_func_err_message(&mut self, node: &RaiseFuncErrMessage<N>) {
+        walk_raise_func_err_message(self, node)
+    }
+    fn visit_range(&mut self, node: &Range<N>) {
+        walk_range(self, node)
+    }
+    fn visit_re_index_stmt(&mut self, node: &ReIndexStmt<N>) {
+        walk_re_index_stmt(self, node)
+    }
+    fn visit_release_stmt(&mut self, node: &ReleaseStmt<N>) {
+        walk_release_stmt(self, node)
+    }
+    fn visit_rename_column(&mut self, node: &RenameColumn<N>) {
+        walk_rename_column(self, node)
+    }
+    fn visit_rename_table(&mut self, node: &RenameTable<N>) {
+        walk_rename_table(self, node)
+    }
+    fn visit_result_column(&mut self, node: &ResultColumn<N>) {
+        walk_result_column(self, node)
+    }
+    fn visit_result_column_all(&mut self, node: &ResultColumnAll<N>) {
+        walk_result_column_all(self, node)
+    }
+    fn visit_result_column_expr(&mut self, node: &ResultColumnExpr<N>) {
+        walk_result_column_expr(self, node)
+    }
+    fn visit_result_column_list(&mut self, node: &ResultColumnList<N>) {
+        walk_result_column_list(self, node)
+    }
+    fn visit_result_column_table_all(&mut self, node: &ResultColumnTableAll<N>) {
+        walk_result_column_table_all(self, node)
+    }
+    fn visit_returning_clause(&mut self, node: &ReturningClause<N>) {
+        walk_returning_clause(self, node)
+    }
+    fn visit_returning_clause_expr(&mut self, node: &ReturningClauseExpr<N>) {
+        walk_returning_clause_expr(self, node)
+    }
+    fn visit_returning_clause_kind(&mut self, node: &ReturningClauseKind<N>) {
+        walk_returning_clause_kind(self, node)
+    }
+    fn visit_rollback_stmt(&mut self, node: &RollbackStmt<N>) {
+        walk_rollback_stmt(self, node)
+    }
+    fn visit_savepoint_name(&mut self, node: &SavepointName<N>) {
+        walk_savepoint_name(self, node)
+    }
+    fn visit_savepoint_stmt(&mut self, node: &SavepointStmt<N>) {
+        walk_savepoint_stmt(self, node)
+    }
+    fn visit_schema_name(&mut self, node: &SchemaName<N>) {
+        walk_schema_name(self, node)
+    }
+    fn visit_schema_name_expr(&mut self, node: &SchemaNameExpr<N>) {
+        walk_schema_name_expr(self, node)
+    }
+    fn visit_schema_or_idx_or_table_name(&mut self, node: &SchemaOrIdxOrTableName<N>) {
+        walk_schema_or_idx_or_table_name(self, node)
+    }
+    fn visit_select_core(&mut self, node: &SelectCore<N>) {
+        walk_select_core(self, node)
+    }
+    fn visit_select_distinct(&mut self, node: &SelectDistinct<N>) {
+        walk_select_distinct(self, node)
+    }
+    fn visit_select_stmt(&mut self, node: &SelectStmt<N>) {
+        walk_select_stmt(self, node)
+    }
+    fn visit_select_stmt_with_cte(&mut self, node: &SelectStmtWithCte<N>) {
+        walk_select_stmt_with_cte(self, node)
+    }
+    fn visit_set_column_expr(&mut self, node: &SetColumnExpr<N>) {
+        walk_set_column_expr(self, node)
+    }
+    fn visit_set_column_kind(&mut self, node: &SetColumnKind<N>) {
+        walk_set_column_kind(self, node)
+    }
+    fn visit_signed_number(&mut self, node: &SignedNumber<N>) {
+        walk_signed_number(self, node)
+    }
+    fn visit_statement(&mut self, node: &Statement<N>) {
+        walk_statement(self, node)
+    }
+    fn visit_statement_kind(&mut self, node: &StatementKind<N>) {
+        walk_statement_kind(self, node)
+    }
+    fn visit_statement_no_cte(&mut self, node: &StatementNoCte<N>) {
+        walk_statement_no_cte(self, node)
+    }
+    fn visit_statement_with_cte(&mut self, node: &StatementWithCte<N>) {
+        walk_statement_with_cte(self, node)
+    }
+    fn visit_table_columns(&mut self, node: &TableColumns<N>) {
+        walk_table_columns(self, node)
+    }
+    fn visit_table_constraint(&mut self, node: &TableConstraint<N>) {
+        walk_table_constraint(self, node)
+    }
+    fn visit_table_constraint_kind(&mut self, node: &TableConstraintKind<N>) {
+        walk_table_constraint_kind(self, node)
+    }
+    fn visit_table_details(&mut self, node: &TableDetails<N>) {
+        walk_table_details(self, node)
+    }
+    fn visit_table_fk_constraint(&mut self, node: &TableFkConstraint<N>) {
+        walk_table_fk_constraint(self, node)
+    }
+    fn visit_table_function_name(&mut self, node: &TableFunctionName<N>) {
+        walk_table_function_name(self, node)
+    }
+    fn visit_table_name(&mut self, node: &TableName<N>) {
+        walk_table_name(self, node)
+    }
+    fn visit_table_name_indexed_by(&mut self, node: &TableNameIndexedBy<N>) {
+        walk_table_name_indexed_by(self, node)
+    }
+    fn visit_table_name_not_indexed(&mut self, node: &TableNameNotIndexed<N>) {
+        walk_table_name_not_indexed(self, node)
+    }
+    fn visit_table_opt_without_row_id(&mut self, node: &TableOptWithoutRowId<N>) {
+        walk_table_opt_without_row_id(self, node)
+    }
+    fn visit_table_options(&mut self, node: &TableOptions<N>) {
+        walk_table_options(self, node)
+    }
+    fn visit_table_options_list(&mut self, node: &TableOptionsList<N>) {
+        walk_table_options_list(self, node)
+    }
+    fn visit_table_or_idx_name_with_schema(&mut self, node: &TableOrIdxNameWithSchema<N>) {
+        walk_table_or_idx_name_with_schema(self, node)
+    }
+    fn visit_table_or_idx_or_collation_name(&mut self, node: &TableOrIdxOrCollationName<N>) {
+        walk_table_or_idx_or_collation_name(self, node)
+    }
+    fn visit_table_or_index_name(&mut self, node: &TableOrIndexName<N>) {
+        walk_table_or_index_name(self, node)
+    }
+    fn visit_table_or_subquery(&mut self, node: &TableOrSubquery<N>) {
+        walk_table_or_subquery(self, node)
+    }
+    fn visit_table_pk_constraint(&mut self, node: &TablePkConstraint<N>) {
+        walk_table_pk_constraint(self, node)
+    }
+    fn visit_table_uq_constraint(&mut self, node: &TableUqConstraint<N>) {
+        walk_table_uq_constraint(self, node)
+    }
+    fn visit_target(&mut self, node: &Target<N>) {
+        walk_target(self, node)
+    }
+    fn visit_temporary(&mut self, node: &Temporary<N>) {
+        walk_temporary(self, node)
+    }
+    fn visit_traditional_select(&mut self, node: &TraditionalSelect<N>) {
+        walk_traditional_select(self, node)
+    }
+    fn visit_trigger_action_kind(&mut self, node: &TriggerActionKind<N>) {
+        walk_trigger_action_kind(self, node)
+    }
+    fn visit_trigger_body_stmt(&mut self, node: &TriggerBodyStmt<N>) {
+        walk_trigger_body_stmt(self, node)
+    }
+    fn visit_trigger_body_stmt_list(&mut self, node: &TriggerBodyStmtList<N>) {
+        walk_trigger_body_stmt_list(self, node)
+    }
+    fn visit_trigger_for_each_row(&mut self, node: &TriggerForEachRow<N>) {
+        walk_trigger_for_each_row(self, node)
+    }
+    fn visit_trigger_instead_of(&mut self, node: &TriggerInsteadOf<N>) {
+        walk_trigger_instead_of(self, node)
+    }
+    fn visit_trigger_name(&mut self, node: &TriggerName<N>) {
+        walk_trigger_name(self, node)
+    }
+    fn visit_trigger_update_action(&mut self, node: &TriggerUpdateAction<N>) {
+        walk_trigger_update_action(self, node)
+    }
+    fn visit_trigger_update_affect_cols(&mut self, node: &TriggerUpdateAffectCols<N>) {
+        walk_trigger_update_affect_cols(self, node)
+    }
+    fn visit_trigger_when(&mut self, node: &TriggerWhen<N>) {
+        walk_trigger_when(self, node)
+    }
+    fn visit_trigger_when_expr(&mut self, node: &TriggerWhenExpr<N>) {
+        walk_trigger_when_expr(self, node)
+    }
+    fn visit_type_name(&mut self, node: &TypeName<N>) {
+        walk_type_name(self, node)
+    }
+    fn visit_type_name_word(&mut self, node: &TypeNameWord<N>) {
+        walk_type_name_word(self, node)
+    }
+    fn visit_union_compound_operator(&mut self, node: &UnionCompoundOperator<N>) {
+        walk_union_compound_operator(self, node)
+    }
+    fn visit_unique_constraint(&mut self, node: &UniqueConstraint<N>) {
+        walk_unique_constraint(self, node)
+    }
+    fn visit_update_stmt(&mut self, node: &UpdateStmt<N>) {
+        walk_update_stmt(self, node)
+    }
+    fn visit_update_stmt_limited(&mut self, node: &UpdateStmtLimited<N>) {
+        walk_update_stmt_limited(self, node)
+    }
+    fn visit_upsert_clause(&mut self, node: &UpsertClause<N>) {
+        walk_upsert_clause(self, node)
+    }
+    fn visit_upsert_clause_action(&mut self, node: &UpsertClauseAction<N>) {
+        walk_upsert_clause_action(self, node)
+    }
+    fn visit_upsert_clause_conflict_target(&mut self, node: &UpsertClauseConflictTarget<N>) {
+        walk_upsert_clause_conflict_target(self, node)
+    }
+    fn visit_upsert_do_update(&mut self, node: &UpsertDoUpdate<N>) {
+        walk_upsert_do_update(self, node)
+    }
+    fn visit_using_constraint(&mut self, node: &UsingConstraint<N>) {
+        walk_using_constraint(self, node)
+    }
+    fn visit_vacuum_stmt(&mut self, node: &VacuumStmt<N>) {
+        walk_vacuum_stmt(self, node)
+    }
+    fn visit_values_clause(&mut self, node: &ValuesClause<N>) {
+        walk_values_clause(self, node)
+    }
+    fn visit_values_select(&mut self, node: &ValuesSelect<N>) {
+        walk_values_select(self, node)
+    }
+    fn visit_view_name(&mut self, node: &ViewName<N>) {
+        walk_view_name(self, node)
+    }
+    fn visit_where_clause(&mut self, node: &WhereClause<N>) {
+        walk_where_clause(self, node)
+    }
+    fn visit_window_base_name(&mut self, node: &WindowBaseName<N>) {
+        walk_window_base_name(self, node)
+    }
+    fn visit_window_clause(&mut self, node: &WindowClause<N>) {
+        walk_window_clause(self, node)
+    }
+    fn visit_window_def(&mut self, node: &WindowDef<N>) {
+        walk_window_def(self, node)
+    }
+    fn visit_window_function(&mut self, node: &WindowFunction<N>) {
+        walk_window_function(self, node)
+    }
+    fn visit_window_name(&mut self, node: &WindowName<N>) {
+        walk_window_name(self, node)
+    }
+    fn visit_window_partition_by_clause(&mut self, node: &WindowPartitionByClause<N>) {
+        walk_window_partition_by_clause(self, node)
+    }
+    fn visit_with_alias(&mut self, node: &WithAlias<N>) {
+        walk_with_alias(self, node)
+    }
+}
+/// Visits an untyped node by casting it to its typed counterpart. Tokens are passed to
+/// [`Visitor::visit_token`] and error nodes are descended into.
+pub fn visit_untyped<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(v: &mut V, node: N) {
+    if node.token().is_some() {
+        v.visit_token(node);
+        return;
+    }
+    match node.tree() {
+        Some(SqliteTreeKind::AddColumn) => {
+            if let Some(it) = AddColumn::cast(node) {
+                v.visit_add_column(&it);
+            }
+        }
+        Some(SqliteTreeKind::AliasName) => {
+            if let Some(it) = AliasName::cast(node) {
+                v.visit_alias_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::AlterTableStmt) => {
+            if let Some(it) = AlterTableStmt::cast(node) {
+                v.visit_alter_table_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::AnalyzeStmt) => {
+            if let Some(it) = AnalyzeStmt::cast(node) {
+                v.visit_analyze_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::AnyValidName) => {
+            if let Some(it) = AnyValidName::cast(node) {
+                v.visit_any_valid_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::ArgExpr) => {
+            if let Some(it) = ArgExpr::cast(node) {
+                v.visit_arg_expr(&it);
+            }
+        }
+        Some(SqliteTreeKind::ArgStar) => {
+            if let Some(it) = ArgStar::cast(node) {
+                v.visit_arg_star(&it);
+            }
+        }
+        Some(SqliteTreeKind::AttachDbStmt) => {
+            if let Some(it) = AttachDbStmt::cast(node) {
+                v.visit_attach_db_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::BeginStmt) => {
+            if let Some(it) = BeginStmt::cast(node) {
+                v.visit_begin_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::CaseElseClause) => {
+            if let Some(it) = CaseElseClause::cast(node) {
+                v.visit_case_else_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::CaseTargetExpr) => {
+            if let Some(it) = CaseTargetExpr::cast(node) {
+                v.visit_case_target_expr(&it);
+            }
+        }
+        Some(SqliteTreeKind::CaseWhenClause) => {
+            if let Some(it) = CaseWhenClause::cast(node) {
+                v.visit_case_when_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::CaseWhenClauseList) => {
+            if let Some(it) = CaseWhenClauseList::cast(node) {
+                v.visit_case_when_clause_list(&it);
+            }
+        }
+        Some(SqliteTreeKind::CheckConstraint) => {
+            if let Some(it) = CheckConstraint::cast(node) {
+                v.visit_check_constraint(&it);
+            }
+        }
+        Some(SqliteTreeKind::ColNameList) => {
+            if let Some(it) = ColNameList::cast(node) {
+                v.visit_col_name_list(&it);
+            }
+        }
+        Some(SqliteTreeKind::Collation) => {
+            if let Some(it) = Collation::cast(node) {
+                v.visit_collation(&it);
+            }
+        }
+        Some(SqliteTreeKind::CollationName) => {
+            if let Some(it) = CollationName::cast(node) {
+                v.visit_collation_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::ColumnConstraint) => {
+            if let Some(it) = ColumnConstraint::cast(node) {
+                v.visit_column_constraint(&it);
+            }
+        }
+        Some(SqliteTreeKind::ColumnConstraintName) => {
+            if let Some(it) = ColumnConstraintName::cast(node) {
+                v.visit_column_constraint_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::ColumnDef) => {
+            if let Some(it) = ColumnDef::cast(node) {
+                v.visit_column_def(&it);
+            }
+        }
+        Some(SqliteTreeKind::ColumnGenerated) => {
+            if let Some(it) = ColumnGenerated::cast(node) {
+                v.visit_column_generated(&it);
+            }
+        }
+        Some(SqliteTreeKind::ColumnGeneratedKind) => {
+            if let Some(it) = ColumnGeneratedKind::cast(node) {
+                v.visit_column_generated_kind(&it);
+            }
+        }
+        Some(SqliteTreeKind::ColumnName) => {
+            if let Some(it) = ColumnName::cast(node) {
+                v.visit_column_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::CommitStmt) => {
+            if let Some(it) = CommitStmt::cast(node) {
+                v.visit_commit_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::CommonTableExpr) => {
+            if let Some(it) = CommonTableExpr::cast(node) {
+                v.visit_common_table_expr(&it);
+            }
+        }
+        Some(SqliteTreeKind::CompoundOperator) => {
+            if let Some(it) = CompoundOperator::cast(node) {
+                v.visit_compound_operator(&it);
+            }
+        }
+        Some(SqliteTreeKind::CompoundSelect) => {
+            if let Some(it) = CompoundSelect::cast(node) {
+                v.visit_compound_select(&it);
+            }
+        }
+        Some(SqliteTreeKind::ConflictAction) => {
+            if let Some(it) = ConflictAction::cast(node) {
+                v.visit_conflict_action(&it);
+            }
+        }
+        Some(SqliteTreeKind::ConflictClause) => {
+            if let Some(it) = ConflictClause::cast(node) {
+                v.visit_conflict_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::ConstraintName) => {
+            if let Some(it) = ConstraintName::cast(node) {
+                v.visit_constraint_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::CreateIndexStmt) => {
+            if let Some(it) = CreateIndexStmt::cast(node) {
+                v.visit_create_index_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::CreateTableSelect) => {
+            if let Some(it) = CreateTableSelect::cast(node) {
+                v.visit_create_table_select(&it);
+            }
+        }
+        Some(SqliteTreeKind::CreateTableStmt) => {
+            if let Some(it) = CreateTableStmt::cast(node) {
+                v.visit_create_table_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::CreateTriggerStmt) => {
+            if let Some(it) = CreateTriggerStmt::cast(node) {
+                v.visit_create_trigger_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::CreateViewStmt) => {
+            if let Some(it) = CreateViewStmt::cast(node) {
+                v.visit_create_view_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::CreateVirtualTableStmt) => {
+            if let Some(it) = CreateVirtualTableStmt::cast(node) {
+                v.visit_create_virtual_table_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::CteClause) => {
+            if let Some(it) = CteClause::cast(node) {
+                v.visit_cte_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::CteName) => {
+            if let Some(it) = CteName::cast(node) {
+                v.visit_cte_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::DbNameExpr) => {
+            if let Some(it) = DbNameExpr::cast(node) {
+                v.visit_db_name_expr(&it);
+            }
+        }
+        Some(SqliteTreeKind::DefaultConstraint) => {
+            if let Some(it) = DefaultConstraint::cast(node) {
+                v.visit_default_constraint(&it);
+            }
+        }
+        Some(SqliteTreeKind::DefaultConstraintExpr) => {
+            if let Some(it) = DefaultConstraintExpr::cast(node) {
+                v.visit_default_constraint_expr(&it);
+            }
+        }
+        Some(SqliteTreeKind::DefaultConstraintIden) => {
+            if let Some(it) = DefaultConstraintIden::cast(node) {
+                v.visit_default_constraint_iden(&it);
+            }
+        }
+        Some(SqliteTreeKind::DefaultConstraintLiteral) => {
+            if let Some(it) = DefaultConstraintLiteral::cast(node) {
+                v.visit_default_constraint_literal(&it);
+            }
+        }
+        Some(SqliteTreeKind::DeleteStmt) => {
+            if let Some(it) = DeleteStmt::cast(node) {
+                v.visit_delete_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::DeleteStmtLimited) => {
+            if let Some(it) = DeleteStmtLimited::cast(node) {
+                v.visit_delete_stmt_limited(&it);
+            }
+        }
+        Some(SqliteTreeKind::DetachStmt) => {
+            if let Some(it) = DetachStmt::cast(node) {
+                v.visit_detach_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::DropColumn) => {
+            if let Some(it) = DropColumn::cast(node) {
+                v.visit_drop_column(&it);
+            }
+        }
+        Some(SqliteTreeKind::DropIndexStmt) => {
+            if let Some(it) = DropIndexStmt::cast(node) {
+                v.visit_drop_index_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::DropTableStmt) => {
+            if let Some(it) = DropTableStmt::cast(node) {
+                v.visit_drop_table_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::DropTriggerStmt) => {
+            if let Some(it) = DropTriggerStmt::cast(node) {
+                v.visit_drop_trigger_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::DropViewStmt) => {
+            if let Some(it) = DropViewStmt::cast(node) {
+                v.visit_drop_view_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::EmptyableExprList) => {
+            if let Some(it) = EmptyableExprList::cast(node) {
+                v.visit_emptyable_expr_list(&it);
+            }
+        }
+        Some(SqliteTreeKind::ExplainClause) => {
+            if let Some(it) = ExplainClause::cast(node) {
+                v.visit_explain_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::Expr) => {
+            if let Some(it) = Expr::cast(node) {
+                v.visit_expr(&it);
+            }
+        }
+        Some(SqliteTreeKind::ExprBindParam) => {
+            if let Some(it) = ExprBindParam::cast(node) {
+                v.visit_expr_bind_param(&it);
+            }
+        }
+        Some(SqliteTreeKind::ExprCase) => {
+            if let Some(it) = ExprCase::cast(node) {
+                v.visit_expr_case(&it);
+            }
+        }
+        Some(SqliteTreeKind::ExprCast) => {
+            if let Some(it) = ExprCast::cast(node) {
+                v.visit_expr_cast(&it);
+            }
+        }
+        Some(SqliteTreeKind::ExprColumnName) => {
+            if let Some(it) = ExprColumnName::cast(node) {
+                v.visit_expr_column_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::ExprExistsSelect) => {
+            if let Some(it) = ExprExistsSelect::cast(node) {
+                v.visit_expr_exists_select(&it);
+            }
+        }
+        Some(SqliteTreeKind::ExprFunc) => {
+            if let Some(it) = ExprFunc::cast(node) {
+                v.visit_expr_func(&it);
+            }
+        }
+        Some(SqliteTreeKind::ExprInfix) => {
+            if let Some(it) = ExprInfix::cast(node) {
+                v.visit_expr_infix(&it);
+            }
+        }
+        Some(SqliteTreeKind::ExprList) => {
+            if let Some(it) = ExprList::cast(node) {
+                v.visit_expr_list(&it);
+            }
+        }
+        Some(SqliteTreeKind::ExprLit) => {
+            if let Some(it) = ExprLit::cast(node) {
+                v.visit_expr_lit(&it);
+            }
+        }
+        Some(SqliteTreeKind::ExprParen) => {
+            if let Some(it) = ExprParen::cast(node) {
+                v.visit_expr_paren(&it);
+            }
+        }
+        Some(SqliteTreeKind::ExprPostfix) => {
+            if let Some(it) = ExprPostfix::cast(node) {
+                v.visit_expr_postfix(&it);
+            }
+        }
+        Some(SqliteTreeKind::ExprPrefix) => {
+            if let Some(it) = ExprPrefix::cast(node) {
+                v.visit_expr_prefix(&it);
+            }
+        }
+        Some(SqliteTreeKind::ExprSelect) => {
+            if let Some(it) = ExprSelect::cast(node) {
+                v.visit_expr_select(&it);
+            }
+        }
+        Some(SqliteTreeKind::File) => {
+            if let Some(it) = File::cast(node) {
+                v.visit_file(&it);
+            }
+        }
+        Some(SqliteTreeKind::FileNameExpr) => {
+            if let Some(it) = FileNameExpr::cast(node) {
+                v.visit_file_name_expr(&it);
+            }
+        }
+        Some(SqliteTreeKind::FilterClause) => {
+            if let Some(it) = FilterClause::cast(node) {
+                v.visit_filter_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::FkCascade) => {
+            if let Some(it) = FkCascade::cast(node) {
+                v.visit_fk_cascade(&it);
+            }
+        }
+        Some(SqliteTreeKind::FkClause) => {
+            if let Some(it) = FkClause::cast(node) {
+                v.visit_fk_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::FkDeferrable) => {
+            if let Some(it) = FkDeferrable::cast(node) {
+                v.visit_fk_deferrable(&it);
+            }
+        }
+        Some(SqliteTreeKind::FkMatchAction) => {
+            if let Some(it) = FkMatchAction::cast(node) {
+                v.visit_fk_match_action(&it);
+            }
+        }
+        Some(SqliteTreeKind::FkNoAction) => {
+            if let Some(it) = FkNoAction::cast(node) {
+                v.visit_fk_no_action(&it);
+            }
+        }
+        Some(SqliteTreeKind::FkOnAction) => {
+            if let Some(it) = FkOnAction::cast(node) {
+                v.visit_fk_on_action(&it);
+            }
+        }
+        Some(SqliteTreeKind::FkRestrict) => {
+            if let Some(it) = FkRestrict::cast(node) {
+                v.visit_fk_restrict(&it);
+            }
+        }
+        Some(SqliteTreeKind::FkSetDefault) => {
+            if let Some(it) = FkSetDefault::cast(node) {
+                v.visit_fk_set_default(&it);
+            }
+        }
+        Some(SqliteTreeKind::FkSetNull) => {
+            if let Some(it) = FkSetNull::cast(node) {
+                v.visit_fk_set_null(&it);
+            }
+        }
+        Some(SqliteTreeKind::FkViolateAction) => {
+            if let Some(it) = FkViolateAction::cast(node) {
+                v.visit_fk_violate_action(&it);
+            }
+        }
+        Some(SqliteTreeKind::FrameSpec) => {
+            if let Some(it) = FrameSpec::cast(node) {
+                v.visit_frame_spec(&it);
+            }
+        }
+        Some(SqliteTreeKind::FrameSpecBetweenClause) => {
+            if let Some(it) = FrameSpecBetweenClause::cast(node) {
+                v.visit_frame_spec_between_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::FrameSpecBetweenLeft) => {
+            if let Some(it) = FrameSpecBetweenLeft::cast(node) {
+                v.visit_frame_spec_between_left(&it);
+            }
+        }
+        Some(SqliteTreeKind::FrameSpecBetweenRight) => {
+            if let Some(it) = FrameSpecBetweenRight::cast(node) {
+                v.visit_frame_spec_between_right(&it);
+            }
+        }
+        Some(SqliteTreeKind::FrameSpecCurrentRow) => {
+            if let Some(it) = FrameSpecCurrentRow::cast(node) {
+                v.visit_frame_spec_current_row(&it);
+            }
+        }
+        Some(SqliteTreeKind::FrameSpecExcludeClause) => {
+            if let Some(it) = FrameSpecExcludeClause::cast(node) {
+                v.visit_frame_spec_exclude_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::FrameSpecFollowing) => {
+            if let Some(it) = FrameSpecFollowing::cast(node) {
+                v.visit_frame_spec_following(&it);
+            }
+        }
+        Some(SqliteTreeKind::FrameSpecNoOthers) => {
+            if let Some(it) = FrameSpecNoOthers::cast(node) {
+                v.visit_frame_spec_no_others(&it);
+            }
+        }
+        Some(SqliteTreeKind::FrameSpecPreceding) => {
+            if let Some(it) = FrameSpecPreceding::cast(node) {
+                v.visit_frame_spec_preceding(&it);
+            }
+        }
+        Some(SqliteTreeKind::FrameSpecUnboundedFollowing) => {
+            if let Some(it) = FrameSpecUnboundedFollowing::cast(node) {
+                v.visit_frame_spec_unbounded_following(&it);
+            }
+        }
+        Some(SqliteTreeKind::FrameSpecUnboundedPreceding) => {
+            if let Some(it) = FrameSpecUnboundedPreceding::cast(node) {
+                v.visit_frame_spec_unbounded_preceding(&it);
+            }
+        }
+        Some(SqliteTreeKind::FromClause) => {
+            if let Some(it) = FromClause::cast(node) {
+                v.visit_from_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::FromClauseTableValueFunction) => {
+            if let Some(it) = FromClauseTableValueFunction::cast(node) {
+                v.visit_from_clause_table_value_function(&it);
+            }
+        }
+        Some(SqliteTreeKind::FullIndexName) => {
+            if let Some(it) = FullIndexName::cast(node) {
+                v.visit_full_index_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::FullPragmaName) => {
+            if let Some(it) = FullPragmaName::cast(node) {
+                v.visit_full_pragma_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::FullTableFunctionName) => {
+            if let Some(it) = FullTableFunctionName::cast(node) {
+                v.visit_full_table_function_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::FullTableName) => {
+            if let Some(it) = FullTableName::cast(node) {
+                v.visit_full_table_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::FullTriggerName) => {
+            if let Some(it) = FullTriggerName::cast(node) {
+                v.visit_full_trigger_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::FullViewName) => {
+            if let Some(it) = FullViewName::cast(node) {
+                v.visit_full_view_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::FuncArguments) => {
+            if let Some(it) = FuncArguments::cast(node) {
+                v.visit_func_arguments(&it);
+            }
+        }
+        Some(SqliteTreeKind::FunctionName) => {
+            if let Some(it) = FunctionName::cast(node) {
+                v.visit_function_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::GroupByClause) => {
+            if let Some(it) = GroupByClause::cast(node) {
+                v.visit_group_by_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::HavingClause) => {
+            if let Some(it) = HavingClause::cast(node) {
+                v.visit_having_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::IfNotExists) => {
+            if let Some(it) = IfNotExists::cast(node) {
+                v.visit_if_not_exists(&it);
+            }
+        }
+        Some(SqliteTreeKind::InTable) => {
+            if let Some(it) = InTable::cast(node) {
+                v.visit_in_table(&it);
+            }
+        }
+        Some(SqliteTreeKind::InTableFunc) => {
+            if let Some(it) = InTableFunc::cast(node) {
+                v.visit_in_table_func(&it);
+            }
+        }
+        Some(SqliteTreeKind::IndexName) => {
+            if let Some(it) = IndexName::cast(node) {
+                v.visit_index_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::IndexedCol) => {
+            if let Some(it) = IndexedCol::cast(node) {
+                v.visit_indexed_col(&it);
+            }
+        }
+        Some(SqliteTreeKind::IndexedColList) => {
+            if let Some(it) = IndexedColList::cast(node) {
+                v.visit_indexed_col_list(&it);
+            }
+        }
+        Some(SqliteTreeKind::InsertDefaultValuesClause) => {
+            if let Some(it) = InsertDefaultValuesClause::cast(node) {
+                v.visit_insert_default_values_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::InsertOrAction) => {
+            if let Some(it) = InsertOrAction::cast(node) {
+                v.visit_insert_or_action(&it);
+            }
+        }
+        Some(SqliteTreeKind::InsertSelectClause) => {
+            if let Some(it) = InsertSelectClause::cast(node) {
+                v.visit_insert_select_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::InsertStmt) => {
+            if let Some(it) = InsertStmt::cast(node) {
+                v.visit_insert_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::InsertStmtKind) => {
+            if let Some(it) = InsertStmtKind::cast(node) {
+                v.visit_insert_stmt_kind(&it);
+            }
+        }
+        Some(SqliteTreeKind::InsertValueKind) => {
+            if let Some(it) = InsertValueKind::cast(node) {
+                v.visit_insert_value_kind(&it);
+            }
+        }
+        Some(SqliteTreeKind::InsertValuesClause) => {
+            if let Some(it) = InsertValuesClause::cast(node) {
+                v.visit_insert_values_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::JoinClause) => {
+            if let Some(it) = JoinClause::cast(node) {
+                v.visit_join_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::JoinConstraint) => {
+            if let Some(it) = JoinConstraint::cast(node) {
+                v.visit_join_constraint(&it);
+            }
+        }
+        Some(SqliteTreeKind::JoinOperator) => {
+            if let Some(it) = JoinOperator::cast(node) {
+                v.visit_join_operator(&it);
+            }
+        }
+        Some(SqliteTreeKind::LimitClause) => {
+            if let Some(it) = LimitClause::cast(node) {
+                v.visit_limit_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::MaterializedCte) => {
+            if let Some(it) = MaterializedCte::cast(node) {
+                v.visit_materialized_cte(&it);
+            }
+        }
+        Some(SqliteTreeKind::ModuleArg) => {
+            if let Some(it) = ModuleArg::cast(node) {
+                v.visit_module_arg(&it);
+            }
+        }
+        Some(SqliteTreeKind::ModuleArgList) => {
+            if let Some(it) = ModuleArgList::cast(node) {
+                v.visit_module_arg_list(&it);
+            }
+        }
+        Some(SqliteTreeKind::ModuleName) => {
+            if let Some(it) = ModuleName::cast(node) {
+                v.visit_module_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::NewColumnName) => {
+            if let Some(it) = NewColumnName::cast(node) {
+                v.visit_new_column_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::NewTableName) => {
+            if let Some(it) = NewTableName::cast(node) {
+                v.visit_new_table_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::NullConstraint) => {
+            if let Some(it) = NullConstraint::cast(node) {
+                v.visit_null_constraint(&it);
+            }
+        }
+        Some(SqliteTreeKind::Offset) => {
+            if let Some(it) = Offset::cast(node) {
+                v.visit_offset(&it);
+            }
+        }
+        Some(SqliteTreeKind::OnConstraint) => {
+            if let Some(it) = OnConstraint::cast(node) {
+                v.visit_on_constraint(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpAdd) => {
+            if let Some(it) = OpAdd::cast(node) {
+                v.visit_op_add(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpAnd) => {
+            if let Some(it) = OpAnd::cast(node) {
+                v.visit_op_and(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpBetweenAnd) => {
+            if let Some(it) = OpBetweenAnd::cast(node) {
+                v.visit_op_between_and(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpBinAnd) => {
+            if let Some(it) = OpBinAnd::cast(node) {
+                v.visit_op_bin_and(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpBinComplement) => {
+            if let Some(it) = OpBinComplement::cast(node) {
+                v.visit_op_bin_complement(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpBinLShift) => {
+            if let Some(it) = OpBinLShift::cast(node) {
+                v.visit_op_bin_l_shift(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpBinOr) => {
+            if let Some(it) = OpBinOr::cast(node) {
+                v.visit_op_bin_or(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpBinRShift) => {
+            if let Some(it) = OpBinRShift::cast(node) {
+                v.visit_op_bin_r_shift(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpCollate) => {
+            if let Some(it) = OpCollate::cast(node) {
+                v.visit_op_collate(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpConcat) => {
+            if let Some(it) = OpConcat::cast(node) {
+                v.visit_op_concat(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpDivide) => {
+            if let Some(it) = OpDivide::cast(node) {
+                v.visit_op_divide(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpEq) => {
+            if let Some(it) = OpEq::cast(node) {
+                v.visit_op_eq(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpEscape) => {
+            if let Some(it) = OpEscape::cast(node) {
+                v.visit_op_escape(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpExtractOne) => {
+            if let Some(it) = OpExtractOne::cast(node) {
+                v.visit_op_extract_one(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpExtractTwo) => {
+            if let Some(it) = OpExtractTwo::cast(node) {
+                v.visit_op_extract_two(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpGT) => {
+            if let Some(it) = OpGT::cast(node) {
+                v.visit_op_gt(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpGTE) => {
+            if let Some(it) = OpGTE::cast(node) {
+                v.visit_op_gte(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpGlob) => {
+            if let Some(it) = OpGlob::cast(node) {
+                v.visit_op_glob(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpIn) => {
+            if let Some(it) = OpIn::cast(node) {
+                v.visit_op_in(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpIs) => {
+            if let Some(it) = OpIs::cast(node) {
+                v.visit_op_is(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpIsDistinctFrom) => {
+            if let Some(it) = OpIsDistinctFrom::cast(node) {
+                v.visit_op_is_distinct_from(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpIsNot) => {
+            if let Some(it) = OpIsNot::cast(node) {
+                v.visit_op_is_not(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpIsNotDistinctFrom) => {
+            if let Some(it) = OpIsNotDistinctFrom::cast(node) {
+                v.visit_op_is_not_distinct_from(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpIsNull) => {
+            if let Some(it) = OpIsNull::cast(node) {
+                v.visit_op_is_null(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpLT) => {
+            if let Some(it) = OpLT::cast(node) {
+                v.visit_op_lt(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpLTE) => {
+            if let Some(it) = OpLTE::cast(node) {
+                v.visit_op_lte(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpLike) => {
+            if let Some(it) = OpLike::cast(node) {
+                v.visit_op_like(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpMatch) => {
+            if let Some(it) = OpMatch::cast(node) {
+                v.visit_op_match(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpModulus) => {
+            if let Some(it) = OpModulus::cast(node) {
+                v.visit_op_modulus(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpMultiply) => {
+            if let Some(it) = OpMultiply::cast(node) {
+                v.visit_op_multiply(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpNot) => {
+            if let Some(it) = OpNot::cast(node) {
+                v.visit_op_not(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpNotBetweenAnd) => {
+            if let Some(it) = OpNotBetweenAnd::cast(node) {
+                v.visit_op_not_between_and(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpNotEq) => {
+            if let Some(it) = OpNotEq::cast(node) {
+                v.visit_op_not_eq(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpNotGlob) => {
+            if let Some(it) = OpNotGlob::cast(node) {
+                v.visit_op_not_glob(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpNotIn) => {
+            if let Some(it) = OpNotIn::cast(node) {
+                v.visit_op_not_in(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpNotLike) => {
+            if let Some(it) = OpNotLike::cast(node) {
+                v.visit_op_not_like(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpNotMatch) => {
+            if let Some(it) = OpNotMatch::cast(node) {
+                v.visit_op_not_match(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpNotNull) => {
+            if let Some(it) = OpNotNull::cast(node) {
+                v.visit_op_not_null(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpNotRegexp) => {
+            if let Some(it) = OpNotRegexp::cast(node) {
+                v.visit_op_not_regexp(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpNotSpaceNull) => {
+            if let Some(it) = OpNotSpaceNull::cast(node) {
+                v.visit_op_not_space_null(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpOr) => {
+            if let Some(it) = OpOr::cast(node) {
+                v.visit_op_or(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpRegexp) => {
+            if let Some(it) = OpRegexp::cast(node) {
+                v.visit_op_regexp(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpSubtract) => {
+            if let Some(it) = OpSubtract::cast(node) {
+                v.visit_op_subtract(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpUnaryMinus) => {
+            if let Some(it) = OpUnaryMinus::cast(node) {
+                v.visit_op_unary_minus(&it);
+            }
+        }
+        Some(SqliteTreeKind::OpUnaryPlus) => {
+            if let Some(it) = OpUnaryPlus::cast(node) {
+                v.visit_op_unary_plus(&it);
+            }
+        }
+        Some(SqliteTreeKind::Order) => {
+            if let Some(it) = Order::cast(node) {
+                v.visit_order(&it);
+            }
+        }
+        Some(SqliteTreeKind::OrderByClause) => {
+            if let Some(it) = OrderByClause::cast(node) {
+                v.visit_order_by_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::OrderingTerm) => {
+            if let Some(it) = OrderingTerm::cast(node) {
+                v.visit_ordering_term(&it);
+            }
+        }
+        Some(SqliteTreeKind::OrderingTermList) => {
+            if let Some(it) = OrderingTermList::cast(node) {
+                v.visit_ordering_term_list(&it);
+            }
+        }
+        Some(SqliteTreeKind::OverClause) => {
+            if let Some(it) = OverClause::cast(node) {
+                v.visit_over_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::PasswordExpr) => {
+            if let Some(it) = PasswordExpr::cast(node) {
+                v.visit_password_expr(&it);
+            }
+        }
+        Some(SqliteTreeKind::PragmaName) => {
+            if let Some(it) = PragmaName::cast(node) {
+                v.visit_pragma_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::PragmaStmt) => {
+            if let Some(it) = PragmaStmt::cast(node) {
+                v.visit_pragma_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::PragmaValue) => {
+            if let Some(it) = PragmaValue::cast(node) {
+                v.visit_pragma_value(&it);
+            }
+        }
+        Some(SqliteTreeKind::PragmaValueName) => {
+            if let Some(it) = PragmaValueName::cast(node) {
+                v.visit_pragma_value_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::PrimaryConstraint) => {
+            if let Some(it) = PrimaryConstraint::cast(node) {
+                v.visit_primary_constraint(&it);
+            }
+        }
+        Some(SqliteTreeKind::QualifiedTableName) => {
+            if let Some(it) = QualifiedTableName::cast(node) {
+                v.visit_qualified_table_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::RaiseAction) => {
+            if let Some(it) = RaiseAction::cast(node) {
+                v.visit_raise_action(&it);
+            }
+        }
+        Some(SqliteTreeKind::RaiseActionAbort) => {
+            if let Some(it) = RaiseActionAbort::cast(node) {
+                v.visit_raise_action_abort(&it);
+            }
+        }
+        Some(SqliteTreeKind::RaiseActionFail) => {
+            if let Some(it) = RaiseActionFail::cast(node) {
+                v.visit_raise_action_fail(&it);
+            }
+        }
+        Some(SqliteTreeKind::RaiseActionRollBack) => {
+            if let Some(it) = RaiseActionRollBack::cast(node) {
+                v.visit_raise_action_roll_back(&it);
+            }
+        }
+        Some(SqliteTreeKind::RaiseFunc) => {
+            if let Some(it) = RaiseFunc::cast(node) {
+                v.visit_raise_func(&it);
+            }
+        }
+        Some(SqliteTreeKind::RaiseFuncErrMessage) => {
+            if let Some(it) = RaiseFuncErrMessage::cast(node) {
+                v.visit_raise_func_err_message(&it);
+            }
+        }
+        Some(SqliteTreeKind::ReIndexStmt) => {
+            if let Some(it) = ReIndexStmt::cast(node) {
+                v.visit_re_index_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::ReleaseStmt) => {
+            if let Some(it) = ReleaseStmt::cast(node) {
+                v.visit_release_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::RenameColumn) => {
+            if let Some(it) = RenameColumn::cast(node) {
+                v.visit_rename_column(&it);
+            }
+        }
+        Some(SqliteTreeKind::RenameTable) => {
+            if let Some(it) = RenameTable::cast(node) {
+                v.visit_rename_table(&it);
+            }
+        }
+        Some(SqliteTreeKind::ResultColumn) => {
+            if let Some(it) = ResultColumn::cast(node) {
+                v.visit_result_column(&it);
+            }
+        }
+        Some(SqliteTreeKind::ResultColumnAll) => {
+            if let Some(it) = ResultColumnAll::cast(node) {
+                v.visit_result_column_all(&it);
+            }
+        }
+        Some(SqliteTreeKind::ResultColumnExpr) => {
+            if let Some(it) = ResultColumnExpr::cast(node) {
+                v.visit_result_column_expr(&it);
+            }
+        }
+        Some(SqliteTreeKind::ResultColumnList) => {
+            if let Some(it) = ResultColumnList::cast(node) {
+                v.visit_result_column_list(&it);
+            }
+        }
+        Some(SqliteTreeKind::ResultColumnTableAll) => {
+            if let Some(it) = ResultColumnTableAll::cast(node) {
+                v.visit_result_column_table_all(&it);
+            }
+        }
+        Some(SqliteTreeKind::ReturningClause) => {
+            if let Some(it) = ReturningClause::cast(node) {
+                v.visit_returning_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::ReturningClauseExpr) => {
+            if let Some(it) = ReturningClauseExpr::cast(node) {
+                v.visit_returning_clause_expr(&it);
+            }
+        }
+        Some(SqliteTreeKind::ReturningClauseKind) => {
+            if let Some(it) = ReturningClauseKind::cast(node) {
+                v.visit_returning_clause_kind(&it);
+            }
+        }
+        Some(SqliteTreeKind::RollbackStmt) => {
+            if let Some(it) = RollbackStmt::cast(node) {
+                v.visit_rollback_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::SavepointName) => {
+            if let Some(it) = SavepointName::cast(node) {
+                v.visit_savepoint_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::SavepointStmt) => {
+            if let Some(it) = SavepointStmt::cast(node) {
+                v.visit_savepoint_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::SchemaName) => {
+            if let Some(it) = SchemaName::cast(node) {
+                v.visit_schema_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::SchemaNameExpr) => {
+            if let Some(it) = SchemaNameExpr::cast(node) {
+                v.visit_schema_name_expr(&it);
+            }
+        }
+        Some(SqliteTreeKind::SchemaOrIdxOrTableName) => {
+            if let Some(it) = SchemaOrIdxOrTableName::cast(node) {
+                v.visit_schema_or_idx_or_table_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::SelectCore) => {
+            if let Some(it) = SelectCore::cast(node) {
+                v.visit_select_core(&it);
+            }
+        }
+        Some(SqliteTreeKind::SelectStmt) => {
+            if let Some(it) = SelectStmt::cast(node) {
+                v.visit_select_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::SelectStmtWithCte) => {
+            if let Some(it) = SelectStmtWithCte::cast(node) {
+                v.visit_select_stmt_with_cte(&it);
+            }
+        }
+        Some(SqliteTreeKind::SetColumnExpr) => {
+            if let Some(it) = SetColumnExpr::cast(node) {
+                v.visit_set_column_expr(&it);
+            }
+        }
+        Some(SqliteTreeKind::SignedNumber) => {
+            if let Some(it) = SignedNumber::cast(node) {
+                v.visit_signed_number(&it);
+            }
+        }
+        Some(SqliteTreeKind::Statement) => {
+            if let Some(it) = Statement::cast(node) {
+                v.visit_statement(&it);
+            }
+        }
+        Some(SqliteTreeKind::StatementNoCte) => {
+            if let Some(it) = StatementNoCte::cast(node) {
+                v.visit_statement_no_cte(&it);
+            }
+        }
+        Some(SqliteTreeKind::StatementWithCte) => {
+            if let Some(it) = StatementWithCte::cast(node) {
+                v.visit_statement_with_cte(&it);
+            }
+        }
+        Some(SqliteTreeKind::TableConstraint) => {
+            if let Some(it) = TableConstraint::cast(node) {
+                v.visit_table_constraint(&it);
+            }
+        }
+        Some(SqliteTreeKind::TableDetails) => {
+            if let Some(it) = TableDetails::cast(node) {
+                v.visit_table_details(&it);
+            }
+        }
+        Some(SqliteTreeKind::TableFkConstraint) => {
+            if let Some(it) = TableFkConstraint::cast(node) {
+                v.visit_table_fk_constraint(&it);
+            }
+        }
+        Some(SqliteTreeKind::TableFunctionName) => {
+            if let Some(it) = TableFunctionName::cast(node) {
+                v.visit_table_function_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::TableName) => {
+            if let Some(it) = TableName::cast(node) {
+                v.visit_table_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::TableNameIndexedBy) => {
+            if let Some(it) = TableNameIndexedBy::cast(node) {
+                v.visit_table_name_indexed_by(&it);
+            }
+        }
+        Some(SqliteTreeKind::TableNameNotIndexed) => {
+            if let Some(it) = TableNameNotIndexed::cast(node) {
+                v.visit_table_name_not_indexed(&it);
+            }
+        }
+        Some(SqliteTreeKind::TableOptWithoutRowId) => {
+            if let Some(it) = TableOptWithoutRowId::cast(node) {
+                v.visit_table_opt_without_row_id(&it);
+            }
+        }
+        Some(SqliteTreeKind::TableOptions) => {
+            if let Some(it) = TableOptions::cast(node) {
+                v.visit_table_options(&it);
+            }
+        }
+        Some(SqliteTreeKind::TableOptionsList) => {
+            if let Some(it) = TableOptionsList::cast(node) {
+                v.visit_table_options_list(&it);
+            }
+        }
+        Some(SqliteTreeKind::TableOrIdxNameWithSchema) => {
+            if let Some(it) = TableOrIdxNameWithSchema::cast(node) {
+                v.visit_table_or_idx_name_with_schema(&it);
+            }
+        }
+        Some(SqliteTreeKind::TableOrIdxOrCollationName) => {
+            if let Some(it) = TableOrIdxOrCollationName::cast(node) {
+                v.visit_table_or_idx_or_collation_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::TableOrIndexName) => {
+            if let Some(it) = TableOrIndexName::cast(node) {
+                v.visit_table_or_index_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::TableOrSubquery) => {
+            if let Some(it) = TableOrSubquery::cast(node) {
+                v.visit_table_or_subquery(&it);
+            }
+        }
+        Some(SqliteTreeKind::TablePkConstraint) => {
+            if let Some(it) = TablePkConstraint::cast(node) {
+                v.visit_table_pk_constraint(&it);
+            }
+        }
+        Some(SqliteTreeKind::TableUqConstraint) => {
+            if let Some(it) = TableUqConstraint::cast(node) {
+                v.visit_table_uq_constraint(&it);
+            }
+        }
+        Some(SqliteTreeKind::TraditionalSelect) => {
+            if let Some(it) = TraditionalSelect::cast(node) {
+                v.visit_traditional_select(&it);
+            }
+        }
+        Some(SqliteTreeKind::TriggerActionKind) => {
+            if let Some(it) = TriggerActionKind::cast(node) {
+                v.visit_trigger_action_kind(&it);
+            }
+        }
+        Some(SqliteTreeKind::TriggerBodyStmt) => {
+            if let Some(it) = TriggerBodyStmt::cast(node) {
+                v.visit_trigger_body_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::TriggerBodyStmtList) => {
+            if let Some(it) = TriggerBodyStmtList::cast(node) {
+                v.visit_trigger_body_stmt_list(&it);
+            }
+        }
+        Some(SqliteTreeKind::TriggerForEachRow) => {
+            if let Some(it) = TriggerForEachRow::cast(node) {
+                v.visit_trigger_for_each_row(&it);
+            }
+        }
+        Some(SqliteTreeKind::TriggerInsteadOf) => {
+            if let Some(it) = TriggerInsteadOf::cast(node) {
+                v.visit_trigger_instead_of(&it);
+            }
+        }
+        Some(SqliteTreeKind::TriggerName) => {
+            if let Some(it) = TriggerName::cast(node) {
+                v.visit_trigger_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::TriggerUpdateAction) => {
+            if let Some(it) = TriggerUpdateAction::cast(node) {
+                v.visit_trigger_update_action(&it);
+            }
+        }
+        Some(SqliteTreeKind::TriggerUpdateAffectCols) => {
+            if let Some(it) = TriggerUpdateAffectCols::cast(node) {
+                v.visit_trigger_update_affect_cols(&it);
+            }
+        }
+        Some(SqliteTreeKind::TriggerWhenExpr) => {
+            if let Some(it) = TriggerWhenExpr::cast(node) {
+                v.visit_trigger_when_expr(&it);
+            }
+        }
+        Some(SqliteTreeKind::TypeName) => {
+            if let Some(it) = TypeName::cast(node) {
+                v.visit_type_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::TypeNameWord) => {
+            if let Some(it) = TypeNameWord::cast(node) {
+                v.visit_type_name_word(&it);
+            }
+        }
+        Some(SqliteTreeKind::UnionCompoundOperator) => {
+            if let Some(it) = UnionCompoundOperator::cast(node) {
+                v.visit_union_compound_operator(&it);
+            }
+        }
+        Some(SqliteTreeKind::UniqueConstraint) => {
+            if let Some(it) = UniqueConstraint::cast(node) {
+                v.visit_unique_constraint(&it);
+            }
+        }
+        Some(SqliteTreeKind::UpdateStmt) => {
+            if let Some(it) = UpdateStmt::cast(node) {
+                v.visit_update_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::UpdateStmtLimited) => {
+            if let Some(it) = UpdateStmtLimited::cast(node) {
+                v.visit_update_stmt_limited(&it);
+            }
+        }
+        Some(SqliteTreeKind::UpsertClause) => {
+            if let Some(it) = UpsertClause::cast(node) {
+                v.visit_upsert_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::UpsertClauseConflictTarget) => {
+            if let Some(it) = UpsertClauseConflictTarget::cast(node) {
+                v.visit_upsert_clause_conflict_target(&it);
+            }
+        }
+        Some(SqliteTreeKind::UpsertDoUpdate) => {
+            if let Some(it) = UpsertDoUpdate::cast(node) {
+                v.visit_upsert_do_update(&it);
+            }
+        }
+        Some(SqliteTreeKind::UsingConstraint) => {
+            if let Some(it) = UsingConstraint::cast(node) {
+                v.visit_using_constraint(&it);
+            }
+        }
+        Some(SqliteTreeKind::VacuumStmt) => {
+            if let Some(it) = VacuumStmt::cast(node) {
+                v.visit_vacuum_stmt(&it);
+            }
+        }
+        Some(SqliteTreeKind::ValuesClause) => {
+            if let Some(it) = ValuesClause::cast(node) {
+                v.visit_values_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::ValuesSelect) => {
+            if let Some(it) = ValuesSelect::cast(node) {
+                v.visit_values_select(&it);
+            }
+        }
+        Some(SqliteTreeKind::ViewName) => {
+            if let Some(it) = ViewName::cast(node) {
+                v.visit_view_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::WhereClause) => {
+            if let Some(it) = WhereClause::cast(node) {
+                v.visit_where_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::WindowBaseName) => {
+            if let Some(it) = WindowBaseName::cast(node) {
+                v.visit_window_base_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::WindowClause) => {
+            if let Some(it) = WindowClause::cast(node) {
+                v.visit_window_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::WindowDef) => {
+            if let Some(it) = WindowDef::cast(node) {
+                v.visit_window_def(&it);
+            }
+        }
+        Some(SqliteTreeKind::WindowFunction) => {
+            if let Some(it) = WindowFunction::cast(node) {
+                v.visit_window_function(&it);
+            }
+        }
+        Some(SqliteTreeKind::WindowName) => {
+            if let Some(it) = WindowName::cast(node) {
+                v.visit_window_name(&it);
+            }
+        }
+        Some(SqliteTreeKind::WindowPartitionByClause) => {
+            if let Some(it) = WindowPartitionByClause::cast(node) {
+                v.visit_window_partition_by_clause(&it);
+            }
+        }
+        Some(SqliteTreeKind::WithAlias) => {
+            if let Some(it) = WithAlias::cast(node) {
+                v.visit_with_alias(&it);
+            }
+        }
+        _ => {
+            for child in node.valid_children() {
+                visit_untyped(v, child);
+            }
+        }
+    }
+}
+pub fn walk_add_column<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &AddColumn<N>,
+) {
+    if let Some(it) = node.column_def() {
+        v.visit_column_def(&it);
+    }
+}
+pub fn walk_alias_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &AliasName<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_alter_table_kind<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &AlterTableKind<N>,
+) {
+    match node {
+        AlterTableKind::RenameTable(it) => v.visit_rename_table(it),
+        AlterTableKind::RenameColumn(it) => v.visit_rename_column(it),
+        AlterTableKind::AddColumn(it) => v.visit_add_column(it),
+        AlterTableKind::DropColumn(it) => v.visit_drop_column(it),
+    }
+}
+pub fn walk_alter_table_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &AlterTableStmt<N>,
+) {
+    if let Some(it) = node.full_table_name() {
+        v.visit_full_table_name(&it);
+    }
+    if let Some(it) = node.alter_table_kind() {
+        v.visit_alter_table_kind(&it);
+    }
+}
+pub fn walk_analyze_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &AnalyzeStmt<N>,
+) {
+    if let Some(it) = node.analyze_target() {
+        v.visit_analyze_target(&it);
+    }
+}
+pub fn walk_analyze_target<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &AnalyzeTarget<N>,
+) {
+    match node {
+        AnalyzeTarget::SchemaOrIdxOrTableName(it) => v.visit_schema_or_idx_or_table_name(it),
+        AnalyzeTarget::TableOrIdxNameWithSchema(it) => v.visit_table_or_idx_name_with_schema(it),
+    }
+}
+pub fn walk_any_valid_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &AnyValidName<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_arg_expr<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ArgExpr<N>,
+) {
+    if let Some(it) = node.distinct() {
+        v.visit_token(it);
+    }
+    for it in node.items() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.order_by_clause() {
+        v.visit_order_by_clause(&it);
+    }
+}
+pub fn walk_arg_star<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ArgStar<N>,
+) {
+}
+pub fn walk_attach_db_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &AttachDbStmt<N>,
+) {
+    if let Some(it) = node.file_name_expr() {
+        v.visit_file_name_expr(&it);
+    }
+    if let Some(it) = node.schema_name_expr() {
+        v.visit_schema_name_expr(&it);
+    }
+    if let Some(it) = node.password_expr() {
+        v.visit_password_expr(&it);
+    }
+}
+pub fn walk_begin_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &BeginStmt<N>,
+) {
+    if let Some(it) = node.begin_stmt_kind() {
+        v.visit_begin_stmt_kind(&it);
+    }
+}
+pub fn walk_begin_stmt_kind<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &BeginStmtKind<N>,
+) {
+    match node {
+        BeginStmtKind::KW_DEFERRED(it) => v.visit_token(*it),
+        BeginStmtKind::KW_IMMEDIATE(it) => v.visit_token(*it),
+        BeginStmtKind::KW_EXCLUSIVE(it) => v.visit_token(*it),
+    }
+}
+pub fn walk_case_else_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &CaseElseClause<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_case_target_expr<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &CaseTargetExpr<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_case_when_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &CaseWhenClause<N>,
+) {
+    if let Some(it) = node.when_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.then_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_case_when_clause_list<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &CaseWhenClauseList<N>,
+) {
+    for it in node.items() {
+        v.visit_case_when_clause(&it);
+    }
+}
+pub fn walk_check_constraint<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &CheckConstraint<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_col_name_list<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ColNameList<N>,
+) {
+    for it in node.items() {
+        v.visit_column_name(&it);
+    }
+}
+pub fn walk_collation<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &Collation<N>,
+) {
+    if let Some(it) = node.collation_name() {
+        v.visit_collation_name(&it);
+    }
+}
+pub fn walk_collation_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &CollationName<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_column_constraint<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ColumnConstraint<N>,
+) {
+    if let Some(it) = node.column_constraint_name() {
+        v.visit_column_constraint_name(&it);
+    }
+    if let Some(it) = node.constraint_type() {
+        v.visit_constraint_type(&it);
+    }
+}
+pub fn walk_column_constraint_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ColumnConstraintName<N>,
+) {
+    if let Some(it) = node.constraint_name() {
+        v.visit_constraint_name(&it);
+    }
+}
+pub fn walk_column_def<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ColumnDef<N>,
+) {
+    if let Some(it) = node.column_name() {
+        v.visit_column_name(&it);
+    }
+    if let Some(it) = node.type_name() {
+        v.visit_type_name(&it);
+    }
+    for it in node.constraints() {
+        v.visit_column_constraint(&it);
+    }
+}
+pub fn walk_column_generated<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ColumnGenerated<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.column_generated_kind() {
+        v.visit_column_generated_kind(&it);
+    }
+}
+pub fn walk_column_generated_kind<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ColumnGeneratedKind<N>,
+) {
+    match node {
+        ColumnGeneratedKind::KW_STORED(it) => v.visit_token(*it),
+        ColumnGeneratedKind::KW_VIRTUAL(it) => v.visit_token(*it),
+    }
+}
+pub fn walk_column_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ColumnName<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_commit_start_kw<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &CommitStartKw<N>,
+) {
+    match node {
+        CommitStartKw::KW_COMMIT(it) => v.visit_token(*it),
+        CommitStartKw::KW_END(it) => v.visit_token(*it),
+    }
+}
+pub fn walk_commit_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &CommitStmt<N>,
+) {
+    if let Some(it) = node.commit_start_kw() {
+        v.visit_commit_start_kw(&it);
+    }
+}
+pub fn walk_common_table_expr<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &CommonTableExpr<N>,
+) {
+    if let Some(it) = node.cte_name() {
+        v.visit_cte_name(&it);
+    }
+    if let Some(it) = node.col_name_list() {
+        v.visit_col_name_list(&it);
+    }
+    if let Some(it) = node.materialized_cte() {
+        v.visit_materialized_cte(&it);
+    }
+    if let Some(it) = node.select_stmt_with_cte() {
+        v.visit_select_stmt_with_cte(&it);
+    }
+}
+pub fn walk_compound_operator<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &CompoundOperator<N>,
+) {
+    match node {
+        CompoundOperator::UnionCompoundOperator(it) => v.visit_union_compound_operator(it),
+        CompoundOperator::KW_INTERSECT(it) => v.visit_token(*it),
+        CompoundOperator::KW_EXCEPT(it) => v.visit_token(*it),
+    }
+}
+pub fn walk_compound_select<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &CompoundSelect<N>,
+) {
+    if let Some(it) = node.compound_operator() {
+        v.visit_compound_operator(&it);
+    }
+    if let Some(it) = node.select_core() {
+        v.visit_select_core(&it);
+    }
+}
+pub fn walk_conflict_action<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ConflictAction<N>,
+) {
+    match node {
+        ConflictAction::KW_ROLLBACK(it) => v.visit_token(*it),
+        ConflictAction::KW_ABORT(it) => v.visit_token(*it),
+        ConflictAction::KW_FAIL(it) => v.visit_token(*it),
+        ConflictAction::KW_IGNORE(it) => v.visit_token(*it),
+        ConflictAction::KW_REPLACE(it) => v.visit_token(*it),
+    }
+}
+pub fn walk_conflict_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ConflictClause<N>,
+) {
+    if let Some(it) = node.conflict_action() {
+        v.visit_conflict_action(&it);
+    }
+}
+pub fn walk_constraint_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ConstraintName<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_constraint_type<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ConstraintType<N>,
+) {
+    match node {
+        ConstraintType::PrimaryConstraint(it) => v.visit_primary_constraint(it),
+        ConstraintType::NullConstraint(it) => v.visit_null_constraint(it),
+        ConstraintType::UniqueConstraint(it) => v.visit_unique_constraint(it),
+        ConstraintType::CheckConstraint(it) => v.visit_check_constraint(it),
+        ConstraintType::DefaultConstraint(it) => v.visit_default_constraint(it),
+        ConstraintType::Collation(it) => v.visit_collation(it),
+        ConstraintType::ColumnGenerated(it) => v.visit_column_generated(it),
+        ConstraintType::FkClause(it) => v.visit_fk_clause(it),
+    }
+}
+pub fn walk_create_index_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &CreateIndexStmt<N>,
+) {
+    if let Some(it) = node.unique() {
+        v.visit_token(it);
+    }
+    if let Some(it) = node.if_not_exists() {
+        v.visit_if_not_exists(&it);
+    }
+    if let Some(it) = node.full_index_name() {
+        v.visit_full_index_name(&it);
+    }
+    if let Some(it) = node.table_name() {
+        v.visit_table_name(&it);
+    }
+    if let Some(it) = node.indexed_col_list() {
+        v.visit_indexed_col_list(&it);
+    }
+    if let Some(it) = node.where_clause() {
+        v.visit_where_clause(&it);
+    }
+}
+pub fn walk_create_table_select<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &CreateTableSelect<N>,
+) {
+    if let Some(it) = node.select_stmt_with_cte() {
+        v.visit_select_stmt_with_cte(&it);
+    }
+}
+pub fn walk_create_table_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &CreateTableStmt<N>,
+) {
+    if let Some(it) = node.temporary() {
+        v.visit_temporary(&it);
+    }
+    if let Some(it) = node.if_not_exists() {
+        v.visit_if_not_exists(&it);
+    }
+    if let Some(it) = node.full_table_name() {
+        v.visit_full_table_name(&it);
+    }
+    if let Some(it) = node.table_columns() {
+        v.visit_table_columns(&it);
+    }
+}
+pub fn walk_create_trigger_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &CreateTriggerStmt<N>,
+) {
+    if let Some(it) = node.temporary() {
+        v.visit_temporary(&it);
+    }
+    if let Some(it) = node.if_not_exists() {
+        v.visit_if_not_exists(&it);
+    }
+    if let Some(it) = node.full_trigger_name() {
+        v.visit_full_trigger_name(&it);
+    }
+    if let Some(it) = node.trigger_when() {
+        v.visit_trigger_when(&it);
+    }
+    if let Some(it) = node.trigger_action_kind() {
+        v.visit_trigger_action_kind(&it);
+    }
+    if let Some(it) = node.full_table_name() {
+        v.visit_full_table_name(&it);
+    }
+    if let Some(it) = node.trigger_for_each_row() {
+        v.visit_trigger_for_each_row(&it);
+    }
+    if let Some(it) = node.trigger_when_expr() {
+        v.visit_trigger_when_expr(&it);
+    }
+    if let Some(it) = node.trigger_body_stmt_list() {
+        v.visit_trigger_body_stmt_list(&it);
+    }
+}
+pub fn walk_create_view_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &CreateViewStmt<N>,
+) {
+    if let Some(it) = node.temporary() {
+        v.visit_temporary(&it);
+    }
+    if let Some(it) = node.if_not_exists() {
+        v.visit_if_not_exists(&it);
+    }
+    if let Some(it) = node.full_view_name() {
+        v.visit_full_view_name(&it);
+    }
+    if let Some(it) = node.col_name_list() {
+        v.visit_col_name_list(&it);
+    }
+    if let Some(it) = node.select_stmt_with_cte() {
+        v.visit_select_stmt_with_cte(&it);
+    }
+}
+pub fn walk_create_virtual_table_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &CreateVirtualTableStmt<N>,
+) {
+    if let Some(it) = node.if_not_exists() {
+        v.visit_if_not_exists(&it);
+    }
+    if let Some(it) = node.full_table_name() {
+        v.visit_full_table_name(&it);
+    }
+    if let Some(it) = node.module_name() {
+        v.visit_module_name(&it);
+    }
+    if let Some(it) = node.module_arg_list() {
+        v.visit_module_arg_list(&it);
+    }
+}
+pub fn walk_cte_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &CteClause<N>,
+) {
+    if let Some(it) = node.recursive() {
+        v.visit_token(it);
+    }
+    for it in node.expressions() {
+        v.visit_common_table_expr(&it);
+    }
+}
+pub fn walk_cte_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &CteName<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_cte_prependable<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &CtePrependable<N>,
+) {
+    match node {
+        CtePrependable::SelectStmt(it) => v.visit_select_stmt(it),
+        CtePrependable::InsertStmt(it) => v.visit_insert_stmt(it),
+        CtePrependable::UpdateStmt(it) => v.visit_update_stmt(it),
+        CtePrependable::DeleteStmt(it) => v.visit_delete_stmt(it),
+    }
+}
+pub fn walk_db_name_expr<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &DbNameExpr<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_default_constraint<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &DefaultConstraint<N>,
+) {
+    if let Some(it) = node.default_value_kind() {
+        v.visit_default_value_kind(&it);
+    }
+}
+pub fn walk_default_constraint_expr<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &DefaultConstraintExpr<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_default_constraint_iden<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &DefaultConstraintIden<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_default_constraint_literal<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &DefaultConstraintLiteral<N>,
+) {
+    if let Some(it) = node.plus_or_minus() {
+        v.visit_plus_or_minus(&it);
+    }
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_default_value_kind<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &DefaultValueKind<N>,
+) {
+    match node {
+        DefaultValueKind::DefaultConstraintExpr(it) => v.visit_default_constraint_expr(it),
+        DefaultValueKind::DefaultConstraintLiteral(it) => v.visit_default_constraint_literal(it),
+        DefaultValueKind::DefaultConstraintIden(it) => v.visit_default_constraint_iden(it),
+    }
+}
+pub fn walk_defer_kind<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &DeferKind<N>,
+) {
+    match node {
+        DeferKind::KW_DEFERRED(it) => v.visit_token(*it),
+        DeferKind::KW_IMMEDIATE(it) => v.visit_token(*it),
+    }
+}
+pub fn walk_delete_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &DeleteStmt<N>,
+) {
+    if let Some(it) = node.qualified_table_name() {
+        v.visit_qualified_table_name(&it);
+    }
+    if let Some(it) = node.where_clause() {
+        v.visit_where_clause(&it);
+    }
+    if let Some(it) = node.returning_clause() {
+        v.visit_returning_clause(&it);
+    }
+    if let Some(it) = node.delete_stmt_limited() {
+        v.visit_delete_stmt_limited(&it);
+    }
+}
+pub fn walk_delete_stmt_limited<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &DeleteStmtLimited<N>,
+) {
+    if let Some(it) = node.order_by_clause() {
+        v.visit_order_by_clause(&it);
+    }
+    if let Some(it) = node.limit_clause() {
+        v.visit_limit_clause(&it);
+    }
+}
+pub fn walk_detach_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &DetachStmt<N>,
+) {
+    if let Some(it) = node.db_name_expr() {
+        v.visit_db_name_expr(&it);
+    }
+}
+pub fn walk_drop_column<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &DropColumn<N>,
+) {
+    if let Some(it) = node.column_name() {
+        v.visit_column_name(&it);
+    }
+}
+pub fn walk_drop_index_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &DropIndexStmt<N>,
+) {
+    if let Some(it) = node.full_index_name() {
+        v.visit_full_index_name(&it);
+    }
+}
+pub fn walk_drop_table_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &DropTableStmt<N>,
+) {
+    if let Some(it) = node.full_table_name() {
+        v.visit_full_table_name(&it);
+    }
+}
+pub fn walk_drop_trigger_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &DropTriggerStmt<N>,
+) {
+    if let Some(it) = node.full_trigger_name() {
+        v.visit_full_trigger_name(&it);
+    }
+}
+pub fn walk_drop_view_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &DropViewStmt<N>,
+) {
+    if let Some(it) = node.full_view_name() {
+        v.visit_full_view_name(&it);
+    }
+}
+pub fn walk_emptyable_expr_list<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &EmptyableExprList<N>,
+) {
+    for it in node.items() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_explain_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ExplainClause<N>,
+) {
+}
+pub fn walk_expr<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(v: &mut V, node: &Expr<N>) {
+    match node {
+        Expr::ExprParen(it) => v.visit_expr_paren(it),
+        Expr::ExprLit(it) => v.visit_expr_lit(it),
+        Expr::ExprColumnName(it) => v.visit_expr_column_name(it),
+        Expr::ExprPrefix(it) => v.visit_expr_prefix(it),
+        Expr::ExprPostfix(it) => v.visit_expr_postfix(it),
+        Expr::ExprInfix(it) => v.visit_expr_infix(it),
+        Expr::ExprBindParam(it) => v.visit_expr_bind_param(it),
+        Expr::ExprFunc(it) => v.visit_expr_func(it),
+        Expr::ExprExistsSelect(it) => v.visit_expr_exists_select(it),
+        Expr::ExprList(it) => v.visit_expr_list(it),
+        Expr::ExprCast(it) => v.visit_expr_cast(it),
+        Expr::ExprCase(it) => v.visit_expr_case(it),
+        Expr::RaiseFunc(it) => v.visit_raise_func(it),
+        Expr::ExprSelect(it) => v.visit_expr_select(it),
+    }
+}
+pub fn walk_expr_bind_param<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ExprBindParam<N>,
+) {
+}
+pub fn walk_expr_case<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ExprCase<N>,
+) {
+    if let Some(it) = node.case_target_expr() {
+        v.visit_case_target_expr(&it);
+    }
+    if let Some(it) = node.case_when_clause_list() {
+        v.visit_case_when_clause_list(&it);
+    }
+    if let Some(it) = node.case_else_clause() {
+        v.visit_case_else_clause(&it);
+    }
+}
+pub fn walk_expr_cast<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ExprCast<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.type_name() {
+        v.visit_type_name(&it);
+    }
+}
+pub fn walk_expr_column_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ExprColumnName<N>,
+) {
+    if let Some(it) = node.schema_name() {
+        v.visit_schema_name(&it);
+    }
+    if let Some(it) = node.table_name() {
+        v.visit_table_name(&it);
+    }
+    if let Some(it) = node.column_name() {
+        v.visit_column_name(&it);
+    }
+}
+pub fn walk_expr_exists_select<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ExprExistsSelect<N>,
+) {
+    if let Some(it) = node.not() {
+        v.visit_token(it);
+    }
+    if let Some(it) = node.select_stmt_with_cte() {
+        v.visit_select_stmt_with_cte(&it);
+    }
+}
+pub fn walk_expr_func<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ExprFunc<N>,
+) {
+    if let Some(it) = node.function_name() {
+        v.visit_function_name(&it);
+    }
+    if let Some(it) = node.func_arguments() {
+        v.visit_func_arguments(&it);
+    }
+    if let Some(it) = node.filter_clause() {
+        v.visit_filter_clause(&it);
+    }
+    if let Some(it) = node.over_clause() {
+        v.visit_over_clause(&it);
+    }
+}
+pub fn walk_expr_infix<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ExprInfix<N>,
+) {
+    match node {
+        ExprInfix::OpConcat(it) => v.visit_op_concat(it),
+        ExprInfix::OpExtractOne(it) => v.visit_op_extract_one(it),
+        ExprInfix::OpExtractTwo(it) => v.visit_op_extract_two(it),
+        ExprInfix::OpMultiply(it) => v.visit_op_multiply(it),
+        ExprInfix::OpDivide(it) => v.visit_op_divide(it),
+        ExprInfix::OpModulus(it) => v.visit_op_modulus(it),
+        ExprInfix::OpAdd(it) => v.visit_op_add(it),
+        ExprInfix::OpSubtract(it) => v.visit_op_subtract(it),
+        ExprInfix::OpBinAnd(it) => v.visit_op_bin_and(it),
+        ExprInfix::OpBinOr(it) => v.visit_op_bin_or(it),
+        ExprInfix::OpBinLShift(it) => v.visit_op_bin_l_shift(it),
+        ExprInfix::OpBinRShift(it) => v.visit_op_bin_r_shift(it),
+        ExprInfix::OpLT(it) => v.visit_op_lt(it),
+        ExprInfix::OpGT(it) => v.visit_op_gt(it),
+        ExprInfix::OpLTE(it) => v.visit_op_lte(it),
+        ExprInfix::OpGTE(it) => v.visit_op_gte(it),
+        ExprInfix::OpEq(it) => v.visit_op_eq(it),
+        ExprInfix::OpNotEq(it) => v.visit_op_not_eq(it),
+        ExprInfix::OpAnd(it) => v.visit_op_and(it),
+        ExprInfix::OpOr(it) => v.visit_op_or(it),
+        ExprInfix::OpMatch(it) => v.visit_op_match(it),
+        ExprInfix::OpLike(it) => v.visit_op_like(it),
+        ExprInfix::OpRegexp(it) => v.visit_op_regexp(it),
+        ExprInfix::OpGlob(it) => v.visit_op_glob(it),
+        ExprInfix::OpBetweenAnd(it) => v.visit_op_between_and(it),
+        ExprInfix::OpNotMatch(it) => v.visit_op_not_match(it),
+        ExprInfix::OpNotLike(it) => v.visit_op_not_like(it),
+        ExprInfix::OpNotRegexp(it) => v.visit_op_not_regexp(it),
+        ExprInfix::OpNotGlob(it) => v.visit_op_not_glob(it),
+        ExprInfix::OpNotBetweenAnd(it) => v.visit_op_not_between_and(it),
+        ExprInfix::OpIsNotDistinctFrom(it) => v.visit_op_is_not_distinct_from(it),
+        ExprInfix::OpIsDistinctFrom(it) => v.visit_op_is_distinct_from(it),
+        ExprInfix::OpIsNot(it) => v.visit_op_is_not(it),
+        ExprInfix::OpIs(it) => v.visit_op_is(it),
+        ExprInfix::OpIn(it) => v.visit_op_in(it),
+        ExprInfix::OpNotIn(it) => v.visit_op_not_in(it),
+    }
+}
+pub fn walk_expr_list<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ExprList<N>,
+) {
+    for it in node.items() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_expr_lit<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ExprLit<N>,
+) {
+    match node {
+        ExprLit::INT_LIT(it) => v.visit_token(*it),
+        ExprLit::HEX_LIT(it) => v.visit_token(*it),
+        ExprLit::STR_LIT(it) => v.visit_token(*it),
+        ExprLit::REAL_LIT(it) => v.visit_token(*it),
+        ExprLit::BLOB_LIT(it) => v.visit_token(*it),
+        ExprLit::KW_NULL(it) => v.visit_token(*it),
+        ExprLit::KW_CURRENT_TIME(it) => v.visit_token(*it),
+        ExprLit::KW_CURRENT_DATE(it) => v.visit_token(*it),
+        ExprLit::KW_CURRENT_TIMESTAMP(it) => v.visit_token(*it),
+    }
+}
+pub fn walk_expr_paren<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ExprParen<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_expr_postfix<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ExprPostfix<N>,
+) {
+    match node {
+        ExprPostfix::OpNotSpaceNull(it) => v.visit_op_not_space_null(it),
+        ExprPostfix::OpCollate(it) => v.visit_op_collate(it),
+        ExprPostfix::OpNotNull(it) => v.visit_op_not_null(it),
+        ExprPostfix::OpIsNull(it) => v.visit_op_is_null(it),
+    }
+}
+pub fn walk_expr_prefix<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ExprPrefix<N>,
+) {
+    match node {
+        ExprPrefix::OpBinComplement(it) => v.visit_op_bin_complement(it),
+        ExprPrefix::OpUnaryPlus(it) => v.visit_op_unary_plus(it),
+        ExprPrefix::OpUnaryMinus(it) => v.visit_op_unary_minus(it),
+        ExprPrefix::OpNot(it) => v.visit_op_not(it),
+    }
+}
+pub fn walk_expr_select<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ExprSelect<N>,
+) {
+    if let Some(it) = node.select_stmt_with_cte() {
+        v.visit_select_stmt_with_cte(&it);
+    }
+}
+pub fn walk_file<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(v: &mut V, node: &File<N>) {
+    for it in node.statements() {
+        v.visit_statement(&it);
+    }
+}
+pub fn walk_file_name_expr<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FileNameExpr<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_filter_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FilterClause<N>,
+) {
+    if let Some(it) = node.where_clause() {
+        v.visit_where_clause(&it);
+    }
+}
+pub fn walk_fk_action<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FkAction<N>,
+) {
+    match node {
+        FkAction::FkSetNull(it) => v.visit_fk_set_null(it),
+        FkAction::FkSetDefault(it) => v.visit_fk_set_default(it),
+        FkAction::FkCascade(it) => v.visit_fk_cascade(it),
+        FkAction::FkRestrict(it) => v.visit_fk_restrict(it),
+        FkAction::FkNoAction(it) => v.visit_fk_no_action(it),
+    }
+}
+pub fn walk_fk_cascade<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FkCascade<N>,
+) {
+}
+pub fn walk_fk_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FkClause<N>,
+) {
+    if let Some(it) = node.table_name() {
+        v.visit_table_name(&it);
+    }
+    if let Some(it) = node.col_name_list() {
+        v.visit_col_name_list(&it);
+    }
+    for it in node.fk_actions() {
+        v.visit_fk_violate_action(&it);
+    }
+    if let Some(it) = node.fk_deferrable() {
+        v.visit_fk_deferrable(&it);
+    }
+}
+pub fn walk_fk_deferrable<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FkDeferrable<N>,
+) {
+    if let Some(it) = node.not() {
+        v.visit_token(it);
+    }
+    if let Some(it) = node.defer_kind() {
+        v.visit_defer_kind(&it);
+    }
+}
+pub fn walk_fk_fail_kind<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FkFailKind<N>,
+) {
+    match node {
+        FkFailKind::KW_DELETE(it) => v.visit_token(*it),
+        FkFailKind::KW_UPDATE(it) => v.visit_token(*it),
+    }
+}
+pub fn walk_fk_match_action<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FkMatchAction<N>,
+) {
+    if let Some(it) = node.any_valid_name() {
+        v.visit_any_valid_name(&it);
+    }
+}
+pub fn walk_fk_no_action<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FkNoAction<N>,
+) {
+}
+pub fn walk_fk_on_action<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FkOnAction<N>,
+) {
+    if let Some(it) = node.fk_fail_kind() {
+        v.visit_fk_fail_kind(&it);
+    }
+    if let Some(it) = node.fk_action() {
+        v.visit_fk_action(&it);
+    }
+}
+pub fn walk_fk_on_or_match<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FkOnOrMatch<N>,
+) {
+    match node {
+        FkOnOrMatch::FkOnAction(it) => v.visit_fk_on_action(it),
+        FkOnOrMatch::FkMatchAction(it) => v.visit_fk_match_action(it),
+    }
+}
+pub fn walk_fk_restrict<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FkRestrict<N>,
+) {
+}
+pub fn walk_fk_set_default<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FkSetDefault<N>,
+) {
+}
+pub fn walk_fk_set_null<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FkSetNull<N>,
+) {
+}
+pub fn walk_fk_violate_action<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FkViolateAction<N>,
+) {
+    if let Some(it) = node.fk_on_or_match() {
+        v.visit_fk_on_or_match(&it);
+    }
+}
+pub fn walk_frame_spec<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FrameSpec<N>,
+) {
+    if let Some(it) = node.range() {
+        v.visit_range(&it);
+    }
+    if let Some(it) = node.frame_spec_kind() {
+        v.visit_frame_spec_kind(&it);
+    }
+    if let Some(it) = node.frame_spec_exclude_clause() {
+        v.visit_frame_spec_exclude_clause(&it);
+    }
+}
+pub fn walk_frame_spec_between_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FrameSpecBetweenClause<N>,
+) {
+    if let Some(it) = node.frame_spec_between_left() {
+        v.visit_frame_spec_between_left(&it);
+    }
+    if let Some(it) = node.frame_spec_between_right() {
+        v.visit_frame_spec_between_right(&it);
+    }
+}
+pub fn walk_frame_spec_between_left<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FrameSpecBetweenLeft<N>,
+) {
+    match node {
+        FrameSpecBetweenLeft::FrameSpecUnboundedPreceding(it) => {
+            v.visit_frame_spec_unbounded_preceding(it)
+        }
+        FrameSpecBetweenLeft::FrameSpecPreceding(it) => v.visit_frame_spec_preceding(it),
+        FrameSpecBetweenLeft::FrameSpecCurrentRow(it) => v.visit_frame_spec_current_row(it),
+        FrameSpecBetweenLeft::FrameSpecFollowing(it) => v.visit_frame_spec_following(it),
+    }
+}
+pub fn walk_frame_spec_between_right<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FrameSpecBetweenRight<N>,
+) {
+    match node {
+        FrameSpecBetweenRight::FrameSpecUnboundedFollowing(it) => {
+            v.visit_frame_spec_unbounded_following(it)
+        }
+        FrameSpecBetweenRight::FrameSpecPreceding(it) => v.visit_frame_spec_preceding(it),
+        FrameSpecBetweenRight::FrameSpecCurrentRow(it) => v.visit_frame_spec_current_row(it),
+        FrameSpecBetweenRight::FrameSpecFollowing(it) => v.visit_frame_spec_following(it),
+    }
+}
+pub fn walk_frame_spec_current_row<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FrameSpecCurrentRow<N>,
+) {
+}
+pub fn walk_frame_spec_exclude_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FrameSpecExcludeClause<N>,
+) {
+    if let Some(it) = node.frame_spec_exclude_kind() {
+        v.visit_frame_spec_exclude_kind(&it);
+    }
+}
+pub fn walk_frame_spec_exclude_kind<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FrameSpecExcludeKind<N>,
+) {
+    match node {
+        FrameSpecExcludeKind::FrameSpecNoOthers(it) => v.visit_frame_spec_no_others(it),
+        FrameSpecExcludeKind::FrameSpecCurrentRow(it) => v.visit_frame_spec_current_row(it),
+        FrameSpecExcludeKind::KW_GROUP(it) => v.visit_token(*it),
+        FrameSpecExcludeKind::KW_TIES(it) => v.visit_token(*it),
+    }
+}
+pub fn walk_frame_spec_following<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FrameSpecFollowing<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_frame_spec_kind<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FrameSpecKind<N>,
+) {
+    match node {
+        FrameSpecKind::FrameSpecBetweenClause(it) => v.visit_frame_spec_between_clause(it),
+        FrameSpecKind::FrameSpecUnboundedPreceding(it) => {
+            v.visit_frame_spec_unbounded_preceding(it)
+        }
+        FrameSpecKind::FrameSpecPreceding(it) => v.visit_frame_spec_preceding(it),
+        FrameSpecKind::FrameSpecCurrentRow(it) => v.visit_frame_spec_current_row(it),
+    }
+}
+pub fn walk_frame_spec_no_others<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FrameSpecNoOthers<N>,
+) {
+}
+pub fn walk_frame_spec_preceding<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FrameSpecPreceding<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_frame_spec_unbounded_following<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FrameSpecUnboundedFollowing<N>,
+) {
+}
+pub fn walk_frame_spec_unbounded_preceding<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FrameSpecUnboundedPreceding<N>,
+) {
+}
+pub fn walk_from_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FromClause<N>,
+) {
+    if let Some(it) = node.from_clause_value() {
+        v.visit_from_clause_value(&it);
+    }
+}
+pub fn walk_from_clause_table_value_function<
+    'a,
+    N: CstNodeTrait<'a>,
+    V: Visitor<'a, N> + ?Sized,
+>(
+    v: &mut V,
+    node: &FromClauseTableValueFunction<N>,
+) {
+    if let Some(it) = node.full_table_function_name() {
+        v.visit_full_table_function_name(&it);
+    }
+    if let Some(it) = node.emptyable_expr_list() {
+        v.visit_emptyable_expr_list(&it);
+    }
+}
+pub fn walk_from_clause_value<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FromClauseValue<N>,
+) {
+    match node {
+        FromClauseValue::TableOrSubquery(it) => v.visit_table_or_subquery(it),
+        FromClauseValue::JoinClause(it) => v.visit_join_clause(it),
+    }
+}
+pub fn walk_full_index_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FullIndexName<N>,
+) {
+    if let Some(it) = node.schema_name() {
+        v.visit_schema_name(&it);
+    }
+    if let Some(it) = node.index_name() {
+        v.visit_index_name(&it);
+    }
+}
+pub fn walk_full_pragma_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FullPragmaName<N>,
+) {
+    if let Some(it) = node.schema_name() {
+        v.visit_schema_name(&it);
+    }
+    if let Some(it) = node.pragma() {
+        v.visit_pragma_name(&it);
+    }
+}
+pub fn walk_full_table_function_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FullTableFunctionName<N>,
+) {
+    if let Some(it) = node.schema_name() {
+        v.visit_schema_name(&it);
+    }
+    if let Some(it) = node.table_func() {
+        v.visit_table_function_name(&it);
+    }
+}
+pub fn walk_full_table_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FullTableName<N>,
+) {
+    if let Some(it) = node.schema_name() {
+        v.visit_schema_name(&it);
+    }
+    if let Some(it) = node.table() {
+        v.visit_table_name(&it);
+    }
+}
+pub fn walk_full_trigger_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FullTriggerName<N>,
+) {
+    if let Some(it) = node.schema_name() {
+        v.visit_schema_name(&it);
+    }
+    if let Some(it) = node.trigger() {
+        v.visit_trigger_name(&it);
+    }
+}
+pub fn walk_full_view_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FullViewName<N>,
+) {
+    if let Some(it) = node.schema_name() {
+        v.visit_schema_name(&it);
+    }
+    if let Some(it) = node.view_name() {
+        v.visit_view_name(&it);
+    }
+}
+pub fn walk_func_arguments<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FuncArguments<N>,
+) {
+    match node {
+        FuncArguments::ArgExpr(it) => v.visit_arg_expr(it),
+        FuncArguments::ArgStar(it) => v.visit_arg_star(it),
+    }
+}
+pub fn walk_function_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &FunctionName<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_group_by_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &GroupByClause<N>,
+) {
+    for it in node.items() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_having_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &HavingClause<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_if_not_exists<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &IfNotExists<N>,
+) {
+}
+pub fn walk_in_expr_kind<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &InExprKind<N>,
+) {
+    match node {
+        InExprKind::EmptyableExprList(it) => v.visit_emptyable_expr_list(it),
+        InExprKind::ExprSelect(it) => v.visit_expr_select(it),
+        InExprKind::InTableFunc(it) => v.visit_in_table_func(it),
+        InExprKind::InTable(it) => v.visit_in_table(it),
+    }
+}
+pub fn walk_in_table<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &InTable<N>,
+) {
+    if let Some(it) = node.full_table_name() {
+        v.visit_full_table_name(&it);
+    }
+}
+pub fn walk_in_table_func<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &InTableFunc<N>,
+) {
+    if let Some(it) = node.full_table_function_name() {
+        v.visit_full_table_function_name(&it);
+    }
+    for it in node.items() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_index_details<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &IndexDetails<N>,
+) {
+    match node {
+        IndexDetails::TableNameIndexedBy(it) => v.visit_table_name_indexed_by(it),
+        IndexDetails::TableNameNotIndexed(it) => v.visit_table_name_not_indexed(it),
+    }
+}
+pub fn walk_index_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &IndexName<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_indexed_col<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &IndexedCol<N>,
+) {
+    if let Some(it) = node.index_column() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.collation() {
+        v.visit_collation(&it);
+    }
+    if let Some(it) = node.order() {
+        v.visit_order(&it);
+    }
+}
+pub fn walk_indexed_col_list<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &IndexedColList<N>,
+) {
+    for it in node.items() {
+        v.visit_indexed_col(&it);
+    }
+}
+pub fn walk_insert_default_values_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &InsertDefaultValuesClause<N>,
+) {
+}
+pub fn walk_insert_or_action<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &InsertOrAction<N>,
+) {
+    if let Some(it) = node.conflict_action() {
+        v.visit_conflict_action(&it);
+    }
+}
+pub fn walk_insert_select_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &InsertSelectClause<N>,
+) {
+    if let Some(it) = node.select_stmt_with_cte() {
+        v.visit_select_stmt_with_cte(&it);
+    }
+    for it in node.upsert_clauses() {
+        v.visit_upsert_clause(&it);
+    }
+}
+pub fn walk_insert_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &InsertStmt<N>,
+) {
+    if let Some(it) = node.insert_stmt_kind() {
+        v.visit_insert_stmt_kind(&it);
+    }
+    if let Some(it) = node.full_table_name() {
+        v.visit_full_table_name(&it);
+    }
+    if let Some(it) = node.with_alias() {
+        v.visit_with_alias(&it);
+    }
+    if let Some(it) = node.col_name_list() {
+        v.visit_col_name_list(&it);
+    }
+    if let Some(it) = node.insert_value_kind() {
+        v.visit_insert_value_kind(&it);
+    }
+    if let Some(it) = node.returning_clause() {
+        v.visit_returning_clause(&it);
+    }
+}
+pub fn walk_insert_stmt_kind<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &InsertStmtKind<N>,
+) {
+    match node {
+        InsertStmtKind::KW_REPLACE(it) => v.visit_token(*it),
+        InsertStmtKind::InsertOrAction(it) => v.visit_insert_or_action(it),
+    }
+}
+pub fn walk_insert_value_kind<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &InsertValueKind<N>,
+) {
+    match node {
+        InsertValueKind::InsertValuesClause(it) => v.visit_insert_values_clause(it),
+        InsertValueKind::InsertSelectClause(it) => v.visit_insert_select_clause(it),
+        InsertValueKind::InsertDefaultValuesClause(it) => v.visit_insert_default_values_clause(it),
+    }
+}
+pub fn walk_insert_values_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &InsertValuesClause<N>,
+) {
+    for it in node.expr_lists() {
+        v.visit_expr_list(&it);
+    }
+    for it in node.upsert_clauses() {
+        v.visit_upsert_clause(&it);
+    }
+}
+pub fn walk_join_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &JoinClause<N>,
+) {
+    for child in node.untyped().valid_children() {
+        visit_untyped(v, child);
+    }
+}
+pub fn walk_join_constraint<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &JoinConstraint<N>,
+) {
+    match node {
+        JoinConstraint::OnConstraint(it) => v.visit_on_constraint(it),
+        JoinConstraint::UsingConstraint(it) => v.visit_using_constraint(it),
+    }
+}
+pub fn walk_join_operator<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &JoinOperator<N>,
+) {
+    for child in node.untyped().valid_children() {
+        visit_untyped(v, child);
+    }
+}
+pub fn walk_limit_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &LimitClause<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.offset() {
+        v.visit_offset(&it);
+    }
+}
+pub fn walk_materialized_cte<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &MaterializedCte<N>,
+) {
+    if let Some(it) = node.not() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_module_arg<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ModuleArg<N>,
+) {
+    match node {
+        ModuleArg::STR_LIT(it) => v.visit_token(*it),
+        ModuleArg::INT_LIT(it) => v.visit_token(*it),
+        ModuleArg::REAL_LIT(it) => v.visit_token(*it),
+        ModuleArg::HEX_LIT(it) => v.visit_token(*it),
+    }
+}
+pub fn walk_module_arg_list<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ModuleArgList<N>,
+) {
+    for it in node.items() {
+        v.visit_module_arg(&it);
+    }
+}
+pub fn walk_module_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ModuleName<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_new_column_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &NewColumnName<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_new_table_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &NewTableName<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_null_constraint<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &NullConstraint<N>,
+) {
+    if let Some(it) = node.is_not_null() {
+        v.visit_token(it);
+    }
+    if let Some(it) = node.conflict_clause() {
+        v.visit_conflict_clause(&it);
+    }
+}
+pub fn walk_nulls_position<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &NullsPosition<N>,
+) {
+    match node {
+        NullsPosition::KW_FIRST(it) => v.visit_token(*it),
+        NullsPosition::KW_LAST(it) => v.visit_token(*it),
+    }
+}
+pub fn walk_offset<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &Offset<N>,
+) {
+    for child in node.untyped().valid_children() {
+        visit_untyped(v, child);
+    }
+}
+pub fn walk_on_constraint<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OnConstraint<N>,
+) {
+    if let Some(it) = node.on_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_add<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpAdd<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_and<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpAnd<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_between_and<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpBetweenAnd<N>,
+) {
+    if let Some(it) = node.target_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.low_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.high_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_bin_and<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpBinAnd<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_bin_complement<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpBinComplement<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_bin_l_shift<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpBinLShift<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_bin_or<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpBinOr<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_bin_r_shift<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpBinRShift<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_collate<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpCollate<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.collation() {
+        v.visit_collation(&it);
+    }
+}
+pub fn walk_op_concat<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpConcat<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_divide<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpDivide<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_eq<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(v: &mut V, node: &OpEq<N>) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_escape<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpEscape<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_extract_one<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpExtractOne<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_extract_two<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpExtractTwo<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_gt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(v: &mut V, node: &OpGT<N>) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_gte<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpGTE<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_glob<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpGlob<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_in<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(v: &mut V, node: &OpIn<N>) {
+    if let Some(it) = node.lhs() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.in_expr_kind() {
+        v.visit_in_expr_kind(&it);
+    }
+}
+pub fn walk_op_is<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(v: &mut V, node: &OpIs<N>) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_is_distinct_from<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpIsDistinctFrom<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_is_not<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpIsNot<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_is_not_distinct_from<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpIsNotDistinctFrom<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_is_null<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpIsNull<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_lt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(v: &mut V, node: &OpLT<N>) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_lte<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpLTE<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_like<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpLike<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.op_escape() {
+        v.visit_op_escape(&it);
+    }
+}
+pub fn walk_op_match<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpMatch<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_modulus<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpModulus<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_multiply<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpMultiply<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_not<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpNot<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_not_between_and<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpNotBetweenAnd<N>,
+) {
+    if let Some(it) = node.target_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.low_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.high_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_not_eq<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpNotEq<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_not_glob<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpNotGlob<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_not_in<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpNotIn<N>,
+) {
+    if let Some(it) = node.lhs() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.in_expr_kind() {
+        v.visit_in_expr_kind(&it);
+    }
+}
+pub fn walk_op_not_like<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpNotLike<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.escape() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_op_not_match<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpNotMatch<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_not_null<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpNotNull<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_not_regexp<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpNotRegexp<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_not_space_null<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpNotSpaceNull<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_or<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(v: &mut V, node: &OpOr<N>) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_regexp<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpRegexp<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_subtract<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpSubtract<N>,
+) {
+    if let Some(it) = node.lhs_expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.rhs_expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_unary_minus<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpUnaryMinus<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_op_unary_plus<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OpUnaryPlus<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_order<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(v: &mut V, node: &Order<N>) {
+    match node {
+        Order::KW_ASC(it) => v.visit_token(*it),
+        Order::KW_DESC(it) => v.visit_token(*it),
+    }
+}
+pub fn walk_order_by_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OrderByClause<N>,
+) {
+    if let Some(it) = node.ordering_term_list() {
+        v.visit_ordering_term_list(&it);
+    }
+}
+pub fn walk_ordering_term<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OrderingTerm<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.collation() {
+        v.visit_collation(&it);
+    }
+    if let Some(it) = node.order() {
+        v.visit_order(&it);
+    }
+    if let Some(it) = node.nulls_position() {
+        v.visit_nulls_position(&it);
+    }
+}
+pub fn walk_ordering_term_list<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OrderingTermList<N>,
+) {
+    for it in node.items() {
+        v.visit_ordering_term(&it);
+    }
+}
+pub fn walk_over_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OverClause<N>,
+) {
+    if let Some(it) = node.over_clause_kind() {
+        v.visit_over_clause_kind(&it);
+    }
+}
+pub fn walk_over_clause_kind<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &OverClauseKind<N>,
+) {
+    match node {
+        OverClauseKind::WindowName(it) => v.visit_window_name(it),
+        OverClauseKind::WindowDef(it) => v.visit_window_def(it),
+    }
+}
+pub fn walk_password_expr<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &PasswordExpr<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_plus_or_minus<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &PlusOrMinus<N>,
+) {
+    match node {
+        PlusOrMinus::PLUS(it) => v.visit_token(*it),
+        PlusOrMinus::MINUS(it) => v.visit_token(*it),
+    }
+}
+pub fn walk_pragma_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &PragmaName<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_pragma_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &PragmaStmt<N>,
+) {
+    for child in node.untyped().valid_children() {
+        visit_untyped(v, child);
+    }
+}
+pub fn walk_pragma_value<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &PragmaValue<N>,
+) {
+    match node {
+        PragmaValue::SignedNumber(it) => v.visit_signed_number(it),
+        PragmaValue::PragmaValueName(it) => v.visit_pragma_value_name(it),
+        PragmaValue::KW_ON(it) => v.visit_token(*it),
+        PragmaValue::KW_DELETE(it) => v.visit_token(*it),
+        PragmaValue::KW_DEFAULT(it) => v.visit_token(*it),
+    }
+}
+pub fn walk_pragma_value_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &PragmaValueName<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_primary_constraint<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &PrimaryConstraint<N>,
+) {
+    if let Some(it) = node.order() {
+        v.visit_order(&it);
+    }
+    if let Some(it) = node.conflict_clause() {
+        v.visit_conflict_clause(&it);
+    }
+    if let Some(it) = node.auto_increment() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_qualified_table_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &QualifiedTableName<N>,
+) {
+    if let Some(it) = node.full_table_name() {
+        v.visit_full_table_name(&it);
+    }
+    if let Some(it) = node.with_alias() {
+        v.visit_with_alias(&it);
+    }
+    if let Some(it) = node.index_details() {
+        v.visit_index_details(&it);
+    }
+}
+pub fn walk_raise_action<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &RaiseAction<N>,
+) {
+    match node {
+        RaiseAction::KW_IGNORE(it) => v.visit_token(*it),
+        RaiseAction::RaiseActionRollBack(it) => v.visit_raise_action_roll_back(it),
+        RaiseAction::RaiseActionAbort(it) => v.visit_raise_action_abort(it),
+        RaiseAction::RaiseActionFail(it) => v.visit_raise_action_fail(it),
+    }
+}
+pub fn walk_raise_action_abort<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &RaiseActionAbort<N>,
+) {
+    if let Some(it) = node.raise_func_err_message() {
+        v.visit_raise_func_err_message(&it);
+    }
+}
+pub fn walk_raise_action_fail<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &RaiseActionFail<N>,
+) {
+    if let Some(it) = node.raise_func_err_message() {
+        v.visit_raise_func_err_message(&it);
+    }
+}
+pub fn walk_raise_action_roll_back<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &RaiseActionRollBack<N>,
+) {
+    if let Some(it) = node.raise_func_err_message() {
+        v.visit_raise_func_err_message(&it);
+    }
+}
+pub fn walk_raise_func<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &RaiseFunc<N>,
+) {
+    if let Some(it) = node.raise_action() {
+        v.visit_raise_action(&it);
+    }
+}
+pub fn walk_raise_func_err_message<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &RaiseFuncErrMessage<N>,
+) {
+}
+pub fn walk_range<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(v: &mut V, node: &Range<N>) {
+    match node {
+        Range::KW_RANGE(it) => v.visit_token(*it),
+        Range::KW_ROWS(it) => v.visit_token(*it),
+        Range::KW_GROUPS(it) => v.visit_token(*it),
+    }
+}
+pub fn walk_re_index_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ReIndexStmt<N>,
+) {
+    if let Some(it) = node.target() {
+        v.visit_target(&it);
+    }
+}
+pub fn walk_release_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ReleaseStmt<N>,
+) {
+    if let Some(it) = node.savepoint_name() {
+        v.visit_savepoint_name(&it);
+    }
+}
+pub fn walk_rename_column<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &RenameColumn<N>,
+) {
+    if let Some(it) = node.old_name() {
+        v.visit_column_name(&it);
+    }
+    if let Some(it) = node.new_name() {
+        v.visit_new_column_name(&it);
+    }
+}
+pub fn walk_rename_table<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &RenameTable<N>,
+) {
+    if let Some(it) = node.table_name() {
+        v.visit_table_name(&it);
+    }
+}
+pub fn walk_result_column<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ResultColumn<N>,
+) {
+    match node {
+        ResultColumn::ResultColumnExpr(it) => v.visit_result_column_expr(it),
+        ResultColumn::ResultColumnAll(it) => v.visit_result_column_all(it),
+        ResultColumn::ResultColumnTableAll(it) => v.visit_result_column_table_all(it),
+    }
+}
+pub fn walk_result_column_all<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ResultColumnAll<N>,
+) {
+}
+pub fn walk_result_column_expr<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ResultColumnExpr<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.with_alias() {
+        v.visit_with_alias(&it);
+    }
+}
+pub fn walk_result_column_list<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ResultColumnList<N>,
+) {
+    for it in node.items() {
+        v.visit_result_column(&it);
+    }
+}
+pub fn walk_result_column_table_all<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ResultColumnTableAll<N>,
+) {
+    if let Some(it) = node.table_name() {
+        v.visit_table_name(&it);
+    }
+}
+pub fn walk_returning_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ReturningClause<N>,
+) {
+    for it in node.columns() {
+        v.visit_returning_clause_kind(&it);
+    }
+}
+pub fn walk_returning_clause_expr<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ReturningClauseExpr<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+    if let Some(it) = node.col_alias() {
+        v.visit_alias_name(&it);
+    }
+}
+pub fn walk_returning_clause_kind<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ReturningClauseKind<N>,
+) {
+    match node {
+        ReturningClauseKind::STAR(it) => v.visit_token(*it),
+        ReturningClauseKind::ReturningClauseExpr(it) => v.visit_returning_clause_expr(it),
+    }
+}
+pub fn walk_rollback_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &RollbackStmt<N>,
+) {
+    if let Some(it) = node.savepoint_name() {
+        v.visit_savepoint_name(&it);
+    }
+}
+pub fn walk_savepoint_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &SavepointName<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_savepoint_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &SavepointStmt<N>,
+) {
+    if let Some(it) = node.savepoint_name() {
+        v.visit_savepoint_name(&it);
+    }
+}
+pub fn walk_schema_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &SchemaName<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_schema_name_expr<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &SchemaNameExpr<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_schema_or_idx_or_table_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &SchemaOrIdxOrTableName<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_select_core<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &SelectCore<N>,
+) {
+    match node {
+        SelectCore::TraditionalSelect(it) => v.visit_traditional_select(it),
+        SelectCore::ValuesSelect(it) => v.visit_values_select(it),
+    }
+}
+pub fn walk_select_distinct<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &SelectDistinct<N>,
+) {
+    match node {
+        SelectDistinct::KW_DISTINCT(it) => v.visit_token(*it),
+        SelectDistinct::KW_ALL(it) => v.visit_token(*it),
+    }
+}
+pub fn walk_select_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &SelectStmt<N>,
+) {
+    if let Some(it) = node.select_core() {
+        v.visit_select_core(&it);
+    }
+    for it in node.compound_selects() {
+        v.visit_compound_select(&it);
+    }
+    if let Some(it) = node.order_by_clause() {
+        v.visit_order_by_clause(&it);
+    }
+    if let Some(it) = node.limit_clause() {
+        v.visit_limit_clause(&it);
+    }
+}
+pub fn walk_select_stmt_with_cte<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &SelectStmtWithCte<N>,
+) {
+    if let Some(it) = node.cte_clause() {
+        v.visit_cte_clause(&it);
+    }
+    if let Some(it) = node.select_stmt() {
+        v.visit_select_stmt(&it);
+    }
+}
+pub fn walk_set_column_expr<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &SetColumnExpr<N>,
+) {
+    if let Some(it) = node.set_column_kind() {
+        v.visit_set_column_kind(&it);
+    }
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_set_column_kind<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &SetColumnKind<N>,
+) {
+    match node {
+        SetColumnKind::ColumnName(it) => v.visit_column_name(it),
+        SetColumnKind::ColNameList(it) => v.visit_col_name_list(it),
+    }
+}
+pub fn walk_signed_number<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &SignedNumber<N>,
+) {
+    if let Some(it) = node.plus_or_minus() {
+        v.visit_plus_or_minus(&it);
+    }
+    if let Some(it) = node.number() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_statement<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &Statement<N>,
+) {
+    if let Some(it) = node.explain_clause() {
+        v.visit_explain_clause(&it);
+    }
+    if let Some(it) = node.statement_kind() {
+        v.visit_statement_kind(&it);
+    }
+}
+pub fn walk_statement_kind<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &StatementKind<N>,
+) {
+    match node {
+        StatementKind::StatementNoCte(it) => v.visit_statement_no_cte(it),
+        StatementKind::StatementWithCte(it) => v.visit_statement_with_cte(it),
+    }
+}
+pub fn walk_statement_no_cte<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &StatementNoCte<N>,
+) {
+    match node {
+        StatementNoCte::CreateTableStmt(it) => v.visit_create_table_stmt(it),
+        StatementNoCte::AlterTableStmt(it) => v.visit_alter_table_stmt(it),
+        StatementNoCte::AnalyzeStmt(it) => v.visit_analyze_stmt(it),
+        StatementNoCte::AttachDbStmt(it) => v.visit_attach_db_stmt(it),
+        StatementNoCte::BeginStmt(it) => v.visit_begin_stmt(it),
+        StatementNoCte::CommitStmt(it) => v.visit_commit_stmt(it),
+        StatementNoCte::CreateIndexStmt(it) => v.visit_create_index_stmt(it),
+        StatementNoCte::CreateTriggerStmt(it) => v.visit_create_trigger_stmt(it),
+        StatementNoCte::CreateViewStmt(it) => v.visit_create_view_stmt(it),
+        StatementNoCte::CreateVirtualTableStmt(it) => v.visit_create_virtual_table_stmt(it),
+        StatementNoCte::DetachStmt(it) => v.visit_detach_stmt(it),
+        StatementNoCte::DropIndexStmt(it) => v.visit_drop_index_stmt(it),
+        StatementNoCte::DropViewStmt(it) => v.visit_drop_view_stmt(it),
+        StatementNoCte::DropTableStmt(it) => v.visit_drop_table_stmt(it),
+        StatementNoCte::DropTriggerStmt(it) => v.visit_drop_trigger_stmt(it),
+        StatementNoCte::PragmaStmt(it) => v.visit_pragma_stmt(it),
+        StatementNoCte::ReIndexStmt(it) => v.visit_re_index_stmt(it),
+        StatementNoCte::ReleaseStmt(it) => v.visit_release_stmt(it),
+        StatementNoCte::RollbackStmt(it) => v.visit_rollback_stmt(it),
+        StatementNoCte::SavepointStmt(it) => v.visit_savepoint_stmt(it),
+        StatementNoCte::VacuumStmt(it) => v.visit_vacuum_stmt(it),
+    }
+}
+pub fn walk_statement_with_cte<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &StatementWithCte<N>,
+) {
+    if let Some(it) = node.cte_clause() {
+        v.visit_cte_clause(&it);
+    }
+    if let Some(it) = node.cte_prependable() {
+        v.visit_cte_prependable(&it);
+    }
+}
+pub fn walk_table_columns<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TableColumns<N>,
+) {
+    match node {
+        TableColumns::TableDetails(it) => v.visit_table_details(it),
+        TableColumns::CreateTableSelect(it) => v.visit_create_table_select(it),
+    }
+}
+pub fn walk_table_constraint<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TableConstraint<N>,
+) {
+    if let Some(it) = node.constraint_name() {
+        v.visit_constraint_name(&it);
+    }
+    if let Some(it) = node.table_constraint_kind() {
+        v.visit_table_constraint_kind(&it);
+    }
+}
+pub fn walk_table_constraint_kind<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TableConstraintKind<N>,
+) {
+    match node {
+        TableConstraintKind::TablePkConstraint(it) => v.visit_table_pk_constraint(it),
+        TableConstraintKind::TableUqConstraint(it) => v.visit_table_uq_constraint(it),
+        TableConstraintKind::CheckConstraint(it) => v.visit_check_constraint(it),
+        TableConstraintKind::TableFkConstraint(it) => v.visit_table_fk_constraint(it),
+    }
+}
+pub fn walk_table_details<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TableDetails<N>,
+) {
+    for it in node.columns() {
+        v.visit_column_def(&it);
+    }
+    for it in node.table_constraints() {
+        v.visit_table_constraint(&it);
+    }
+    if let Some(it) = node.table_options_list() {
+        v.visit_table_options_list(&it);
+    }
+}
+pub fn walk_table_fk_constraint<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TableFkConstraint<N>,
+) {
+    if let Some(it) = node.col_name_list() {
+        v.visit_col_name_list(&it);
+    }
+    if let Some(it) = node.fk_clause() {
+        v.visit_fk_clause(&it);
+    }
+}
+pub fn walk_table_function_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TableFunctionName<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_table_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TableName<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_table_name_indexed_by<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TableNameIndexedBy<N>,
+) {
+    if let Some(it) = node.index_name() {
+        v.visit_index_name(&it);
+    }
+}
+pub fn walk_table_name_not_indexed<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TableNameNotIndexed<N>,
+) {
+}
+pub fn walk_table_opt_without_row_id<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TableOptWithoutRowId<N>,
+) {
+}
+pub fn walk_table_options<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TableOptions<N>,
+) {
+    match node {
+        TableOptions::TableOptWithoutRowId(it) => v.visit_table_opt_without_row_id(it),
+        TableOptions::KW_STRICT(it) => v.visit_token(*it),
+    }
+}
+pub fn walk_table_options_list<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TableOptionsList<N>,
+) {
+    for it in node.items() {
+        v.visit_table_options(&it);
+    }
+}
+pub fn walk_table_or_idx_name_with_schema<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TableOrIdxNameWithSchema<N>,
+) {
+    if let Some(it) = node.schema_name() {
+        v.visit_schema_name(&it);
+    }
+    if let Some(it) = node.table_or_index_name() {
+        v.visit_table_or_index_name(&it);
+    }
+}
+pub fn walk_table_or_idx_or_collation_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TableOrIdxOrCollationName<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_table_or_index_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TableOrIndexName<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_table_or_subquery<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TableOrSubquery<N>,
+) {
+    for child in node.untyped().valid_children() {
+        visit_untyped(v, child);
+    }
+}
+pub fn walk_table_pk_constraint<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TablePkConstraint<N>,
+) {
+    for it in node.columns() {
+        v.visit_indexed_col(&it);
+    }
+    if let Some(it) = node.auto_increment() {
+        v.visit_token(it);
+    }
+    if let Some(it) = node.conflict_clause() {
+        v.visit_conflict_clause(&it);
+    }
+}
+pub fn walk_table_uq_constraint<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TableUqConstraint<N>,
+) {
+    if let Some(it) = node.indexed_col_list() {
+        v.visit_indexed_col_list(&it);
+    }
+    if let Some(it) = node.conflict_clause() {
+        v.visit_conflict_clause(&it);
+    }
+}
+pub fn walk_target<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &Target<N>,
+) {
+    match node {
+        Target::TableOrIdxOrCollationName(it) => v.visit_table_or_idx_or_collation_name(it),
+        Target::TableOrIdxNameWithSchema(it) => v.visit_table_or_idx_name_with_schema(it),
+    }
+}
+pub fn walk_temporary<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &Temporary<N>,
+) {
+    match node {
+        Temporary::KW_TEMP(it) => v.visit_token(*it),
+        Temporary::KW_TEMPORARY(it) => v.visit_token(*it),
+    }
+}
+pub fn walk_traditional_select<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TraditionalSelect<N>,
+) {
+    if let Some(it) = node.select_distinct() {
+        v.visit_select_distinct(&it);
+    }
+    if let Some(it) = node.result_column_list() {
+        v.visit_result_column_list(&it);
+    }
+    if let Some(it) = node.from_clause() {
+        v.visit_from_clause(&it);
+    }
+    if let Some(it) = node.where_clause() {
+        v.visit_where_clause(&it);
+    }
+    if let Some(it) = node.group_by_clause() {
+        v.visit_group_by_clause(&it);
+    }
+    if let Some(it) = node.having_clause() {
+        v.visit_having_clause(&it);
+    }
+    if let Some(it) = node.window_clause() {
+        v.visit_window_clause(&it);
+    }
+}
+pub fn walk_trigger_action_kind<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TriggerActionKind<N>,
+) {
+    match node {
+        TriggerActionKind::KW_DELETE(it) => v.visit_token(*it),
+        TriggerActionKind::KW_INSERT(it) => v.visit_token(*it),
+        TriggerActionKind::TriggerUpdateAction(it) => v.visit_trigger_update_action(it),
+    }
+}
+pub fn walk_trigger_body_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TriggerBodyStmt<N>,
+) {
+    match node {
+        TriggerBodyStmt::UpdateStmt(it) => v.visit_update_stmt(it),
+        TriggerBodyStmt::InsertStmt(it) => v.visit_insert_stmt(it),
+        TriggerBodyStmt::DeleteStmt(it) => v.visit_delete_stmt(it),
+        TriggerBodyStmt::SelectStmtWithCte(it) => v.visit_select_stmt_with_cte(it),
+    }
+}
+pub fn walk_trigger_body_stmt_list<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TriggerBodyStmtList<N>,
+) {
+    for it in node.items() {
+        v.visit_trigger_body_stmt(&it);
+    }
+}
+pub fn walk_trigger_for_each_row<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TriggerForEachRow<N>,
+) {
+}
+pub fn walk_trigger_instead_of<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TriggerInsteadOf<N>,
+) {
+}
+pub fn walk_trigger_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TriggerName<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_trigger_update_action<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TriggerUpdateAction<N>,
+) {
+    if let Some(it) = node.trigger_update_affect_cols() {
+        v.visit_trigger_update_affect_cols(&it);
+    }
+}
+pub fn walk_trigger_update_affect_cols<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TriggerUpdateAffectCols<N>,
+) {
+    for it in node.columns() {
+        v.visit_column_name(&it);
+    }
+}
+pub fn walk_trigger_when<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TriggerWhen<N>,
+) {
+    match node {
+        TriggerWhen::KW_BEFORE(it) => v.visit_token(*it),
+        TriggerWhen::KW_AFTER(it) => v.visit_token(*it),
+        TriggerWhen::TriggerInsteadOf(it) => v.visit_trigger_instead_of(it),
+    }
+}
+pub fn walk_trigger_when_expr<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TriggerWhenExpr<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_type_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TypeName<N>,
+) {
+    for it in node.words() {
+        v.visit_type_name_word(&it);
+    }
+    if let Some(it) = node.lhs_signed_number() {
+        v.visit_signed_number(&it);
+    }
+    if let Some(it) = node.rhs_signed_number() {
+        v.visit_signed_number(&it);
+    }
+}
+pub fn walk_type_name_word<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &TypeNameWord<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_union_compound_operator<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &UnionCompoundOperator<N>,
+) {
+    if let Some(it) = node.all() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_unique_constraint<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &UniqueConstraint<N>,
+) {
+    if let Some(it) = node.conflict_clause() {
+        v.visit_conflict_clause(&it);
+    }
+}
+pub fn walk_update_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &UpdateStmt<N>,
+) {
+    if let Some(it) = node.conflict_action() {
+        v.visit_conflict_action(&it);
+    }
+    if let Some(it) = node.qualified_table_name() {
+        v.visit_qualified_table_name(&it);
+    }
+    for it in node.set_expressions() {
+        v.visit_set_column_expr(&it);
+    }
+    if let Some(it) = node.from_clause() {
+        v.visit_from_clause(&it);
+    }
+    if let Some(it) = node.where_clause() {
+        v.visit_where_clause(&it);
+    }
+    if let Some(it) = node.returning_clause() {
+        v.visit_returning_clause(&it);
+    }
+    if let Some(it) = node.update_stmt_limited() {
+        v.visit_update_stmt_limited(&it);
+    }
+}
+pub fn walk_update_stmt_limited<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &UpdateStmtLimited<N>,
+) {
+    if let Some(it) = node.order_by_clause() {
+        v.visit_order_by_clause(&it);
+    }
+    if let Some(it) = node.limit_clause() {
+        v.visit_limit_clause(&it);
+    }
+}
+pub fn walk_upsert_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &UpsertClause<N>,
+) {
+    if let Some(it) = node.upsert_clause_conflict_target() {
+        v.visit_upsert_clause_conflict_target(&it);
+    }
+    if let Some(it) = node.upsert_clause_action() {
+        v.visit_upsert_clause_action(&it);
+    }
+}
+pub fn walk_upsert_clause_action<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &UpsertClauseAction<N>,
+) {
+    match node {
+        UpsertClauseAction::UpsertDoUpdate(it) => v.visit_upsert_do_update(it),
+        UpsertClauseAction::KW_NOTHING(it) => v.visit_token(*it),
+    }
+}
+pub fn walk_upsert_clause_conflict_target<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &UpsertClauseConflictTarget<N>,
+) {
+    if let Some(it) = node.indexed_col_list() {
+        v.visit_indexed_col_list(&it);
+    }
+    if let Some(it) = node.where_clause() {
+        v.visit_where_clause(&it);
+    }
+}
+pub fn walk_upsert_do_update<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &UpsertDoUpdate<N>,
+) {
+    for it in node.set_expressions() {
+        v.visit_set_column_expr(&it);
+    }
+    if let Some(it) = node.where_clause() {
+        v.visit_where_clause(&it);
+    }
+}
+pub fn walk_using_constraint<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &UsingConstraint<N>,
+) {
+    if let Some(it) = node.col_name_list() {
+        v.visit_col_name_list(&it);
+    }
+}
+pub fn walk_vacuum_stmt<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &VacuumStmt<N>,
+) {
+    if let Some(it) = node.schema_name() {
+        v.visit_schema_name(&it);
+    }
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_values_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ValuesClause<N>,
+) {
+    if let Some(it) = node.expr_list() {
+        v.visit_expr_list(&it);
+    }
+}
+pub fn walk_values_select<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ValuesSelect<N>,
+) {
+    for it in node.expr_lists() {
+        v.visit_expr_list(&it);
+    }
+}
+pub fn walk_view_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &ViewName<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_where_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &WhereClause<N>,
+) {
+    if let Some(it) = node.expr() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_window_base_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &WindowBaseName<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_window_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &WindowClause<N>,
+) {
+    for it in node.functions() {
+        v.visit_window_function(&it);
+    }
+}
+pub fn walk_window_def<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &WindowDef<N>,
+) {
+    if let Some(it) = node.window_base_name() {
+        v.visit_window_base_name(&it);
+    }
+    if let Some(it) = node.window_partition_by_clause() {
+        v.visit_window_partition_by_clause(&it);
+    }
+    if let Some(it) = node.order_by_clause() {
+        v.visit_order_by_clause(&it);
+    }
+    if let Some(it) = node.frame_spec() {
+        v.visit_frame_spec(&it);
+    }
+}
+pub fn walk_window_function<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &WindowFunction<N>,
+) {
+    if let Some(it) = node.window_name() {
+        v.visit_window_name(&it);
+    }
+    if let Some(it) = node.window_def() {
+        v.visit_window_def(&it);
+    }
+}
+pub fn walk_window_name<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &WindowName<N>,
+) {
+    if let Some(it) = node.value() {
+        v.visit_token(it);
+    }
+}
+pub fn walk_window_partition_by_clause<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &WindowPartitionByClause<N>,
+) {
+    for it in node.items() {
+        v.visit_expr(&it);
+    }
+}
+pub fn walk_with_alias<'a, N: CstNodeTrait<'a>, V: Visitor<'a, N> + ?Sized>(
+    v: &mut V,
+    node: &WithAlias<N>,
+) {
+    if let Some(it) = node.alias_name() {
+        v.visit_alias_name(&it);
+    }
+}
@@ -0,0 +1,122 @@
+use enumset::EnumSet;
+
+use crate::{grammar::common::IDEN_SET, SqliteTokenKind, SqliteVersion};
+
+/// Describes the build of SQLite that the parsed SQL is meant for. Compile-time options are
+/// documented at https://www.sqlite.org/compile.html
+///
+/// Apart from deciding which keywords fall back to identifiers (See [`ParserConfig::iden_set`]),
+/// the parser accepts every construct regardless of the config. Constructs unsupported by the
+/// build are reported by [`crate::check_compile_options`] so that users get a precise error
+/// instead of a generic parse error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ParserConfig {
+    pub version: SqliteVersion,
+    /// `SQLITE_ENABLE_UPDATE_DELETE_LIMIT`: allows `ORDER BY` and `LIMIT` in DELETE and UPDATE
+    pub enable_update_delete_limit: bool,
+    /// `SQLITE_OMIT_ALTERTABLE`
+    pub omit_alter_table: bool,
+    /// `SQLITE_OMIT_ANALYZE`
+    pub omit_analyze: bool,
+    /// `SQLITE_OMIT_ATTACH`: removes ATTACH and DETACH
+    pub omit_attach: bool,
+    /// `SQLITE_OMIT_COMPOUND_SELECT`: `UNION`, `INTERSECT` and `EXCEPT` become identifiers
+    pub omit_compound_select: bool,
+    /// `SQLITE_OMIT_CTE`
+    pub omit_cte: bool,
+    /// `SQLITE_OMIT_EXPLAIN`
+    pub omit_explain: bool,
+    /// `SQLITE_OMIT_GENERATED_COLUMNS`
+    pub omit_generated_columns: bool,
+    /// `SQLITE_OMIT_PRAGMA`
+    pub omit_pragma: bool,
+    /// `SQLITE_OMIT_REINDEX`
+    pub omit_reindex: bool,
+    /// `SQLITE_OMIT_TRIGGER`
+    pub omit_trigger: bool,
+    /// `SQLITE_OMIT_VACUUM`
+    pub omit_vacuum: bool,
+    /// `SQLITE_OMIT_VIRTUALTABLE`
+    pub omit_virtual_table: bool,
+    /// `SQLITE_OMIT_WINDOWFUNC`
+    pub omit_window_func: bool,
+    /// Not a compile-time option: the SQL is a template (Ex: dbt models or sqlc queries).
+    /// `{{ ... }}` and `sqlc.arg(...)` are lexed as [`SqliteTokenKind::TEMPLATE_EXPR`], which is
+    /// accepted wherever an identifier is, while `{% ... %}` and `{# ... #}` are trivia.
+    pub templates: bool,
+}
+
+impl ParserConfig {
+    pub fn with_version(version: SqliteVersion) -> Self {
+        Self {
+            version,
+            ..Default::default()
+        }
+    }
+
+    /// Enables a compile-time option by its name (Ex: `SQLITE_OMIT_CTE`). The `SQLITE_` prefix
+    /// is optional.
+    pub fn set_compile_option(&mut self, name: &str) -> Result<(), String> {
+        let option = name.trim().to_ascii_uppercase();
+        let option = option.strip_prefix("SQLITE_").unwrap_or(&option);
+
+        let flag = match option {
+            "ENABLE_UPDATE_DELETE_LIMIT" => &mut self.enable_update_delete_limit,
+            "OMIT_ALTERTABLE" => &mut self.omit_alter_table,
+            "OMIT_ANALYZE" => &mut self.omit_analyze,
+            "OMIT_ATTACH" => &mut self.omit_attach,
+            "OMIT_COMPOUND_SELECT" => &mut self.omit_compound_select,
+            "OMIT_CTE" => &mut self.omit_cte,
+            "OMIT_EXPLAIN" => &mut self.omit_explain,
+            "OMIT_GENERATED_COLUMNS" => &mut self.omit_generated_columns,
+            "OMIT_PRAGMA" => &mut self.omit_pragma,
+            "OMIT_REINDEX" => &mut self.omit_reindex,
+            "OMIT_TRIGGER" => &mut self.omit_trigger,
+            "OMIT_VACUUM" => &mut self.omit_vacuum,
+            "OMIT_VIRTUALTABLE" => &mut self.omit_virtual_table,
+            "OMIT_WINDOWFUNC" => &mut self.omit_window_func,
+            _ => return Err(format!("Unsupported compile option: {name}")),
+        };
+
+        *flag = true;
+
+        Ok(())
+    }
+
+    /// Same as the `ID` terminal in SQLite's parse.y, whose `%fallback` list depends on the
+    /// compile-time options
+    pub fn iden_set(&self) -> EnumSet<SqliteTokenKind> {
+        use SqliteTokenKind::*;
+
+        let mut iden_set = IDEN_SET;
+
+        if !self.omit_compound_select {
+            iden_set = iden_set.difference(KW_EXCEPT | KW_INTERSECT | KW_UNION);
+        }
+
+        if self.omit_window_func {
+            iden_set = iden_set.difference(
+                KW_CURRENT
+                    | KW_FOLLOWING
+                    | KW_PARTITION
+                    | KW_PRECEDING
+                    | KW_RANGE
+                    | KW_UNBOUNDED
+                    | KW_EXCLUDE
+                    | KW_GROUPS
+                    | KW_OTHERS
+                    | KW_TIES,
+            );
+        }
+
+        if self.omit_generated_columns {
+            iden_set = iden_set.difference(KW_GENERATED | KW_ALWAYS);
+        }
+
+        if self.templates {
+            iden_set |= TEMPLATE_EXPR;
+        }
+
+        iden_set
+    }
+}
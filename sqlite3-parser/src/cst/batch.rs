@@ -182,6 +182,14 @@ impl<'a> CstNodeTrait<'a> for CstNode<'a> {
         (start..end).map(move |id| self.cst.node(id.into()))
     }
 
+    fn child_at_offset(&self, offset: TextSize) -> Option<Self> {
+        let children = self.children_slice();
+        let idx = children
+            .partition_point(|&it| self.cst.abs_pos + self.cst.data[it].relative_pos <= offset);
+
+        idx.checked_sub(1).map(|idx| self.cst.node(children[idx]))
+    }
+
     fn is_root(&self) -> bool {
         self.id == NodeId(0)
     }
@@ -203,6 +203,10 @@ impl IncrSqlCst {
         self.branches.len() - 1
     }
 
+    pub(crate) fn abs_pos(&self) -> TextSize {
+        self.abs_pos
+    }
+
     fn has_branch(&self, branch_id: usize) -> bool {
         branch_id < self.num_branches() && branch_id > 0
     }
@@ -244,7 +248,7 @@ impl IncrSqlCst {
                     .find(|it| it.token_kind() == Some(T![;]))
             })
             .map(|it| it.end_pos())
-            .unwrap_or(0.into());
+            .unwrap_or(self.abs_pos);
 
         let affected_node_byte_len = match patch {
             TextPatch {
@@ -387,6 +391,98 @@ impl IncrSqlCst {
 
         modified_info
     }
+
+    /// Replaces the subtree of `node` with the only child of the root of `subtree`, which must
+    /// be parsed with an `abs_pos` of 0. Unlike [`IncrSqlCst::merge_cst`], only the branch that
+    /// contains `node` is rebuilt (no other node ids change).
+    ///
+    /// `old_len` is the byte length of the subtree being replaced. Panics if `node` is the root
+    /// of a branch.
+    pub(crate) fn replace_subtree(
+        &mut self,
+        node: FatId,
+        old_len: TextSize,
+        subtree: &batch::SqlCst,
+    ) {
+        let FatId { branch_id, id } = node;
+        assert!(id != NodeId(0), "Branch roots cannot be replaced");
+
+        let CstBranch::Tree {
+            data,
+            parents,
+            children,
+        } = &mut self.branches[branch_id]
+        else {
+            unreachable!("Token branches have no descendants");
+        };
+
+        let start = usize::from(id);
+        let end = {
+            let mut last = id;
+            while let Some(&child) = children[last].last() {
+                last = child;
+            }
+            usize::from(last) + 1
+        };
+
+        let new_count = subtree.data.len() - 1;
+        let new_len = subtree.byte_len;
+        let node_pos = data[id].relative_pos;
+        let parent = parents[id];
+
+        // Old ids after the subtree move to make space for the new nodes. New ids are offset by
+        // the position of the subtree (minus one since the root of `subtree` is skipped)
+        let shift = |it: NodeId| {
+            let it = usize::from(it);
+            if it >= end {
+                NodeId::new(it - end + start + new_count)
+            } else {
+                NodeId::new(it)
+            }
+        };
+        let from_subtree = |it: batch::NodeId| NodeId::new(usize::from(it) + start - 1);
+
+        data.splice(
+            start..end,
+            subtree.data[1..].iter().map(|it| CstNodeData {
+                relative_pos: node_pos + it.relative_pos,
+                kind: it.kind.clone(),
+            }),
+        );
+        parents.splice(
+            start..end,
+            subtree.parent[1..].iter().map(|&it| {
+                if usize::from(it) == 0 {
+                    parent
+                } else {
+                    Some(from_subtree(it))
+                }
+            }),
+        );
+        children.splice(
+            start..end,
+            subtree.children[1..]
+                .iter()
+                .map(|it| it.iter().map(|&it| from_subtree(it)).collect()),
+        );
+
+        let tail = start + new_count;
+        for it in &mut data[tail..] {
+            it.relative_pos = it.relative_pos - old_len + new_len;
+        }
+        for it in parents[tail..].iter_mut().flatten() {
+            *it = shift(*it);
+        }
+        let (before, after) = children.split_at_mut(start);
+        for list in before.iter_mut().chain(&mut after[new_count..]) {
+            list.iter_mut().for_each(|it| *it = shift(*it));
+        }
+
+        self.byte_len = self.byte_len - old_len + new_len;
+        self.branch_positions[usize::from(branch_id) + 1..]
+            .iter_mut()
+            .for_each(|it| *it = *it - old_len + new_len);
+    }
 }
 
 impl CstTrait for IncrSqlCst {
@@ -402,7 +498,7 @@ impl CstTrait for IncrSqlCst {
         let mut branch_positions = Vec::with_capacity(10);
         // First branch is a special branch that belongs to the root
         branches.push(CstBranch::root_branch());
-        branch_positions.push(TextSize::new(0));
+        branch_positions.push(abs_pos);
 
         IncrSqlCst {
             branches,
@@ -655,6 +751,25 @@ impl<'a> CstNodeTrait<'a> for IncrCstNode<'a> {
         }
     }
 
+    fn child_at_offset(&self, offset: TextSize) -> Option<Self> {
+        let FatId { branch_id, id } = self.fat_id;
+
+        if self.is_root() {
+            // Every child of the root is the root of a branch
+            let idx = self.cst.branch_positions[1..].partition_point(|&it| it <= offset);
+
+            (idx > 0).then(|| self.cst.branch_root(BranchId::new(idx)))
+        } else {
+            let branch = &self.cst.branches[branch_id];
+            let children = branch.children_slice(id);
+            let idx = children
+                .partition_point(|&it| self.offset() + branch.data(it).relative_pos <= offset);
+
+            idx.checked_sub(1)
+                .map(|idx| self.cst.node(branch_id, children[idx]))
+        }
+    }
+
     fn me_and_descendants(self) -> impl DoubleEndedIterator<Item = IncrCstNode<'a>> {
         if self.is_root() {
             Either::Left(
@@ -2,13 +2,16 @@
 
 pub mod batch;
 pub mod incr;
+mod ptr;
 pub mod slot;
 pub mod slot_list;
+pub use ptr::{NodePtr, NodePtrKind};
 use smol_str::SmolStr;
-use text_size::TextSize;
+use text_size::{TextRange, TextSize};
 
 use crate::{
-    parser::{Event, ParseErrorKind},
+    named_query::{statement_annotation, AnnotatedStatement},
+    parser::{Event, ExpectedItem, ParseErrorKind},
     SqliteTokenKind, SqliteTreeKind,
 };
 
@@ -54,6 +57,42 @@ pub enum LexError {
     UnterminatedQuotedIdentifier,
     UnterminatedStringLiteral,
     MalformedParam,
+    UnterminatedTemplate,
+}
+
+impl LexError {
+    /// A stable identifier for the error (See [`ParseErrorKind::code`])
+    pub const fn code(&self) -> &'static str {
+        match self {
+            LexError::UnknownToken => "L0001",
+            LexError::UnterminatedBlobLiteral => "L0002",
+            LexError::MalformedBlobLiteral => "L0003",
+            LexError::TrailingJunkAfterNumericLiteral => "L0004",
+            LexError::UnterminatedQuotedIdentifier => "L0005",
+            LexError::UnterminatedStringLiteral => "L0006",
+            LexError::MalformedParam => "L0007",
+            LexError::UnterminatedTemplate => "L0008",
+        }
+    }
+}
+
+impl std::fmt::Display for LexError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let msg = match self {
+            LexError::UnknownToken => "unrecognized token",
+            LexError::UnterminatedBlobLiteral => "unterminated blob literal",
+            LexError::MalformedBlobLiteral => {
+                "malformed blob literal: expected an even number of hexadecimal digits"
+            }
+            LexError::TrailingJunkAfterNumericLiteral => "trailing junk after numeric literal",
+            LexError::UnterminatedQuotedIdentifier => "unterminated quoted identifier",
+            LexError::UnterminatedStringLiteral => "unterminated string literal",
+            LexError::MalformedParam => "expected a parameter name after the prefix",
+            LexError::UnterminatedTemplate => "unterminated template tag",
+        };
+
+        f.write_str(msg)
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
@@ -115,10 +154,32 @@ pub trait CstTrait: std::fmt::Display {
             .filter(|it| it.error().is_some())
     }
 
-    fn statements<'a>(&'a self) -> impl Iterator<Item = Self::Node<'a>> {
+    /// Tokens the lexer could not make sense of (they carry a [`LexError`])
+    fn lex_errors<'a>(&'a self) -> impl DoubleEndedIterator<Item = Self::Node<'a>> {
         self.root()
-            .children()
-            .filter(|it| it.tree() == Some(SqliteTreeKind::Statement))
+            .me_and_descendants()
+            .filter(|it| it.token().is_some_and(|tk| tk.error.is_some()))
+    }
+
+    /// Top-level statements along with the sqlc-style annotation (`-- name: GetUser :one`) in the
+    /// comments before them
+    fn statements<'a>(&'a self) -> impl Iterator<Item = AnnotatedStatement<Self::Node<'a>>> {
+        let mut annotation = None;
+
+        self.root().children().filter_map(move |it| {
+            if it.tree() == Some(SqliteTreeKind::Statement) {
+                return Some(AnnotatedStatement {
+                    node: it,
+                    annotation: annotation.take(),
+                });
+            }
+
+            if let Some(it) = statement_annotation(it) {
+                annotation = Some(it);
+            }
+
+            None
+        })
     }
 
     fn to_events_and_tokens<'a>(&'a self) -> (Vec<Event>, Vec<SqliteToken>) {
@@ -134,6 +195,51 @@ pub trait CstTrait: std::fmt::Display {
         crate::ast::File::cast(self.root()).unwrap()
     }
 
+    /// The token(s) at `offset`. If `offset` is on the boundary between two tokens, both are
+    /// returned (use [`TokenAtOffset::left_biased`] or [`TokenAtOffset::right_biased`] to pick
+    /// one)
+    fn token_at_offset<'a>(&'a self, offset: TextSize) -> TokenAtOffset<Self::Node<'a>> {
+        let root = self.root();
+        let right = root.token_containing(offset);
+        let left = offset
+            .checked_sub(TextSize::new(1))
+            .and_then(|offset| root.token_containing(offset));
+
+        match (left, right) {
+            (Some(left), Some(right)) if left.equals(&right) => TokenAtOffset::Single(right),
+            (Some(left), Some(right)) => TokenAtOffset::Between(left, right),
+            (Some(it), None) | (None, Some(it)) => TokenAtOffset::Single(it),
+            (None, None) => TokenAtOffset::None,
+        }
+    }
+
+    /// The smallest node that contains all of `range` (trivia included). Falls back to the root
+    /// if `range` is not inside the file.
+    fn covering_element<'a>(&'a self, range: TextRange) -> Self::Node<'a> {
+        let root = self.root();
+
+        if range.is_empty() {
+            return match self.token_at_offset(range.start()).right_biased() {
+                Some(token) => token,
+                None => root,
+            };
+        }
+
+        let first = root.token_containing(range.start());
+        let last = root.token_containing(range.end() - TextSize::new(1));
+
+        let (Some(first), Some(last)) = (first, last) else {
+            return root;
+        };
+
+        let first_ancestors: Vec<_> = std::iter::once(first).chain(first.ancestors()).collect();
+
+        std::iter::once(last)
+            .chain(last.ancestors())
+            .find(|it| first_ancestors.iter().any(|ancestor| ancestor.equals(it)))
+            .unwrap_or(root)
+    }
+
     fn build<'a>(
         abs_pos: TextSize,
         all_tokens: impl Iterator<Item = SqliteToken>,
@@ -329,8 +435,8 @@ where
             .filter(|it| it.error().is_none())
     }
 
-    fn find_children(&self, key: impl ChildNodeKey) -> impl Iterator<Item = Self> {
-        key.find_children(*self)
+    fn find_children(self, key: impl ChildNodeKey) -> impl Iterator<Item = Self> {
+        key.find_children(self)
     }
 
     fn find_child_by_tag(&self, tag: SqliteTreeTag) -> Option<Self> {
@@ -346,6 +452,122 @@ where
     /// Panics if node is root
     fn right_siblings(&self) -> impl DoubleEndedIterator<Item = Self>;
 
+    /// Human readable message for an error node. The closest preceding sibling is used as
+    /// context. Ex: "expected `,` or `)` after column definition"
+    fn error_message(&self) -> Option<String> {
+        let err = self.error()?;
+
+        if !matches!(err, ParseErrorKind::ExpectedItems(_)) || self.is_root() {
+            return Some(err.to_string());
+        }
+
+        let context = self
+            .left_siblings()
+            .rev()
+            .find(|it| !it.is_trivia())
+            .and_then(|it| match &it.data().kind {
+                CstNodeDataKind::Tree(kind, _) => Some(ExpectedItem::Tree(*kind)),
+                CstNodeDataKind::Token(tk) => Some(ExpectedItem::Token(tk.kind)),
+                CstNodeDataKind::Error(_) => None,
+            });
+
+        let mut message = match context {
+            Some(context) => format!("{err} after {context}"),
+            None => err.to_string(),
+        };
+
+        if let Some((_, keyword)) = self.keyword_typo() {
+            message.push_str(&format!("; did you mean `{}`?", keyword.as_str()));
+        }
+
+        Some(message)
+    }
+
+    /// For error nodes caused by a misspelled keyword (Ex: `SELCT`), returns the misspelled
+    /// token along with the keyword that was most likely meant
+    fn keyword_typo(&self) -> Option<(Self, SqliteTokenKind)> {
+        let err = self.error()?;
+        let token = self
+            .me_and_descendants()
+            .find(|it| it.is_non_trivia_token())
+            .filter(|it| it.token_kind() == Some(SqliteTokenKind::IDEN))?;
+
+        let keyword = err.keyword_suggestion(&token.token()?.text)?;
+
+        Some((token, keyword))
+    }
+
+    /// Range to highlight for an error node. Errors that skipped over tokens cover those
+    /// tokens. Errors for missing items are empty, so we point at the token right before
+    /// them (or right after them if they are at the very start)
+    fn error_range(&self) -> Option<TextRange> {
+        self.error()?;
+
+        let token_range = |it: Self| TextRange::new(it.start_pos(), it.end_pos());
+
+        if self.me_and_descendants().any(|it| it.is_non_trivia_token()) {
+            return Some(TextRange::new(
+                self.start_pos_skip_trivia(),
+                self.end_pos_skip_trivia(),
+            ));
+        }
+
+        let range = self
+            .prev_token_skip_trivia()
+            .or_else(|| self.next_token_skip_trivia())
+            .map(token_range)
+            .unwrap_or_else(|| TextRange::empty(self.start_pos()));
+
+        Some(range)
+    }
+
+    fn is_non_trivia_token(&self) -> bool {
+        self.token().is_some_and(|it| !it.is_trivia())
+    }
+
+    /// The closest non-trivial token that ends before this node starts
+    fn prev_token_skip_trivia(&self) -> Option<Self> {
+        let mut curr = *self;
+
+        while !curr.is_root() {
+            let prev = curr.left_siblings().rev().find_map(|sibling| {
+                sibling
+                    .me_and_descendants()
+                    .rev()
+                    .find(|it| it.is_non_trivia_token())
+            });
+
+            if prev.is_some() {
+                return prev;
+            }
+
+            curr = curr.parent();
+        }
+
+        None
+    }
+
+    /// The closest non-trivial token that starts after this node ends
+    fn next_token_skip_trivia(&self) -> Option<Self> {
+        let mut curr = *self;
+
+        while !curr.is_root() {
+            let next = curr.right_siblings().find_map(|sibling| {
+                sibling
+                    .me_and_descendants()
+                    .find(|it| it.is_non_trivia_token())
+            });
+
+            if next.is_some() {
+                return next;
+            }
+
+            curr = curr.parent();
+        }
+
+        None
+    }
+
     fn has_errors(&self) -> bool {
         self.me_and_descendants()
             .find_map(|it| it.error())
@@ -371,6 +593,23 @@ where
 
     fn me_and_descendants(self) -> impl DoubleEndedIterator<Item = Self>;
 
+    /// The last child that starts at or before `offset`. If any child contains `offset`, it is
+    /// this one.
+    fn child_at_offset(&self, offset: TextSize) -> Option<Self>;
+
+    /// The token containing `offset` (A token contains its start but not its end). Descends the
+    /// tree using [`CstNodeTrait::child_at_offset`].
+    fn token_containing(&self, offset: TextSize) -> Option<Self> {
+        let mut curr = *self;
+
+        while curr.token().is_none() {
+            curr = curr.child_at_offset(offset)?;
+        }
+
+        let start = curr.start_pos();
+        (start <= offset && offset < start + curr.token()?.text_len()).then_some(curr)
+    }
+
     fn is_root(&self) -> bool;
 
     fn is_trivia(&self) -> bool {
@@ -523,6 +762,50 @@ impl std::fmt::Display for CstNodeData {
     }
 }
 
+/// Result of [`CstTrait::token_at_offset`]
+#[derive(Debug, Clone, Copy)]
+pub enum TokenAtOffset<N> {
+    None,
+    Single(N),
+    /// `offset` is at the end of the first token and at the start of the second
+    Between(N, N),
+}
+
+impl<N> TokenAtOffset<N> {
+    /// Prefers the token that ends at the offset (Ex: the identifier being typed at the cursor)
+    pub fn left_biased(self) -> Option<N> {
+        match self {
+            TokenAtOffset::None => None,
+            TokenAtOffset::Single(it) => Some(it),
+            TokenAtOffset::Between(left, _) => Some(left),
+        }
+    }
+
+    /// Prefers the token that starts at the offset
+    pub fn right_biased(self) -> Option<N> {
+        match self {
+            TokenAtOffset::None => None,
+            TokenAtOffset::Single(it) => Some(it),
+            TokenAtOffset::Between(_, right) => Some(right),
+        }
+    }
+}
+
+impl<N> Iterator for TokenAtOffset<N> {
+    type Item = N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match std::mem::replace(self, TokenAtOffset::None) {
+            TokenAtOffset::None => None,
+            TokenAtOffset::Single(it) => Some(it),
+            TokenAtOffset::Between(left, right) => {
+                *self = TokenAtOffset::Single(right);
+                Some(left)
+            }
+        }
+    }
+}
+
 pub struct AncestorIter<N> {
     curr: N,
 }
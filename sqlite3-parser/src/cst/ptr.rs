@@ -0,0 +1,112 @@
+use text_size::TextRange;
+
+use crate::{SqliteTokenKind, SqliteTreeKind};
+
+use super::{CstNodeDataKind, CstNodeTrait, CstTrait, TextPatch, TextPatchKind};
+
+/// What a [`NodePtr`] points to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NodePtrKind {
+    Tree(SqliteTreeKind),
+    Token(SqliteTokenKind),
+    Error,
+}
+
+/// A handle to a node that, unlike node ids, can be kept across document versions (node ids
+/// are invalidated when the incremental parser splices in new branches).
+///
+/// The pointer stores the kind and the text range of the node. Use [`NodePtr::apply_patch`] to
+/// keep it up to date with edits to the text and [`NodePtr::resolve`] to get the node back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodePtr {
+    kind: NodePtrKind,
+    range: TextRange,
+}
+
+impl NodePtr {
+    pub fn new<'a>(node: impl CstNodeTrait<'a>) -> Self {
+        let kind = match &node.data().kind {
+            CstNodeDataKind::Tree(tree, _) => NodePtrKind::Tree(*tree),
+            CstNodeDataKind::Token(token) => NodePtrKind::Token(token.kind),
+            CstNodeDataKind::Error(_) => NodePtrKind::Error,
+        };
+
+        Self {
+            kind,
+            range: TextRange::new(node.start_pos(), node.end_pos()),
+        }
+    }
+
+    pub fn kind(&self) -> NodePtrKind {
+        self.kind
+    }
+
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+
+    /// Moves the pointer to account for the text patch. Returns `None` if the patch changes the
+    /// text of the node, in which case the node it pointed to is gone.
+    ///
+    /// Insertions right before or right after the node do not affect it.
+    pub fn apply_patch<T, B>(&self, patch: &TextPatch<T, B>) -> Option<NodePtr> {
+        let end = match patch.kind {
+            TextPatchKind::Insert => patch.start,
+            TextPatchKind::Replace { end } => end,
+        };
+
+        if end <= self.range.start() {
+            // `end - patch.start` bytes were replaced by `patch.size` bytes
+            let start = self.range.start() - (end - patch.start) + patch.size;
+
+            Some(NodePtr {
+                kind: self.kind,
+                range: TextRange::at(start, self.range.len()),
+            })
+        } else if patch.start >= self.range.end() {
+            Some(*self)
+        } else {
+            None
+        }
+    }
+
+    /// Finds the node this pointer points to. Returns `None` if there is no such node (Ex: the
+    /// pointer was made for a different version of the document and not kept up to date).
+    pub fn resolve<'a, CST: CstTrait>(&self, cst: &'a CST) -> Option<CST::Node<'a>> {
+        let mut curr = cst.root();
+
+        loop {
+            if self.matches(curr) {
+                return Some(curr);
+            }
+
+            let child = curr.child_at_offset(self.range.start())?;
+
+            // Empty nodes (Ex: errors) start at the same position as the node after them
+            let child_start = child.start_pos();
+            let empty_siblings = child
+                .left_siblings()
+                .rev()
+                .take_while(|it| it.start_pos() == child_start);
+
+            for sibling in empty_siblings {
+                if self.matches(sibling) {
+                    return Some(sibling);
+                }
+            }
+
+            curr = child;
+        }
+    }
+
+    fn matches<'a>(&self, node: impl CstNodeTrait<'a>) -> bool {
+        let kind_matches = match (&node.data().kind, self.kind) {
+            (CstNodeDataKind::Tree(tree, _), NodePtrKind::Tree(kind)) => *tree == kind,
+            (CstNodeDataKind::Token(token), NodePtrKind::Token(kind)) => token.kind == kind,
+            (CstNodeDataKind::Error(_), NodePtrKind::Error) => true,
+            _ => false,
+        };
+
+        kind_matches && node.start_pos() == self.range.start() && node.end_pos() == self.range.end()
+    }
+}
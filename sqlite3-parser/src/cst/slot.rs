@@ -747,6 +747,26 @@ impl<'a> CstNodeTrait<'a> for SlotCstNode<'a> {
         }
     }
 
+    /// KNOWN LIMIT: At the root this is linear in the number of statements. Branches are kept in
+    /// a linked list and `branch_positions` is keyed by branch, so there is no ordered index to
+    /// binary search. Keeping one would mean updating every later entry on each edit, which is
+    /// what [`super::incr::IncrSqlCst`] (the CST the server uses) does instead.
+    fn child_at_offset(&self, offset: TextSize) -> Option<Self> {
+        if self.is_root() {
+            // Statements are usually appended at the end of a file, so we search from the back
+            self.children().rev().find(|it| it.offset() <= offset)
+        } else {
+            let NodeId { branch_key, id } = self.fat_id;
+            let branch = &self.cst.branches[branch_key];
+            let children = branch.children_slice(id);
+            let idx = children
+                .partition_point(|&it| self.offset() + branch.data(it).relative_pos <= offset);
+
+            idx.checked_sub(1)
+                .map(|idx| self.cst.node(NodeId::new(branch_key, children[idx])))
+        }
+    }
+
     fn me_and_descendants(self) -> impl DoubleEndedIterator<Item = SlotCstNode<'a>> {
         if self.is_root() {
             Either::Left(
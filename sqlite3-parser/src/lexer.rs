@@ -1,8 +1,8 @@
 use std::str::Chars;
 
 use crate::{
-    grammar::common::IDEN_SET, sqlite_keywords, LexError, SqliteToken, SqliteTokenKind,
-    SqliteVersion, MAX_KEYWORD_LEN,
+    grammar::common::IDEN_SET, sqlite_keywords, LexError, ParserConfig, SqliteToken,
+    SqliteTokenKind, SqliteVersion, MAX_KEYWORD_LEN,
 };
 
 use SqliteTokenKind::*;
@@ -19,6 +19,8 @@ pub struct SqliteLexer<'a> {
     prev_non_triv_tk: Option<SqliteTokenKind>, // Needed for now to deal with ambiguities with Window, Filter and Over keywords
     cursor: TokenBuilder<'a>,
     version: SqliteVersion,
+    /// Lex template tags and sqlc macros (See [`ParserConfig::templates`])
+    templates: bool,
 }
 
 impl<'a> Iterator for SqliteLexer<'a> {
@@ -40,6 +42,14 @@ impl<'a> SqliteLexer<'a> {
             cursor: TokenBuilder::new(input),
             input,
             version,
+            templates: false,
+        }
+    }
+
+    pub fn with_config(input: &str, config: ParserConfig) -> SqliteLexer<'_> {
+        SqliteLexer {
+            templates: config.templates,
+            ..SqliteLexer::new(input, config.version)
         }
     }
 
@@ -88,6 +98,8 @@ impl<'a> SqliteLexer<'a> {
 
         // This is 3-tuple long because that's our longest 'fixed' token
         let tk_tuple = (first, self.cursor.second(), self.cursor.third());
+        let templates = self.templates;
+        let at_sqlc_macro = templates && is_sqlc_macro_start(self.input);
 
         let mut build_token = |kind: SqliteTokenKind| {
             assert!(self.cursor_is_fresh());
@@ -98,6 +110,8 @@ impl<'a> SqliteLexer<'a> {
 
         let token = match tk_tuple {
             (c, ..) if c.is_whitespace() => self.process_whitespace(),
+            ('{', Some('{' | '%' | '#'), ..) if templates => self.process_template_tag(),
+            ('s', ..) if at_sqlc_macro => self.process_sqlc_macro(),
             ('"', ..) | ('`', ..) | ('[', ..) => self.process_quoted_identifier(),
             ('-', Some('>'), Some('>')) => build_token(EXTRACT_TWO),
             ('-', Some('>'), ..) => build_token(EXTRACT_ONE),
@@ -291,6 +305,48 @@ impl<'a> SqliteLexer<'a> {
         self.build_token(M_LINE_COMMENT)
     }
 
+    /// Jinja's `{{ expr }}`, `{% stmt %}` and `{# comment #}`. Everything up to the closing
+    /// delimiter is part of the token.
+    fn process_template_tag(&mut self) -> SqliteToken {
+        assert!(self.cursor_is_fresh());
+
+        let (close, kind) = match self.cursor.second() {
+            Some('{') => ("}}", TEMPLATE_EXPR),
+            Some('%') => ("%}", TEMPLATE_STMT),
+            Some('#') => ("#}", TEMPLATE_STMT),
+            _ => unreachable!(),
+        };
+        self.cursor.advance_by(2);
+
+        while !self.input[self.cursor.curr_byte_len..].starts_with(close) {
+            if self.cursor.next().is_none() {
+                return self.build_err_token(LexError::UnterminatedTemplate, false);
+            }
+        }
+        self.cursor.advance_by(2);
+
+        self.build_token(kind)
+    }
+
+    /// sqlc's `sqlc.arg(name)`, `sqlc.narg(name)`, `sqlc.slice(name)` and so on
+    fn process_sqlc_macro(&mut self) -> SqliteToken {
+        assert!(self.cursor_is_fresh());
+
+        self.cursor.advance_while(|ch| ch != '(');
+
+        let mut depth = 0;
+        while let Some(ch) = self.cursor.next() {
+            match ch {
+                '(' => depth += 1,
+                ')' if depth == 1 => return self.build_token(TEMPLATE_EXPR),
+                ')' => depth -= 1,
+                _ => {}
+            }
+        }
+
+        self.build_err_token(LexError::UnterminatedTemplate, false)
+    }
+
     fn process_param(&mut self) -> SqliteToken {
         assert!(self.cursor_is_fresh());
         match self.cursor.next() {
@@ -582,6 +638,16 @@ pub(crate) fn is_identifier_continue(ch: char) -> bool {
     ch == '$' || ch.is_ascii_alphanumeric() || ch == '_' || ch > '\u{7F}'
 }
 
+/// `sqlc.` followed by a name and an opening parenthesis
+fn is_sqlc_macro_start(input: &str) -> bool {
+    let Some(rest) = input.strip_prefix("sqlc.") else {
+        return false;
+    };
+    let name_len = rest.len() - rest.trim_start_matches(is_identifier_continue).len();
+
+    name_len > 0 && rest[name_len..].starts_with('(')
+}
+
 #[inline(always)]
 pub(crate) fn is_separate_token_start(ch: char) -> bool {
     !is_identifier_start(ch) && ch != '$'
@@ -739,4 +805,39 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn can_lex_templates() {
+        let config = ParserConfig {
+            templates: true,
+            ..Default::default()
+        };
+        let kinds = |input: &str| {
+            SqliteLexer::with_config(input, config)
+                .map(|it| it.kind)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            kinds("{{ ref('users') }}{% if x %}{# } #}sqlc.arg(name) sqlc.foo"),
+            [
+                TEMPLATE_EXPR,
+                TEMPLATE_STMT,
+                TEMPLATE_STMT,
+                TEMPLATE_EXPR,
+                WHITESPACE,
+                IDEN,
+                DOT,
+                IDEN
+            ]
+        );
+        assert_eq!(kinds("sqlc.slice((ids)"), [ERROR]);
+        assert_eq!(kinds("{{ x }"), [ERROR]);
+
+        // Templates are opt-in
+        check!(
+            "{{ x }}",
+            [ERROR, ERROR, WHITESPACE, IDEN, WHITESPACE, ERROR, ERROR]
+        );
+    }
 }
@@ -2,14 +2,19 @@
 //! resillient parsing [guide]((https://matklad.github.io/2023/05/21/resilient-ll-parsing-tutorial.html))
 //! by matklad
 
-mod ast;
+pub mod ast;
+mod config;
 mod cst;
 mod grammar;
 mod lexer;
+mod named_query;
 mod parser;
+mod reparse;
+mod rewrite;
 mod token_kind;
 mod tree_kind;
 pub mod ungram;
+mod validation;
 mod version;
 
 pub use cst::*;
@@ -30,7 +35,20 @@ pub use token_kind::{sqlite_keywords, SqliteTokenKind, MAX_KEYWORD_LEN};
 
 pub use tree_kind::SqliteTreeKind;
 
-pub use version::SqliteVersion;
+pub use config::ParserConfig;
+
+pub use reparse::reparse_block;
+
+pub use rewrite::{CstRewriter, RewriteError, TextEdit};
+
+pub use named_query::{
+    named_queries, AnnotatedStatement, NamedQuery, QueryAnnotation, QueryColumn, QueryCommand,
+    QueryParam,
+};
+
+pub use version::{SqliteFeature, SqliteVersion};
+
+pub use validation::{check_compile_options, check_version, validate, ValidationError};
 
 pub use parser::{
     ExpectedItem, NormalLexer, OnDemandLexer, ParseErrorKind, SqliteParseError, SqliteParser,
@@ -38,15 +56,25 @@ pub use parser::{
 
 pub use lexer::SqliteLexer;
 pub use text_size;
-// pub mod ast;
 
 #[cfg(feature = "test_utils")]
 pub mod test_utils;
 
 pub fn parse_with_abs_pos<CST: CstTrait>(abs_pos: TextSize, text: &str) -> CST {
-    let lexer = SqliteLexer::new(text, SqliteVersion([3, 46, 0]));
+    parse_with_abs_pos_and_config(abs_pos, text, ParserConfig::default())
+}
 
-    let mut p = SqliteParser::with_abs_pos(NormalLexer::from(lexer), abs_pos);
+/// The version only affects lexing (Ex: underscores in numeric literals). Syntax that is too new
+/// for the given version (or not part of the SQLite build) is still parsed and is reported by
+/// [`validate`] instead.
+pub fn parse_with_abs_pos_and_config<CST: CstTrait>(
+    abs_pos: TextSize,
+    text: &str,
+    config: ParserConfig,
+) -> CST {
+    let lexer = SqliteLexer::with_config(text, config);
+
+    let mut p = SqliteParser::with_config(NormalLexer::from(lexer), abs_pos, config);
     grammar::file(&mut p, Default::default());
 
     p.build_cst()
@@ -56,8 +84,12 @@ pub fn parse<CST: CstTrait>(text: &str) -> CST {
     parse_with_abs_pos(TextSize::new(0), text)
 }
 
+pub fn parse_with_config<CST: CstTrait>(text: &str, config: ParserConfig) -> CST {
+    parse_with_abs_pos_and_config(TextSize::new(0), text, config)
+}
+
 pub fn parse_events_and_tokens(text: &str) -> (Vec<Event>, Vec<SqliteToken>) {
-    let lexer = SqliteLexer::new(text, SqliteVersion([3, 46, 0]));
+    let lexer = SqliteLexer::new(text, SqliteVersion::LATEST);
 
     let mut p = SqliteParser::with_abs_pos(NormalLexer::from(lexer), TextSize::new(0));
     grammar::file(&mut p, Default::default());
@@ -69,7 +101,7 @@ pub fn incremental_parse(
     text: &str,
     text_patch: TextPatch<TextSize, TextSize>,
 ) -> (Vec<Event>, Vec<SqliteToken>) {
-    let on_demand_lexer = new_on_demand_lexer(text, SqliteVersion([3, 46, 0]));
+    let on_demand_lexer = new_on_demand_lexer(text, ParserConfig::default());
     let mut p = SqliteParser::with_abs_pos(on_demand_lexer, text_patch.relex_start);
 
     let m = p.open();
@@ -124,9 +156,10 @@ pub fn incremental_parse(
 pub fn incremental_parse2<Cst: CstTrait>(
     text: &str,
     text_patch: TextPatch<TextSize, TextSize>,
+    config: ParserConfig,
 ) -> Cst {
-    let on_demand_lexer = new_on_demand_lexer(text, SqliteVersion([3, 46, 0]));
-    let mut p = SqliteParser::with_abs_pos(on_demand_lexer, text_patch.relex_start);
+    let on_demand_lexer = new_on_demand_lexer(text, config);
+    let mut p = SqliteParser::with_config(on_demand_lexer, text_patch.relex_start, config);
 
     let m = p.open();
     let r = STATEMENT_START | T![;];
@@ -177,25 +210,181 @@ pub fn incremental_parse2<Cst: CstTrait>(
     p.build_cst()
 }
 
+/// Applies several text patches (Ex: all the edits of a multi-cursor rename) with as few
+/// reparses as possible. The patches may be given in any order, but their positions must be
+/// relative to the text before any of them were applied. `text` is the text of the whole CST
+/// (starting at its `abs_pos`) after all of them were applied.
+///
+/// Patches are grouped by the statement they are in and each group is applied by reparsing the
+/// smallest block around it (See [`reparse_block`]). When a group cannot be, the statements are
+/// parsed again from that group up to the end of the text, which applies the later groups too.
+/// Overlapping patches are applied by parsing the whole text again.
+pub fn incremental_parse_batch(
+    cst: &mut incr::IncrSqlCst,
+    text: &str,
+    patches: &[TextPatch<(), ()>],
+    config: ParserConfig,
+) {
+    let end = |patch: &TextPatch<(), ()>| match patch.kind {
+        TextPatchKind::Insert => patch.start,
+        TextPatchKind::Replace { end } => end,
+    };
+
+    // Stable, so insertions at the same position keep their order
+    let mut patches = patches.to_vec();
+    patches.sort_by_key(|it| it.start);
+
+    if patches.windows(2).any(|it| it[1].start < end(&it[0])) {
+        *cst = parse_with_abs_pos_and_config(cst.abs_pos(), text, config);
+        return;
+    }
+
+    let groups = {
+        let statement_at = |pos: TextSize| cst.root().child_at_offset(pos).map(|it| it.start_pos());
+
+        let mut groups = Vec::new();
+        let mut group_start = 0;
+
+        for idx in 1..patches.len() {
+            if statement_at(patches[idx].start) != statement_at(end(&patches[idx - 1])) {
+                groups.push(group_start..idx);
+                group_start = idx;
+            }
+        }
+
+        if !patches.is_empty() {
+            groups.push(group_start..patches.len());
+        }
+
+        groups
+    };
+
+    // Bytes inserted and removed by the groups that were already applied
+    let (mut inserted, mut removed) = (TextSize::new(0), TextSize::new(0));
+
+    // Everything from the start of the first patch to the end of the last one is replaced in
+    // one go (in the positions of the CST after the earlier groups were applied)
+    let merge = |group: &[TextPatch<(), ()>], inserted: TextSize, removed: TextSize| {
+        let (first, last) = (group[0], group[group.len() - 1]);
+        let old_len = end(&last) - first.start;
+        let group_removed: TextSize = group.iter().map(|it| end(it) - it.start).sum::<TextSize>();
+        let group_inserted: TextSize = group.iter().map(|it| it.size).sum::<TextSize>();
+
+        TextPatch {
+            relex_start: (),
+            affected_node_byte_len: (),
+            start: first.start - removed + inserted,
+            size: old_len - group_removed + group_inserted,
+            kind: if old_len == TextSize::new(0) {
+                TextPatchKind::Insert
+            } else {
+                TextPatchKind::Replace {
+                    end: end(&last) - removed + inserted,
+                }
+            },
+        }
+    };
+
+    for group in groups {
+        let patch = merge(&patches[group.clone()], inserted, removed);
+
+        if reparse_block(cst, text, patch, config) {
+            inserted += patches[group.clone()]
+                .iter()
+                .map(|it| it.size)
+                .sum::<TextSize>();
+            removed += patches[group]
+                .iter()
+                .map(|it| end(it) - it.start)
+                .sum::<TextSize>();
+            continue;
+        }
+
+        // Statements are parsed up to the end of the text, so this applies the later groups too
+        let patch = cst.updated_text_patch(merge(&patches[group.start..], inserted, removed));
+        let relex_input = &text[usize::from(patch.relex_start - cst.abs_pos())..];
+        let new_cst = incremental_parse2(relex_input, patch, config);
+        cst.merge_cst(new_cst, patch);
+
+        return;
+    }
+}
+
 pub fn parse_any<CST: CstTrait>(
     text: &str,
     r: EnumSet<SqliteTokenKind>,
     parse_function: fn(&mut SqliteParser<NormalLexer>, EnumSet<SqliteTokenKind>),
 ) -> CST {
-    let lexer = SqliteLexer::new(text, SqliteVersion([3, 46, 0]));
+    parse_any_with_config(text, r, parse_function, ParserConfig::default())
+}
+
+fn parse_any_with_config<CST: CstTrait>(
+    text: &str,
+    r: EnumSet<SqliteTokenKind>,
+    parse_function: fn(&mut SqliteParser<NormalLexer>, EnumSet<SqliteTokenKind>),
+    config: ParserConfig,
+) -> CST {
+    let lexer = SqliteLexer::with_config(text, config);
 
-    let mut p = SqliteParser::new(NormalLexer::from(lexer));
+    let mut p = SqliteParser::with_config(NormalLexer::from(lexer), TextSize::new(0), config);
     let root_m = p.open();
     parse_function(&mut p, r);
 
     if !p.eof() {
         p.proceed_with_err(r, ParseErrorKind::UnknownTokens);
     }
+    p.eat_trivia();
     p.close(root_m, SqliteTreeKind::File);
 
     p.build_cst()
 }
 
+/// Grammar rules that can be parsed on their own using [`parse_fragment`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FragmentKind {
+    /// Ex: `a + 1 > 10`
+    Expr,
+    /// Ex: `VARCHAR(255)`
+    TypeName,
+    /// Ex: `id INTEGER PRIMARY KEY`
+    ColumnDef,
+    /// A SELECT statement, optionally with a CTE (Ex: `WITH a AS (SELECT 1) SELECT * FROM a`)
+    Select,
+    /// Ordering terms with or without the leading `ORDER BY` (Ex: `name DESC, id`)
+    OrderBy,
+}
+
+/// Parses a piece of SQL that is not a complete statement, such as SQL generated by an ORM. Like
+/// [`parse`], the root of the CST is a `File` node and the fragment is its child. Any tokens left
+/// over after the fragment are reported as an error.
+pub fn parse_fragment<CST: CstTrait>(kind: FragmentKind, text: &str) -> CST {
+    parse_fragment_with_config(kind, text, ParserConfig::default())
+}
+
+pub fn parse_fragment_with_config<CST: CstTrait>(
+    kind: FragmentKind,
+    text: &str,
+    config: ParserConfig,
+) -> CST {
+    let parse_function: fn(&mut SqliteParser<NormalLexer>, EnumSet<SqliteTokenKind>) = match kind {
+        FragmentKind::Expr => |p, r| {
+            grammar::expr(p, r);
+        },
+        FragmentKind::TypeName => grammar::type_name,
+        FragmentKind::ColumnDef => grammar::column_def,
+        FragmentKind::Select => grammar::select_stmt_with_cte,
+        FragmentKind::OrderBy => |p, r| {
+            if p.at(SqliteTokenKind::KW_ORDER) {
+                grammar::order_by_clause(p, r)
+            } else {
+                grammar::ordering_term_list(p, r)
+            }
+        },
+    };
+
+    parse_any_with_config(text, Default::default(), parse_function, config)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,303 @@
+//! sqlc-style query annotations. The comment before a statement names it and tells code
+//! generators what the query returns:
+//!
+//! ```sql
+//! -- name: GetUserById :one
+//! SELECT id, name FROM users WHERE id = ?;
+//! ```
+
+use smol_str::SmolStr;
+use text_size::TextRange;
+
+use crate::{CstNodeTrait, CstTrait, SqliteTokenKind, SqliteTreeKind};
+
+/// What the generated code does with the result of the query. Source:
+/// https://docs.sqlc.dev/en/latest/reference/query-annotations.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QueryCommand {
+    One,
+    Many,
+    Exec,
+    ExecResult,
+    ExecRows,
+    ExecLastId,
+    BatchExec,
+    BatchMany,
+    BatchOne,
+    CopyFrom,
+}
+
+impl QueryCommand {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            QueryCommand::One => ":one",
+            QueryCommand::Many => ":many",
+            QueryCommand::Exec => ":exec",
+            QueryCommand::ExecResult => ":execresult",
+            QueryCommand::ExecRows => ":execrows",
+            QueryCommand::ExecLastId => ":execlastid",
+            QueryCommand::BatchExec => ":batchexec",
+            QueryCommand::BatchMany => ":batchmany",
+            QueryCommand::BatchOne => ":batchone",
+            QueryCommand::CopyFrom => ":copyfrom",
+        }
+    }
+
+    fn from_str(command: &str) -> Option<QueryCommand> {
+        let command = match command {
+            ":one" => QueryCommand::One,
+            ":many" => QueryCommand::Many,
+            ":exec" => QueryCommand::Exec,
+            ":execresult" => QueryCommand::ExecResult,
+            ":execrows" => QueryCommand::ExecRows,
+            ":execlastid" => QueryCommand::ExecLastId,
+            ":batchexec" => QueryCommand::BatchExec,
+            ":batchmany" => QueryCommand::BatchMany,
+            ":batchone" => QueryCommand::BatchOne,
+            ":copyfrom" => QueryCommand::CopyFrom,
+            _ => return None,
+        };
+
+        Some(command)
+    }
+}
+
+/// `-- name: GetUserById :one` (or the same inside `/* */`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryAnnotation {
+    pub name: SmolStr,
+    pub command: QueryCommand,
+    /// Range of the comment
+    pub range: TextRange,
+}
+
+impl QueryAnnotation {
+    /// `None` if the comment is not an annotation
+    pub fn parse(comment: &str, range: TextRange) -> Option<QueryAnnotation> {
+        let body = match comment.strip_prefix("--") {
+            Some(body) => body,
+            None => comment.strip_prefix("/*")?.trim_end_matches("*/"),
+        };
+
+        let mut words = body.trim().strip_prefix("name:")?.split_whitespace();
+
+        let annotation = QueryAnnotation {
+            name: words.next()?.into(),
+            command: QueryCommand::from_str(words.next()?)?,
+            range,
+        };
+
+        words.next().is_none().then_some(annotation)
+    }
+}
+
+/// A top-level statement along with the annotation in the comments before it (See
+/// [`CstTrait::statements`])
+#[derive(Debug, Clone)]
+pub struct AnnotatedStatement<N> {
+    pub node: N,
+    pub annotation: Option<QueryAnnotation>,
+}
+
+/// An annotated statement with what code generators need to know about it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamedQuery {
+    pub annotation: QueryAnnotation,
+    /// Range of the statement (without the trivia around it)
+    pub range: TextRange,
+    pub sql: String,
+    pub params: Vec<QueryParam>,
+    /// Empty if the statement does not return rows
+    pub columns: Vec<QueryColumn>,
+}
+
+/// A bind parameter. Parameters that are used more than once are listed once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryParam {
+    /// `id` for `:id`, `@id`, `$id` and `sqlc.arg(id)`. `None` for `?` and `?NNN`
+    pub name: Option<SmolStr>,
+    pub text: SmolStr,
+    /// Where the parameter is first used
+    pub range: TextRange,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryColumn {
+    /// The alias, the name of the column (Ex: `name` for `u.name`) or the text of the expression.
+    /// Wildcards (`*` and `u.*`) are not expanded.
+    pub name: SmolStr,
+    pub range: TextRange,
+}
+
+/// The annotated statements of the file
+pub fn named_queries<Cst: CstTrait>(cst: &Cst) -> Vec<NamedQuery> {
+    cst.statements()
+        .filter_map(|stmt| {
+            Some(NamedQuery {
+                annotation: stmt.annotation?,
+                range: trimmed_range(stmt.node),
+                sql: stmt.node.to_text().trim().to_owned(),
+                params: query_params(stmt.node),
+                columns: query_columns(stmt.node),
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn statement_annotation<'a, N: CstNodeTrait<'a>>(node: N) -> Option<QueryAnnotation> {
+    let token = node.token()?;
+
+    match token.kind {
+        SqliteTokenKind::S_LINE_COMMENT | SqliteTokenKind::M_LINE_COMMENT => {
+            let range = TextRange::new(node.start_pos(), node.end_pos());
+            QueryAnnotation::parse(&token.text, range)
+        }
+        _ => None,
+    }
+}
+
+fn query_params<'a, N: CstNodeTrait<'a>>(stmt: N) -> Vec<QueryParam> {
+    let mut params: Vec<QueryParam> = Vec::new();
+
+    for node in stmt.me_and_descendants() {
+        let Some(token) = node.token() else {
+            continue;
+        };
+
+        let name = match token.kind {
+            SqliteTokenKind::PARAM if token.text.starts_with('?') => None,
+            SqliteTokenKind::PARAM => Some(token.text[1..].into()),
+            SqliteTokenKind::TEMPLATE_EXPR => match sqlc_arg(&token.text) {
+                Some(arg) => Some(arg.into()),
+                None => continue,
+            },
+            _ => continue,
+        };
+
+        let is_new = match &name {
+            Some(_) => params.iter().all(|it| it.name != name),
+            None if token.text != "?" => params.iter().all(|it| it.text != token.text),
+            None => true,
+        };
+
+        if is_new {
+            params.push(QueryParam {
+                name,
+                text: token.text.clone(),
+                range: TextRange::new(node.start_pos(), node.end_pos()),
+            });
+        }
+    }
+
+    params
+}
+
+/// The name in `sqlc.arg(name)`, `sqlc.narg('name')` and so on
+fn sqlc_arg(text: &str) -> Option<&str> {
+    let (_, arg) = text.strip_prefix("sqlc.")?.split_once('(')?;
+
+    Some(arg.strip_suffix(')')?.trim().trim_matches(['\'', '"']))
+}
+
+/// Columns of the SELECT (the first one in compound selects) or the RETURNING clause
+fn query_columns<'a, N: CstNodeTrait<'a>>(stmt: N) -> Vec<QueryColumn> {
+    use SqliteTreeKind::*;
+
+    let Some(stmt) = stmt
+        .find_children(StatementWithCte)
+        .next()
+        .and_then(|it| it.valid_children().find(|it| it.tree() != Some(CteClause)))
+    else {
+        return Vec::new();
+    };
+
+    let column = |name: &str, node: N| QueryColumn {
+        name: name.into(),
+        range: trimmed_range(node),
+    };
+
+    match stmt.tree() {
+        Some(SelectStmt) => {
+            let Some(core) = stmt
+                .find_children(SelectCore)
+                .next()
+                .and_then(|it| it.valid_children().next())
+            else {
+                return Vec::new();
+            };
+
+            match core.tree() {
+                Some(TraditionalSelect) => core
+                    .find_children(ResultColumnList)
+                    .flat_map(|it| it.find_children(ResultColumn))
+                    .filter_map(|it| it.valid_children().next())
+                    .map(|it| match it.tree() {
+                        Some(ResultColumnExpr) => {
+                            let alias = it
+                                .find_children(WithAlias)
+                                .next()
+                                .and_then(|it| it.find_children(AliasName).next());
+                            column(&alias.map_or_else(|| expr_name(it), name_text), it)
+                        }
+                        _ => column(&trimmed_text(it), it),
+                    })
+                    .collect(),
+                // SQLite names them `column1`, `column2` and so on
+                Some(ValuesSelect) => core
+                    .find_children(ExprList)
+                    .next()
+                    .into_iter()
+                    .flat_map(|it| it.find_children(Expr))
+                    .enumerate()
+                    .map(|(idx, it)| column(&format!("column{}", idx + 1), it))
+                    .collect(),
+                _ => Vec::new(),
+            }
+        }
+        Some(InsertStmt | UpdateStmt | DeleteStmt) => stmt
+            .find_children(ReturningClause)
+            .flat_map(|it| it.find_children(ReturningClauseKind))
+            .map(|it| match it.find_children(ReturningClauseExpr).next() {
+                Some(expr) => {
+                    let alias = expr.find_children(AliasName).next();
+                    column(&alias.map_or_else(|| expr_name(expr), name_text), it)
+                }
+                None => column(&trimmed_text(it), it),
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// The name of the column if the expression is just a column (Ex: `u.name`)
+fn expr_name<'a, N: CstNodeTrait<'a>>(parent: N) -> String {
+    let Some(expr) = parent.find_children(SqliteTreeKind::Expr).next() else {
+        return trimmed_text(parent);
+    };
+
+    expr.find_children(SqliteTreeKind::ExprColumnName)
+        .next()
+        .and_then(|it| it.find_children(SqliteTreeKind::ColumnName).next())
+        .map_or_else(|| trimmed_text(expr), name_text)
+}
+
+/// The name without its quotes (Ex: `"user id"`)
+fn name_text<'a, N: CstNodeTrait<'a>>(node: N) -> String {
+    let text = trimmed_text(node);
+
+    match text.chars().next() {
+        Some(quote @ ('"' | '`')) if text.len() > 1 => {
+            text[1..text.len() - 1].replace(&format!("{quote}{quote}"), &quote.to_string())
+        }
+        Some('[') if text.len() > 1 => text[1..text.len() - 1].to_owned(),
+        _ => text,
+    }
+}
+
+fn trimmed_text<'a, N: CstNodeTrait<'a>>(node: N) -> String {
+    node.to_text().trim().to_owned()
+}
+
+fn trimmed_range<'a, N: CstNodeTrait<'a>>(node: N) -> TextRange {
+    TextRange::new(node.start_pos_skip_trivia(), node.end_pos_skip_trivia())
+}
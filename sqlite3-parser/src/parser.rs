@@ -4,9 +4,9 @@ use enumset::EnumSet;
 use text_size::TextSize;
 
 use crate::{
-    grammar::common::{EXPR_LIT_START, EXPR_PREFIX_START, IDEN_SET, JOIN_KEYWORDS},
-    CstTrait, SqliteLexer, SqliteToken, SqliteTokenKind, SqliteTreeKind, SqliteTreeTag,
-    SqliteVersion, T,
+    grammar::common::{EXPR_LIT_START, EXPR_PREFIX_START, JOIN_KEYWORDS},
+    sqlite_keywords, CstTrait, ParserConfig, SqliteLexer, SqliteToken, SqliteTokenKind,
+    SqliteTreeKind, SqliteTreeTag, T,
 };
 
 pub struct SqliteParser<T> {
@@ -16,7 +16,7 @@ pub struct SqliteParser<T> {
 
     // The following identifier related token sets come from parse.y of SQLite. Because
     // the IDEN_SET (or `ID` in parse.y) changes depending on the build of SQLite, we put
-    // it here so that we can adjust it for each parser invocation (in the future)
+    // it here so that we can adjust it for each parser invocation (See `ParserConfig`)
     #[allow(dead_code)]
     pub(crate) iden_set: EnumSet<SqliteTokenKind>,
 
@@ -99,7 +99,57 @@ pub enum ExpectedItem {
 
 impl std::fmt::Display for SqliteParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(&format!("{:?}", self.message))
+        std::write!(f, "{}", self.message)
+    }
+}
+
+impl std::fmt::Display for ExpectedItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use SqliteTokenKind::*;
+
+        match self {
+            ExpectedItem::Token(tk) => match tk {
+                IDEN => f.write_str("identifier"),
+                STR_LIT => f.write_str("string literal"),
+                INT_LIT => f.write_str("integer literal"),
+                REAL_LIT => f.write_str("real literal"),
+                HEX_LIT => f.write_str("hex literal"),
+                BLOB_LIT => f.write_str("blob literal"),
+                PARAM => f.write_str("parameter"),
+                WHITESPACE => f.write_str("whitespace"),
+                S_LINE_COMMENT | M_LINE_COMMENT => f.write_str("comment"),
+                EOF => f.write_str("end of file"),
+                ERROR => f.write_str("invalid token"),
+                TEMPLATE_EXPR => f.write_str("template expression"),
+                TEMPLATE_STMT => f.write_str("template tag"),
+                _ => std::write!(f, "`{}`", tk.as_str()),
+            },
+            ExpectedItem::Tree(tree) => f.write_str(&tree.display_name()),
+        }
+    }
+}
+
+impl std::fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseErrorKind::ExpectedItems(items) => {
+                f.write_str("expected ")?;
+
+                for (idx, item) in items.iter().enumerate() {
+                    if idx == 0 {
+                        std::write!(f, "{item}")?;
+                    } else if idx == items.len() - 1 {
+                        std::write!(f, " or {item}")?;
+                    } else {
+                        std::write!(f, ", {item}")?;
+                    }
+                }
+
+                Ok(())
+            }
+            ParseErrorKind::UnknownTokens => f.write_str("unexpected input"),
+            ParseErrorKind::IllegalJoinOperator => f.write_str("illegal join operator"),
+        }
     }
 }
 impl std::error::Error for SqliteParseError {
@@ -133,6 +183,50 @@ impl From<&'static [ExpectedItem]> for ParseErrorKind {
 }
 
 impl ParseErrorKind {
+    /// A stable identifier for the error. Unlike the message, this never changes between
+    /// releases so clients can rely on it (for filtering, suppressing etc.)
+    pub const fn code(&self) -> &'static str {
+        match self {
+            ParseErrorKind::ExpectedItems(_) => "E0001",
+            ParseErrorKind::UnknownTokens => "E0002",
+            ParseErrorKind::IllegalJoinOperator => "E0003",
+        }
+    }
+
+    /// Keywords that would have been accepted at the location of the error
+    pub fn expected_keywords(&self) -> EnumSet<SqliteTokenKind> {
+        let ParseErrorKind::ExpectedItems(items) = self else {
+            return EnumSet::new();
+        };
+
+        items
+            .iter()
+            .map(|it| match it {
+                ExpectedItem::Token(tk) if sqlite_keywords(tk.as_str().as_bytes()).is_some() => {
+                    EnumSet::only(*tk)
+                }
+                ExpectedItem::Token(_) => EnumSet::new(),
+                ExpectedItem::Tree(tree) => crate::ungram::first_keywords(tree.as_str()),
+            })
+            .collect()
+    }
+
+    /// Given the text of an unexpected token, finds the closest expected keyword. Used for
+    /// typos like `SELCT` or `FORM`
+    pub fn keyword_suggestion(&self, text: &str) -> Option<SqliteTokenKind> {
+        let text = text.to_ascii_uppercase();
+
+        // Short words need to be almost right otherwise every three letter word is a typo
+        let max_distance = if text.len() <= 4 { 1 } else { 2 };
+
+        self.expected_keywords()
+            .iter()
+            .map(|kw| (edit_distance(&text, kw.as_str()), kw))
+            .filter(|(distance, _)| *distance > 0 && *distance <= max_distance)
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, kw)| kw)
+    }
+
     pub fn is_missing_semicolon_err(&self) -> bool {
         match self {
             ParseErrorKind::ExpectedItems(items) => {
@@ -143,6 +237,36 @@ impl ParseErrorKind {
     }
 }
 
+/// Optimal string alignment distance (Levenshtein distance that also counts swapping two
+/// adjacent characters as one edit, so `FORM` is one edit away from `FROM`)
+fn edit_distance(a: &str, b: &str) -> usize {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in table.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in table[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+
+            table[i][j] = (table[i - 1][j] + 1)
+                .min(table[i][j - 1] + 1)
+                .min(table[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                table[i][j] = table[i][j].min(table[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    table[a.len()][b.len()]
+}
+
 pub trait Lexer {
     fn all_tokens(self) -> Vec<SqliteToken>;
     fn eof(&self) -> bool;
@@ -225,8 +349,8 @@ pub struct NormalLexer {
     tokens: Vec<SqliteTokenKind>,
 }
 
-pub fn new_on_demand_lexer<'a>(text: &'a str, version: SqliteVersion) -> OnDemandLexer<'a> {
-    let lexer = SqliteLexer::new(text, version);
+pub fn new_on_demand_lexer<'a>(text: &'a str, config: ParserConfig) -> OnDemandLexer<'a> {
+    let lexer = SqliteLexer::with_config(text, config);
 
     OnDemandLexer::from(lexer.clone())
 }
@@ -443,47 +567,21 @@ impl Lexer for NormalLexer {
 
 impl<T: Lexer> SqliteParser<T> {
     pub fn new(lexer: T) -> Self {
-        Self::_new(lexer, TextSize::new(0))
+        Self::_new(lexer, TextSize::new(0), ParserConfig::default())
     }
 
     pub fn with_abs_pos(lexer: T, abs_pos: TextSize) -> Self {
-        Self::_new(lexer, abs_pos)
+        Self::_new(lexer, abs_pos, ParserConfig::default())
     }
 
-    pub fn _new(lexer: T, abs_pos: TextSize) -> Self {
-        use SqliteTokenKind::*;
-        // TODO: Use a parser context to configure IDEN_SET at runtime
-
-        let mut iden_set = IDEN_SET;
-
-        let omit_compound_select = false;
-
-        if !omit_compound_select {
-            iden_set = iden_set.difference(KW_EXCEPT | KW_INTERSECT | KW_UNION);
-        }
-
-        let omit_window_func = false;
-
-        if omit_window_func {
-            iden_set = iden_set.difference(
-                KW_CURRENT
-                    | KW_FOLLOWING
-                    | KW_PARTITION
-                    | KW_PRECEDING
-                    | KW_RANGE
-                    | KW_UNBOUNDED
-                    | KW_EXCLUDE
-                    | KW_GROUPS
-                    | KW_OTHERS
-                    | KW_TIES,
-            );
-        }
+    pub fn with_config(lexer: T, abs_pos: TextSize, config: ParserConfig) -> Self {
+        Self::_new(lexer, abs_pos, config)
+    }
 
-        let omit_generated_columns = false;
+    pub fn _new(lexer: T, abs_pos: TextSize, config: ParserConfig) -> Self {
+        use SqliteTokenKind::*;
 
-        if omit_generated_columns {
-            iden_set = iden_set.difference(KW_GENERATED | KW_ALWAYS);
-        }
+        let iden_set = config.iden_set();
 
         let iden = iden_set | KW_INDEXED;
         let iden_or_str = iden_set | STR_LIT;
@@ -0,0 +1,164 @@
+//! Reparsing of blocks smaller than a statement. [`crate::incremental_parse2`] always reparses
+//! whole statements, which is slow for edits inside huge statements (Ex: a `CREATE TRIGGER`
+//! with a long body or an `INSERT` with thousands of rows). Instead, we look for the smallest
+//! block around the edit that can be parsed on its own and only reparse that.
+
+use enumset::EnumSet;
+use text_size::{TextRange, TextSize};
+
+use crate::{
+    batch, grammar, incr, parse_any_with_config, CstNodeTrait, CstTrait, NormalLexer, ParserConfig,
+    SqliteLexer, SqliteParser, SqliteTokenKind, SqliteTreeKind, TextPatch, TextPatchKind, T,
+};
+
+type ParseFunction = fn(&mut SqliteParser<NormalLexer>, EnumSet<SqliteTokenKind>);
+
+/// Applies the text patch by reparsing a single parenthesised subquery, trigger body statement
+/// or `VALUES` row. `text` is the text of the whole CST after the patch.
+///
+/// The block is only reused when its first and last tokens keep their kind, its brackets stay
+/// balanced and it still parses without errors (so the rest of the statement is parsed the same
+/// way). Returns `false` and leaves the CST untouched otherwise, in which case the patch should
+/// be applied with [`crate::incremental_parse2`] and [`incr::IncrSqlCst::merge_cst`].
+pub fn reparse_block(
+    cst: &mut incr::IncrSqlCst,
+    text: &str,
+    patch: TextPatch<(), ()>,
+    config: ParserConfig,
+) -> bool {
+    let end = match patch.kind {
+        TextPatchKind::Insert => patch.start,
+        TextPatchKind::Replace { end } => end,
+    };
+    let edit = TextRange::new(patch.start, end);
+
+    let abs_pos = cst.abs_pos();
+    let reparsed = {
+        let covering = cst.covering_element(edit);
+
+        std::iter::once(covering)
+            .chain(covering.ancestors())
+            .filter_map(|node| Some((node, block_parse_function(node)?)))
+            // Edits at the very start of a block may join it with the token before it
+            .filter(|(node, _)| node.start_pos() < edit.start() && edit.end() <= node.end_pos())
+            .find_map(|(node, parse_function)| {
+                let new_block = reparse(
+                    node,
+                    text,
+                    abs_pos,
+                    edit,
+                    patch.size,
+                    parse_function,
+                    config,
+                )?;
+
+                Some((node.id(), node.byte_len(), new_block))
+            })
+    };
+
+    match reparsed {
+        Some((id, old_len, new_block)) => {
+            cst.replace_subtree(id, old_len, &new_block);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Blocks are nodes that can be parsed without knowing what is around them. The tokens around
+/// them are always the same (Ex: `(` and `)`, or `;`)
+fn block_parse_function<'a>(node: impl CstNodeTrait<'a>) -> Option<ParseFunction> {
+    use SqliteTreeKind::*;
+
+    if node.is_root() || node.parent().is_root() {
+        return None;
+    }
+
+    let parse_function: ParseFunction = match (node.tree()?, node.parent().tree()?) {
+        (TriggerBodyStmt, TriggerBodyStmtList) => {
+            |p, _| grammar::trigger_body_stmt(p, T![;].into())
+        }
+        (SelectStmtWithCte, ExprSelect | ExprExistsSelect | TableOrSubquery | CommonTableExpr) => {
+            |p, _| grammar::select_stmt_with_cte(p, T![')'].into())
+        }
+        (ExprList, InsertValuesClause | ValuesSelect | ValuesClause) => {
+            |p, _| grammar::expr_list(p, T![,].into())
+        }
+        _ => return None,
+    };
+
+    Some(parse_function)
+}
+
+fn reparse<'a>(
+    node: impl CstNodeTrait<'a>,
+    text: &str,
+    abs_pos: TextSize,
+    edit: TextRange,
+    size: TextSize,
+    parse_function: ParseFunction,
+    config: ParserConfig,
+) -> Option<batch::SqlCst> {
+    let old_range = TextRange::new(node.start_pos(), node.end_pos());
+    let new_range = TextRange::new(old_range.start(), old_range.end() - edit.len() + size);
+
+    let new_text = text.get(std::ops::Range::<usize>::from(new_range - abs_pos))?;
+
+    let old_tokens = || {
+        node.me_and_descendants()
+            .filter(|it| it.is_non_trivia_token())
+    };
+    let (first, last) = (old_tokens().next()?, old_tokens().next_back()?);
+
+    // The token after the block must be lexed the same way (Ex: An unterminated string literal
+    // would otherwise swallow the `)` after the block)
+    let next = last.next_token_skip_trivia()?;
+    let next_start = next.start_pos() - edit.len() + size;
+    let lookahead = TextRange::new(new_range.start(), next_start) - abs_pos;
+    let lookahead = text.get(std::ops::Range::<usize>::from(lookahead))?;
+    let lookahead = format!("{lookahead}{}", next.token()?.text);
+
+    let tokens: Vec<_> = SqliteLexer::with_config(&lookahead, config)
+        .filter(|it| !it.is_trivia())
+        .collect();
+    let (new_next, tokens) = tokens.split_last()?;
+
+    let unchanged = new_next.kind == next.token()?.kind
+        && new_next.text == next.token()?.text
+        && tokens.first()?.kind == first.token()?.kind
+        && tokens.last()?.kind == last.token()?.kind;
+
+    if !unchanged || !is_balanced(tokens) {
+        return None;
+    }
+
+    let new_block: batch::SqlCst =
+        parse_any_with_config(new_text, Default::default(), parse_function, config);
+
+    let is_same_block = {
+        let mut children = new_block.root().children();
+
+        children.next().is_some_and(|it| it.tree() == node.tree())
+            && children.next().is_none()
+            && new_block.errors().next().is_none()
+    };
+
+    is_same_block.then_some(new_block)
+}
+
+fn is_balanced(tokens: &[crate::SqliteToken]) -> bool {
+    let mut depth = 0_usize;
+
+    for token in tokens {
+        match token.kind {
+            T!['('] => depth += 1,
+            T![')'] => match depth.checked_sub(1) {
+                Some(it) => depth = it,
+                None => return false,
+            },
+            _ => {}
+        }
+    }
+
+    depth == 0
+}
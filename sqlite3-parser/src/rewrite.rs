@@ -0,0 +1,234 @@
+use text_size::{TextRange, TextSize};
+
+use crate::{
+    parse_with_abs_pos_and_config, CstNodeTrait, CstTrait, ParserConfig, SqliteTokenKind,
+    TextPatch, TextPatchKind,
+};
+
+/// Replaces the text in `range` with `new_text`. Ranges use the same (absolute) positions as
+/// the CST the edit was made on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub range: TextRange,
+    pub new_text: String,
+}
+
+impl TextEdit {
+    pub fn to_text_patch(&self) -> TextPatch<(), ()> {
+        TextPatch {
+            relex_start: (),
+            affected_node_byte_len: (),
+            start: self.range.start(),
+            size: TextSize::of(self.new_text.as_str()),
+            kind: if self.range.is_empty() {
+                TextPatchKind::Insert
+            } else {
+                TextPatchKind::Replace {
+                    end: self.range.end(),
+                }
+            },
+        }
+    }
+}
+
+/// Records changes to the nodes of a CST (Ex: for refactorings and quick fixes). The CST itself
+/// is left untouched; the changes are available as text edits (which can be fed to the
+/// incremental parser) or as a new CST.
+///
+/// Nodes are edited without their surrounding trivia so whitespace and comments around them are
+/// preserved. Edits may not overlap (except insertions at the same position, which are applied
+/// in the order they were made). An edit that does is dropped and reported by [`Self::edits`].
+#[derive(Debug, Clone)]
+pub struct CstRewriter {
+    abs_pos: TextSize,
+    text: String,
+    edits: Vec<TextEdit>,
+    /// The first edit that could not be made
+    error: Option<RewriteError>,
+}
+
+/// An edit that cannot be combined with the other edits of a [`CstRewriter`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RewriteError {
+    /// The range is not inside the root node of the rewriter
+    OutsideRoot { range: TextRange },
+    /// The range overlaps with the range of an earlier edit
+    Overlap { range: TextRange, other: TextRange },
+}
+
+impl std::fmt::Display for RewriteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RewriteError::OutsideRoot { range } => {
+                write!(f, "edit at {range:?} is outside of the root node")
+            }
+            RewriteError::Overlap { range, other } => {
+                write!(
+                    f,
+                    "edit at {range:?} overlaps with an earlier edit at {other:?}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for RewriteError {}
+
+impl CstRewriter {
+    /// Edits can be made to `root` and any of its descendants
+    pub fn new<'a>(root: impl CstNodeTrait<'a>) -> Self {
+        Self {
+            abs_pos: root.start_pos(),
+            text: root.to_text(),
+            edits: Vec::new(),
+            error: None,
+        }
+    }
+
+    pub fn replace<'a>(&mut self, node: impl CstNodeTrait<'a>, new_text: impl Into<String>) {
+        self.edit(node_range(node), new_text.into());
+    }
+
+    pub fn insert_before<'a>(&mut self, node: impl CstNodeTrait<'a>, text: impl Into<String>) {
+        self.edit(TextRange::empty(node_range(node).start()), text.into());
+    }
+
+    pub fn insert_after<'a>(&mut self, node: impl CstNodeTrait<'a>, text: impl Into<String>) {
+        self.edit(TextRange::empty(node_range(node).end()), text.into());
+    }
+
+    /// Deletes the node. If the node is an item of a comma separated list, the comma separating
+    /// it from its neighbour is deleted as well.
+    pub fn delete<'a>(&mut self, node: impl CstNodeTrait<'a>) {
+        let range = node_range(node);
+        let is_comma = |it: &_| CstNodeTrait::token_kind(it) == Some(SqliteTokenKind::COMMA);
+
+        let range = if node.is_root() {
+            range
+        } else if let Some(next) = node.right_siblings().find(|it| !it.is_trivia()) {
+            // `a, b` -> `b`
+            match next.next_token_skip_trivia().filter(|_| is_comma(&next)) {
+                Some(after_comma) => TextRange::new(range.start(), after_comma.start_pos()),
+                None if is_comma(&next) => range.cover(node_range(next)),
+                None => range,
+            }
+        } else if let Some(prev) = node.left_siblings().rev().find(|it| !it.is_trivia()) {
+            // `a, b` -> `a`
+            if is_comma(&prev) {
+                range.cover(node_range(prev))
+            } else {
+                range
+            }
+        } else {
+            range
+        };
+
+        self.edit(range, String::new());
+    }
+
+    /// Surrounds the node with the given text (Ex: `(` and `)`)
+    pub fn wrap<'a>(
+        &mut self,
+        node: impl CstNodeTrait<'a>,
+        prefix: impl Into<String>,
+        suffix: impl Into<String>,
+    ) {
+        let range = node_range(node);
+
+        self.edit(TextRange::empty(range.start()), prefix.into());
+        self.edit(TextRange::empty(range.end()), suffix.into());
+    }
+
+    /// An edit that overlaps with an earlier one or is outside of the root node is dropped. The
+    /// rewriter then reports the error instead of its edits.
+    pub fn edit(&mut self, range: TextRange, new_text: String) {
+        let root_range = TextRange::at(self.abs_pos, TextSize::of(self.text.as_str()));
+        let overlapping = self
+            .edits
+            .iter()
+            .find(|it| range.start() < it.range.end() && it.range.start() < range.end());
+
+        let error = if !root_range.contains_range(range) {
+            Some(RewriteError::OutsideRoot { range })
+        } else {
+            overlapping.map(|other| RewriteError::Overlap {
+                range,
+                other: other.range,
+            })
+        };
+
+        if let Some(error) = error {
+            self.error.get_or_insert(error);
+            return;
+        }
+
+        // Keep edits sorted. Insertions at the same position go after the existing insertions
+        // but before the edit that replaces the text starting there
+        let idx = self.edits.partition_point(|it| {
+            it.range.start() < range.start()
+                || (it.range.start() == range.start() && (it.range.is_empty() || !range.is_empty()))
+        });
+        self.edits.insert(idx, TextEdit { range, new_text });
+    }
+
+    /// The minimal edits needed to get the new text, sorted by position. Adjacent edits are
+    /// merged and edits that do not change anything are dropped.
+    ///
+    /// Applied from last to first, the positions of an edit are not affected by the edits before
+    /// it. This allows feeding them one by one to the incremental parser (See
+    /// [`TextEdit::to_text_patch`]).
+    pub fn edits(&self) -> Result<Vec<TextEdit>, RewriteError> {
+        if let Some(error) = &self.error {
+            return Err(error.clone());
+        }
+
+        let mut edits: Vec<TextEdit> = Vec::new();
+
+        for edit in &self.edits {
+            match edits.last_mut() {
+                Some(last) if last.range.end() == edit.range.start() => {
+                    last.range = last.range.cover(edit.range);
+                    last.new_text.push_str(&edit.new_text);
+                }
+                _ => edits.push(edit.clone()),
+            }
+        }
+
+        edits.retain(|it| self.original_text(it.range) != it.new_text);
+
+        Ok(edits)
+    }
+
+    /// Text of the root node after applying the edits
+    pub fn new_text(&self) -> Result<String, RewriteError> {
+        let mut text = self.text.clone();
+
+        for edit in self.edits()?.iter().rev() {
+            let range = edit.range - self.abs_pos;
+            text.replace_range(std::ops::Range::<usize>::from(range), &edit.new_text);
+        }
+
+        Ok(text)
+    }
+
+    /// Parses the new text. The new CST starts at the same position as the original root.
+    pub fn to_cst<CST: CstTrait>(&self, config: ParserConfig) -> Result<CST, RewriteError> {
+        Ok(parse_with_abs_pos_and_config(
+            self.abs_pos,
+            &self.new_text()?,
+            config,
+        ))
+    }
+
+    fn original_text(&self, range: TextRange) -> &str {
+        &self.text[range - self.abs_pos]
+    }
+}
+
+fn node_range<'a>(node: impl CstNodeTrait<'a>) -> TextRange {
+    if node.me_and_descendants().any(|it| it.is_non_trivia_token()) {
+        TextRange::new(node.start_pos_skip_trivia(), node.end_pos_skip_trivia())
+    } else {
+        TextRange::new(node.start_pos(), node.end_pos())
+    }
+}
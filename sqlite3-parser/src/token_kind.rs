@@ -235,6 +235,12 @@ pub enum SqliteTokenKind {
     KW_ROWID,
     KW_STRICT,
     PARAM,
+
+    // Only produced when templates are enabled (See `ParserConfig::templates`)
+    /// `{{ ref('users') }}` or `sqlc.arg(name)`
+    TEMPLATE_EXPR,
+    /// `{% if cond %}` or `{# comment #}`
+    TEMPLATE_STMT,
 }
 
 impl SqliteTokenKind {
@@ -264,6 +270,7 @@ impl SqliteTokenKind {
             SqliteTokenKind::WHITESPACE
                 | SqliteTokenKind::S_LINE_COMMENT
                 | SqliteTokenKind::M_LINE_COMMENT
+                | SqliteTokenKind::TEMPLATE_STMT
         )
     }
 }
@@ -553,6 +560,8 @@ impl SqliteTokenKind {
             KW_ROWID => "ROWID",
             KW_STRICT => "STRICT",
             PARAM => "PARAM",
+            TEMPLATE_EXPR => "TEMPLATE_EXPR",
+            TEMPLATE_STMT => "TEMPLATE_STMT",
         }
     }
 }
@@ -1,6 +1,6 @@
 use crate::ExpectedItem;
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
 pub enum SqliteTreeKind {
     File,
     Statement,
@@ -276,6 +276,48 @@ impl SqliteTreeKind {
         ExpectedItem::Tree(*self)
     }
 
+    /// Name of the tree kind meant for humans (used in error messages). Ex: `ColumnDef` is
+    /// displayed as "column definition"
+    pub fn display_name(&self) -> String {
+        let name = self.as_str();
+        let mut words: Vec<&str> = Vec::new();
+        let mut word_start = 0;
+
+        // Split on the camel case boundaries. A run of uppercase letters is kept as one
+        // word (Ex: `OpLTE` => ["Op", "LTE"])
+        for (idx, ch) in name.char_indices().skip(1) {
+            let prev_is_upper = name.as_bytes()[idx - 1].is_ascii_uppercase();
+            if ch.is_ascii_uppercase() && !prev_is_upper {
+                words.push(&name[word_start..idx]);
+                word_start = idx;
+            }
+        }
+        words.push(&name[word_start..]);
+
+        words
+            .into_iter()
+            .map(|word| match word {
+                "Stmt" => "statement".to_owned(),
+                "Def" => "definition".to_owned(),
+                "Expr" => "expression".to_owned(),
+                "Func" => "function".to_owned(),
+                "Op" => "operator".to_owned(),
+                "Db" => "database".to_owned(),
+                "Idx" => "index".to_owned(),
+                "Col" => "column".to_owned(),
+                "Fk" => "foreign key".to_owned(),
+                "Pk" => "primary key".to_owned(),
+                "Uq" => "unique".to_owned(),
+                "Opt" | "Opts" => "option".to_owned(),
+                "Err" => "error".to_owned(),
+                "Cte" => "CTE".to_owned(),
+                it if it.len() > 1 && it.bytes().all(|b| b.is_ascii_uppercase()) => it.to_owned(),
+                it => it.to_ascii_lowercase(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
     pub fn as_str(&self) -> &'static str {
         use SqliteTreeKind::*;
         match self {
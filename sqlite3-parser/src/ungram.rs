@@ -1,5 +1,6 @@
-use crate::CstNodeTrait;
-use std::collections::HashMap;
+use crate::{sqlite_keywords, CstNodeTrait, SqliteTokenKind};
+use enumset::EnumSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::LazyLock;
 pub use ungrammar::{Node, NodeData, Rule, Token, TokenData};
 
@@ -486,3 +487,316 @@ pub fn rule_to_str(r: &Rule) -> String {
         Rule::Rep(rule) => format!("({})*", rule_to_str(rule)),
     }
 }
+
+/// Keywords that can appear at the start of the given ungrammar node
+pub fn first_keywords(node_name: &str) -> EnumSet<SqliteTokenKind> {
+    let mut keywords = EnumSet::new();
+
+    if let Some(node) = UNGRAMMAR.map.get(node_name) {
+        first_keywords_helper(&Rule::Node(*node), &mut HashSet::new(), &mut keywords);
+    }
+
+    keywords
+}
+
+/// Returns true if the rule can match nothing (in which case the rule that follows it also
+/// contributes to the first set)
+fn first_keywords_helper(
+    rule: &Rule,
+    visited: &mut HashSet<Node>,
+    keywords: &mut EnumSet<SqliteTokenKind>,
+) -> bool {
+    match rule {
+        Rule::Labeled { rule, .. } => first_keywords_helper(rule, visited, keywords),
+        Rule::Node(node) => {
+            // Guards against left recursive nodes (Ex: Expr)
+            if !visited.insert(*node) {
+                return false;
+            }
+
+            first_keywords_helper(&UNGRAMMAR.get_node(*node).rule, visited, keywords)
+        }
+        Rule::Token(token) => {
+            let name = UNGRAMMAR.get_token(*token);
+
+            if let Some(kw) = name
+                .strip_prefix("KW_")
+                .and_then(|it| sqlite_keywords(it.as_bytes()))
+            {
+                keywords.insert(kw);
+            }
+
+            false
+        }
+        Rule::Seq(rules) => rules
+            .iter()
+            .all(|it| first_keywords_helper(it, visited, keywords)),
+        Rule::Alt(rules) => {
+            // Not `any`, every alternative has to be visited to collect its keywords
+            let mut nullable = false;
+            for rule in rules {
+                nullable |= first_keywords_helper(rule, visited, keywords);
+            }
+
+            nullable
+        }
+        Rule::Opt(rule) | Rule::Rep(rule) => {
+            first_keywords_helper(rule, visited, keywords);
+            true
+        }
+    }
+}
+
+/// A value that can be given to the builder of an ungrammar node (See [`crate::ast::NodeBuilder`])
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuilderField {
+    pub name: String,
+    pub kind: BuilderFieldKind,
+    /// The field can be given more than once (Ex: the items of a `ResultColumnList`)
+    pub repeated: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BuilderFieldKind {
+    /// A child node with the given name
+    Node(String),
+    /// One of the given nodes or tokens (Ex: `from_clause_value: (TableOrSubquery | JoinClause)`)
+    Choice(Vec<String>),
+    /// A token whose text is given by the user (Ex: `'$NAME'`, `'STR_LIT'`)
+    Token(String),
+    /// Keywords that are either present or not (Ex: `'KW_IF' 'KW_EXISTS'`)
+    Flag,
+}
+
+/// Fields of the builder of the given ungrammar node. Field names match the method names of the
+/// typed AST where possible.
+pub fn builder_fields(node_name: &str) -> Vec<BuilderField> {
+    let Some(node) = UNGRAMMAR.get_by_name(node_name) else {
+        return Vec::new();
+    };
+
+    let mut fields = Vec::new();
+    collect_builder_fields(&node.rule, &rep_labels(&node.rule), &mut fields);
+
+    fields
+}
+
+/// Maps a node that is repeated under a label to that label (Ex: `ResultColumn` to `items` in
+/// `ResultColumn items: (',' ResultColumn)*`). Like in the typed AST, an occurrence of the node
+/// before the repetition is considered a part of it.
+pub(crate) fn rep_labels(rule: &Rule) -> HashMap<String, String> {
+    let mut labels = HashMap::new();
+    let mut rules = vec![rule];
+
+    while let Some(rule) = rules.pop() {
+        match rule {
+            Rule::Labeled { label, rule } => match remove_opt(rule) {
+                Rule::Rep(inner) => {
+                    let elem = match remove_opt(inner) {
+                        Rule::Seq(items) => items.iter().find(|it| matches!(it, Rule::Node(_))),
+                        inner => Some(inner),
+                    };
+
+                    if let Some(Rule::Node(node_id)) = elem {
+                        let name = UNGRAMMAR.get_node(*node_id).name.clone();
+                        labels.insert(name, label.clone());
+                    }
+                }
+                rule => rules.push(rule),
+            },
+            Rule::Seq(items) | Rule::Alt(items) => rules.extend(items),
+            Rule::Opt(rule) | Rule::Rep(rule) => rules.push(rule),
+            Rule::Node(_) | Rule::Token(_) => {}
+        }
+    }
+
+    labels
+}
+
+/// The field filled in by the given rule. Returns `None` for fixed tokens and for rules that fill
+/// in several fields (Ex: sequences)
+pub(crate) fn builder_field(
+    rule: &Rule,
+    rep_labels: &HashMap<String, String>,
+) -> Option<BuilderField> {
+    match rule {
+        Rule::Node(node_id) => {
+            let node_name = &UNGRAMMAR.get_node(*node_id).name;
+            let rep_label = rep_labels.get(node_name);
+
+            Some(BuilderField {
+                name: rep_label.cloned().unwrap_or_else(|| snake_case(node_name)),
+                kind: BuilderFieldKind::Node(node_name.clone()),
+                repeated: rep_label.is_some(),
+            })
+        }
+        Rule::Token(token_id) => {
+            let token_name = UNGRAMMAR.get_token(*token_id);
+
+            is_variable_token(token_name).then(|| BuilderField {
+                name: token_name.trim_start_matches('$').to_ascii_lowercase(),
+                kind: BuilderFieldKind::Token(token_name.to_owned()),
+                repeated: false,
+            })
+        }
+        Rule::Labeled { label, rule } => {
+            let (kind, repeated) = match remove_opt(rule) {
+                Rule::Node(node_id) => {
+                    let node_name = UNGRAMMAR.get_node(*node_id).name.clone();
+                    (BuilderFieldKind::Node(node_name), false)
+                }
+                Rule::Token(token_id) => {
+                    let token_name = UNGRAMMAR.get_token(*token_id);
+                    if is_variable_token(token_name) {
+                        (BuilderFieldKind::Token(token_name.to_owned()), false)
+                    } else {
+                        (BuilderFieldKind::Flag, false)
+                    }
+                }
+                Rule::Alt(alts) => {
+                    let choices = alts
+                        .iter()
+                        .map(|it| match it {
+                            Rule::Node(node_id) => Some(UNGRAMMAR.get_node(*node_id).name.clone()),
+                            Rule::Token(token_id) => {
+                                Some(UNGRAMMAR.get_token(*token_id).to_owned())
+                            }
+                            _ => None,
+                        })
+                        .collect::<Option<Vec<_>>>()?;
+
+                    (BuilderFieldKind::Choice(choices), false)
+                }
+                Rule::Rep(_) => {
+                    let node_name = rep_labels
+                        .iter()
+                        .find_map(|(node, it)| (it == label).then(|| node.clone()))?;
+
+                    (BuilderFieldKind::Node(node_name), true)
+                }
+                _ => return None,
+            };
+
+            // Same naming as the typed AST (Ex: `__lhs: Expr` is `lhs_expr`)
+            let name = match (label.strip_prefix("__"), &kind) {
+                (Some(tag), BuilderFieldKind::Node(node_name)) => {
+                    format!("{tag}_{}", snake_case(node_name))
+                }
+                _ => label.clone(),
+            };
+
+            Some(BuilderField {
+                name,
+                kind,
+                repeated,
+            })
+        }
+        Rule::Seq(_) | Rule::Alt(_) | Rule::Opt(_) | Rule::Rep(_) => None,
+    }
+}
+
+pub(crate) fn collect_builder_fields(
+    rule: &Rule,
+    rep_labels: &HashMap<String, String>,
+    fields: &mut Vec<BuilderField>,
+) {
+    let field = if let Some(field) = builder_field(rule, rep_labels) {
+        field
+    } else if let Some(name) = flag_name(rule).filter(|_| matches!(rule, Rule::Opt(_))) {
+        BuilderField {
+            name,
+            kind: BuilderFieldKind::Flag,
+            repeated: false,
+        }
+    } else {
+        match rule {
+            Rule::Labeled { rule, .. } | Rule::Opt(rule) | Rule::Rep(rule) => {
+                collect_builder_fields(rule, rep_labels, fields)
+            }
+            Rule::Seq(items) => items
+                .iter()
+                .for_each(|it| collect_builder_fields(it, rep_labels, fields)),
+            Rule::Alt(alts) => alts.iter().for_each(|alt| match flag_name(alt) {
+                Some(name) => fields.push(BuilderField {
+                    name,
+                    kind: BuilderFieldKind::Flag,
+                    repeated: false,
+                }),
+                None => collect_builder_fields(alt, rep_labels, fields),
+            }),
+            Rule::Node(_) | Rule::Token(_) => {}
+        }
+
+        return;
+    };
+
+    match fields.iter_mut().find(|it| it.name == field.name) {
+        // Ex: `'$ANY_JOIN_KW'? '$ANY_JOIN_KW'?`
+        Some(existing) => existing.repeated = true,
+        None => fields.push(field),
+    }
+}
+
+/// Name of the flag that toggles a rule made of fixed tokens (Ex: `if_exists` for
+/// `('KW_IF' 'KW_EXISTS')?`). Returns `None` if the rule contains anything else.
+pub(crate) fn flag_name(rule: &Rule) -> Option<String> {
+    fn helper(rule: &Rule, names: &mut Vec<String>) -> bool {
+        match rule {
+            Rule::Token(token_id) => {
+                let token_name = UNGRAMMAR.get_token(*token_id);
+                if is_variable_token(token_name) {
+                    return false;
+                }
+
+                let name = match token_name.strip_prefix("KW_") {
+                    Some(keyword) => keyword.to_ascii_lowercase(),
+                    None => EnumSet::<SqliteTokenKind>::all()
+                        .iter()
+                        .find(|it| it.as_str() == token_name)
+                        .map(|it| format!("{it:?}").to_ascii_lowercase())
+                        .unwrap_or_default(),
+                };
+                names.push(name);
+
+                true
+            }
+            Rule::Seq(items) => items.iter().all(|it| helper(it, names)),
+            Rule::Opt(rule) => helper(rule, names),
+            _ => false,
+        }
+    }
+
+    let mut names = Vec::new();
+    (helper(rule, &mut names) && !names.is_empty()).then(|| names.join("_"))
+}
+
+/// Tokens whose text is not fixed by the grammar (Ex: `'$NAME'`, `'INT_LIT'`)
+pub(crate) fn is_variable_token(token_name: &str) -> bool {
+    token_name.starts_with('$')
+        || matches!(
+            token_name,
+            "IDEN" | "PARAM" | "INT_LIT" | "HEX_LIT" | "REAL_LIT" | "STR_LIT" | "BLOB_LIT"
+        )
+}
+
+/// The rule inside any number of `?`
+pub fn remove_opt(mut rule: &Rule) -> &Rule {
+    while let Rule::Opt(inner) = rule {
+        rule = inner
+    }
+
+    rule
+}
+
+fn snake_case(name: &str) -> String {
+    let mut result = String::new();
+
+    for (idx, ch) in name.char_indices() {
+        if ch.is_ascii_uppercase() && idx > 0 {
+            result.push('_');
+        }
+        result.push(ch.to_ascii_lowercase());
+    }
+
+    result
+}
@@ -0,0 +1,198 @@
+//! Checks that are not done by the parser because the CST is the same either way. The parser
+//! always accepts the syntax of the latest SQLite version and it is up to these checks to
+//! flag whatever the targeted SQLite does not understand.
+
+use text_size::TextRange;
+
+use crate::{
+    version::function_introduced_in, CstNodeTrait, ParserConfig, SqliteFeature, SqliteTokenKind,
+    SqliteTreeKind, SqliteVersion,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    pub range: TextRange,
+    /// Stable identifier for the error (See [`crate::ParseErrorKind::code`])
+    pub code: &'static str,
+    pub message: String,
+}
+
+/// Runs all the checks that depend on the targeted build of SQLite
+pub fn validate<'a, N: CstNodeTrait<'a>>(root: N, config: &ParserConfig) -> Vec<ValidationError> {
+    let mut errors = check_version(root, config.version);
+    errors.extend(check_compile_options(root, config));
+
+    errors
+}
+
+/// Reports syntax used in the tree that is newer than the given SQLite version
+pub fn check_version<'a, N: CstNodeTrait<'a>>(
+    root: N,
+    version: SqliteVersion,
+) -> Vec<ValidationError> {
+    use SqliteTokenKind::*;
+    use SqliteTreeKind::*;
+
+    let mut errors = Vec::new();
+
+    for node in root.me_and_descendants() {
+        let parent_tree = || {
+            if node.is_root() {
+                None
+            } else {
+                node.parent().tree()
+            }
+        };
+
+        let feature = match (node.tree(), node.token_kind()) {
+            (Some(UpsertClause), _) => SqliteFeature::Upsert,
+            (Some(OverClause | WindowClause), _) => SqliteFeature::WindowFunctions,
+            (Some(RenameColumn), _) => SqliteFeature::RenameColumn,
+            (Some(FilterClause), _) => SqliteFeature::FilterClause,
+            (Some(ColumnGenerated), _) => SqliteFeature::GeneratedColumns,
+            (Some(ReturningClause), _) => SqliteFeature::ReturningClause,
+            (Some(DropColumn), _) => SqliteFeature::DropColumn,
+            (Some(MaterializedCte), _) => SqliteFeature::MaterializedCte,
+            (Some(OpIsDistinctFrom | OpIsNotDistinctFrom), _) => SqliteFeature::IsDistinctFrom,
+            (_, Some(KW_STRICT)) if parent_tree() == Some(TableOptions) => {
+                SqliteFeature::StrictTables
+            }
+            (_, Some(EXTRACT_ONE | EXTRACT_TWO)) => SqliteFeature::JsonOperators,
+            (_, Some(KW_RIGHT | KW_FULL)) if parent_tree() == Some(JoinOperator) => {
+                SqliteFeature::RightAndFullJoin
+            }
+            (Some(FunctionName), _) => {
+                let Some(name) = first_non_trivia_token(node).and_then(|it| it.token()) else {
+                    continue;
+                };
+
+                match function_introduced_in(&name.text) {
+                    Some(introduced_in) if version < introduced_in => {
+                        errors.push(ValidationError {
+                            range: leading_token_range(node),
+                            code: "V0001",
+                            message: format!(
+                                "`{}()` requires SQLite {introduced_in} (targeting {version})",
+                                name.text
+                            ),
+                        })
+                    }
+                    _ => {}
+                }
+
+                continue;
+            }
+            _ => continue,
+        };
+
+        if !version.supports(feature) {
+            errors.push(ValidationError {
+                range: leading_token_range(node),
+                code: "V0001",
+                message: format!(
+                    "{} requires SQLite {} (targeting {version})",
+                    feature.name(),
+                    feature.introduced_in()
+                ),
+            });
+        }
+    }
+
+    errors
+}
+
+/// Reports constructs that are disabled (or not enabled) by the compile-time options of the
+/// targeted SQLite build.
+///
+/// NOTE: `SQLITE_OMIT_COMPOUND_SELECT` is not checked here because the compound operators become
+/// identifiers in such builds and are therefore handled by the parser itself.
+pub fn check_compile_options<'a, N: CstNodeTrait<'a>>(
+    root: N,
+    config: &ParserConfig,
+) -> Vec<ValidationError> {
+    use SqliteTreeKind::*;
+
+    let mut errors = Vec::new();
+
+    for node in root.me_and_descendants() {
+        let Some(tree) = node.tree() else {
+            continue;
+        };
+
+        let message = match tree {
+            DeleteStmtLimited | UpdateStmtLimited if !config.enable_update_delete_limit => {
+                let stmt = if tree == DeleteStmtLimited {
+                    "DELETE"
+                } else {
+                    "UPDATE"
+                };
+
+                format!(
+                    "ORDER BY and LIMIT on {stmt} require SQLite to be built with \
+                    SQLITE_ENABLE_UPDATE_DELETE_LIMIT"
+                )
+            }
+            _ => {
+                let (disabled, construct, option) = match tree {
+                    AlterTableStmt => (config.omit_alter_table, "ALTER TABLE", "ALTERTABLE"),
+                    AnalyzeStmt => (config.omit_analyze, "ANALYZE", "ANALYZE"),
+                    AttachDbStmt => (config.omit_attach, "ATTACH", "ATTACH"),
+                    DetachStmt => (config.omit_attach, "DETACH", "ATTACH"),
+                    CteClause => (config.omit_cte, "Common table expressions", "CTE"),
+                    ExplainClause => (config.omit_explain, "EXPLAIN", "EXPLAIN"),
+                    ColumnGenerated => (
+                        config.omit_generated_columns,
+                        "Generated columns",
+                        "GENERATED_COLUMNS",
+                    ),
+                    PragmaStmt => (config.omit_pragma, "PRAGMA", "PRAGMA"),
+                    ReIndexStmt => (config.omit_reindex, "REINDEX", "REINDEX"),
+                    CreateTriggerStmt | DropTriggerStmt => {
+                        (config.omit_trigger, "Triggers", "TRIGGER")
+                    }
+                    VacuumStmt => (config.omit_vacuum, "VACUUM", "VACUUM"),
+                    CreateVirtualTableStmt => {
+                        (config.omit_virtual_table, "Virtual tables", "VIRTUALTABLE")
+                    }
+                    OverClause | WindowClause => {
+                        (config.omit_window_func, "Window functions", "WINDOWFUNC")
+                    }
+                    _ => continue,
+                };
+
+                if !disabled {
+                    continue;
+                }
+
+                format!("{construct} is not available in SQLite built with SQLITE_OMIT_{option}")
+            }
+        };
+
+        errors.push(ValidationError {
+            range: leading_token_range(node),
+            code: "V0002",
+            message,
+        });
+    }
+
+    errors
+}
+
+fn first_non_trivia_token<'a, N: CstNodeTrait<'a>>(node: N) -> Option<N> {
+    node.me_and_descendants()
+        .find(|it| it.token().is_some_and(|tk| !tk.is_trivia()))
+}
+
+/// Highlighting an entire clause is noisy. Instead, we prefer the first token that belongs
+/// to the node itself (usually the keyword that introduces the clause)
+pub(crate) fn leading_token_range<'a, N: CstNodeTrait<'a>>(node: N) -> TextRange {
+    let token = node
+        .non_trivial_children()
+        .find(|it| it.token().is_some())
+        .or_else(|| first_non_trivia_token(node));
+
+    match token {
+        Some(token) => TextRange::new(token.start_pos(), token.end_pos()),
+        None => TextRange::new(node.start_pos_skip_trivia(), node.end_pos_skip_trivia()),
+    }
+}
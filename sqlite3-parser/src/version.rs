@@ -1,10 +1,124 @@
-#[derive(Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct SqliteVersion(pub [u16; 3]);
 
 impl SqliteVersion {
+    /// The newest version of SQLite whose syntax we understand
+    pub const LATEST: SqliteVersion = SqliteVersion([3, 46, 0]);
+
     /// SQLite added support for underscores in numeric literals in version 3.46.0.
     /// https://www.sqlite.org/lang_expr.html
     pub fn underscore_in_numerics(&self) -> bool {
-        self.0 >= [3, 46, 0]
+        self.supports(SqliteFeature::UnderscoreInNumerics)
+    }
+
+    pub fn supports(&self, feature: SqliteFeature) -> bool {
+        *self >= feature.introduced_in()
+    }
+}
+
+impl Default for SqliteVersion {
+    fn default() -> Self {
+        Self::LATEST
+    }
+}
+
+impl std::fmt::Display for SqliteVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [major, minor, patch] = self.0;
+        std::write!(f, "{major}.{minor}.{patch}")
     }
 }
+
+/// Parses versions like `3.31` or `3.31.1`
+impl std::str::FromStr for SqliteVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut version = [0; 3];
+        let parts: Vec<_> = s.trim().split('.').collect();
+
+        if parts.len() < 2 || parts.len() > 3 {
+            return Err(format!("Invalid SQLite version: {s}"));
+        }
+
+        for (idx, part) in parts.into_iter().enumerate() {
+            version[idx] = part
+                .parse()
+                .map_err(|_| format!("Invalid SQLite version: {s}"))?;
+        }
+
+        Ok(SqliteVersion(version))
+    }
+}
+
+/// Syntax that is only understood by newer versions of SQLite
+/// Source: https://www.sqlite.org/changes.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SqliteFeature {
+    Upsert,
+    WindowFunctions,
+    RenameColumn,
+    FilterClause,
+    GeneratedColumns,
+    ReturningClause,
+    DropColumn,
+    MaterializedCte,
+    StrictTables,
+    JsonOperators,
+    RightAndFullJoin,
+    IsDistinctFrom,
+    UnderscoreInNumerics,
+}
+
+impl SqliteFeature {
+    pub const fn introduced_in(&self) -> SqliteVersion {
+        let version = match self {
+            SqliteFeature::Upsert => [3, 24, 0],
+            SqliteFeature::WindowFunctions => [3, 25, 0],
+            SqliteFeature::RenameColumn => [3, 25, 0],
+            SqliteFeature::FilterClause => [3, 30, 0],
+            SqliteFeature::GeneratedColumns => [3, 31, 0],
+            SqliteFeature::ReturningClause => [3, 35, 0],
+            SqliteFeature::DropColumn => [3, 35, 0],
+            SqliteFeature::MaterializedCte => [3, 35, 0],
+            SqliteFeature::StrictTables => [3, 37, 0],
+            SqliteFeature::JsonOperators => [3, 38, 0],
+            SqliteFeature::RightAndFullJoin => [3, 39, 0],
+            SqliteFeature::IsDistinctFrom => [3, 39, 0],
+            SqliteFeature::UnderscoreInNumerics => [3, 46, 0],
+        };
+
+        SqliteVersion(version)
+    }
+
+    pub const fn name(&self) -> &'static str {
+        match self {
+            SqliteFeature::Upsert => "UPSERT",
+            SqliteFeature::WindowFunctions => "window functions",
+            SqliteFeature::RenameColumn => "RENAME COLUMN",
+            SqliteFeature::FilterClause => "FILTER clause",
+            SqliteFeature::GeneratedColumns => "generated columns",
+            SqliteFeature::ReturningClause => "RETURNING clause",
+            SqliteFeature::DropColumn => "DROP COLUMN",
+            SqliteFeature::MaterializedCte => "MATERIALIZED hint",
+            SqliteFeature::StrictTables => "STRICT tables",
+            SqliteFeature::JsonOperators => "`->` and `->>` operators",
+            SqliteFeature::RightAndFullJoin => "RIGHT and FULL joins",
+            SqliteFeature::IsDistinctFrom => "IS DISTINCT FROM",
+            SqliteFeature::UnderscoreInNumerics => "underscores in numeric literals",
+        }
+    }
+}
+
+/// Built-in functions that only exist in newer versions of SQLite
+pub fn function_introduced_in(name: &str) -> Option<SqliteVersion> {
+    let version = match name.to_ascii_lowercase().as_str() {
+        "iif" => [3, 32, 0],
+        "unixepoch" | "format" => [3, 38, 0],
+        "octet_length" | "timediff" => [3, 43, 0],
+        "concat" | "concat_ws" | "string_agg" => [3, 44, 0],
+        _ => return None,
+    };
+
+    Some(SqliteVersion(version))
+}
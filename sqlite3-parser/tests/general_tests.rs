@@ -32,3 +32,881 @@ fn ensure_both_cst_kinds_match() {
     assert_eq!(incr_cst_display, batch_cst_display);
     assert_eq!(incr_cst_display, slot_cst_display);
 }
+
+#[test]
+fn parse_errors_are_human_readable() {
+    use bord_sqlite3_parser::{
+        CstNodeTrait, ExpectedItem, ParseErrorKind, SqliteTokenKind, SqliteTreeKind,
+    };
+
+    let err = ParseErrorKind::ExpectedItems(vec![
+        ExpectedItem::Token(SqliteTokenKind::SEMICOLON),
+        ExpectedItem::Token(SqliteTokenKind::COMMA),
+    ]);
+    assert_eq!(err.to_string(), "expected `;` or `,`");
+    assert_eq!(
+        SqliteTreeKind::ColumnDef.display_name(),
+        "column definition"
+    );
+
+    let input = "SELECT * FROM;";
+    let cst: batch::SqlCst = parse(input);
+    let err_node = cst.errors().next().unwrap();
+    let range = err_node.error_range().unwrap();
+
+    // Missing items are highlighted on the token before them instead of an empty range
+    assert_eq!(&input[range], "FROM");
+    assert!(err_node.error_message().unwrap().starts_with("expected "));
+}
+
+#[test]
+fn misspelled_keywords_get_suggestions() {
+    use bord_sqlite3_parser::{CstNodeTrait, ExpectedItem, ParseErrorKind, SqliteTokenKind};
+
+    let err = ParseErrorKind::ExpectedItems(vec![
+        ExpectedItem::Token(SqliteTokenKind::KW_FROM),
+        ExpectedItem::Token(SqliteTokenKind::COMMA),
+    ]);
+    assert_eq!(
+        err.keyword_suggestion("FORM"),
+        Some(SqliteTokenKind::KW_FROM)
+    );
+    assert_eq!(
+        err.keyword_suggestion("form"),
+        Some(SqliteTokenKind::KW_FROM)
+    );
+    assert_eq!(err.keyword_suggestion("users"), None);
+
+    let cst: batch::SqlCst = parse("SELCT 1;");
+    let err_node = cst.errors().next().unwrap();
+    let (token, keyword) = err_node.keyword_typo().unwrap();
+
+    assert_eq!(token.token().unwrap().text, "SELCT");
+    assert_eq!(keyword, SqliteTokenKind::KW_SELECT);
+    assert!(err_node
+        .error_message()
+        .unwrap()
+        .ends_with("did you mean `SELECT`?"));
+}
+
+#[test]
+fn lex_errors_are_reported() {
+    use bord_sqlite3_parser::{CstNodeTrait, LexError};
+
+    let cst: batch::SqlCst = parse("SELECT x'abc', 'unterminated;");
+    let errors: Vec<_> = cst
+        .lex_errors()
+        .map(|it| it.token().unwrap().error.clone().unwrap())
+        .collect();
+
+    assert_eq!(
+        errors,
+        vec![
+            LexError::MalformedBlobLiteral,
+            LexError::UnterminatedStringLiteral
+        ]
+    );
+    assert_eq!(
+        LexError::UnterminatedStringLiteral.to_string(),
+        "unterminated string literal"
+    );
+}
+
+#[test]
+fn newer_syntax_is_reported_for_older_versions() {
+    use bord_sqlite3_parser::{check_version, SqliteVersion};
+
+    let input = "CREATE TABLE t(a INT, b INT AS (a + 1)) STRICT;
+        INSERT INTO t(a) VALUES (1) ON CONFLICT DO NOTHING RETURNING a;
+        SELECT a -> '$.x', unixepoch() FROM t RIGHT JOIN u;";
+    let cst: batch::SqlCst = parse(input);
+
+    let flagged = |version: SqliteVersion| -> Vec<&str> {
+        check_version(cst.root(), version)
+            .into_iter()
+            .map(|it| &input[it.range])
+            .collect()
+    };
+
+    assert_eq!(
+        flagged(SqliteVersion([3, 23, 0])),
+        vec![
+            "AS",
+            "STRICT",
+            "ON",
+            "RETURNING",
+            "->",
+            "unixepoch",
+            "RIGHT"
+        ]
+    );
+    assert_eq!(
+        flagged(SqliteVersion([3, 37, 0])),
+        vec!["->", "unixepoch", "RIGHT"]
+    );
+    assert!(flagged(SqliteVersion::LATEST).is_empty());
+    assert_eq!("3.31".parse(), Ok(SqliteVersion([3, 31, 0])));
+}
+
+#[test]
+fn compile_options_are_respected() {
+    use bord_sqlite3_parser::{check_compile_options, parse_with_config, ParserConfig};
+
+    let input = "DELETE FROM t WHERE a = 1 LIMIT 10;
+        WITH c AS (SELECT 1) SELECT * FROM c;
+        VACUUM;";
+    let cst: batch::SqlCst = parse(input);
+
+    let flagged = |config: &ParserConfig| -> Vec<&str> {
+        check_compile_options(cst.root(), config)
+            .into_iter()
+            .map(|it| &input[it.range])
+            .collect()
+    };
+
+    let mut config = ParserConfig::default();
+    assert_eq!(flagged(&config), vec!["LIMIT"]);
+
+    config
+        .set_compile_option("SQLITE_ENABLE_UPDATE_DELETE_LIMIT")
+        .unwrap();
+    config.set_compile_option("OMIT_CTE").unwrap();
+    config.set_compile_option("SQLITE_OMIT_VACUUM").unwrap();
+    assert_eq!(flagged(&config), vec!["WITH", "VACUUM"]);
+    assert!(config.set_compile_option("SQLITE_OMIT_EVERYTHING").is_err());
+
+    // Compound operators fall back to identifiers when compound selects are omitted
+    let config = ParserConfig {
+        omit_compound_select: true,
+        ..Default::default()
+    };
+    let cst: batch::SqlCst = parse_with_config("SELECT 1 AS union;", config);
+    assert_eq!(cst.errors().count(), 0);
+
+    let cst: batch::SqlCst = parse("SELECT 1 AS union;");
+    assert_ne!(cst.errors().count(), 0);
+}
+
+#[test]
+fn templates_are_parsed_as_opaque_names() {
+    use bord_sqlite3_parser::{parse_with_config, CstNodeTrait, ParserConfig, SqliteTokenKind};
+
+    let input = "SELECT id, {{ dbt_utils.star(ref('users')) }}
+        FROM {{ ref('users') }} AS u
+        WHERE {% if active %} u.active AND {% endif %} u.id = sqlc.arg(id)
+        {# LIMIT 10 #};";
+    let config = ParserConfig {
+        templates: true,
+        ..Default::default()
+    };
+    let cst: batch::SqlCst = parse_with_config(input, config);
+    assert_eq!(cst.errors().count(), 0, "{cst}");
+
+    let templates: Vec<_> = cst
+        .root()
+        .me_and_descendants()
+        .filter_map(|it| it.token())
+        .filter(|it| it.kind == SqliteTokenKind::TEMPLATE_EXPR)
+        .map(|it| it.text.as_str())
+        .collect();
+    assert_eq!(
+        templates,
+        [
+            "{{ dbt_utils.star(ref('users')) }}",
+            "{{ ref('users') }}",
+            "sqlc.arg(id)"
+        ]
+    );
+
+    let cst: batch::SqlCst = parse(input);
+    assert_ne!(cst.errors().count(), 0);
+}
+
+#[test]
+fn named_queries_are_indexed() {
+    use bord_sqlite3_parser::{named_queries, QueryCommand};
+
+    let input = "-- Not an annotation
+        CREATE TABLE users(id, name);
+
+        -- name: GetUser :one
+        SELECT id, u.name, \"full name\", count(*) AS total, u.* FROM users u
+        WHERE id = :id OR (:id IS NULL AND name = ?);
+
+        /* name: CreateUser :one */
+        INSERT INTO users(name) VALUES (?1) RETURNING id, name AS n, *;
+
+        -- name: ListPairs :many
+        VALUES (1, ?), (?, 2);
+
+        -- name: DeleteUsers :unknown
+        DELETE FROM users;";
+    let cst: batch::SqlCst = parse(input);
+
+    let annotations: Vec<_> = cst
+        .statements()
+        .map(|it| it.annotation.map(|it| it.name))
+        .collect();
+    assert_eq!(
+        annotations,
+        [
+            None,
+            Some("GetUser".into()),
+            Some("CreateUser".into()),
+            Some("ListPairs".into()),
+            None
+        ]
+    );
+
+    let queries = named_queries(&cst);
+    let summary: Vec<_> = queries
+        .iter()
+        .map(|it| {
+            let params: Vec<_> = it.params.iter().map(|it| it.text.as_str()).collect();
+            let columns: Vec<_> = it.columns.iter().map(|it| it.name.as_str()).collect();
+            (it.annotation.command, params, columns)
+        })
+        .collect();
+
+    assert_eq!(
+        summary,
+        [
+            (
+                QueryCommand::One,
+                vec![":id", "?"],
+                vec!["id", "name", "full name", "total", "u.*"]
+            ),
+            (QueryCommand::One, vec!["?1"], vec!["id", "n", "*"]),
+            (
+                QueryCommand::Many,
+                vec!["?", "?"],
+                vec!["column1", "column2"]
+            ),
+        ]
+    );
+    assert_eq!(queries[0].params[0].name.as_deref(), Some("id"));
+    assert_eq!(&input[queries[1].range], queries[1].sql);
+    assert!(queries[1].sql.starts_with("INSERT"));
+}
+
+#[test]
+fn fragments_can_be_parsed_on_their_own() {
+    use bord_sqlite3_parser::{parse_fragment, CstNodeTrait, FragmentKind, SqliteTreeKind};
+
+    let check = |kind: FragmentKind, input: &str, expected_tree: SqliteTreeKind| {
+        let cst: batch::SqlCst = parse_fragment(kind, input);
+
+        assert_eq!(cst.errors().count(), 0, "{cst}");
+        assert_eq!(cst.root().to_text(), input);
+        assert!(cst
+            .root()
+            .children()
+            .any(|it| it.tree() == Some(expected_tree)));
+    };
+
+    check(FragmentKind::Expr, " a + 1 > 10 ", SqliteTreeKind::Expr);
+    check(
+        FragmentKind::TypeName,
+        "VARCHAR(255)",
+        SqliteTreeKind::TypeName,
+    );
+    check(
+        FragmentKind::ColumnDef,
+        "id INTEGER PRIMARY KEY",
+        SqliteTreeKind::ColumnDef,
+    );
+    check(
+        FragmentKind::Select,
+        "WITH a AS (SELECT 1) SELECT * FROM a",
+        SqliteTreeKind::SelectStmtWithCte,
+    );
+    check(
+        FragmentKind::OrderBy,
+        "name DESC, id",
+        SqliteTreeKind::OrderingTermList,
+    );
+    check(
+        FragmentKind::OrderBy,
+        "ORDER BY name",
+        SqliteTreeKind::OrderByClause,
+    );
+
+    // Leftover and invalid input is reported without losing any text
+    let input = "a + ) b";
+    let cst: batch::SqlCst = parse_fragment(FragmentKind::Expr, input);
+    assert_ne!(cst.errors().count(), 0);
+    assert_eq!(cst.root().to_text(), input);
+}
+
+#[test]
+fn typed_ast_can_be_visited() {
+    use bord_sqlite3_parser::ast::{self, Visitor};
+    use bord_sqlite3_parser::CstNodeTrait;
+
+    #[derive(Default)]
+    struct ColumnCollector {
+        columns: Vec<String>,
+        exprs: usize,
+    }
+
+    impl<'a, N: CstNodeTrait<'a>> Visitor<'a, N> for ColumnCollector {
+        fn visit_column_def(&mut self, node: &ast::ColumnDef<N>) {
+            if let Some(name) = node.column_name().and_then(|it| it.value()) {
+                self.columns.push(name.token().unwrap().text.to_string());
+            }
+            ast::walk_column_def(self, node);
+        }
+
+        fn visit_expr(&mut self, node: &ast::Expr<N>) {
+            self.exprs += 1;
+            ast::walk_expr(self, node);
+        }
+    }
+
+    let cst: batch::SqlCst = parse(
+        "CREATE TABLE users(id INTEGER PRIMARY KEY, name TEXT CHECK (length(name) > 0));
+        SELECT id FROM users WHERE name = 'bob';",
+    );
+
+    let mut collector = ColumnCollector::default();
+    collector.visit_file(&cst.typed_ast());
+    assert_eq!(collector.columns, vec!["id", "name"]);
+    // length(name) > 0, length(name), name, 0, id, name = 'bob', name, 'bob'
+    assert_eq!(collector.exprs, 8);
+
+    // Traversal can also start from an untyped node
+    let mut collector = ColumnCollector::default();
+    ast::visit_untyped(&mut collector, cst.root());
+    assert_eq!(collector.columns, vec!["id", "name"]);
+}
+
+#[test]
+fn typed_ast_can_be_built() {
+    use bord_sqlite3_parser::ast::*;
+    use bord_sqlite3_parser::CstNodeTrait;
+
+    let column = |name: &str| -> Built<Expr<()>> {
+        ExprColumnName::build()
+            .column_name(ColumnName::new(name).unwrap())
+            .finish()
+            .unwrap()
+            .into()
+    };
+    let result_column = |name: &str| -> Built<ResultColumn<()>> {
+        ResultColumnExpr::build()
+            .expr(column(name))
+            .finish()
+            .unwrap()
+            .into()
+    };
+
+    let condition = OpAnd::build()
+        .lhs_expr(
+            OpEq::build()
+                .lhs_expr(column("name"))
+                .rhs_expr(ExprLit::build_str_lit("'bob'").unwrap())
+                .finish()
+                .unwrap(),
+        )
+        .rhs_expr(
+            OpOr::build()
+                .lhs_expr(column("age"))
+                .rhs_expr(ExprLit::build_kw_null())
+                .finish()
+                .unwrap(),
+        )
+        .finish()
+        .unwrap();
+
+    let table = TableOrSubquery::build()
+        .qualified_table_name(
+            QualifiedTableName::build()
+                .full_table_name(
+                    FullTableName::build()
+                        .table(TableName::new("order").unwrap())
+                        .finish()
+                        .unwrap(),
+                )
+                .finish()
+                .unwrap(),
+        )
+        .finish()
+        .unwrap();
+
+    let select = SelectStmt::build()
+        .select_core(
+            TraditionalSelect::build()
+                .select_distinct(SelectDistinct::build_kw_distinct())
+                .result_column_list(
+                    ResultColumnList::build()
+                        .items(result_column("id"))
+                        .items(result_column("name"))
+                        .finish()
+                        .unwrap(),
+                )
+                .from_clause(
+                    FromClause::build()
+                        .from_clause_value(table)
+                        .finish()
+                        .unwrap(),
+                )
+                .where_clause(WhereClause::build().expr(condition).finish().unwrap())
+                .finish()
+                .unwrap(),
+        )
+        .finish()
+        .unwrap();
+
+    // Keywords used as names are quoted and nested operators are parenthesized
+    assert_eq!(
+        select.text(),
+        r#"SELECT DISTINCT id, name FROM "order" WHERE (name = 'bob') AND (age OR NULL)"#
+    );
+
+    let cst: batch::SqlCst = select.to_cst();
+    assert!(cst.errors().next().is_none());
+    assert_eq!(cst.root().to_text(), select.text());
+
+    // Fields are checked against the grammar
+    assert!(matches!(
+        ResultColumnList::build().finish(),
+        Err(BuildError::MissingField { .. })
+    ));
+    assert!(matches!(
+        ExprLit::build_int_lit("'1'"),
+        Err(BuildError::InvalidToken { .. })
+    ));
+}
+
+#[test]
+fn cst_can_be_rewritten() {
+    use bord_sqlite3_parser::{
+        incremental_parse2, CstNodeTrait, CstRewriter, ParserConfig, RewriteError, SqliteTreeKind,
+    };
+
+    let input = "SELECT id, name, age FROM users WHERE age > 18;\nDELETE FROM logs;";
+    let cst: incr::IncrSqlCst = parse(input);
+    let find = |kind: SqliteTreeKind, nth: usize| {
+        cst.root()
+            .me_and_descendants()
+            .filter(|it| it.tree() == Some(kind))
+            .nth(nth)
+            .unwrap()
+    };
+
+    let mut rewriter = CstRewriter::new(cst.root());
+    rewriter.delete(find(SqliteTreeKind::ResultColumn, 1));
+    rewriter.replace(find(SqliteTreeKind::TableName, 0), "people");
+    rewriter.wrap(find(SqliteTreeKind::OpGT, 0), "NOT (", ")");
+    rewriter.insert_after(find(SqliteTreeKind::ResultColumn, 2), " AS years");
+    rewriter.insert_before(find(SqliteTreeKind::Statement, 1), "-- cleanup\n");
+
+    let expected =
+        "SELECT id, age AS years FROM people WHERE NOT (age > 18);\n-- cleanup\nDELETE FROM logs;";
+    assert_eq!(rewriter.new_text().unwrap(), expected);
+
+    assert_eq!(rewriter.edits().unwrap().len(), 6);
+
+    let new_cst: batch::SqlCst = rewriter.to_cst(ParserConfig::default()).unwrap();
+    assert!(new_cst.errors().next().is_none());
+    assert_eq!(new_cst.root().to_text(), expected);
+
+    // Edits can be applied one after the other using the incremental parser
+    let mut text = input.to_owned();
+    let mut incr_cst: incr::IncrSqlCst = parse(input);
+    for edit in rewriter.edits().unwrap().iter().rev() {
+        text.replace_range(std::ops::Range::<usize>::from(edit.range), &edit.new_text);
+
+        let patch = incr_cst.updated_text_patch(edit.to_text_patch());
+        let relex_input = &text[usize::from(patch.relex_start)..];
+        let new_cst = incremental_parse2(relex_input, patch, ParserConfig::default());
+        incr_cst.merge_cst(new_cst, patch);
+    }
+
+    assert_eq!(text, expected);
+    assert_eq!(incr_cst.root().comparable(), new_cst.root().comparable());
+
+    // Insertions around a replaced node stay around it
+    let statement = find(SqliteTreeKind::Statement, 0);
+    let column = find(SqliteTreeKind::ResultColumn, 0);
+
+    let mut rewriter = CstRewriter::new(statement);
+    rewriter.replace(column, "x");
+    rewriter.wrap(column, "(", ")");
+    assert_eq!(
+        rewriter.new_text().unwrap(),
+        "SELECT (x), name, age FROM users WHERE age > 18"
+    );
+
+    let mut rewriter = CstRewriter::new(statement);
+    rewriter.replace(column, "x");
+    rewriter.insert_before(column, "DISTINCT ");
+    assert_eq!(
+        rewriter.new_text().unwrap(),
+        "SELECT DISTINCT x, name, age FROM users WHERE age > 18"
+    );
+
+    // Conflicting edits are reported instead of being applied
+    let mut rewriter = CstRewriter::new(statement);
+    rewriter.replace(find(SqliteTreeKind::TableName, 0), "people");
+    rewriter.delete(find(SqliteTreeKind::FromClause, 0));
+    assert!(matches!(
+        rewriter.edits(),
+        Err(RewriteError::Overlap { .. })
+    ));
+
+    let mut rewriter = CstRewriter::new(statement);
+    rewriter.delete(find(SqliteTreeKind::Statement, 1));
+    assert!(matches!(
+        rewriter.new_text(),
+        Err(RewriteError::OutsideRoot { .. })
+    ));
+}
+
+#[test]
+fn tokens_can_be_found_by_offset() {
+    use bord_sqlite3_parser::{CstNodeTrait, SqliteTokenKind, SqliteTreeKind, TokenAtOffset};
+    use text_size::{TextRange, TextSize};
+
+    fn check<CST: CstTrait>(input: &str) {
+        let cst: CST = parse(input);
+
+        for offset in 0..=input.len() as u32 {
+            let offset = TextSize::new(offset);
+            let containing = |offset: TextSize| {
+                cst.root()
+                    .me_and_descendants()
+                    .filter(|it| it.token().is_some())
+                    .find(|it| it.start_pos() <= offset && offset < it.end_pos())
+                    .map(|it| it.start_pos())
+            };
+
+            let left = offset.checked_sub(TextSize::new(1)).and_then(&containing);
+            let right = containing(offset);
+
+            let found = cst.token_at_offset(offset);
+            assert_eq!(found.left_biased().map(|it| it.start_pos()), left.or(right));
+            assert_eq!(
+                found.right_biased().map(|it| it.start_pos()),
+                right.or(left)
+            );
+        }
+
+        // `users` starts at 21
+        let at_users = cst.token_at_offset(TextSize::new(21));
+        assert!(matches!(at_users, TokenAtOffset::Between(..)));
+        assert_eq!(
+            at_users.right_biased().and_then(|it| it.token_kind()),
+            Some(SqliteTokenKind::IDEN)
+        );
+
+        let covering = cst.covering_element(TextRange::new(TextSize::new(7), TextSize::new(15)));
+        assert_eq!(covering.tree(), Some(SqliteTreeKind::ResultColumnList));
+
+        let covering = cst.covering_element(TextRange::new(TextSize::new(0), TextSize::new(50)));
+        assert_eq!(covering.tree(), Some(SqliteTreeKind::File));
+    }
+
+    let input = "SELECT id, name FROM users WHERE age > 18;\n-- done\nDELETE FROM logs;";
+    check::<batch::SqlCst>(input);
+    check::<incr::IncrSqlCst>(input);
+    check::<slot::SlotIncrSqlCst>(input);
+}
+
+#[test]
+fn node_ptrs_survive_incremental_edits() {
+    use bord_sqlite3_parser::{
+        incremental_parse2, CstNodeTrait, NodePtr, ParserConfig, SqliteTreeKind, TextEdit,
+    };
+    use text_size::{TextRange, TextSize};
+
+    let input = "SELECT id FROM users;\nDELETE FROM logs;";
+    let mut cst: incr::IncrSqlCst = parse(input);
+    let find = |cst: &incr::IncrSqlCst, kind: SqliteTreeKind, nth: usize| {
+        let node = cst
+            .root()
+            .me_and_descendants()
+            .filter(|it| it.tree() == Some(kind))
+            .nth(nth)
+            .unwrap();
+        NodePtr::new(node)
+    };
+
+    let logs = find(&cst, SqliteTreeKind::QualifiedTableName, 1);
+    let users = find(&cst, SqliteTreeKind::TableOrSubquery, 0);
+    let id = find(&cst, SqliteTreeKind::ResultColumn, 0);
+
+    // `id` -> `id, name`
+    let edit = TextEdit {
+        range: TextRange::new(TextSize::new(7), TextSize::new(9)),
+        new_text: "id, name".to_owned(),
+    };
+    let mut text = input.to_owned();
+    text.replace_range(7..9, &edit.new_text);
+
+    let patch = cst.updated_text_patch(edit.to_text_patch());
+    let new_cst = incremental_parse2(
+        &text[usize::from(patch.relex_start)..],
+        patch,
+        ParserConfig::default(),
+    );
+    cst.merge_cst(new_cst, patch);
+
+    let patch = edit.to_text_patch();
+    assert!(id.apply_patch(&patch).is_none());
+
+    let logs = logs.apply_patch(&patch).unwrap();
+    assert_eq!(logs.resolve(&cst).unwrap().to_text(), " logs");
+
+    let users = users.apply_patch(&patch).unwrap();
+    let resolved = users.resolve(&cst).unwrap();
+    assert_eq!(resolved.to_text(), " users");
+    assert_eq!(resolved.tree(), Some(SqliteTreeKind::TableOrSubquery));
+
+    // Pointers work with every kind of CST
+    let batch_cst: batch::SqlCst = parse(&text);
+    assert_eq!(logs.resolve(&batch_cst).unwrap().to_text(), " logs");
+
+    // A pointer that was not kept up to date no longer resolves
+    let name = batch_cst
+        .root()
+        .me_and_descendants()
+        .filter(|it| it.tree() == Some(SqliteTreeKind::ResultColumn))
+        .nth(1)
+        .unwrap();
+    assert!(NodePtr::new(name).resolve(&cst).is_some());
+    assert!(NodePtr::new(name)
+        .resolve(&parse::<batch::SqlCst>(input))
+        .is_none());
+}
+
+#[test]
+fn blocks_are_reparsed_on_their_own() {
+    use bord_sqlite3_parser::{
+        reparse_block, CstNodeTrait, ParserConfig, TextPatch, TextPatchKind,
+    };
+    use text_size::TextSize;
+
+    let input = "CREATE TRIGGER t AFTER INSERT ON users BEGIN
+    UPDATE stats SET n = n + 1;
+    DELETE FROM logs WHERE id IN (SELECT id FROM old_logs);
+END;
+INSERT INTO users VALUES (1, 'bob'), (2, 'alice');
+SELECT * FROM (SELECT a FROM b) WHERE EXISTS (SELECT 1);";
+
+    let check = |start: usize, end: usize, new_text: &str| {
+        let mut text = input.to_owned();
+        text.replace_range(start..end, new_text);
+
+        let patch = TextPatch {
+            relex_start: (),
+            affected_node_byte_len: (),
+            start: TextSize::new(start as u32),
+            size: TextSize::of(new_text),
+            kind: if start == end {
+                TextPatchKind::Insert
+            } else {
+                TextPatchKind::Replace {
+                    end: TextSize::new(end as u32),
+                }
+            },
+        };
+
+        let mut cst: incr::IncrSqlCst = parse(input);
+        let reparsed = reparse_block(&mut cst, &text, patch, ParserConfig::default());
+
+        if reparsed {
+            let expected: batch::SqlCst = parse(&text);
+            assert_eq!(cst.root().to_text(), text);
+            assert_eq!(
+                cst.root().comparable(),
+                expected.root().comparable(),
+                "{text}"
+            );
+        }
+
+        reparsed
+    };
+
+    let pos = |needle: &str| input.find(needle).unwrap();
+
+    // Trigger body statement
+    let n = pos("n + 1");
+    assert!(check(n + 4, n + 5, "42"));
+    // Subqueries
+    let old_logs = pos("old_logs");
+    assert!(check(old_logs, old_logs + 3, "new"));
+    assert!(check(pos("a FROM b"), pos("a FROM b") + 1, "a, c"));
+    assert!(check(pos("1);"), pos("1);") + 1, "2"));
+    // VALUES row
+    let bob = pos("'bob'");
+    assert!(check(bob, bob + 5, "'robert'"));
+
+    // Edits that change the structure around the block fall back to reparsing the statement
+    assert!(!check(bob + 5, bob + 5, ")"));
+    assert!(!check(n, n, "'"));
+    assert!(!check(old_logs, old_logs, "/*"));
+
+    // The CST stays usable after a block was replaced
+    let mut cst: incr::IncrSqlCst = parse(input);
+    let mut text = input.to_owned();
+    let at = pos("alice") + 1;
+    for _ in 0..3 {
+        text.insert_str(at, "10");
+        let patch = TextPatch {
+            relex_start: (),
+            affected_node_byte_len: (),
+            start: TextSize::new(at as u32),
+            size: TextSize::new(2),
+            kind: TextPatchKind::Insert,
+        };
+        assert!(reparse_block(
+            &mut cst,
+            &text,
+            patch,
+            ParserConfig::default()
+        ));
+    }
+    let expected: batch::SqlCst = parse(&text);
+    assert_eq!(cst.root().comparable(), expected.root().comparable());
+
+    // Whatever is reparsed must match a full reparse
+    for offset in 0..input.len() {
+        check(offset, offset, "x");
+        check(offset, offset, " ");
+        check(offset, offset, "(");
+        check(offset, offset + 1, "");
+    }
+}
+
+#[test]
+fn multiple_patches_are_applied_together() {
+    use bord_sqlite3_parser::{
+        incremental_parse_batch, CstNodeTrait, ParserConfig, TextPatch, TextPatchKind,
+    };
+    use text_size::TextSize;
+
+    let input = "SELECT * FROM users;\nDELETE FROM users WHERE id = 1;\nUPDATE users SET a = 1;";
+
+    // Rename every `users` to `people` and drop the WHERE clause
+    let mut patches: Vec<_> = input
+        .match_indices("users")
+        .map(|(idx, _)| TextPatch {
+            relex_start: (),
+            affected_node_byte_len: (),
+            start: TextSize::new(idx as u32),
+            size: TextSize::of("people"),
+            kind: TextPatchKind::Replace {
+                end: TextSize::new((idx + "users".len()) as u32),
+            },
+        })
+        .collect();
+
+    let where_start = input.find(" WHERE").unwrap();
+    patches.insert(
+        2,
+        TextPatch {
+            relex_start: (),
+            affected_node_byte_len: (),
+            start: TextSize::new(where_start as u32),
+            size: TextSize::new(0),
+            kind: TextPatchKind::Replace {
+                end: TextSize::new(input.find(";\nUPDATE").unwrap() as u32),
+            },
+        },
+    );
+
+    let text = "SELECT * FROM people;\nDELETE FROM people;\nUPDATE people SET a = 1;";
+
+    let mut cst: incr::IncrSqlCst = parse(input);
+    incremental_parse_batch(&mut cst, text, &patches, ParserConfig::default());
+
+    let expected: batch::SqlCst = parse(text);
+    assert_eq!(cst.root().to_text(), text);
+    assert_eq!(cst.root().comparable(), expected.root().comparable());
+}
+
+#[test]
+fn patches_are_grouped_by_statement() {
+    use bord_sqlite3_parser::{
+        incremental_parse_batch, CstNodeTrait, ParserConfig, TextPatch, TextPatchKind,
+    };
+    use text_size::TextSize;
+
+    let input = "SELECT * FROM (SELECT a FROM t);\nSELECT 1;\nSELECT * FROM (SELECT b FROM u);";
+    let replace = |pat: &str, new_text: &str| {
+        let start = input.find(pat).unwrap();
+        TextPatch {
+            relex_start: (),
+            affected_node_byte_len: (),
+            start: TextSize::new(start as u32),
+            size: TextSize::of(new_text),
+            kind: TextPatchKind::Replace {
+                end: TextSize::new((start + pat.len()) as u32),
+            },
+        }
+    };
+
+    let check = |patches: &[TextPatch<(), ()>], text: &str| {
+        let mut cst: incr::IncrSqlCst = parse(input);
+        incremental_parse_batch(&mut cst, text, patches, ParserConfig::default());
+
+        let expected: batch::SqlCst = parse(text);
+        assert_eq!(cst.root().to_text(), text);
+        assert_eq!(cst.root().comparable(), expected.root().comparable());
+    };
+
+    // Unsorted, each one inside the subquery of a different statement
+    check(
+        &[replace("b FROM", "bb FROM"), replace("a FROM", "a, c FROM")],
+        "SELECT * FROM (SELECT a, c FROM t);\nSELECT 1;\nSELECT * FROM (SELECT bb FROM u);",
+    );
+
+    // The first group cannot be reparsed on its own
+    check(
+        &[replace("1;", "1 "), replace("b FROM", "bb FROM")],
+        "SELECT * FROM (SELECT a FROM t);\nSELECT 1 \nSELECT * FROM (SELECT bb FROM u);",
+    );
+
+    // Overlapping patches
+    check(
+        &[replace("SELECT 1", "VALUES (1)"), replace("1;", "2;")],
+        "SELECT * FROM (SELECT a FROM t);\nVALUES (1)2;\nSELECT * FROM (SELECT b FROM u);",
+    );
+}
+
+#[test]
+fn csts_not_starting_at_zero_are_updated_incrementally() {
+    use bord_sqlite3_parser::{
+        incremental_parse_batch, parse_with_abs_pos, CstNodeTrait, ParserConfig, TextPatch,
+        TextPatchKind,
+    };
+    use text_size::TextSize;
+
+    // Ex: The SQL inside `let q = sql!("...");`
+    let abs_pos = TextSize::new(14);
+    let input = "SELECT a FROM t;\nSELECT b FROM t WHERE b = 1;";
+
+    let mut cst: incr::IncrSqlCst = parse_with_abs_pos(abs_pos, input);
+
+    let where_start = input.find(" WHERE").unwrap() as u32;
+    let patch = TextPatch {
+        relex_start: (),
+        affected_node_byte_len: (),
+        start: abs_pos + TextSize::new(where_start),
+        size: TextSize::new(0),
+        kind: TextPatchKind::Replace {
+            end: abs_pos + TextSize::new(where_start + " WHERE b = 1".len() as u32),
+        },
+    };
+
+    let text = "SELECT a FROM t;\nSELECT b FROM t;";
+    incremental_parse_batch(&mut cst, text, &[patch], ParserConfig::default());
+
+    let expected: batch::SqlCst = parse_with_abs_pos(abs_pos, text);
+    assert_eq!(cst.root().to_text(), text);
+    assert_eq!(cst.root().comparable(), expected.root().comparable());
+
+    let empty: incr::IncrSqlCst = parse_with_abs_pos(abs_pos, "");
+    assert_eq!(empty.root().start_pos(), abs_pos);
+}